@@ -0,0 +1,16 @@
+; Constructs that add a path through a function, each adding one to its cyclomatic complexity.
+
+(if_statement) @branch
+(for_statement) @branch
+(for_range_loop) @branch
+(while_statement) @branch
+(do_statement) @branch
+(catch_clause) @branch
+(conditional_expression) @branch
+
+; `default:` has no value
+(case_statement
+  value: (_)) @branch
+
+(binary_expression
+  operator: ["&&" "||"]) @branch
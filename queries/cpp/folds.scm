@@ -0,0 +1,32 @@
+; Ranges of lines editors can fold. `@fold` folds a bracketed construct, leaving the line with its
+; closing bracket visible; `@fold.comment` and `@fold.imports` are folded together with those on
+; the lines right before and after them; and a region folds from the directive captured as
+; `@fold.region.start` to the matching `@fold.region.end`.
+
+[
+  (compound_statement)
+  (field_declaration_list)
+  (declaration_list)
+  (enumerator_list)
+  (initializer_list)
+  (argument_list)
+  (parameter_list)
+  (preproc_if)
+  (preproc_ifdef)
+] @fold
+
+(preproc_include) @fold.imports
+
+((preproc_call
+  directive: (preproc_directive) @_directive
+  argument: (preproc_arg) @_argument) @fold.region.start
+  (#eq? @_directive "#pragma")
+  (#match? @_argument "^\\s*region"))
+
+((preproc_call
+  directive: (preproc_directive) @_directive
+  argument: (preproc_arg) @_argument) @fold.region.end
+  (#eq? @_directive "#pragma")
+  (#match? @_argument "^\\s*endregion"))
+
+(comment) @fold.comment
@@ -0,0 +1,89 @@
+; The C part of the C++ highlights, which tree-sitter-cpp's own query builds on. Patterns here
+; come first, so they take precedence over the crate's.
+
+(comment) @comment
+
+[
+  (string_literal)
+  (system_lib_string)
+  (char_literal)
+] @string
+
+(number_literal) @number
+
+[
+  (true)
+  (false)
+  (null)
+] @constant.builtin
+
+(call_expression
+  function: (identifier) @function)
+(call_expression
+  function: (field_expression
+    field: (field_identifier) @function))
+(function_declarator
+  declarator: (identifier) @function)
+(preproc_function_def
+  name: (identifier) @function.macro)
+
+(primitive_type) @type.builtin
+(sized_type_specifier) @type.builtin
+(type_identifier) @type
+(namespace_identifier) @namespace
+
+(field_identifier) @property
+(parameter_declaration
+  declarator: (identifier) @variable.parameter)
+
+[
+  "break"
+  "case"
+  "const"
+  "continue"
+  "default"
+  "do"
+  "else"
+  "enum"
+  "extern"
+  "for"
+  "if"
+  "inline"
+  "return"
+  "sizeof"
+  "static"
+  "struct"
+  "switch"
+  "typedef"
+  "union"
+  "volatile"
+  "while"
+  "#define"
+  "#elif"
+  "#else"
+  "#endif"
+  "#if"
+  "#ifdef"
+  "#ifndef"
+  "#include"
+  (preproc_directive)
+] @keyword
+
+[
+  "--"
+  "-"
+  "-="
+  "->"
+  "="
+  "!="
+  "*"
+  "&"
+  "&&"
+  "+"
+  "++"
+  "+="
+  "<"
+  "=="
+  ">"
+  "||"
+] @operator
@@ -0,0 +1,8 @@
+; Dependencies on other modules. `@module` is the string naming the module, and the suffix of the
+; `@import` capture says how it's depended on.
+
+(preproc_include
+  path: (string_literal) @module) @import.include
+
+(preproc_include
+  path: (system_lib_string) @module) @import.system
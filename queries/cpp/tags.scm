@@ -0,0 +1,65 @@
+; Definitions shown as document symbols. The suffix of each `@definition` capture is the LSP
+; symbol kind. `@reference` captures are uses of a name, which export-sqlite records.
+
+(namespace_definition
+  name: (_) @name) @definition.namespace
+
+(class_specifier
+  name: (type_identifier) @name
+  body: (_)) @definition.class
+
+(struct_specifier
+  name: (type_identifier) @name
+  body: (_)) @definition.struct
+
+(union_specifier
+  name: (type_identifier) @name
+  body: (_)) @definition.struct
+
+(enum_specifier
+  name: (type_identifier) @name
+  body: (_)) @definition.enum
+
+(enumerator
+  name: (identifier) @name) @definition.enum_member
+
+(type_definition
+  declarator: (type_identifier) @name) @definition.class
+
+(function_definition
+  declarator: (function_declarator
+    declarator: (identifier) @name)) @definition.function
+
+(function_definition
+  declarator: (function_declarator
+    declarator: (qualified_identifier) @name)) @definition.method
+
+(function_definition
+  declarator: (function_declarator
+    declarator: (field_identifier) @name)) @definition.method
+
+(field_declaration
+  declarator: (function_declarator
+    declarator: (field_identifier) @name)) @definition.method
+
+(field_declaration
+  declarator: (field_identifier) @name) @definition.field
+
+(preproc_def
+  name: (identifier) @name) @definition.constant
+
+(preproc_function_def
+  name: (identifier) @name) @definition.function
+
+(call_expression
+  function: (identifier) @name) @reference.call
+
+(call_expression
+  function: (field_expression
+    field: (field_identifier) @name)) @reference.call
+
+(call_expression
+  function: (qualified_identifier
+    name: (identifier) @name)) @reference.call
+
+(type_identifier) @name @reference.type
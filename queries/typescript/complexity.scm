@@ -0,0 +1,13 @@
+; Constructs that add a path through a function, each adding one to its cyclomatic complexity.
+
+(if_statement) @branch
+(for_statement) @branch
+(for_in_statement) @branch
+(while_statement) @branch
+(do_statement) @branch
+(switch_case) @branch
+(catch_clause) @branch
+(ternary_expression) @branch
+
+(binary_expression
+  operator: ["&&" "||" "??"]) @branch
@@ -0,0 +1,28 @@
+; Ranges of lines editors can fold. `@fold` folds a bracketed construct, leaving the line with its
+; closing bracket visible; `@fold.comment` and `@fold.imports` are folded together with those on
+; the lines right before and after them; and a region folds from the comment captured as
+; `@fold.region.start` to the matching `@fold.region.end`.
+
+[
+  (statement_block)
+  (class_body)
+  (object_type)
+  (enum_body)
+  (switch_body)
+  (object)
+  (object_pattern)
+  (array)
+  (arguments)
+  (formal_parameters)
+  (template_string)
+] @fold
+
+(import_statement) @fold.imports
+
+((comment) @fold.region.start
+  (#match? @fold.region.start "^//\\s*#region"))
+
+((comment) @fold.region.end
+  (#match? @fold.region.end "^//\\s*#endregion"))
+
+(comment) @fold.comment
@@ -0,0 +1,105 @@
+; The JavaScript part of the TypeScript highlights, which tree-sitter-typescript's own query builds
+; on. Patterns here come first, so they take precedence over the crate's.
+
+(comment) @comment
+
+[
+  (string)
+  (template_string)
+] @string
+
+(regex) @string.special
+(number) @number
+
+[
+  (true)
+  (false)
+  (null)
+  (undefined)
+] @constant.builtin
+
+[
+  (this)
+  (super)
+] @variable.builtin
+
+(function_declaration
+  name: (identifier) @function)
+(function
+  name: (identifier) @function)
+(method_definition
+  name: (property_identifier) @function.method)
+(call_expression
+  function: (identifier) @function)
+(call_expression
+  function: (member_expression
+    property: (property_identifier) @function.method))
+
+(class_declaration
+  name: (type_identifier) @type)
+
+(property_identifier) @property
+
+[
+  "as"
+  "async"
+  "await"
+  "break"
+  "case"
+  "catch"
+  "class"
+  "const"
+  "continue"
+  "debugger"
+  "default"
+  "delete"
+  "do"
+  "else"
+  "export"
+  "extends"
+  "finally"
+  "for"
+  "from"
+  "function"
+  "get"
+  "if"
+  "import"
+  "in"
+  "instanceof"
+  "let"
+  "new"
+  "of"
+  "return"
+  "set"
+  "static"
+  "switch"
+  "throw"
+  "try"
+  "typeof"
+  "var"
+  "void"
+  "while"
+  "yield"
+] @keyword
+
+[
+  "--"
+  "-"
+  "-="
+  "&&"
+  "+"
+  "++"
+  "+="
+  "<"
+  "<="
+  "="
+  "=="
+  "==="
+  "!="
+  "!=="
+  "=>"
+  ">"
+  ">="
+  "||"
+  "??"
+] @operator
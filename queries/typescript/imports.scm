@@ -0,0 +1,18 @@
+; Dependencies on other modules. `@module` is the string naming the module, and the suffix of the
+; `@import` capture says how it's depended on.
+
+(import_statement
+  source: (string) @module) @import.static
+
+; re-exports
+(export_statement
+  source: (string) @module) @import.export
+
+(call_expression
+  function: (import)
+  arguments: (arguments . [(string) (template_string)] @module)) @import.dynamic
+
+(call_expression
+  function: (identifier) @_function
+  arguments: (arguments . (string) @module)
+  (#eq? @_function "require")) @import.require
@@ -0,0 +1,82 @@
+; Definitions shown as document symbols. The suffix of each `@definition` capture is the LSP
+; symbol kind. `@reference` captures are uses of a name, which export-sqlite records.
+
+(function_declaration
+  name: (identifier) @name) @definition.function
+
+(generator_function_declaration
+  name: (identifier) @name) @definition.function
+
+(function_signature
+  name: (identifier) @name) @definition.function
+
+(class_declaration
+  name: (type_identifier) @name) @definition.class
+
+(abstract_class_declaration
+  name: (type_identifier) @name) @definition.class
+
+(method_definition
+  name: (_) @name) @definition.method
+
+(method_signature
+  name: (_) @name) @definition.method
+
+(abstract_method_signature
+  name: (_) @name) @definition.method
+
+(public_field_definition
+  name: (_) @name) @definition.field
+
+(interface_declaration
+  name: (type_identifier) @name) @definition.interface
+
+(property_signature
+  name: (_) @name) @definition.property
+
+(type_alias_declaration
+  name: (type_identifier) @name) @definition.class
+
+(enum_declaration
+  name: (identifier) @name) @definition.enum
+
+(module
+  name: (_) @name) @definition.module
+
+(internal_module
+  name: (_) @name) @definition.namespace
+
+(lexical_declaration
+  (variable_declarator
+    name: (identifier) @name
+    value: [(arrow_function) (function)])) @definition.function
+
+(program
+  (lexical_declaration
+    (variable_declarator
+      name: (identifier) @name)) @definition.variable)
+
+(program
+  (export_statement
+    (lexical_declaration
+      (variable_declarator
+        name: (identifier) @name)) @definition.variable))
+
+(call_expression
+  function: (identifier) @name) @reference.call
+
+(call_expression
+  function: (member_expression
+    property: (property_identifier) @name)) @reference.call
+
+(new_expression
+  constructor: (identifier) @name) @reference.class
+
+(type_annotation
+  (type_identifier) @name) @reference.type
+
+(extends_clause
+  value: (identifier) @name) @reference.class
+
+(implements_clause
+  (type_identifier) @name) @reference.interface
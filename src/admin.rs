@@ -0,0 +1,103 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use axum::Json;
+use serde_json::{json, Value};
+
+use crate::{
+	error::Error,
+	languages,
+	permissions::{self, Capability},
+	timings, STATE_MAP,
+};
+
+/// tree-sitter doesn't expose how much memory a tree uses, so estimate it from the node count.
+const ESTIMATED_BYTES_PER_NODE: usize = 64;
+
+/// `GET /admin/state`: what every session has loaded and cached.
+pub async fn state() -> Json<Value> {
+	let sessions = STATE_MAP
+		.iter()
+		.map(|state| {
+			let files = state
+				.documents
+				.iter()
+				.map(|(key, document)| {
+					let node_count = node_count(&document.tree);
+					json!({
+						"path": key.to_string(),
+						"version": document.version,
+						"language": document.language,
+						"node_count": node_count,
+						"estimated_bytes": node_count * ESTIMATED_BYTES_PER_NODE,
+						"last_access": unix_secs(document.accessed),
+					})
+				})
+				.collect::<Vec<_>>();
+			let estimated_bytes = files
+				.iter()
+				.map(|file| file["estimated_bytes"].as_u64().unwrap())
+				.sum::<u64>();
+
+			json!({
+				"session": state.key(),
+				"language": state.lang,
+				"plain_text": state.plain_text,
+				"unparsed": state.unparsed.len(),
+				"snapshots": state.snapshots.len(),
+				"estimated_bytes": estimated_bytes,
+				"files": files,
+			})
+		})
+		.collect::<Vec<_>>();
+
+	Json(json!({
+		"languages": languages::names(),
+		"sessions": sessions,
+	}))
+}
+
+/// `POST /admin/flush`: drops every session's cached trees (and snapshots).
+pub async fn flush() -> Result<Json<Value>, Error> {
+	permissions::check(Capability::Flush)?;
+
+	let mut flushed = 0;
+	for mut state in STATE_MAP.iter_mut() {
+		flushed += state.documents.len() + state.unparsed.len();
+		state.documents.clear();
+		state.unparsed.clear();
+		state.snapshots.clear();
+	}
+	tracing::info!("flushed {} cached trees", flushed);
+
+	Ok(Json(json!({ "flushed": flushed })))
+}
+
+/// `GET /admin/profile`: latency percentiles of recent requests, by request type and by phase.
+pub async fn profile() -> Json<Value> {
+	Json(timings::percentiles())
+}
+
+fn node_count(tree: &tree_sitter::Tree) -> usize {
+	let mut cursor = tree.walk();
+	let mut count = 1;
+	// pre-order walk over every node
+	loop {
+		if cursor.goto_first_child() || cursor.goto_next_sibling() {
+			count += 1;
+			continue;
+		}
+		loop {
+			if !cursor.goto_parent() {
+				return count;
+			}
+			if cursor.goto_next_sibling() {
+				count += 1;
+				break;
+			}
+		}
+	}
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+	time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use axum::{
+	extract::State,
+	http::{header::AUTHORIZATION, Request},
+	middleware::Next,
+	response::{IntoResponse, Response},
+};
+
+use crate::{error::Error, tenants::Tenant};
+
+/// Who clients can authenticate as.
+pub struct Keys {
+	/// Gives access to the default session and the admin routes
+	pub token: Option<String>,
+	pub tenants: Vec<Arc<Tenant>>,
+}
+
+/// A random token clients have to present, as 32 hex-encoded bytes.
+pub fn generate_token() -> Result<String> {
+	let mut bytes = [0; 32];
+	// getrandom's error type doesn't implement std::error::Error without its std feature
+	getrandom::getrandom(&mut bytes).map_err(|e| anyhow!("Error generating auth token: {}", e))?;
+	Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// Middleware rejecting requests that don't carry `Authorization: Bearer <key>`, with either the
+/// auth token or a tenant's key. Requests from a tenant are held to its rate limit, and carry the
+/// tenant as an extension.
+pub async fn authenticate<B>(
+	State(keys): State<Arc<Keys>>,
+	mut req: Request<B>,
+	next: Next<B>,
+) -> Response {
+	let header = req
+		.headers()
+		.get(AUTHORIZATION)
+		.and_then(|value| value.to_str().ok());
+	match identify(&keys, header) {
+		Ok(Some(tenant)) => {
+			// they see every session
+			if req.uri().path().starts_with("/admin/") {
+				return Error::Forbidden(format!("Tenant {} can't use admin routes", tenant.name))
+					.into_response();
+			}
+			req.extensions_mut().insert(tenant);
+		}
+		Ok(None) => {}
+		Err(e) => return e.into_response(),
+	}
+
+	next.run(req).await
+}
+
+/// Who sent a request with `header` as its `Authorization`: the tenant whose key it carries, or
+/// `None` for the auth token (or anyone, with neither a token nor tenants). Fails for other
+/// callers, and for tenants over their rate limit.
+pub fn identify(keys: &Keys, header: Option<&str>) -> Result<Option<Arc<Tenant>>, Error> {
+	let tenant = keys
+		.tenants
+		.iter()
+		.find(|tenant| is_authorized(header, &tenant.key));
+	match tenant {
+		Some(tenant) => {
+			if let Some(Err(retry_after)) = tenant.rate.as_ref().map(|rate| rate.take_token(())) {
+				tracing::warn!("rate limiting tenant {}", tenant.name);
+				return Err(Error::TooManyRequests(
+					format!("Too many requests from tenant {}", tenant.name),
+					retry_after,
+				));
+			}
+			Ok(Some(tenant.clone()))
+		}
+		None => match &keys.token {
+			Some(token) if is_authorized(header, token) => Ok(None),
+			None if keys.tenants.is_empty() => Ok(None),
+			// with only tenants configured, nobody gets at the default session
+			_ => Err(Error::Unauthorized(
+				"Missing or invalid auth token".to_string(),
+			)),
+		},
+	}
+}
+
+/// Whether an `Authorization` header value is `Bearer <token>`.
+pub fn is_authorized(header: Option<&str>, token: &str) -> bool {
+	header
+		.and_then(|value| value.strip_prefix("Bearer "))
+		.is_some_and(|given| constant_time_eq(given, token))
+}
+
+/// Compares without bailing out at the first difference, so response times don't give away how
+/// much of a guessed token was right.
+fn constant_time_eq(a: &str, b: &str) -> bool {
+	a.len() == b.len()
+		&& a.bytes()
+			.zip(b.bytes())
+			.fold(0, |diff, (a, b)| diff | (a ^ b))
+			== 0
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tenants::TenantConfig;
+
+	fn tenant(name: &str, rate_limit: Option<u32>) -> Arc<Tenant> {
+		Arc::new(Tenant::new(&TenantConfig {
+			name: name.to_string(),
+			key: format!("{}-key", name),
+			max_documents: None,
+			rate_limit,
+			roots: Vec::new(),
+		}))
+	}
+
+	#[test]
+	fn identifies_tenants_by_their_key() {
+		let keys = Keys {
+			token: Some("token".to_string()),
+			tenants: vec![tenant("a", None), tenant("b", None)],
+		};
+		let identified = identify(&keys, Some("Bearer b-key")).unwrap();
+		assert_eq!(identified.unwrap().name, "b");
+		assert!(identify(&keys, Some("Bearer token")).unwrap().is_none());
+	}
+
+	#[test]
+	fn rejects_unknown_callers() {
+		let keys = Keys {
+			token: Some("token".to_string()),
+			tenants: vec![tenant("a", None)],
+		};
+		for header in [
+			None,
+			Some("Bearer nope"),
+			Some("token"),
+			Some("Bearer a-key "),
+		] {
+			assert!(matches!(
+				identify(&keys, header),
+				Err(Error::Unauthorized(_))
+			));
+		}
+	}
+
+	#[test]
+	fn keeps_the_default_session_from_anyone_with_only_tenants() {
+		let keys = Keys {
+			token: None,
+			tenants: vec![tenant("a", None)],
+		};
+		assert!(matches!(identify(&keys, None), Err(Error::Unauthorized(_))));
+
+		let open = Keys {
+			token: None,
+			tenants: Vec::new(),
+		};
+		assert!(identify(&open, None).unwrap().is_none());
+	}
+
+	#[test]
+	fn holds_tenants_to_their_rate_limit() {
+		let keys = Keys {
+			token: None,
+			tenants: vec![tenant("a", Some(1))],
+		};
+		assert!(identify(&keys, Some("Bearer a-key")).is_ok());
+		assert!(matches!(
+			identify(&keys, Some("Bearer a-key")),
+			Err(Error::TooManyRequests(_, 1))
+		));
+	}
+}
@@ -0,0 +1,373 @@
+use std::{
+	ffi::c_void,
+	fs,
+	path::{Path, PathBuf},
+	sync::atomic::{AtomicUsize, Ordering},
+	time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use tree_sitter::{Parser, Tree};
+
+use crate::{
+	edit, languages,
+	text::Text,
+	tree_serialize::{self, ParseInfo},
+};
+
+// The subcommand measuring what incremental parsing saves. (Not a doc comment, which clap would
+// use to describe the whole program.)
+#[derive(clap::Subcommand)]
+pub enum BenchCommand {
+	/// Replay an edit script against a file, comparing incremental parses with full ones
+	Bench {
+		file: PathBuf,
+		/// The edits to make in order, one JSON object per line like
+		/// `{"start_byte": 10, "old_end_byte": 14, "text": "x"}`, with byte offsets into the UTF-16
+		/// text like `TextEdit`
+		#[arg(long)]
+		edits: PathBuf,
+		/// The language to parse the file as, instead of picking one by file extension
+		#[arg(long)]
+		lang: Option<String>,
+		/// How many times to replay the script
+		#[arg(long, default_value_t = 5)]
+		iterations: usize,
+	},
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Edit {
+	start_byte: usize,
+	old_end_byte: usize,
+	text: String,
+}
+
+/// Installs the allocator counting tree-sitter's memory. Must be called before anything is
+/// allocated through tree-sitter, which would otherwise be freed without being counted.
+pub fn count_allocations() {
+	unsafe {
+		tree_sitter::set_allocator(
+			Some(counting_malloc),
+			Some(counting_calloc),
+			Some(counting_realloc),
+			Some(counting_free),
+		);
+	}
+}
+
+pub fn run(command: BenchCommand) -> Result<()> {
+	let BenchCommand::Bench {
+		file,
+		edits,
+		lang,
+		iterations,
+	} = command;
+	if iterations == 0 {
+		bail!("--iterations must be at least 1");
+	}
+
+	let lang = match lang {
+		Some(lang) => lang,
+		None => file
+			.extension()
+			.and_then(|ext| ext.to_str())
+			.and_then(languages::for_extension)
+			.with_context(|| format!("No language for {}, pass --lang", file.display()))?,
+	};
+	let language = languages::get(&lang).with_context(|| format!("No grammar for {}", lang))?;
+	let mut parser = Parser::new();
+	parser
+		.set_language(language)
+		.with_context(|| format!("Error loading tree-sitter {} language", lang))?;
+
+	let text =
+		crate::read_text(&file).with_context(|| format!("Error reading {}", file.display()))?;
+	let edits = read_edits(&edits)?;
+	let tree = text
+		.parse(&mut parser, None)
+		.with_context(|| format!("Error parsing {}", file.display()))?;
+
+	let mut full = Run::default();
+	let mut incremental = Run::default();
+	for iteration in 0..iterations {
+		// the first replay also checks the two agree, which isn't timed
+		let first = iteration == 0;
+		replay(&mut parser, &text, &tree, &edits, false, first, &mut full)?;
+		replay(
+			&mut parser,
+			&text,
+			&tree,
+			&edits,
+			true,
+			first,
+			&mut incremental,
+		)?;
+	}
+
+	println!(
+		"{}: {} edits to {} ({}), replayed {} times",
+		file.display(),
+		edits.len(),
+		lang,
+		format_bytes(text.len() * 2),
+		iterations
+	);
+	println!(
+		"{:<12} {:>22} {:>22} {:>16}",
+		"", "parse (mean / max)", "serialize (mean / max)", "trees retained"
+	);
+	for (name, run) in [("full", &full), ("incremental", &incremental)] {
+		println!(
+			"{:<12} {:>22} {:>22} {:>16}",
+			name,
+			format!(
+				"{} / {}",
+				format_ms(mean(&run.parse)),
+				format_ms(max(&run.parse))
+			),
+			format!(
+				"{} / {}",
+				format_ms(mean(&run.serialize)),
+				format_ms(max(&run.serialize))
+			),
+			format_bytes(run.retained),
+		);
+	}
+	if !edits.is_empty() {
+		println!(
+			"incremental parses take {:.1}% of the time of full ones",
+			mean(&incremental.parse).as_secs_f64() / mean(&full.parse).as_secs_f64() * 100.0
+		);
+	}
+	if let Some(edit) = incremental.mismatch {
+		println!(
+			"the incremental tree differs from the full one after edit {}",
+			edit + 1
+		);
+	}
+	Ok(())
+}
+
+/// Reads an edit script into (start, old end, new text) edits in UTF-16 code units.
+fn read_edits(path: &Path) -> Result<Vec<(usize, usize, Vec<u16>)>> {
+	let script =
+		fs::read_to_string(path).with_context(|| format!("Error reading {}", path.display()))?;
+	let mut edits = Vec::new();
+	for (i, line) in script.lines().enumerate() {
+		if line.trim().is_empty() {
+			continue;
+		}
+		let edit = serde_json::from_str::<Edit>(line)
+			.with_context(|| format!("Invalid edit on line {} of {}", i + 1, path.display()))?;
+		if edit.start_byte % 2 != 0
+			|| edit.old_end_byte % 2 != 0
+			|| edit.start_byte > edit.old_end_byte
+		{
+			bail!(
+				"Invalid edit range {}..{} on line {} of {}",
+				edit.start_byte,
+				edit.old_end_byte,
+				i + 1,
+				path.display()
+			);
+		}
+		edits.push((
+			edit.start_byte / 2,
+			edit.old_end_byte / 2,
+			edit.text.encode_utf16().collect(),
+		));
+	}
+	Ok(edits)
+}
+
+/// The measurements of every replay of the script in one mode.
+#[derive(Default)]
+struct Run {
+	parse: Vec<Duration>,
+	serialize: Vec<Duration>,
+	/// What tree-sitter allocated for the trees of every version of the text, kept alive together
+	/// like the versions of a document being edited and the snapshots of it
+	retained: usize,
+	/// The first edit after which the incremental tree didn't match a full parse
+	mismatch: Option<usize>,
+}
+
+/// Applies `edits` to `text` one at a time, reparsing after each one either incrementally
+/// (reusing the previous tree) or from scratch. The first replay measures memory and, for
+/// incremental parses, compares the trees with full parses.
+fn replay(
+	parser: &mut Parser,
+	text: &Text,
+	tree: &Tree,
+	edits: &[(usize, usize, Vec<u16>)],
+	incremental: bool,
+	first: bool,
+	run: &mut Run,
+) -> Result<()> {
+	let mut text = text.clone();
+	let mut tree = tree.clone();
+	let mut versions = Vec::new();
+	let before = LIVE.load(Ordering::Relaxed);
+
+	for (i, (start, old_end, new_text)) in edits.iter().enumerate() {
+		edit::apply(&mut text, &mut tree, *start, *old_end, new_text)
+			.with_context(|| format!("Error applying edit {}", i + 1))?;
+
+		let start = Instant::now();
+		let new_tree = text
+			.parse(parser, incremental.then_some(&tree))
+			.with_context(|| format!("Error parsing after edit {}", i + 1))?;
+		let duration = start.elapsed();
+		run.parse.push(duration);
+
+		let parse_info = ParseInfo {
+			duration,
+			reused_old_tree: incremental,
+			cached_tree: false,
+			changed_ranges: Vec::new(),
+			version: i as u32 + 1,
+			content_hash: text.content_hash(),
+			mtime: None,
+		};
+		let start = Instant::now();
+		tree_serialize::serialize(&text, &new_tree, &parse_info, Default::default());
+		run.serialize.push(start.elapsed());
+
+		if first && incremental && run.mismatch.is_none() {
+			let full = text
+				.parse(parser, None)
+				.with_context(|| format!("Error parsing after edit {}", i + 1))?;
+			if !same_tree(&new_tree, &full) {
+				run.mismatch = Some(i);
+			}
+		}
+		if first {
+			versions.push(tree);
+		}
+		tree = new_tree;
+	}
+
+	if first {
+		run.retained = LIVE.load(Ordering::Relaxed).saturating_sub(before);
+	}
+	drop(versions);
+	Ok(())
+}
+
+/// Whether two trees have the same nodes in the same places.
+pub fn same_tree(a: &Tree, b: &Tree) -> bool {
+	let mut a = a.walk();
+	let mut b = b.walk();
+	loop {
+		let (x, y) = (a.node(), b.node());
+		if x.kind_id() != y.kind_id() || x.byte_range() != y.byte_range() {
+			return false;
+		}
+		if a.goto_first_child() {
+			if !b.goto_first_child() {
+				return false;
+			}
+			continue;
+		}
+		if b.goto_first_child() {
+			return false;
+		}
+		loop {
+			match (a.goto_next_sibling(), b.goto_next_sibling()) {
+				(true, true) => break,
+				(false, false) => {}
+				_ => return false,
+			}
+			match (a.goto_parent(), b.goto_parent()) {
+				(true, true) => {}
+				(false, false) => return true,
+				_ => return false,
+			}
+		}
+	}
+}
+
+fn mean(durations: &[Duration]) -> Duration {
+	match durations.len() {
+		0 => Duration::ZERO,
+		n => durations.iter().sum::<Duration>() / n as u32,
+	}
+}
+
+fn max(durations: &[Duration]) -> Duration {
+	durations.iter().max().copied().unwrap_or_default()
+}
+
+fn format_ms(duration: Duration) -> String {
+	format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn format_bytes(bytes: usize) -> String {
+	match bytes {
+		0..=1023 => format!("{} B", bytes),
+		1024..=1048575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+		_ => format!("{:.1} MiB", bytes as f64 / 1048576.0),
+	}
+}
+
+/// Bytes allocated through tree-sitter and not yet freed.
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+/// Room for the size in front of every allocation, keeping malloc's alignment.
+const HEADER: usize = 16;
+
+extern "C" {
+	fn malloc(size: usize) -> *mut c_void;
+	fn realloc(ptr: *mut c_void, size: usize) -> *mut c_void;
+	fn free(ptr: *mut c_void);
+}
+
+unsafe extern "C" fn counting_malloc(size: usize) -> *mut c_void {
+	let base = malloc(size + HEADER) as *mut usize;
+	if base.is_null() {
+		return std::ptr::null_mut();
+	}
+	*base = size;
+	LIVE.fetch_add(size, Ordering::Relaxed);
+	(base as *mut u8).add(HEADER) as *mut c_void
+}
+
+unsafe extern "C" fn counting_calloc(count: usize, size: usize) -> *mut c_void {
+	let size = match count.checked_mul(size) {
+		Some(size) => size,
+		None => return std::ptr::null_mut(),
+	};
+	let ptr = counting_malloc(size);
+	if !ptr.is_null() {
+		std::ptr::write_bytes(ptr as *mut u8, 0, size);
+	}
+	ptr
+}
+
+unsafe extern "C" fn counting_realloc(ptr: *mut c_void, size: usize) -> *mut c_void {
+	if ptr.is_null() {
+		return counting_malloc(size);
+	}
+	let base = (ptr as *mut u8).sub(HEADER) as *mut c_void;
+	let old_size = *(base as *const usize);
+	let base = realloc(base, size + HEADER) as *mut usize;
+	if base.is_null() {
+		return std::ptr::null_mut();
+	}
+	*base = size;
+	LIVE.fetch_sub(old_size, Ordering::Relaxed);
+	LIVE.fetch_add(size, Ordering::Relaxed);
+	(base as *mut u8).add(HEADER) as *mut c_void
+}
+
+unsafe extern "C" fn counting_free(ptr: *mut c_void) {
+	if ptr.is_null() {
+		return;
+	}
+	let base = (ptr as *mut u8).sub(HEADER);
+	LIVE.fetch_sub(*(base as *const usize), Ordering::Relaxed);
+	free(base as *mut c_void);
+}
@@ -0,0 +1,182 @@
+use std::cmp::Reverse;
+
+use anyhow::Result;
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, Vector, WIPOffset};
+use tree_sitter::Node;
+
+use crate::{
+	error::Error,
+	folding,
+	git::{self, Blame},
+	message_generated::asted::interface::{
+		BlameCommit, BlameCommitArgs, BlameRequest, BlameResponse, BlameResponseArgs, Location,
+		NodeBlame, NodeBlameArgs,
+	},
+	node_edit,
+	uri::DocumentKey,
+	State,
+};
+
+/// Blames the file of the document at `key` and returns a serialized `BlameResponse` of the
+/// commits behind each of the nodes in `req`.
+pub fn blame(state: &mut State, key: &DocumentKey, req: BlameRequest) -> Result<Vec<u8>> {
+	let _span = tracing::info_span!("blame").entered();
+	let path = match key {
+		DocumentKey::File(path) => path,
+		DocumentKey::Uri(_) => {
+			return Err(Error::UnknownFile(format!("{} is not a file:// URI", key)).into())
+		}
+	};
+	// the document may have been opened with its content, but git reads the file's history anyway
+	state.check_root(path)?;
+	let document = state.document(key)?;
+	let tree = &document.tree;
+	let mut nodes = Vec::new();
+	for selector in req.nodes().into_iter().flatten() {
+		nodes.push(node_edit::find(tree, selector, "node")?);
+	}
+	for range in req.ranges().into_iter().flatten() {
+		let (start, end) = (range.start_byte() as usize, range.end_byte() as usize);
+		let root = tree.root_node();
+		nodes.push(root.descendant_for_byte_range(start, end).unwrap_or(root));
+	}
+
+	let text = String::from_utf16_lossy(&document.text.to_utf16());
+	let blame = git::RepoFile::new(path)?.blame(&text)?;
+
+	let mut builder = FlatBufferBuilder::new();
+	let mut strings = vec![None; blame.commits.len()];
+	let nodes = nodes
+		.into_iter()
+		.map(|node| {
+			let children = req.children().then(|| {
+				let mut cursor = node.walk();
+				let children = node
+					.named_children(&mut cursor)
+					.map(|child| node_blame(&mut builder, &blame, &mut strings, child, None))
+					.collect::<Vec<_>>();
+				builder.create_vector(&children)
+			});
+			node_blame(&mut builder, &blame, &mut strings, node, children)
+		})
+		.collect::<Vec<_>>();
+	let nodes = builder.create_vector(&nodes);
+	let resp = BlameResponse::create(
+		&mut builder,
+		&BlameResponseArgs {
+			nodes: Some(nodes),
+			version: document.version,
+			content_hash: document.text.content_hash(),
+		},
+	);
+	builder.finish(resp, None);
+
+	Ok(builder.finished_data().to_vec())
+}
+
+/// The ID, author and summary of each commit of the blame, made once and shared by every node's.
+type CommitStrings<'b> = Vec<Option<[WIPOffset<&'b str>; 3]>>;
+
+fn node_blame<'b>(
+	builder: &mut FlatBufferBuilder<'b>,
+	blame: &Blame,
+	strings: &mut CommitStrings<'b>,
+	node: Node,
+	children: Option<WIPOffset<Vector<'b, ForwardsUOffset<NodeBlame<'b>>>>>,
+) -> WIPOffset<NodeBlame<'b>> {
+	let (start_row, end_row) = (node.start_position().row, folding::last_row(node));
+	// how many of the node's lines each commit last changed, by index
+	let mut lines = vec![0; blame.commits.len()];
+	for &i in blame.lines.iter().take(end_row + 1).skip(start_row) {
+		lines[i] += 1;
+	}
+	let mut commits = (0..lines.len())
+		.filter(|&i| lines[i] > 0)
+		.collect::<Vec<_>>();
+	commits.sort_by_key(|&i| (Reverse(lines[i]), Reverse(blame.commits[i].time)));
+	let last = commits
+		.iter()
+		.copied()
+		.max_by_key(|&i| blame.commits[i].time);
+
+	let mut commit = |builder: &mut FlatBufferBuilder<'b>, i: usize| {
+		let commit = &blame.commits[i];
+		let [id, author, summary] = *strings[i].get_or_insert_with(|| {
+			[
+				builder.create_string(&commit.id),
+				builder.create_string(&commit.author),
+				builder.create_string(&commit.summary),
+			]
+		});
+		BlameCommit::create(
+			builder,
+			&BlameCommitArgs {
+				commit: Some(id),
+				author: Some(author),
+				time: commit.time,
+				summary: Some(summary),
+				lines: lines[i],
+			},
+		)
+	};
+	let last_commit = last.map(|i| commit(builder, i));
+	let commits = commits
+		.iter()
+		.map(|&i| commit(builder, i))
+		.collect::<Vec<_>>();
+	let commits = builder.create_vector(&commits);
+	let kind = builder.create_string(node.kind());
+	NodeBlame::create(
+		builder,
+		&NodeBlameArgs {
+			kind: Some(kind),
+			location: Some(&Location::new(
+				node.start_byte() as u32,
+				node.end_byte() as u32,
+			)),
+			start_row: start_row as u32,
+			end_row: end_row as u32,
+			last_commit,
+			commits: Some(commits),
+			children,
+		},
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{message_generated::asted::interface::BlameRequestArgs, tenants::TenantConfig};
+
+	#[test]
+	fn refuses_files_outside_the_roots() {
+		let root = tempfile::tempdir().unwrap();
+		let mut state = State::for_tenant(&TenantConfig {
+			name: "a".to_string(),
+			key: "key".to_string(),
+			max_documents: None,
+			rate_limit: None,
+			roots: vec![root.path().to_path_buf()],
+		});
+		let outside = tempfile::tempdir().unwrap();
+		let key = DocumentKey::file(&outside.path().join("main.ts"));
+
+		let mut builder = FlatBufferBuilder::new();
+		let path = builder.create_string(&key.uri());
+		let req = BlameRequest::create(
+			&mut builder,
+			&BlameRequestArgs {
+				path: Some(path),
+				..Default::default()
+			},
+		);
+		builder.finish(req, None);
+		let req = flatbuffers::root::<BlameRequest>(builder.finished_data()).unwrap();
+
+		let e = blame(&mut state, &key, req).unwrap_err();
+		assert!(matches!(
+			e.downcast_ref::<Error>(),
+			Some(Error::Forbidden(_))
+		));
+	}
+}
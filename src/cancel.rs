@@ -0,0 +1,142 @@
+use std::{
+	cell::RefCell,
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc, Mutex, PoisonError, Weak,
+	},
+};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use tree_sitter::Parser;
+
+use crate::error::Error;
+
+/// A request with an ID, which a `CancelRequest` from the same session can cancel while it runs.
+/// Whatever is still working on the request (on any thread) holds on to it; the ID is forgotten
+/// once nothing does.
+pub struct Registration {
+	key: (String, u64),
+	/// Nonzero once cancelled, in the form tree-sitter's parsers check while parsing
+	flag: AtomicUsize,
+}
+
+/// The requests running, by session and ID.
+type Running = HashMap<(String, u64), Weak<Registration>>;
+
+static RUNNING: Lazy<Mutex<Running>> = Lazy::new(Default::default);
+
+thread_local! {
+	/// The request this thread is working on, if it has an ID.
+	static CURRENT: RefCell<Option<Arc<Registration>>> = const { RefCell::new(None) };
+}
+
+/// Registers request `id` of `session` as running. A request reusing the ID of one still running
+/// takes it over, so only the newer one can be cancelled.
+pub fn register(session: &str, id: u64) -> Arc<Registration> {
+	let registration = Arc::new(Registration {
+		key: (session.to_string(), id),
+		flag: AtomicUsize::new(0),
+	});
+	running().insert(registration.key.clone(), Arc::downgrade(&registration));
+	registration
+}
+
+/// A request without an ID, which only whoever holds on to it can cancel, like a gRPC call when
+/// its client gives up on it.
+#[cfg(feature = "grpc")]
+pub fn anonymous() -> Arc<Registration> {
+	Arc::new(Registration {
+		key: (String::new(), 0),
+		flag: AtomicUsize::new(0),
+	})
+}
+
+/// Cancels request `id` of `session`, returning whether it was running.
+pub fn cancel(session: &str, id: u64) -> bool {
+	let registration = running()
+		.get(&(session.to_string(), id))
+		.and_then(Weak::upgrade);
+	match registration {
+		Some(registration) => {
+			registration.cancel();
+			true
+		}
+		None => false,
+	}
+}
+
+/// Runs `f` as work on `registration`'s request, so the parses in it can be cancelled.
+pub fn scope<T>(registration: Option<Arc<Registration>>, f: impl FnOnce() -> T) -> T {
+	let outer = CURRENT.with(|current| current.replace(registration));
+	let res = f();
+	CURRENT.with(|current| *current.borrow_mut() = outer);
+	res
+}
+
+/// The request this thread is working on, to carry on with on other threads with `scope`.
+pub fn current() -> Option<Arc<Registration>> {
+	CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Fails with a cancelled error if the request this thread is working on was cancelled.
+pub fn check() -> Result<()> {
+	match current() {
+		Some(registration) if registration.cancelled() => Err(registration.error().into()),
+		_ => Ok(()),
+	}
+}
+
+/// Runs `f` with `parser`, which stops parsing (returning `None`) if `registration`'s request is
+/// cancelled meanwhile.
+pub fn parse<T>(
+	parser: &mut Parser,
+	registration: Option<&Registration>,
+	f: impl FnOnce(&mut Parser) -> T,
+) -> T {
+	let registration = match registration {
+		Some(registration) => registration,
+		None => return f(parser),
+	};
+	// Safety: the parser only holds on to the flag until it's unset below, and the registration
+	// outlives that
+	unsafe { parser.set_cancellation_flag(Some(&registration.flag)) };
+	let res = f(parser);
+	unsafe { parser.set_cancellation_flag(None) };
+	res
+}
+
+impl Registration {
+	pub fn cancelled(&self) -> bool {
+		self.flag.load(Ordering::Relaxed) != 0
+	}
+
+	pub fn cancel(&self) {
+		self.flag.store(1, Ordering::Relaxed);
+	}
+
+	pub fn error(&self) -> Error {
+		match self.key.1 {
+			0 => Error::Cancelled("The request was cancelled".to_string()),
+			id => Error::Cancelled(format!("Request {} was cancelled", id)),
+		}
+	}
+}
+
+impl Drop for Registration {
+	fn drop(&mut self) {
+		let mut running = running();
+		// unless a newer request took the ID over
+		if running
+			.get(&self.key)
+			.is_some_and(|registration| std::ptr::eq(registration.as_ptr(), self))
+		{
+			running.remove(&self.key);
+		}
+	}
+}
+
+fn running() -> std::sync::MutexGuard<'static, Running> {
+	RUNNING.lock().unwrap_or_else(PoisonError::into_inner)
+}
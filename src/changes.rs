@@ -0,0 +1,118 @@
+use std::{
+	collections::VecDeque,
+	sync::{Mutex, PoisonError},
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use tokio::sync::broadcast;
+
+use crate::{deterministic, error::Error, uri::DocumentKey};
+
+/// A document whose text changed (or that was parsed for the first time).
+#[derive(Clone)]
+pub struct Change {
+	/// The session the document is in
+	pub session: String,
+	pub key: DocumentKey,
+	pub version: u32,
+	pub content_hash: u64,
+}
+
+/// How far a subscriber can fall behind before it starts missing changes.
+const CAPACITY: usize = 256;
+
+/// How many of the latest changes are kept for clients resuming after a reconnect.
+const HISTORY: usize = 4096;
+
+/// The changes published so far, numbered in order.
+struct Published {
+	/// The number the next change gets
+	next: u64,
+	latest: VecDeque<(u64, Change)>,
+}
+
+/// The changes a resumed subscription missed, and the subscription for the ones still to come.
+type Resumed = (Vec<(u64, Change)>, broadcast::Receiver<(u64, Change)>);
+
+static CHANGES: Lazy<broadcast::Sender<(u64, Change)>> =
+	Lazy::new(|| broadcast::channel(CAPACITY).0);
+
+static PUBLISHED: Lazy<Mutex<Published>> = Lazy::new(|| {
+	Mutex::new(Published {
+		next: 1,
+		latest: VecDeque::new(),
+	})
+});
+
+/// Tells this server's resume tokens from those of the servers that ran before it.
+static INSTANCE: Lazy<String> = Lazy::new(|| {
+	if deterministic::enabled() {
+		return "0".to_string();
+	}
+	let started = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.unwrap_or_default()
+		.as_nanos();
+	format!("{:x}{:x}", std::process::id(), started)
+});
+
+pub fn publish(change: Change) {
+	let mut published = published();
+	let seq = published.next;
+	published.next += 1;
+	if published.latest.len() == HISTORY {
+		published.latest.pop_front();
+	}
+	published.latest.push_back((seq, change.clone()));
+	// nobody listening isn't an error
+	let _ = CHANGES.send((seq, change));
+}
+
+pub fn subscribe() -> broadcast::Receiver<(u64, Change)> {
+	CHANGES.subscribe()
+}
+
+/// The token to resume from after change `seq`.
+pub fn resume_token(seq: u64) -> String {
+	format!("{}.{}", *INSTANCE, seq)
+}
+
+/// A token to resume from after the latest change.
+pub fn latest_token() -> String {
+	resume_token(published().next - 1)
+}
+
+/// Subscribes to the changes published after `token` was handed out, returning the ones published
+/// already along with the subscription. Returns `None` if that can't be done without missing any,
+/// because the token is from an earlier server or so old the changes since are forgotten.
+pub fn resume(token: &str) -> Result<Option<Resumed>> {
+	let (instance, seq) = token
+		.rsplit_once('.')
+		.and_then(|(instance, seq)| Some((instance, seq.parse::<u64>().ok()?)))
+		.ok_or_else(|| Error::InvalidRequest(format!("Invalid resume token {:?}", token)))?;
+	// subscribing while publishing is held off, so no change is missed or seen twice
+	let published = published();
+	if instance != *INSTANCE || seq >= published.next {
+		return Ok(None);
+	}
+	let forgotten = published
+		.latest
+		.front()
+		.map_or(published.next, |(first, _)| *first);
+	if seq + 1 < forgotten {
+		return Ok(None);
+	}
+	let missed = published
+		.latest
+		.iter()
+		.filter(|(published, _)| *published > seq)
+		.cloned()
+		.collect();
+	Ok(Some((missed, CHANGES.subscribe())))
+}
+
+fn published() -> std::sync::MutexGuard<'static, Published> {
+	PUBLISHED.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,163 @@
+use std::{
+	io::{self, Write},
+	path::PathBuf,
+};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::{
+	export,
+	sarif::{self, Level, Location, Region, Rule, SarifResult},
+	syntax_errors::{self, ErrorGroup, SyntaxError},
+	watch::{self, FileLine},
+	workspace,
+};
+
+// The subcommand gating CI on files parsing without syntax errors. (Not a doc comment, which clap
+// would use to describe the whole program.)
+#[derive(clap::Subcommand)]
+pub enum CheckCommand {
+	/// Parse files (and every file under directories) and print their syntax errors, exiting with
+	/// 1 if there are any
+	Check {
+		#[arg(required = true)]
+		paths: Vec<PathBuf>,
+		/// How to print the syntax errors
+		#[arg(long, value_enum, default_value_t)]
+		format: Format,
+		#[command(flatten)]
+		options: export::Options,
+	},
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+	/// A `file:line:column: message` line per error
+	#[default]
+	Text,
+	/// A JSON object with the files that have errors
+	Json,
+	/// A SARIF log, for code scanning tools
+	Sarif,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+	files_checked: usize,
+	files: Vec<FileLine<'a>>,
+}
+
+/// Checks the files of `command`, returning whether none of them have syntax errors.
+pub fn run(command: CheckCommand) -> Result<bool> {
+	let CheckCommand::Check {
+		paths,
+		format,
+		options,
+	} = command;
+	let mut files = Vec::new();
+	for path in &paths {
+		if path.is_dir() {
+			files.extend(export::files(path, &options)?);
+		} else {
+			let lang = export::language_for(path, &options)
+				.with_context(|| format!("No grammar for {}", path.display()))?;
+			files.push((path.clone(), lang));
+		}
+	}
+
+	// the files with errors, kept for the JSON and SARIF output, which is written once every file
+	// is checked
+	let mut broken = Vec::new();
+	let mut out = io::stdout().lock();
+	workspace::parse_all(&files, |parsed| {
+		let parsed = parsed?;
+		if !parsed.tree.root_node().has_error() {
+			return Ok(());
+		}
+		if format == Format::Text {
+			let errors = syntax_errors::merge_errors(syntax_errors::syntax_errors(
+				&parsed.text,
+				&parsed.tree,
+			));
+			watch::write_errors(&mut out, &parsed.path.to_string_lossy(), &errors)?;
+		}
+		broken.push(parsed);
+		Ok(())
+	})?;
+
+	let names = broken
+		.iter()
+		.map(|parsed| parsed.path.to_string_lossy())
+		.collect::<Vec<_>>();
+	let errors = broken
+		.iter()
+		.map(|parsed| {
+			syntax_errors::merge_errors(syntax_errors::syntax_errors(&parsed.text, &parsed.tree))
+		})
+		.collect::<Vec<_>>();
+	match format {
+		Format::Text => {}
+		Format::Json => {
+			let report = Report {
+				files_checked: files.len(),
+				files: broken
+					.iter()
+					.zip(&names)
+					.zip(&errors)
+					.map(|((parsed, name), errors)| FileLine::new(name, &parsed.lang, errors))
+					.collect(),
+			};
+			serde_json::to_writer_pretty(&mut out, &report)?;
+			writeln!(out)?;
+		}
+		Format::Sarif => {
+			let rules = vec![
+				Rule::new("syntax-error", "Code the grammar doesn't allow here"),
+				Rule::new(
+					"missing-node",
+					"A token or construct the grammar expected here",
+				),
+			];
+			let results = broken
+				.iter()
+				.zip(&errors)
+				.flat_map(|(parsed, errors)| {
+					let uri = sarif::path_uri(&parsed.path);
+					let location = |error: &SyntaxError| {
+						let region =
+							Region::new(error.node.start_position(), error.node.end_position());
+						Location::new(&uri, region)
+					};
+					errors
+						.iter()
+						.map(|ErrorGroup { first, rest }| {
+							let rule = match first.node.is_missing() {
+								true => "missing-node",
+								false => "syntax-error",
+							};
+							let mut result = SarifResult::new(
+								rule,
+								Level::Error,
+								first.message.clone(),
+								location(first),
+							);
+							// the errors taken for the same mistake
+							result.related_locations = rest.iter().map(location).collect();
+							result
+						})
+						.collect::<Vec<_>>()
+				})
+				.collect();
+			serde_json::to_writer_pretty(&mut out, &sarif::log(rules, results))?;
+			writeln!(out)?;
+		}
+	}
+	out.flush()?;
+	eprintln!(
+		"checked {} files, {} with syntax errors",
+		files.len(),
+		broken.len()
+	);
+	Ok(broken.is_empty())
+}
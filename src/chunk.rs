@@ -0,0 +1,108 @@
+use tree_sitter::Node;
+
+use crate::{
+	document::Document,
+	message_generated::asted::interface::{
+		Chunk as FbChunk, ChunkArgs, ChunkResponse, ChunkResponseArgs, Location,
+	},
+};
+
+/// The usual rule of thumb for how many characters of code make up an LLM token.
+pub const CHARS_PER_TOKEN: usize = 4;
+
+/// The budget of chunk requests that don't set one, in a workspace that doesn't either.
+pub const DEFAULT_MAX_CHARS: u32 = 1500;
+
+struct Chunk<'tree> {
+	nodes: Vec<Node<'tree>>,
+}
+
+impl Chunk<'_> {
+	fn start_byte(&self) -> usize {
+		self.nodes[0].start_byte()
+	}
+
+	fn end_byte(&self) -> usize {
+		self.nodes[self.nodes.len() - 1].end_byte()
+	}
+}
+
+/// Splits `document` into chunks of at most `budget` characters and returns a serialized
+/// `ChunkResponse`.
+pub fn chunk(document: &Document, budget: usize, with_text: bool) -> Vec<u8> {
+	let _span = tracing::info_span!("chunk").entered();
+	let mut chunks = Vec::new();
+	split(document.tree.root_node(), budget.max(1), &mut chunks);
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let chunks = chunks
+		.iter()
+		.map(|chunk| {
+			let kinds = chunk
+				.nodes
+				.iter()
+				.map(|node| builder.create_string(node.kind()))
+				.collect::<Vec<_>>();
+			let kinds = builder.create_vector(&kinds);
+			let text = with_text.then(|| {
+				builder.create_vector(
+					&document
+						.text
+						.slice(chunk.start_byte() / 2..chunk.end_byte() / 2),
+				)
+			});
+			FbChunk::create(
+				&mut builder,
+				&ChunkArgs {
+					location: Some(&Location::new(
+						chunk.start_byte() as u32,
+						chunk.end_byte() as u32,
+					)),
+					kinds: Some(kinds),
+					start_row: chunk.nodes[0].start_position().row as u32,
+					end_row: chunk.nodes[chunk.nodes.len() - 1].end_position().row as u32,
+					text,
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let chunks = builder.create_vector(&chunks);
+	let resp = ChunkResponse::create(
+		&mut builder,
+		&ChunkResponseArgs {
+			chunks: Some(chunks),
+			version: document.version,
+			content_hash: document.text.content_hash(),
+		},
+	);
+	builder.finish(resp, None);
+
+	builder.finished_data().to_vec()
+}
+
+/// Groups the children of `node` into chunks, greedily adding siblings to a chunk until the next
+/// one would take it over budget. Children that don't fit a chunk on their own are split the same
+/// way.
+fn split<'tree>(node: Node<'tree>, budget: usize, chunks: &mut Vec<Chunk<'tree>>) {
+	let mut current = Chunk { nodes: Vec::new() };
+	let mut cursor = node.walk();
+	for child in node.children(&mut cursor) {
+		let oversized = (child.end_byte() - child.start_byte()) / 2 > budget;
+		if !current.nodes.is_empty()
+			&& (oversized || (child.end_byte() - current.start_byte()) / 2 > budget)
+		{
+			chunks.push(std::mem::replace(&mut current, Chunk { nodes: Vec::new() }));
+		}
+		if oversized && child.child_count() > 0 {
+			split(child, budget, chunks);
+			continue;
+		}
+		current.nodes.push(child);
+		if oversized {
+			chunks.push(std::mem::replace(&mut current, Chunk { nodes: Vec::new() }));
+		}
+	}
+	if !current.nodes.is_empty() {
+		chunks.push(current);
+	}
+}
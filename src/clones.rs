@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::{
+	document::Document,
+	fingerprint::{self, Fingerprint, Normalize},
+	message_generated::asted::interface::{
+		CloneGroup, CloneGroupArgs, CloneInstance, CloneInstanceArgs, ClonesResponse,
+		ClonesResponseArgs, Location,
+	},
+	uri::DocumentKey,
+};
+
+/// Finds subtrees of at least `min_nodes` nodes that occur more than once across `documents`,
+/// and returns a serialized `ClonesResponse`.
+///
+/// Only the largest clones are reported: a group is left out when each of its subtrees is part of
+/// a bigger clone.
+pub fn clones(
+	documents: &HashMap<DocumentKey, Document>,
+	min_nodes: u32,
+	normalize: Normalize,
+) -> Vec<u8> {
+	let _span = tracing::info_span!("clones", documents = documents.len()).entered();
+
+	let fingerprints = documents
+		.iter()
+		.map(|(key, document)| {
+			let fingerprints = fingerprint::fingerprints(&document.tree, &document.text, normalize);
+			(key, fingerprints)
+		})
+		.collect::<HashMap<_, _>>();
+
+	let mut groups = HashMap::<u64, Vec<(&DocumentKey, Node)>>::new();
+	for (key, document) in documents {
+		let fingerprints = &fingerprints[key];
+		let mut cursor = document.tree.walk();
+		'walk: loop {
+			let node = cursor.node();
+			// subtrees only get smaller further down
+			let big_enough = match fingerprints.get(&node.id()) {
+				Some(fingerprint) if fingerprint.size >= min_nodes => {
+					if node.is_named() {
+						groups
+							.entry(fingerprint.hash)
+							.or_default()
+							.push((key, node));
+					}
+					true
+				}
+				_ => false,
+			};
+			if big_enough && cursor.goto_first_child() {
+				continue;
+			}
+			while !cursor.goto_next_sibling() {
+				if !cursor.goto_parent() {
+					break 'walk;
+				}
+			}
+		}
+	}
+	groups.retain(|_, instances| instances.len() > 1);
+	// the documents come in no particular order
+	for instances in groups.values_mut() {
+		instances.sort_by_cached_key(|(key, node)| (key.uri(), node.start_byte()));
+	}
+
+	let fingerprint = |key: &DocumentKey, node: Node| -> Option<Fingerprint> {
+		fingerprints[key].get(&node.id()).copied()
+	};
+	let mut reported = groups
+		.iter()
+		.filter(|(_, instances)| {
+			!instances.iter().all(|(key, node)| {
+				node.parent()
+					.and_then(|parent| fingerprint(key, parent))
+					.is_some_and(|parent| groups.contains_key(&parent.hash))
+			})
+		})
+		.map(|(hash, instances)| {
+			let (key, node) = instances[0];
+			(*hash, fingerprint(key, node).unwrap().size, instances)
+		})
+		.collect::<Vec<_>>();
+	// biggest first, and in a stable order
+	reported.sort_by_key(|(hash, size, _)| (std::cmp::Reverse(*size), *hash));
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let groups = reported
+		.iter()
+		.map(|(hash, size, instances)| {
+			let instances = instances
+				.iter()
+				.map(|(key, node)| {
+					let path = builder.create_string(&key.uri());
+					let kind = builder.create_string(node.kind());
+					CloneInstance::create(
+						&mut builder,
+						&CloneInstanceArgs {
+							path: Some(path),
+							location: Some(&Location::new(
+								node.start_byte() as u32,
+								node.end_byte() as u32,
+							)),
+							kind: Some(kind),
+							start_row: node.start_position().row as u32,
+							end_row: node.end_position().row as u32,
+						},
+					)
+				})
+				.collect::<Vec<_>>();
+			let instances = builder.create_vector(&instances);
+			CloneGroup::create(
+				&mut builder,
+				&CloneGroupArgs {
+					hash: *hash,
+					node_count: *size,
+					instances: Some(instances),
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let groups = builder.create_vector(&groups);
+	let resp = ClonesResponse::create(
+		&mut builder,
+		&ClonesResponseArgs {
+			groups: Some(groups),
+		},
+	);
+	builder.finish(resp, None);
+
+	builder.finished_data().to_vec()
+}
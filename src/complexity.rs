@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use tree_sitter::{Node, QueryCursor};
+
+use crate::{
+	document::Document,
+	message_generated::asted::interface::{
+		ComplexityResponse, ComplexityResponseArgs, FunctionComplexity, FunctionComplexityArgs,
+		Location,
+	},
+	queries::{self, Kind},
+};
+
+/// Computes the complexity of every function in `document` and returns a serialized
+/// `ComplexityResponse`.
+pub fn complexity(document: &Document) -> Vec<u8> {
+	let _span = tracing::info_span!("complexity").entered();
+	let text = &document.text;
+
+	let functions = queries::tags(&document.language, &document.tree, text)
+		.into_iter()
+		.filter(|tag| tag.is_function())
+		.collect::<Vec<_>>();
+	let mut branches = functions
+		.iter()
+		.map(|function| (function.node.id(), 0))
+		.collect::<HashMap<_, u32>>();
+
+	if let Some(query) = queries::get(
+		&document.language,
+		document.tree.language(),
+		Kind::Complexity,
+	) {
+		let mut cursor = QueryCursor::new();
+		let captures = cursor.captures(query, document.tree.root_node(), |node: Node| {
+			text.chunks(node.start_byte() / 2..node.end_byte() / 2)
+		});
+		for (m, index) in captures {
+			// a branch counts towards the innermost function it's in
+			let mut parent = m.captures[index].node.parent();
+			while let Some(node) = parent {
+				if let Some(count) = branches.get_mut(&node.id()) {
+					*count += 1;
+					break;
+				}
+				parent = node.parent();
+			}
+		}
+	}
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let functions = functions
+		.iter()
+		.map(|function| {
+			let name = builder.create_string(&function.name_text(text));
+			let kind = builder.create_string(function.kind);
+			let node = function.node;
+			FunctionComplexity::create(
+				&mut builder,
+				&FunctionComplexityArgs {
+					name: Some(name),
+					kind: Some(kind),
+					location: Some(&Location::new(
+						node.start_byte() as u32,
+						node.end_byte() as u32,
+					)),
+					complexity: branches[&node.id()] + 1,
+					lines: (node.end_position().row - node.start_position().row + 1) as u32,
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let functions = builder.create_vector(&functions);
+	let resp = ComplexityResponse::create(
+		&mut builder,
+		&ComplexityResponseArgs {
+			functions: Some(functions),
+			version: document.version,
+			content_hash: text.content_hash(),
+		},
+	);
+	builder.finish(resp, None);
+
+	builder.finished_data().to_vec()
+}
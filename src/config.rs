@@ -0,0 +1,194 @@
+use std::{
+	collections::HashMap,
+	fs,
+	path::{Path, PathBuf},
+	sync::{Mutex, PoisonError},
+};
+
+use anyhow::{Context, Result};
+use serde::{de, Deserialize, Deserializer};
+use tracing_subscriber::filter::LevelFilter;
+
+use crate::{
+	languages::{self, LanguageConfig},
+	limits::{self, Limits},
+	logging, node_types,
+	permissions::{self, Capability},
+	queries,
+	tenants::TenantConfig,
+	tree_cache,
+	webhooks::Webhook,
+	workspace_config,
+};
+
+/// Settings read from the `--config` file.
+#[derive(Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+	/// The language to initialize the default session with at startup
+	pub preload_lang: Option<String>,
+	/// Files (or glob patterns) to parse into the default session's cache at startup
+	pub preload_paths: Vec<String>,
+	/// URLs to POST events to, each with the events it wants
+	pub webhooks: Vec<Webhook>,
+	/// Like `--read-only`
+	pub read_only: bool,
+	/// Like `--deny`
+	pub deny: Vec<Capability>,
+	/// Like `--log-level`
+	#[serde(deserialize_with = "log_level")]
+	pub log_level: Option<LevelFilter>,
+	/// Like `--rate-limit`
+	#[serde(deserialize_with = "crate::tenants::rate_limit")]
+	pub rate_limit: Option<u32>,
+	/// Like `--max-concurrent-requests`
+	pub max_concurrent_requests: Option<usize>,
+	/// API keys with sessions, limits and filesystem access of their own
+	pub tenants: Vec<TenantConfig>,
+	/// Settings for each language, by its name in the language registry
+	pub languages: HashMap<String, LanguageConfig>,
+}
+
+impl Config {
+	pub fn load(path: &Path) -> Result<Self> {
+		let text = fs::read_to_string(path)
+			.with_context(|| format!("Error reading config file {}", path.display()))?;
+		toml::from_str(&text)
+			.with_context(|| format!("Error parsing config file {}", path.display()))
+	}
+}
+
+fn log_level<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<LevelFilter>, D::Error> {
+	String::deserialize(deserializer)?
+		.parse()
+		.map(Some)
+		.map_err(de::Error::custom)
+}
+
+/// Settings given on the command line, which the config file's add to or give way to.
+pub struct Flags {
+	pub read_only: bool,
+	pub deny: Vec<Capability>,
+	pub log_level: Option<LevelFilter>,
+	pub rate_limit: Option<u32>,
+	pub max_concurrent_requests: Option<usize>,
+}
+
+impl Flags {
+	/// The permissions of `config` and the command line, together.
+	pub fn deny(&self, config: &Config) {
+		permissions::deny(
+			self.read_only || config.read_only,
+			self.deny.iter().chain(&config.deny).copied(),
+		);
+	}
+
+	pub fn log_level(&self, config: &Config) -> LevelFilter {
+		self.log_level
+			.or(config.log_level)
+			.unwrap_or(LevelFilter::INFO)
+	}
+
+	/// The most concurrent requests and requests per second of `config` and the command line.
+	pub fn limits(&self, config: &Config) -> (Option<usize>, Option<u32>) {
+		(
+			self.max_concurrent_requests
+				.or(config.max_concurrent_requests),
+			self.rate_limit.or(config.rate_limit),
+		)
+	}
+}
+
+/// Rereads the config file while the server runs, applying the settings that can change without
+/// a restart: language settings (with the queries they point to), permissions, the log level and
+/// limits. Sessions are
+/// kept, as are those settings' effects on documents already open.
+pub struct Reloader {
+	path: PathBuf,
+	applied: Mutex<Config>,
+	flags: Flags,
+}
+
+impl Reloader {
+	pub fn new(path: PathBuf, applied: Config, flags: Flags) -> Self {
+		Reloader {
+			path,
+			applied: Mutex::new(applied),
+			flags,
+		}
+	}
+
+	/// Rereads and applies the config file, logging what changed. A file that doesn't load leaves
+	/// everything as it was.
+	pub fn reload(&self) {
+		if let Err(e) = self.try_reload() {
+			tracing::error!("Failed to reload config: {:#}", e);
+		}
+	}
+
+	fn try_reload(&self) -> Result<()> {
+		let config = Config::load(&self.path)?;
+		let mut applied = self.applied.lock().unwrap_or_else(PoisonError::into_inner);
+
+		let mut reloaded = Vec::new();
+		if config.languages != applied.languages {
+			languages::set_configs(config.languages.clone());
+			if let Err(e) = queries::reload() {
+				languages::set_configs(applied.languages.clone());
+				return Err(e);
+			}
+			// they may have been serialized with other kind aliases, or other node types
+			node_types::forget();
+			tree_cache::forget_serialized();
+			// their queries were compiled when they were read
+			workspace_config::forget();
+			reloaded.push("languages");
+		}
+		if (config.read_only, &config.deny) != (applied.read_only, &applied.deny) {
+			self.flags.deny(&config);
+			reloaded.push("permissions");
+		}
+		if self.flags.log_level(&config) != self.flags.log_level(&applied) {
+			logging::set_level(self.flags.log_level(&config))?;
+			reloaded.push("log level");
+		}
+		if self.flags.limits(&config) != self.flags.limits(&applied) {
+			let (max_concurrent_requests, rate_limit) = self.flags.limits(&config);
+			limits::set(Limits::new(max_concurrent_requests, rate_limit));
+			reloaded.push("limits");
+		}
+		if !reloaded.is_empty() {
+			tracing::info!(
+				"reloaded {} from {}",
+				reloaded.join(", "),
+				self.path.display()
+			);
+		}
+
+		let needs_restart = [
+			("preload_lang", config.preload_lang != applied.preload_lang),
+			(
+				"preload_paths",
+				config.preload_paths != applied.preload_paths,
+			),
+			("webhooks", config.webhooks != applied.webhooks),
+			("tenants", config.tenants != applied.tenants),
+		];
+		for (name, _) in needs_restart.iter().filter(|(_, changed)| *changed) {
+			tracing::warn!(
+				"{} changed in {}, which needs a restart",
+				name,
+				self.path.display()
+			);
+		}
+
+		// the others stay as they were applied, so they're reported again until the restart
+		applied.languages = config.languages;
+		applied.read_only = config.read_only;
+		applied.deny = config.deny;
+		applied.log_level = config.log_level;
+		applied.rate_limit = config.rate_limit;
+		applied.max_concurrent_requests = config.max_concurrent_requests;
+		Ok(())
+	}
+}
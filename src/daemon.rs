@@ -0,0 +1,257 @@
+use std::{
+	fs,
+	io::Write,
+	net::SocketAddr,
+	path::{Path, PathBuf},
+	process::{Command, Stdio},
+	time::Duration,
+};
+
+use anyhow::{bail, Context, Result};
+use axum::body::Body;
+use http::{header::AUTHORIZATION, Method, Request, StatusCode};
+use once_cell::sync::{Lazy, OnceCell};
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+
+use crate::message_generated::asted::interface::{
+	Request as FbRequest, RequestArgs, RequestUnion, ShutdownRequest, ShutdownRequestArgs,
+};
+
+/// Subcommands for managing a server running in the background.
+#[derive(Clone, Copy, clap::Subcommand)]
+pub enum DaemonCommand {
+	/// Start a server in the background (with the options given before `start`), unless one is
+	/// already running
+	Start,
+	/// Ask the background server to shut down
+	Stop,
+	/// Check whether the background server is up
+	Status,
+}
+
+/// Where a running server can be reached, written to the discovery file.
+#[derive(Serialize, Deserialize)]
+pub struct Discovery {
+	pub pid: u32,
+	pub addrs: Vec<SocketAddr>,
+	/// The token clients have to send, if the server was started with `--auth`
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub token: Option<String>,
+}
+
+/// How long `start` and `stop` wait for the server to come up or go away.
+const WAIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+static SHUTDOWN: Lazy<watch::Sender<bool>> = Lazy::new(|| watch::channel(false).0);
+
+/// Tells every listener to stop accepting connections and finish what it's handling.
+pub fn shutdown() {
+	SHUTDOWN.send_replace(true);
+}
+
+/// Resolves once `shutdown` has been called.
+pub async fn shutdown_signal() {
+	let mut shutdown = SHUTDOWN.subscribe();
+	let _ = shutdown.wait_for(|&shutdown| shutdown).await;
+}
+
+/// `$XDG_RUNTIME_DIR/asted-tree-sitter`, or a directory under the temp dir if that isn't set.
+fn runtime_dir() -> PathBuf {
+	std::env::var_os("XDG_RUNTIME_DIR")
+		.map(PathBuf::from)
+		.unwrap_or_else(std::env::temp_dir)
+		.join("asted-tree-sitter")
+}
+
+static DISCOVERY_FILE: OnceCell<PathBuf> = OnceCell::new();
+
+/// Sets where the discovery file is written and read, instead of `daemon.json` in the runtime
+/// directory. Only the first call has any effect.
+pub fn set_discovery_file(path: PathBuf) {
+	let _ = DISCOVERY_FILE.set(path);
+}
+
+pub fn discovery_path() -> PathBuf {
+	DISCOVERY_FILE
+		.get()
+		.cloned()
+		.unwrap_or_else(|| runtime_dir().join("daemon.json"))
+}
+
+pub fn write_discovery(addrs: &[SocketAddr], token: Option<String>) -> Result<()> {
+	let discovery = Discovery {
+		pid: std::process::id(),
+		addrs: addrs.to_vec(),
+		token,
+	};
+	let path = discovery_path();
+	let dir = path.parent().unwrap_or(Path::new("."));
+	fs::create_dir_all(dir).context("Error creating runtime directory")?;
+
+	// it may hold the auth token, so it's only readable by its owner. Temp files are created that
+	// way, and renaming one over an older discovery file doesn't keep the older file's permissions.
+	let mut file = tempfile::NamedTempFile::new_in(dir).context("Error writing discovery file")?;
+	file.write_all(&serde_json::to_vec(&discovery)?)
+		.context("Error writing discovery file")?;
+	file.persist(&path)
+		.with_context(|| format!("Error writing {}", path.display()))?;
+	Ok(())
+}
+
+/// Removes the discovery file, unless another server has replaced it with its own.
+pub fn remove_discovery() {
+	if let Ok(discovery) = read_discovery() {
+		if discovery.pid == std::process::id() {
+			let _ = fs::remove_file(discovery_path());
+		}
+	}
+}
+
+fn read_discovery() -> Result<Discovery> {
+	let path = discovery_path();
+	let contents = fs::read(&path).with_context(|| format!("Error reading {}", path.display()))?;
+	Ok(serde_json::from_slice(&contents)?)
+}
+
+pub async fn run(command: DaemonCommand) -> Result<()> {
+	match command {
+		DaemonCommand::Start => start().await,
+		DaemonCommand::Stop => stop().await,
+		DaemonCommand::Status => status().await,
+	}
+}
+
+/// The discovery info of the background server, if it's up and answering health checks.
+async fn running() -> Option<Discovery> {
+	let discovery = read_discovery().ok()?;
+	let addr = *discovery.addrs.first()?;
+	match send(addr, None, Method::GET, "/healthz", Body::empty()).await {
+		Ok(StatusCode::OK) => Some(discovery),
+		_ => None,
+	}
+}
+
+async fn send(
+	addr: SocketAddr,
+	token: Option<&str>,
+	method: Method,
+	path: &str,
+	body: Body,
+) -> Result<StatusCode> {
+	let mut req = Request::builder()
+		.method(method)
+		.uri(format!("http://{}{}", addr, path));
+	if let Some(token) = token {
+		req = req.header(AUTHORIZATION, format!("Bearer {}", token));
+	}
+	let req = req.body(body)?;
+	let res = tokio::time::timeout(Duration::from_secs(2), hyper::Client::new().request(req))
+		.await
+		.context("Timed out waiting for the server")??;
+	Ok(res.status())
+}
+
+async fn start() -> Result<()> {
+	if let Some(discovery) = running().await {
+		println!("already running (pid {})", discovery.pid);
+		return Ok(());
+	}
+
+	// pass every option through, dropping the `start` subcommand (which always comes last)
+	let mut args = std::env::args_os().skip(1).collect::<Vec<_>>();
+	if let Some(i) = args.iter().rposition(|arg| arg == "start") {
+		args.remove(i);
+	}
+
+	// next to the discovery file, so servers with discovery files of their own don't share it
+	let log_path = discovery_path().with_extension("log");
+	if let Some(dir) = log_path.parent() {
+		fs::create_dir_all(dir).context("Error creating runtime directory")?;
+	}
+	let log = fs::File::create(&log_path).context("Error creating log")?;
+	let mut command = Command::new(std::env::current_exe()?);
+	command
+		.args(args)
+		.stdin(Stdio::null())
+		.stdout(log.try_clone()?)
+		.stderr(log);
+	#[cfg(unix)]
+	{
+		use std::os::unix::process::CommandExt;
+		// don't take the server down along with the terminal it was started from
+		command.process_group(0);
+	}
+	let child = command.spawn().context("Error starting server")?;
+
+	let deadline = tokio::time::Instant::now() + WAIT_TIMEOUT;
+	while tokio::time::Instant::now() < deadline {
+		if let Some(discovery) = running().await {
+			if discovery.pid == child.id() {
+				println!("started (pid {}) on {}", discovery.pid, discovery.addrs[0]);
+				return Ok(());
+			}
+		}
+		tokio::time::sleep(Duration::from_millis(100)).await;
+	}
+	bail!("Server didn't come up; see {}", log_path.display())
+}
+
+async fn stop() -> Result<()> {
+	let discovery = match running().await {
+		Some(discovery) => discovery,
+		None => {
+			println!("not running");
+			return Ok(());
+		}
+	};
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let shutdown = ShutdownRequest::create(&mut builder, &ShutdownRequestArgs {});
+	let req = FbRequest::create(
+		&mut builder,
+		&RequestArgs {
+			request_type: RequestUnion::ShutdownRequest,
+			request: Some(shutdown.as_union_value()),
+			id: 0,
+		},
+	);
+	builder.finish(req, None);
+	let body = Body::from(builder.finished_data().to_vec());
+	let status = send(
+		discovery.addrs[0],
+		discovery.token.as_deref(),
+		Method::POST,
+		"/",
+		body,
+	)
+	.await?;
+	if status != StatusCode::OK {
+		bail!("Server refused to shut down: {}", status);
+	}
+
+	let deadline = tokio::time::Instant::now() + WAIT_TIMEOUT;
+	while tokio::time::Instant::now() < deadline {
+		if running().await.is_none() {
+			println!("stopped (pid {})", discovery.pid);
+			return Ok(());
+		}
+		tokio::time::sleep(Duration::from_millis(100)).await;
+	}
+	bail!("Server (pid {}) is still running", discovery.pid)
+}
+
+async fn status() -> Result<()> {
+	match running().await {
+		Some(discovery) => {
+			let addrs = discovery
+				.addrs
+				.iter()
+				.map(ToString::to_string)
+				.collect::<Vec<_>>();
+			println!("running (pid {}) on {}", discovery.pid, addrs.join(", "));
+			Ok(())
+		}
+		None => bail!("not running"),
+	}
+}
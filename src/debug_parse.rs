@@ -0,0 +1,99 @@
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use tree_sitter::{Language, LogType, Parser};
+
+use crate::message_generated::asted::interface::{DebugParseResponse, DebugParseResponseArgs};
+
+/// Parses `text` from scratch with logging enabled and returns a serialized `DebugParseResponse`.
+///
+/// A throwaway parser is used so the session's parser (and its cached trees) are unaffected.
+pub fn debug_parse(language: Language, text: &[u16], dot_graphs: bool) -> Result<Vec<u8>> {
+	let _span = tracing::info_span!("debug_parse").entered();
+	let mut parser = Parser::new();
+	parser
+		.set_language(language)
+		.context("Error loading tree-sitter language")?;
+
+	let log = Arc::new(Mutex::new(Vec::new()));
+	{
+		let log = log.clone();
+		parser.set_logger(Some(Box::new(move |log_type, message| {
+			let prefix = match log_type {
+				LogType::Parse => "parse",
+				LogType::Lex => "lex",
+			};
+			log.lock().unwrap().push(format!("{}: {}", prefix, message));
+		})));
+	}
+
+	let graphs = if dot_graphs {
+		parse_with_dot_graphs(&mut parser, text)?
+	} else {
+		parser
+			.parse_utf16(text, None)
+			.context("Error parsing file")?;
+		Vec::new()
+	};
+	parser.set_logger(None);
+
+	let log = log.lock().unwrap();
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let log = log
+		.iter()
+		.map(|line| builder.create_string(line))
+		.collect::<Vec<_>>();
+	let log = builder.create_vector(&log);
+	let graphs = graphs
+		.iter()
+		.map(|graph| builder.create_string(graph))
+		.collect::<Vec<_>>();
+	let graphs = builder.create_vector(&graphs);
+	let resp = DebugParseResponse::create(
+		&mut builder,
+		&DebugParseResponseArgs {
+			log: Some(log),
+			dot_graphs: Some(graphs),
+		},
+	);
+	builder.finish(resp, None);
+
+	Ok(builder.finished_data().to_vec())
+}
+
+#[cfg(unix)]
+fn parse_with_dot_graphs(parser: &mut Parser, text: &[u16]) -> Result<Vec<String>> {
+	use std::io::{Read, Seek};
+
+	let mut file = tempfile::tempfile().context("Error creating dot graph file")?;
+	parser.print_dot_graphs(&file);
+	let res = parser.parse_utf16(text, None);
+	// closes tree-sitter's copy of the file descriptor, flushing what it wrote
+	parser.stop_printing_dot_graphs();
+	res.context("Error parsing file")?;
+
+	let mut output = String::new();
+	file.rewind().context("Error reading dot graphs")?;
+	file.read_to_string(&mut output)
+		.context("Error reading dot graphs")?;
+
+	// tree-sitter writes one graph after another into the same file: a labelled `graph` per log
+	// message and a `digraph` of the parse stack after each step
+	let mut graphs = Vec::<String>::new();
+	for line in output.lines() {
+		if line.starts_with("graph ") || line.starts_with("digraph ") || graphs.is_empty() {
+			graphs.push(String::new());
+		}
+		let graph = graphs.last_mut().unwrap();
+		graph.push_str(line);
+		graph.push('\n');
+	}
+
+	Ok(graphs)
+}
+
+#[cfg(not(unix))]
+fn parse_with_dot_graphs(_parser: &mut Parser, _text: &[u16]) -> Result<Vec<String>> {
+	anyhow::bail!("Dot graphs are only supported on unix")
+}
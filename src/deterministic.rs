@@ -0,0 +1,15 @@
+use once_cell::sync::OnceCell;
+
+static DETERMINISTIC: OnceCell<bool> = OnceCell::new();
+
+/// Sets whether responses leave out everything that differs from run to run (timings, file
+/// modification times, the server instance in resume tokens, batches cut short by time) for the
+/// rest of the process, so client tests can compare them byte for byte. Only the first call has any
+/// effect.
+pub fn set(deterministic: bool) {
+	let _ = DETERMINISTIC.set(deterministic);
+}
+
+pub fn enabled() -> bool {
+	DETERMINISTIC.get().copied().unwrap_or_default()
+}
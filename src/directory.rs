@@ -0,0 +1,79 @@
+use std::{
+	ops::ControlFlow,
+	path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+
+use crate::{
+	languages,
+	message_generated::asted::interface::{
+		DirectoryEntry, DirectoryEntryArgs, DirectoryListing, DirectoryListingArgs,
+	},
+	uri::DocumentKey,
+	workspace::Filter,
+	workspace_config, State,
+};
+
+/// How many files a listing holds at most.
+const MAX_ENTRIES: usize = 10_000;
+
+struct Entry {
+	path: PathBuf,
+	language: String,
+	size: u64,
+}
+
+/// Lists the files under `dir` (going `depth` levels of subdirectories down) that have a grammar
+/// and that `filter` takes in, and returns a serialized `DirectoryListing`.
+pub fn list(state: &State, dir: &Path, depth: u32, filter: &Filter) -> Result<Vec<u8>> {
+	let _span = tracing::info_span!("list", dir = %dir.display()).entered();
+	state.check_root(dir)?;
+	let mut entries = Vec::new();
+	let truncated = filter
+		.walk(dir, Some(depth), |path, metadata| {
+			let language =
+				workspace_config::language_for(path).filter(|lang| languages::get(lang).is_some());
+			if let Some(language) = language {
+				if entries.len() == MAX_ENTRIES {
+					return Ok(ControlFlow::Break(()));
+				}
+				entries.push(Entry {
+					path: path.to_path_buf(),
+					language,
+					size: metadata.len(),
+				});
+			}
+			Ok(ControlFlow::Continue(()))
+		})?
+		.is_break();
+	entries.sort_by(|a, b| a.path.cmp(&b.path));
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let entries = entries
+		.iter()
+		.map(|entry| {
+			let path = builder.create_string(&DocumentKey::file(&entry.path).uri());
+			let language = builder.create_string(&entry.language);
+			DirectoryEntry::create(
+				&mut builder,
+				&DirectoryEntryArgs {
+					path: Some(path),
+					language: Some(language),
+					file_size: entry.size,
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let entries = builder.create_vector(&entries);
+	let resp = DirectoryListing::create(
+		&mut builder,
+		&DirectoryListingArgs {
+			entries: Some(entries),
+			truncated,
+		},
+	);
+	builder.finish(resp, None);
+
+	Ok(builder.finished_data().to_vec())
+}
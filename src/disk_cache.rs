@@ -0,0 +1,204 @@
+use std::{
+	collections::HashMap,
+	fs,
+	hash::{Hash, Hasher},
+	io::Write,
+	path::PathBuf,
+	sync::{Mutex, PoisonError},
+	time::SystemTime,
+};
+
+use anyhow::{Context, Result};
+use once_cell::sync::OnceCell;
+use tree_sitter::Language;
+
+use crate::{fnv::Fnv, languages, text::Text, tree_serialize::Options};
+
+/// Serialized `FileResponse`s kept on disk across restarts, one file per text, language and set
+/// of serialization options, so files that haven't changed don't have to be parsed again right
+/// away. Entries are never invalidated, only evicted: their names hash everything the response
+/// depends on, including the server's version, the grammar's library and its kind aliases, and
+/// they start with the text's length and hash, checked when they're read.
+struct DiskCache {
+	dir: PathBuf,
+	max_size: u64,
+	index: Mutex<Index>,
+}
+
+#[derive(Default)]
+struct Index {
+	/// The size and last use of every entry, by key
+	entries: HashMap<u64, (u64, SystemTime)>,
+	size: u64,
+}
+
+static CACHE: OnceCell<DiskCache> = OnceCell::new();
+
+/// The length of the text's length and hash that entries start with.
+const HEADER_LEN: usize = 16;
+
+/// `$XDG_CACHE_HOME/asted-tree-sitter/trees`, or under `~/.cache` if that isn't set.
+pub fn default_dir() -> Option<PathBuf> {
+	let cache = match std::env::var_os("XDG_CACHE_HOME") {
+		Some(dir) => PathBuf::from(dir),
+		None => PathBuf::from(std::env::var_os("HOME")?).join(".cache"),
+	};
+	Some(cache.join("asted-tree-sitter").join("trees"))
+}
+
+/// Starts caching responses in `dir`, evicting the least recently used ones once they take more
+/// than `max_size` bytes. Only the first call has any effect.
+pub fn enable(dir: PathBuf, max_size: u64) -> Result<()> {
+	fs::create_dir_all(&dir).with_context(|| format!("Error creating {}", dir.display()))?;
+	let mut index = Index::default();
+	for entry in fs::read_dir(&dir).with_context(|| format!("Error reading {}", dir.display()))? {
+		let entry = entry?;
+		let key = entry
+			.file_name()
+			.to_str()
+			.and_then(|name| name.strip_suffix(".fb"))
+			.and_then(|key| u64::from_str_radix(key, 16).ok());
+		let metadata = entry.metadata()?;
+		if let Some(key) = key {
+			let used = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+			index.entries.insert(key, (metadata.len(), used));
+			index.size += metadata.len();
+		}
+	}
+	let cache = DiskCache {
+		dir,
+		max_size,
+		index: Mutex::new(index),
+	};
+	cache.evict();
+	let _ = CACHE.set(cache);
+	Ok(())
+}
+
+/// The response cached for `text` parsed with `language`, if there is one. It describes the
+/// document it was serialized for, so it needs restamping.
+pub fn get(lang: &str, language: Language, text: &Text, options: Options) -> Option<Vec<u8>> {
+	let cache = CACHE.get()?;
+	let key = key(lang, language, text, options);
+	let mut index = cache.index.lock().unwrap_or_else(PoisonError::into_inner);
+	if !index.entries.contains_key(&key) {
+		return None;
+	}
+	let path = cache.path(key);
+	let mut res = match fs::read(&path) {
+		Ok(res) => res,
+		Err(e) => {
+			tracing::warn!("Error reading {}: {}", path.display(), e);
+			let (size, _) = index.entries.remove(&key)?;
+			index.size -= size;
+			return None;
+		}
+	};
+	if !res.starts_with(&header(text)) {
+		tracing::warn!("{} was cached for another text", path.display());
+		let (size, _) = index.entries.remove(&key)?;
+		index.size -= size;
+		let _ = fs::remove_file(&path);
+		return None;
+	}
+	let now = SystemTime::now();
+	index.entries.insert(key, (res.len() as u64, now));
+	// so eviction after a restart still goes by last use
+	if let Ok(file) = fs::File::options().append(true).open(&path) {
+		let _ = file.set_modified(now);
+	}
+	res.drain(..HEADER_LEN);
+	Some(res)
+}
+
+/// Caches `res`, the response serialized for `text` parsed with `language`.
+pub fn insert(lang: &str, language: Language, text: &Text, options: Options, res: &[u8]) {
+	let cache = match CACHE.get() {
+		Some(cache) => cache,
+		None => return,
+	};
+	let key = key(lang, language, text, options);
+	if let Err(e) = cache.insert(key, &header(text), res) {
+		tracing::warn!("Error caching tree: {:#}", e);
+	}
+}
+
+impl DiskCache {
+	fn path(&self, key: u64) -> PathBuf {
+		self.dir.join(format!("{:016x}.fb", key))
+	}
+
+	fn insert(&self, key: u64, header: &[u8], res: &[u8]) -> Result<()> {
+		let cached = self
+			.index
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.entries
+			.contains_key(&key);
+		let len = (header.len() + res.len()) as u64;
+		if cached || len > self.max_size {
+			return Ok(());
+		}
+		// written whole or not at all, so readers never see part of a response
+		let mut file = tempfile::NamedTempFile::new_in(&self.dir)?;
+		file.write_all(header)?;
+		file.write_all(res)?;
+		file.persist(self.path(key))?;
+
+		let mut index = self.index.lock().unwrap_or_else(PoisonError::into_inner);
+		let replaced = index.entries.insert(key, (len, SystemTime::now()));
+		if let Some((size, _)) = replaced {
+			index.size -= size;
+		}
+		index.size += len;
+		drop(index);
+		self.evict();
+		Ok(())
+	}
+
+	/// Deletes the least recently used entries until the cache is within its size.
+	fn evict(&self) {
+		let mut index = self.index.lock().unwrap_or_else(PoisonError::into_inner);
+		while index.size > self.max_size {
+			let oldest = index
+				.entries
+				.iter()
+				.min_by_key(|(_, (_, used))| *used)
+				.map(|(key, (size, _))| (*key, *size));
+			let (key, size) = match oldest {
+				Some(oldest) => oldest,
+				None => break,
+			};
+			index.entries.remove(&key);
+			index.size -= size;
+			let _ = fs::remove_file(self.path(key));
+		}
+	}
+}
+
+/// What the cached response for `text` is stored under.
+fn key(lang: &str, language: Language, text: &Text, options: Options) -> u64 {
+	let mut hasher = Fnv::default();
+	env!("CARGO_PKG_VERSION").hash(&mut hasher);
+	lang.hash(&mut hasher);
+	language.version().hash(&mut hasher);
+	languages::grammar_hash(language).hash(&mut hasher);
+	// the kinds are renamed as the tree is serialized
+	let mut aliases = languages::kind_aliases(language)
+		.into_iter()
+		.collect::<Vec<_>>();
+	aliases.sort();
+	aliases.hash(&mut hasher);
+	options.hash(&mut hasher);
+	text.len().hash(&mut hasher);
+	text.stable_hash().hash(&mut hasher);
+	hasher.finish()
+}
+
+/// What an entry starts with, so a key shared with another text by chance is caught when read.
+fn header(text: &Text) -> [u8; HEADER_LEN] {
+	let mut header = [0; HEADER_LEN];
+	header[..8].copy_from_slice(&(text.len() as u64).to_le_bytes());
+	header[8..].copy_from_slice(&text.stable_hash().to_le_bytes());
+	header
+}
@@ -0,0 +1,67 @@
+use std::time::SystemTime;
+
+use anyhow::Result;
+use axum::body::Bytes;
+use tree_sitter::Tree;
+
+use crate::{edit, error::Error, text::Text, tree_cache, tree_serialize};
+
+/// An open document. Every request that reads or changes a document goes through one of these,
+/// so its text, tree and version always agree with each other.
+#[derive(Clone)]
+pub struct Document {
+	pub text: Text,
+	pub tree: Tree,
+	/// Bumped every time the text changes, so edits made against an older text can be rejected
+	pub version: u32,
+	/// The language the tree was parsed with
+	pub language: String,
+	/// When the file was last read from disk, if the filesystem reports it
+	pub mtime: Option<SystemTime>,
+	pub accessed: SystemTime,
+}
+
+impl Document {
+	/// A copy of the tree, edited so it can be reused to parse `text`, and the version `text` will
+	/// have.
+	pub fn tree_for(&self, text: &Text) -> (Tree, u32) {
+		let mut tree = self.tree.clone();
+		if edit::edit_to_match(&mut tree, &self.text, text) {
+			(tree, self.version + 1)
+		} else {
+			(tree, self.version)
+		}
+	}
+
+	/// Applies `edits` (start byte, old end byte, new text) in order to copies of the text and
+	/// tree.
+	pub fn edited(&self, edits: Vec<(usize, usize, Vec<u16>)>) -> Result<(Text, Tree)> {
+		let mut text = self.text.clone();
+		let mut tree = self.tree.clone();
+		for (start_byte, old_end_byte, new_text) in edits {
+			if start_byte % 2 != 0 || old_end_byte % 2 != 0 || start_byte > old_end_byte {
+				return Err(Error::InvalidRequest(format!(
+					"Invalid edit range {}..{}",
+					start_byte, old_end_byte
+				))
+				.into());
+			}
+			edit::apply(
+				&mut text,
+				&mut tree,
+				start_byte / 2,
+				old_end_byte / 2,
+				&new_text,
+			)?;
+		}
+		Ok((text, tree))
+	}
+
+	pub fn serialize(
+		&self,
+		parse_info: &tree_serialize::ParseInfo,
+		options: tree_serialize::Options,
+	) -> Bytes {
+		tree_cache::serialize(&self.text, &self.tree, parse_info, options)
+	}
+}
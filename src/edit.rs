@@ -0,0 +1,176 @@
+use std::ops::Range;
+
+use anyhow::Result;
+use tree_sitter::{InputEdit, Tree};
+
+use crate::{error::Error, text::Text};
+
+// Offsets here are indices into UTF-16 text. tree-sitter (and the protocol) count bytes instead,
+// which are twice that.
+
+/// Replaces `text[start..old_end]` with `new_text`, editing `tree` to match.
+pub fn apply(
+	text: &mut Text,
+	tree: &mut Tree,
+	start: usize,
+	old_end: usize,
+	new_text: &[u16],
+) -> Result<()> {
+	// checks the range before the points below are computed from it
+	let mut edited = text.clone();
+	edited.replace(start..old_end, new_text)?;
+	let new_end = start + new_text.len();
+
+	tree.edit(&InputEdit {
+		start_byte: start * 2,
+		old_end_byte: old_end * 2,
+		new_end_byte: new_end * 2,
+		start_position: text.point_at(start),
+		old_end_position: text.point_at(old_end),
+		new_end_position: edited.point_at(new_end),
+	});
+	*text = edited;
+	Ok(())
+}
+
+/// Edits `tree`, parsed from `old`, so it can be reused to parse `new`. Everything between the
+/// common prefix and suffix of the two texts is treated as replaced. Returns whether the texts
+/// differ at all.
+pub fn edit_to_match(tree: &mut Tree, old: &Text, new: &Text) -> bool {
+	let prefix = old
+		.units()
+		.zip(new.units())
+		.take_while(|(a, b)| a == b)
+		.count();
+	if prefix == old.len() && prefix == new.len() {
+		return false;
+	}
+	let suffix = old
+		.units_rev()
+		.zip(new.units_rev())
+		.take(old.len().min(new.len()) - prefix)
+		.take_while(|(a, b)| a == b)
+		.count();
+	let old_end = old.len() - suffix;
+	let new_end = new.len() - suffix;
+
+	tree.edit(&InputEdit {
+		start_byte: prefix * 2,
+		old_end_byte: old_end * 2,
+		new_end_byte: new_end * 2,
+		start_position: old.point_at(prefix),
+		old_end_position: old.point_at(old_end),
+		new_end_position: new.point_at(new_end),
+	});
+	true
+}
+
+/// Turns `edits` (each replacing a range of `text` with new text) into the fewest edits making the
+/// same change, so clients can apply them blindly: each is cut down to what it actually changes,
+/// edits that touch are merged and ones that change nothing dropped. They come out last to first,
+/// so applying them one after the other never moves one that's still to come. Edits at the same
+/// place keep their order, so insertions there end up in the order given; edits that overlap are
+/// an error, since there's no telling what they would mean together.
+pub fn normalize(
+	text: &Text,
+	mut edits: Vec<(Range<usize>, Vec<u16>)>,
+) -> Result<Vec<(Range<usize>, Vec<u16>)>> {
+	// stable, so edits at the same place keep their order
+	edits.sort_by_key(|(range, _)| (range.start, range.end));
+	let mut merged = Vec::<(Range<usize>, Vec<u16>)>::with_capacity(edits.len());
+	for (range, new_text) in edits {
+		if range.start > range.end || range.end > text.len() {
+			return Err(Error::InvalidRequest(format!(
+				"Invalid edit range {}..{}",
+				range.start * 2,
+				range.end * 2
+			))
+			.into());
+		}
+		match merged.last_mut() {
+			Some((last, _)) if range.start < last.end => {
+				return Err(Error::InvalidRequest(format!(
+					"Edits {}..{} and {}..{} overlap",
+					last.start * 2,
+					last.end * 2,
+					range.start * 2,
+					range.end * 2
+				))
+				.into());
+			}
+			Some((last, last_text)) if range.start == last.end => {
+				last.end = range.end;
+				last_text.extend(new_text);
+			}
+			_ => merged.push((range, new_text)),
+		}
+	}
+
+	let mut normalized = merged
+		.into_iter()
+		.filter_map(|(range, new_text)| {
+			let old_text = text.slice(range.clone());
+			let mut prefix = old_text
+				.iter()
+				.zip(&new_text)
+				.take_while(|(a, b)| a == b)
+				.count();
+			// a character made of a surrogate pair is kept or replaced as a whole
+			if prefix > 0 && is_high_surrogate(old_text[prefix - 1]) {
+				prefix -= 1;
+			}
+			let mut suffix = old_text[prefix..]
+				.iter()
+				.rev()
+				.zip(new_text[prefix..].iter().rev())
+				.take_while(|(a, b)| a == b)
+				.count();
+			if suffix > 0 && is_low_surrogate(old_text[old_text.len() - suffix]) {
+				suffix -= 1;
+			}
+			let range = range.start + prefix..range.end - suffix;
+			let new_text = new_text[prefix..new_text.len() - suffix].to_vec();
+			(!range.is_empty() || !new_text.is_empty()).then_some((range, new_text))
+		})
+		.collect::<Vec<_>>();
+	normalized.reverse();
+	Ok(normalized)
+}
+
+fn is_high_surrogate(unit: u16) -> bool {
+	(0xD800..0xDC00).contains(&unit)
+}
+
+fn is_low_surrogate(unit: u16) -> bool {
+	(0xDC00..0xE000).contains(&unit)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn units(s: &str) -> Vec<u16> {
+		s.encode_utf16().collect()
+	}
+
+	#[test]
+	fn normalize_keeps_surrogate_pairs_whole() {
+		let text = Text::from("a😀b");
+		let edits = normalize(&text, vec![(0..4, units("a😁b"))]).unwrap();
+		assert_eq!(edits, vec![(1..3, units("😁"))]);
+
+		let mut text = text;
+		for (range, new_text) in edits {
+			text.replace(range, &new_text).unwrap();
+		}
+		assert_eq!(text.to_utf16(), units("a😁b"));
+	}
+
+	#[test]
+	fn normalize_keeps_shared_low_surrogates_whole() {
+		// U+1F600 and U+10600 share their low surrogate
+		let text = Text::from("😀");
+		let edits = normalize(&text, vec![(0..2, units("\u{10600}"))]).unwrap();
+		assert_eq!(edits, vec![(0..2, units("\u{10600}"))]);
+	}
+}
@@ -0,0 +1,1695 @@
+use anyhow::Result;
+use axum::{
+	body::{Body, Bytes},
+	http::{
+		header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE},
+		HeaderMap, HeaderValue,
+	},
+	response::{IntoResponse, Response},
+};
+use serde::Serialize;
+
+use crate::{error::Error, message_generated::asted::interface as fb, sarif};
+
+/// How response bodies are encoded, picked from the request's `Accept` header. Responses are
+/// always built as flatbuffers, and transcoded into the same model in the other encodings for
+/// clients without good flatbuffers support.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+	Flatbuffers,
+	MessagePack,
+	Cbor,
+	/// A SARIF log of the results, for code scanning tools; only search and clone results can be
+	/// encoded this way, and errors come as plain JSON
+	Sarif,
+}
+
+impl Encoding {
+	/// The first encoding listed in `Accept` that the server knows (quality values aren't
+	/// weighed), or flatbuffers if there's none.
+	pub fn negotiate(headers: &HeaderMap) -> Encoding {
+		let accept = match headers.get(ACCEPT).and_then(|accept| accept.to_str().ok()) {
+			Some(accept) => accept,
+			None => return Encoding::Flatbuffers,
+		};
+		for media_type in accept.split(',') {
+			let media_type = media_type.split(';').next().unwrap().trim();
+			match media_type.to_ascii_lowercase().as_str() {
+				"application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+					return Encoding::MessagePack
+				}
+				"application/cbor" => return Encoding::Cbor,
+				"application/sarif+json" => return Encoding::Sarif,
+				"application/octet-stream" | "application/x-flatbuffers" | "*/*" => {
+					return Encoding::Flatbuffers
+				}
+				_ => {}
+			}
+		}
+		Encoding::Flatbuffers
+	}
+
+	/// Encodes a `FileResponse` built by the tree or plain-text serializer.
+	pub fn file_response(self, res: Bytes) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(stream_bytes(res));
+		}
+		// Safety: the buffer was just built by our own serializer. Verifying it would also limit
+		// how deep the tree can be.
+		let res = unsafe { flatbuffers::root_unchecked::<fb::FileResponse>(&res) };
+		self.encode(&FileResponse::from(res))
+	}
+
+	pub fn init_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::InitResponse>(&res)?;
+		self.encode(&InitResponse {
+			language: res.language().map(LanguageInfo::from),
+		})
+	}
+
+	pub fn list_languages_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::ListLanguagesResponse>(&res)?;
+		self.encode(&ListLanguagesResponse {
+			languages: res.languages().iter().map(LanguageInfo::from).collect(),
+			min_abi_version: res.min_abi_version(),
+			max_abi_version: res.max_abi_version(),
+		})
+	}
+
+	pub fn reload_grammars_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::ReloadGrammarsResponse>(&res)?;
+		self.encode(&ReloadGrammarsResponse {
+			languages: strings(res.languages()),
+			failed: strings(res.failed()),
+		})
+	}
+
+	pub fn debug_parse_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::DebugParseResponse>(&res)?;
+		self.encode(&DebugParseResponse {
+			log: strings(res.log()),
+			dot_graphs: strings(res.dot_graphs()),
+		})
+	}
+
+	pub fn chunk_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::ChunkResponse>(&res)?;
+		self.encode(&ChunkResponse {
+			chunks: res
+				.chunks()
+				.into_iter()
+				.flatten()
+				.map(|chunk| {
+					let (start_byte, end_byte) = chunk.location().map_or((0, 0), |location| {
+						(location.start_byte(), location.end_byte())
+					});
+					Chunk {
+						start_byte,
+						end_byte,
+						kinds: strings(chunk.kinds()),
+						start_row: chunk.start_row(),
+						end_row: chunk.end_row(),
+						text: chunk
+							.text()
+							.map(|text| String::from_utf16_lossy(&text.iter().collect::<Vec<_>>())),
+					}
+				})
+				.collect(),
+			version: res.version(),
+			content_hash: res.content_hash(),
+		})
+	}
+
+	pub fn metrics_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::MetricsResponse>(&res)?;
+		self.encode(&MetricsResponse {
+			node_counts: res
+				.node_counts()
+				.into_iter()
+				.flatten()
+				.map(|count| KindCount {
+					kind: count.kind(),
+					count: count.count(),
+				})
+				.collect(),
+			max_depth: res.max_depth(),
+			lines: res.lines(),
+			approximate_tokens: res.approximate_tokens(),
+			functions: res
+				.functions()
+				.into_iter()
+				.flatten()
+				.map(|function| {
+					let (start_byte, end_byte) = function.location().map_or((0, 0), |location| {
+						(location.start_byte(), location.end_byte())
+					});
+					FunctionMetrics {
+						name: function.name(),
+						kind: function.kind(),
+						start_byte,
+						end_byte,
+						lines: function.lines(),
+						approximate_tokens: function.approximate_tokens(),
+					}
+				})
+				.collect(),
+			version: res.version(),
+			content_hash: res.content_hash(),
+		})
+	}
+
+	pub fn complexity_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::ComplexityResponse>(&res)?;
+		self.encode(&ComplexityResponse {
+			functions: res
+				.functions()
+				.into_iter()
+				.flatten()
+				.map(|function| {
+					let (start_byte, end_byte) = function.location().map_or((0, 0), |location| {
+						(location.start_byte(), location.end_byte())
+					});
+					FunctionComplexity {
+						name: function.name(),
+						kind: function.kind(),
+						start_byte,
+						end_byte,
+						complexity: function.complexity(),
+						lines: function.lines(),
+					}
+				})
+				.collect(),
+			version: res.version(),
+			content_hash: res.content_hash(),
+		})
+	}
+
+	pub fn imports_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::ImportsResponse>(&res)?;
+		self.encode(&ImportsResponse {
+			imports: res
+				.imports()
+				.into_iter()
+				.flatten()
+				.map(|import| {
+					let (start_byte, end_byte) = import.location().map_or((0, 0), |location| {
+						(location.start_byte(), location.end_byte())
+					});
+					Import {
+						start_byte,
+						end_byte,
+						kind: import.kind(),
+						module: import.module(),
+						resolved: import.resolved(),
+					}
+				})
+				.collect(),
+			version: res.version(),
+			content_hash: res.content_hash(),
+		})
+	}
+
+	pub fn todos_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::TodosResponse>(&res)?;
+		self.encode(&TodosResponse {
+			todos: res
+				.todos()
+				.into_iter()
+				.flatten()
+				.map(|todo| {
+					let (start_byte, end_byte) = todo.location().map_or((0, 0), |location| {
+						(location.start_byte(), location.end_byte())
+					});
+					Todo {
+						start_byte,
+						end_byte,
+						row: todo.row(),
+						marker: todo.marker(),
+						author: todo.author(),
+						message: todo.message(),
+						context: todo.context(),
+					}
+				})
+				.collect(),
+			version: res.version(),
+			content_hash: res.content_hash(),
+		})
+	}
+
+	pub fn format_ranges_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::FormatRangesResponse>(&res)?;
+		self.encode(&FormatRangesResponse {
+			ranges: res
+				.ranges()
+				.iter()
+				.map(|range| {
+					let (start_byte, end_byte) = range.location().map_or((0, 0), |location| {
+						(location.start_byte(), location.end_byte())
+					});
+					FormatRange {
+						start_byte,
+						end_byte,
+						start_point: range.start_point().map(Point::from),
+						end_point: range.end_point().map(Point::from),
+						kind: range.kind(),
+					}
+				})
+				.collect(),
+			version: res.version(),
+			content_hash: res.content_hash(),
+		})
+	}
+
+	pub fn traverse_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::TraverseResponse>(&res)?;
+		self.encode(&TraverseResponse {
+			nodes: res
+				.nodes()
+				.iter()
+				.map(|node| {
+					let (start_byte, end_byte) = node.location().map_or((0, 0), |location| {
+						(location.start_byte(), location.end_byte())
+					});
+					TraversedNode {
+						kind: node.kind(),
+						named: node.named(),
+						depth: node.depth(),
+						start_byte,
+						end_byte,
+						start_point: node.start_point().map(Point::from),
+						end_point: node.end_point().map(Point::from),
+					}
+				})
+				.collect(),
+			cursor: res.cursor(),
+			version: res.version(),
+			content_hash: res.content_hash(),
+		})
+	}
+
+	pub fn selection_ranges_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		// Safety: like in `file_response`, the buffer was just built by our own serializer, and the
+		// ranges nest as deep as the tree
+		let res = unsafe { flatbuffers::root_unchecked::<fb::SelectionRangesResponse>(&res) };
+		self.encode(&SelectionRangesResponse {
+			ranges: res.ranges().iter().map(SelectionRange::from).collect(),
+			version: res.version(),
+			content_hash: res.content_hash(),
+		})
+	}
+
+	pub fn symbols_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		// Safety: like in `file_response`, the buffer was just built by our own serializer, and the
+		// symbols nest as deep as the definitions do
+		let res = unsafe { flatbuffers::root_unchecked::<fb::SymbolsResponse>(&res) };
+		self.encode(&SymbolsResponse {
+			symbols: res.symbols().iter().map(Symbol::from).collect(),
+			version: res.version(),
+			content_hash: res.content_hash(),
+		})
+	}
+
+	pub fn folding_ranges_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::FoldingRangesResponse>(&res)?;
+		self.encode(&FoldingRangesResponse {
+			ranges: res
+				.ranges()
+				.iter()
+				.map(|range| {
+					let (start_byte, end_byte) = range.location().map_or((0, 0), |location| {
+						(location.start_byte(), location.end_byte())
+					});
+					FoldingRange {
+						start_line: range.start_line(),
+						end_line: range.end_line(),
+						kind: range.kind().variant_name().unwrap_or_default(),
+						level: range.level(),
+						start_byte,
+						end_byte,
+					}
+				})
+				.collect(),
+			version: res.version(),
+			content_hash: res.content_hash(),
+		})
+	}
+
+	pub fn history_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::HistoryResponse>(&res)?;
+		self.encode(&HistoryResponse {
+			commits: res
+				.commits()
+				.iter()
+				.map(|commit| HistoryCommit {
+					commit: commit.commit(),
+					author: commit.author().unwrap_or_default(),
+					time: commit.time(),
+					summary: commit.summary().unwrap_or_default(),
+					deleted: commit.deleted(),
+					syntax_errors: commit.syntax_errors(),
+					changes: commit
+						.changes()
+						.iter()
+						.map(|change| {
+							let (start_byte, end_byte) =
+								change.location().map_or((0, 0), |location| {
+									(location.start_byte(), location.end_byte())
+								});
+							DefinitionChange {
+								kind: change.kind().variant_name().unwrap_or_default(),
+								name: change.name(),
+								symbol_kind: change
+									.symbol_kind()
+									.variant_name()
+									.unwrap_or_default(),
+								start_byte,
+								end_byte,
+								start_row: change.start_row(),
+								end_row: change.end_row(),
+							}
+						})
+						.collect(),
+				})
+				.collect(),
+		})
+	}
+
+	pub fn blame_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::BlameResponse>(&res)?;
+		self.encode(&BlameResponse {
+			nodes: res.nodes().iter().map(NodeBlame::from).collect(),
+			version: res.version(),
+			content_hash: res.content_hash(),
+		})
+	}
+
+	pub fn verify_rewrite_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::VerifyRewriteResponse>(&res)?;
+		self.encode(&VerifyRewriteResponse {
+			ok: res.ok(),
+			invariants: res
+				.invariants()
+				.iter()
+				.map(|invariant| InvariantResult {
+					holds: invariant.holds(),
+					matches_before: invariant.matches_before(),
+					matches_after: invariant.matches_after(),
+				})
+				.collect(),
+			documents: res
+				.documents()
+				.iter()
+				.map(|document| RewrittenDocument {
+					name: document.name(),
+					syntax_errors_before: document.syntax_errors_before(),
+					syntax_errors_after: document.syntax_errors_after(),
+					diagnostics: document
+						.diagnostics()
+						.iter()
+						.map(Diagnostic::from)
+						.collect(),
+				})
+				.collect(),
+		})
+	}
+
+	pub fn clones_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::ClonesResponse>(&res)?;
+		if self == Encoding::Sarif {
+			return sarif_response(&sarif::clones(res));
+		}
+		self.encode(&ClonesResponse {
+			groups: res
+				.groups()
+				.into_iter()
+				.flatten()
+				.map(|group| CloneGroup {
+					hash: group.hash(),
+					node_count: group.node_count(),
+					instances: group
+						.instances()
+						.into_iter()
+						.flatten()
+						.map(|instance| {
+							let (start_byte, end_byte) =
+								instance.location().map_or((0, 0), |location| {
+									(location.start_byte(), location.end_byte())
+								});
+							CloneInstance {
+								path: instance.path(),
+								start_byte,
+								end_byte,
+								kind: instance.kind(),
+								start_row: instance.start_row(),
+								end_row: instance.end_row(),
+							}
+						})
+						.collect(),
+				})
+				.collect(),
+		})
+	}
+
+	pub fn directory_listing_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::DirectoryListing>(&res)?;
+		self.encode(&DirectoryListing {
+			entries: res
+				.entries()
+				.into_iter()
+				.flatten()
+				.map(|entry| DirectoryEntry {
+					path: entry.path(),
+					language: entry.language(),
+					file_size: entry.file_size(),
+				})
+				.collect(),
+			truncated: res.truncated(),
+		})
+	}
+
+	pub fn plugin_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::PluginResponse>(&res)?;
+		self.encode(&PluginResponse {
+			payload: res.payload().map_or(&[], |payload| payload.bytes()),
+		})
+	}
+
+	#[cfg(feature = "wasm")]
+	pub fn run_plugin_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::RunPluginResponse>(&res)?;
+		let results = res.results().map_or(&[][..], |results| results.bytes());
+		self.encode(&RunPluginResponse {
+			version: res.version(),
+			content_hash: res.content_hash(),
+			// the plugin checked out as returning a value
+			results: rmp_serde::from_slice(results)?,
+		})
+	}
+
+	#[cfg(feature = "scripting")]
+	pub fn script_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::ScriptResponse>(&res)?;
+		let result = res.result().map_or(&[][..], |result| result.bytes());
+		self.encode(&ScriptResponse {
+			version: res.version(),
+			content_hash: res.content_hash(),
+			output: res.output().iter().collect(),
+			result: rmp_serde::from_slice(result)?,
+		})
+	}
+
+	pub fn cancel_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::CancelResponse>(&res)?;
+		self.encode(&CancelResponse {
+			running: res.running(),
+		})
+	}
+
+	pub fn resume_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::ResumeResponse>(&res)?;
+		self.encode(&ResumeResponse {
+			token: res.token(),
+			resumed: res.resumed(),
+			documents: res
+				.documents()
+				.iter()
+				.map(|document| OpenDocument {
+					path: document.path(),
+					language: document.language(),
+					version: document.version(),
+					content_hash: document.content_hash(),
+				})
+				.collect(),
+			changed: res.changed().iter().collect(),
+		})
+	}
+
+	pub fn transaction_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		let res = flatbuffers::root::<fb::TransactionResponse>(&res)?;
+		self.encode(&TransactionResponse {
+			documents: res
+				.documents()
+				.iter()
+				.map(|document| EditedDocument {
+					path: document.path(),
+					version: document.version(),
+					content_hash: document.content_hash(),
+				})
+				.collect(),
+		})
+	}
+
+	pub fn node_edit_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		// Safety: like in `file_response`, the buffer (and the one nested in it) was just built by
+		// our own serializer
+		let res = unsafe { flatbuffers::root_unchecked::<fb::NodeEditResponse>(&res) };
+		self.encode(&NodeEditResponse {
+			edits: res
+				.edits()
+				.iter()
+				.map(|edit| TextEdit {
+					start_byte: edit.start_byte(),
+					old_end_byte: edit.old_end_byte(),
+					text: edit.text().map_or(String::new(), |text| {
+						String::from_utf16_lossy(&text.iter().collect::<Vec<_>>())
+					}),
+				})
+				.collect(),
+			file: FileResponse::from(res.file_nested_flatbuffer()),
+		})
+	}
+
+	pub fn playground_response(self, res: Vec<u8>) -> Result<Response> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res.into_response());
+		}
+		// Safety: like in `node_edit_response`
+		let res = unsafe { flatbuffers::root_unchecked::<fb::PlaygroundResponse>(&res) };
+		self.encode(&PlaygroundResponse {
+			file: FileResponse::from(res.file_nested_flatbuffer()),
+			diagnostics: res.diagnostics().iter().map(Diagnostic::from).collect(),
+			highlights: res.highlights().map(|highlights| {
+				highlights
+					.iter()
+					.map(|span| {
+						let (start_byte, end_byte) = span.location().map_or((0, 0), |location| {
+							(location.start_byte(), location.end_byte())
+						});
+						HighlightSpan {
+							capture: span.capture(),
+							start_byte,
+							end_byte,
+							start_point: span.start_point().map(Point::from),
+							end_point: span.end_point().map(Point::from),
+						}
+					})
+					.collect()
+			}),
+		})
+	}
+
+	/// A batch of a streamed query response, from a size-prefixed `QueryResults`. MessagePack and
+	/// CBOR values delimit themselves, so their batches go without a prefix.
+	pub fn query_results_frame(self, res: Vec<u8>) -> Result<Vec<u8>> {
+		if self == Encoding::Flatbuffers {
+			return Ok(res);
+		}
+		let res = flatbuffers::size_prefixed_root::<fb::QueryResults>(&res)?;
+		self.to_vec(&QueryResults {
+			matches: res
+				.matches()
+				.into_iter()
+				.flatten()
+				.map(|m| QueryMatch {
+					path: m.path(),
+					pattern_index: m.pattern_index(),
+					captures: m
+						.captures()
+						.into_iter()
+						.flatten()
+						.map(QueryCapture::from)
+						.collect(),
+					groups: m.groups().map(|groups| {
+						groups
+							.iter()
+							.map(|group| QueryCaptureGroup {
+								name: group.name(),
+								quantifier: group.quantifier().variant_name().unwrap_or_default(),
+								captures: group.captures().iter().map(QueryCapture::from).collect(),
+							})
+							.collect()
+					}),
+				})
+				.collect(),
+			cursor: res.cursor(),
+			done: res.done(),
+			counts: res.counts().map(|counts| {
+				counts
+					.iter()
+					.map(|count| QueryFileCount {
+						path: count.path(),
+						matches: count.matches(),
+						captures: count
+							.captures()
+							.into_iter()
+							.flatten()
+							.map(|capture| QueryCaptureCount {
+								name: capture.name(),
+								count: capture.count(),
+							})
+							.collect(),
+					})
+					.collect()
+			}),
+		})
+	}
+
+	/// A SARIF log of every batch of a search, from size-prefixed `QueryResults`.
+	pub fn query_results_sarif(self, batches: Vec<Vec<u8>>) -> Result<Response> {
+		let batches = batches
+			.iter()
+			.map(|batch| flatbuffers::size_prefixed_root::<fb::QueryResults>(batch))
+			.collect::<Result<Vec<_>, _>>()?;
+		sarif_response(&sarif::query_matches(&batches))
+	}
+
+	/// A response streaming the frames sent into `body`.
+	pub fn stream_response(self, body: Body) -> Response {
+		let content_type = match self {
+			Encoding::Flatbuffers => "application/octet-stream",
+			Encoding::MessagePack => "application/msgpack",
+			Encoding::Cbor => "application/cbor-seq",
+			Encoding::Sarif => unreachable!("SARIF logs aren't streamed"),
+		};
+		(
+			[(CONTENT_TYPE, HeaderValue::from_static(content_type))],
+			axum::body::boxed(body),
+		)
+			.into_response()
+	}
+
+	/// Like `Error::into_response`, with the `ErrorResponse` in this encoding.
+	pub fn error_response(self, error: Error) -> Response {
+		if self == Encoding::Flatbuffers {
+			return error.into_response();
+		}
+		let body = ErrorResponse {
+			code: error.code().variant_name().unwrap_or_default(),
+			message: error.to_string(),
+		};
+		// keep the status and headers (like Retry-After) of the flatbuffers response
+		let (mut parts, _) = error.into_response().into_parts();
+		parts.headers.remove(CONTENT_LENGTH);
+		match self.encode(&body) {
+			Ok(res) => {
+				let (encoded, body) = res.into_parts();
+				parts.headers.extend(encoded.headers);
+				Response::from_parts(parts, body)
+			}
+			Err(e) => Error::Internal(format!("{:#}", e)).into_response(),
+		}
+	}
+
+	fn encode<T: Serialize>(self, value: &T) -> Result<Response> {
+		let content_type = match self {
+			Encoding::Flatbuffers => unreachable!("flatbuffers responses aren't transcoded"),
+			Encoding::MessagePack => "application/msgpack",
+			Encoding::Cbor => "application/cbor",
+			Encoding::Sarif => "application/json",
+		};
+		let body = self.to_vec(value)?;
+		Ok((
+			[(CONTENT_TYPE, HeaderValue::from_static(content_type))],
+			body,
+		)
+			.into_response())
+	}
+
+	fn to_vec<T: Serialize>(self, value: &T) -> Result<Vec<u8>> {
+		match self {
+			Encoding::Flatbuffers => unreachable!("flatbuffers responses aren't transcoded"),
+			Encoding::MessagePack => Ok(rmp_serde::to_vec_named(value)?),
+			Encoding::Cbor => {
+				let mut body = Vec::new();
+				ciborium::into_writer(value, &mut body)?;
+				Ok(body)
+			}
+			Encoding::Sarif => Ok(serde_json::to_vec(value)?),
+		}
+	}
+}
+
+fn sarif_response(log: &sarif::Log) -> Result<Response> {
+	Ok((
+		[(
+			CONTENT_TYPE,
+			HeaderValue::from_static("application/sarif+json"),
+		)],
+		serde_json::to_vec(log)?,
+	)
+		.into_response())
+}
+
+fn strings(
+	strings: Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<&str>>>,
+) -> Vec<String> {
+	strings.into_iter().flatten().map(str::to_string).collect()
+}
+
+// The model mirrors the flatbuffers schema's tables, with leaf text as strings instead of UTF-16.
+
+/// How many bytes of a big response body are sent at a time.
+const STREAM_CHUNK: usize = 64 * 1024;
+
+/// A response sending `body` a chunk at a time, so a multi-megabyte tree starts reaching the client
+/// without hyper (or a slow client) holding up the worker that built it. Small bodies, and bodies
+/// built outside the runtime, are sent whole.
+fn stream_bytes(mut body: Bytes) -> Response {
+	let runtime = match tokio::runtime::Handle::try_current() {
+		Ok(runtime) if body.len() > STREAM_CHUNK => runtime,
+		_ => return body.into_response(),
+	};
+	let len = body.len();
+	let (mut sender, streamed) = Body::channel();
+	runtime.spawn(async move {
+		while !body.is_empty() {
+			let chunk = body.split_to(body.len().min(STREAM_CHUNK));
+			// the client went away
+			if sender.send_data(chunk).await.is_err() {
+				break;
+			}
+		}
+	});
+	(
+		[
+			(
+				CONTENT_TYPE,
+				HeaderValue::from_static("application/octet-stream"),
+			),
+			(CONTENT_LENGTH, HeaderValue::from(len)),
+		],
+		axum::body::boxed(streamed),
+	)
+		.into_response()
+}
+
+#[derive(Serialize)]
+struct FileResponse<'a> {
+	tree: Node<'a>,
+	stats: Option<ParseStats>,
+	version: u32,
+	content_hash: u64,
+	mtime_ms: u64,
+	line_ending: &'static str,
+	checksum: u64,
+}
+
+impl<'a> From<fb::FileResponse<'a>> for FileResponse<'a> {
+	fn from(res: fb::FileResponse<'a>) -> Self {
+		FileResponse {
+			tree: Node::from(res.tree()),
+			stats: res.stats().map(|stats| ParseStats {
+				parse_duration_us: stats.parse_duration_us(),
+				reused_old_tree: stats.reused_old_tree(),
+				node_count: stats.node_count(),
+				error_count: stats.error_count(),
+				serialized_size: stats.serialized_size(),
+				cached_tree: stats.cached_tree(),
+				changed_ranges: stats
+					.changed_ranges()
+					.into_iter()
+					.flatten()
+					.map(|range| Range {
+						start_byte: range.start_byte(),
+						end_byte: range.end_byte(),
+					})
+					.collect(),
+			}),
+			version: res.version(),
+			content_hash: res.content_hash(),
+			mtime_ms: res.mtime_ms(),
+			line_ending: res.line_ending().variant_name().unwrap_or_default(),
+			checksum: res.checksum(),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct ParseStats {
+	parse_duration_us: u64,
+	reused_old_tree: bool,
+	node_count: u32,
+	error_count: u32,
+	serialized_size: u32,
+	cached_tree: bool,
+	changed_ranges: Vec<Range>,
+}
+
+#[derive(Serialize)]
+struct Range {
+	start_byte: u32,
+	end_byte: u32,
+}
+
+#[derive(Serialize)]
+struct Node<'a> {
+	kind: &'a str,
+	start_byte: u32,
+	end_byte: u32,
+	children: Vec<Node<'a>>,
+	named: bool,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	text: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	hash: Option<u64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	field: Option<&'a str>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	start_point: Option<Point>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	end_point: Option<Point>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	offsets: Option<Offsets>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	child_count: Option<u32>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	named_descendant_count: Option<u32>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	supertypes: Option<Vec<&'a str>>,
+}
+
+#[derive(Serialize)]
+struct Point {
+	row: u32,
+	column: u32,
+}
+
+impl From<&fb::Point> for Point {
+	fn from(point: &fb::Point) -> Self {
+		Point {
+			row: point.row(),
+			column: point.column(),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct Offsets {
+	utf8_start: u32,
+	utf8_end: u32,
+	utf16_start: u32,
+	utf16_end: u32,
+	utf8_start_column: u32,
+	utf8_end_column: u32,
+	utf16_start_column: u32,
+	utf16_end_column: u32,
+}
+
+impl From<&fb::Offsets> for Offsets {
+	fn from(offsets: &fb::Offsets) -> Self {
+		Offsets {
+			utf8_start: offsets.utf8_start(),
+			utf8_end: offsets.utf8_end(),
+			utf16_start: offsets.utf16_start(),
+			utf16_end: offsets.utf16_end(),
+			utf8_start_column: offsets.utf8_start_column(),
+			utf8_end_column: offsets.utf8_end_column(),
+			utf16_start_column: offsets.utf16_start_column(),
+			utf16_end_column: offsets.utf16_end_column(),
+		}
+	}
+}
+
+impl<'a> From<fb::Node<'a>> for Node<'a> {
+	fn from(node: fb::Node<'a>) -> Self {
+		let (start_byte, end_byte) = node.location().map_or((0, 0), |location| {
+			(location.start_byte(), location.end_byte())
+		});
+		Node {
+			kind: node.kind(),
+			start_byte,
+			end_byte,
+			children: node
+				.children()
+				.into_iter()
+				.flatten()
+				.map(Node::from)
+				.collect(),
+			named: node.named(),
+			text: node
+				.text()
+				.map(|text| String::from_utf16_lossy(&text.iter().collect::<Vec<_>>())),
+			hash: node.hash(),
+			field: node.field(),
+			start_point: node.start_point().map(Point::from),
+			end_point: node.end_point().map(Point::from),
+			offsets: node.offsets().map(Offsets::from),
+			child_count: node.child_count(),
+			named_descendant_count: node.named_descendant_count(),
+			supertypes: node
+				.supertypes()
+				.map(|supertypes| supertypes.iter().collect()),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+	code: &'static str,
+	message: String,
+}
+
+#[derive(Serialize)]
+struct LanguageInfo<'a> {
+	name: &'a str,
+	abi_version: u32,
+	grammar_version: Option<&'a str>,
+}
+
+impl<'a> From<fb::LanguageInfo<'a>> for LanguageInfo<'a> {
+	fn from(info: fb::LanguageInfo<'a>) -> Self {
+		LanguageInfo {
+			name: info.name(),
+			abi_version: info.abi_version(),
+			grammar_version: info.grammar_version(),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct InitResponse<'a> {
+	language: Option<LanguageInfo<'a>>,
+}
+
+#[derive(Serialize)]
+struct ListLanguagesResponse<'a> {
+	languages: Vec<LanguageInfo<'a>>,
+	min_abi_version: u32,
+	max_abi_version: u32,
+}
+
+#[derive(Serialize)]
+struct ReloadGrammarsResponse {
+	languages: Vec<String>,
+	failed: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct DebugParseResponse {
+	log: Vec<String>,
+	dot_graphs: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ChunkResponse {
+	chunks: Vec<Chunk>,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct Chunk {
+	start_byte: u32,
+	end_byte: u32,
+	kinds: Vec<String>,
+	start_row: u32,
+	end_row: u32,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	text: Option<String>,
+}
+
+#[derive(Serialize)]
+struct MetricsResponse<'a> {
+	node_counts: Vec<KindCount<'a>>,
+	max_depth: u32,
+	lines: u32,
+	approximate_tokens: u32,
+	functions: Vec<FunctionMetrics<'a>>,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct KindCount<'a> {
+	kind: &'a str,
+	count: u32,
+}
+
+#[derive(Serialize)]
+struct FunctionMetrics<'a> {
+	name: &'a str,
+	kind: &'a str,
+	start_byte: u32,
+	end_byte: u32,
+	lines: u32,
+	approximate_tokens: u32,
+}
+
+#[derive(Serialize)]
+struct ComplexityResponse<'a> {
+	functions: Vec<FunctionComplexity<'a>>,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct FunctionComplexity<'a> {
+	name: &'a str,
+	kind: &'a str,
+	start_byte: u32,
+	end_byte: u32,
+	complexity: u32,
+	lines: u32,
+}
+
+#[derive(Serialize)]
+struct ImportsResponse<'a> {
+	imports: Vec<Import<'a>>,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct Import<'a> {
+	start_byte: u32,
+	end_byte: u32,
+	kind: &'a str,
+	module: &'a str,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	resolved: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct TodosResponse<'a> {
+	todos: Vec<Todo<'a>>,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct Todo<'a> {
+	start_byte: u32,
+	end_byte: u32,
+	row: u32,
+	marker: &'a str,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	author: Option<&'a str>,
+	message: &'a str,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	context: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct FormatRangesResponse<'a> {
+	ranges: Vec<FormatRange<'a>>,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct FormatRange<'a> {
+	start_byte: u32,
+	end_byte: u32,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	start_point: Option<Point>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	end_point: Option<Point>,
+	kind: &'a str,
+}
+
+#[derive(Serialize)]
+struct TraverseResponse<'a> {
+	nodes: Vec<TraversedNode<'a>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	cursor: Option<&'a str>,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct TraversedNode<'a> {
+	kind: &'a str,
+	named: bool,
+	depth: u32,
+	start_byte: u32,
+	end_byte: u32,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	start_point: Option<Point>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	end_point: Option<Point>,
+}
+
+#[derive(Serialize)]
+struct SelectionRangesResponse {
+	ranges: Vec<SelectionRange>,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct SelectionRange {
+	start_byte: u32,
+	end_byte: u32,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	start_point: Option<Point>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	end_point: Option<Point>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	parent: Option<Box<SelectionRange>>,
+}
+
+impl From<fb::SelectionRange<'_>> for SelectionRange {
+	fn from(range: fb::SelectionRange) -> Self {
+		let (start_byte, end_byte) = range.location().map_or((0, 0), |location| {
+			(location.start_byte(), location.end_byte())
+		});
+		SelectionRange {
+			start_byte,
+			end_byte,
+			start_point: range.start_point().map(Point::from),
+			end_point: range.end_point().map(Point::from),
+			parent: range
+				.parent()
+				.map(|parent| Box::new(SelectionRange::from(parent))),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct FoldingRangesResponse<'a> {
+	ranges: Vec<FoldingRange<'a>>,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct FoldingRange<'a> {
+	start_line: u32,
+	end_line: u32,
+	kind: &'a str,
+	level: u32,
+	start_byte: u32,
+	end_byte: u32,
+}
+
+#[derive(Serialize)]
+struct VerifyRewriteResponse<'a> {
+	ok: bool,
+	invariants: Vec<InvariantResult>,
+	documents: Vec<RewrittenDocument<'a>>,
+}
+
+#[derive(Serialize)]
+struct InvariantResult {
+	holds: bool,
+	matches_before: u32,
+	matches_after: u32,
+}
+
+#[derive(Serialize)]
+struct RewrittenDocument<'a> {
+	name: Option<&'a str>,
+	syntax_errors_before: u32,
+	syntax_errors_after: u32,
+	diagnostics: Vec<Diagnostic<'a>>,
+}
+
+#[derive(Serialize)]
+struct BlameResponse<'a> {
+	nodes: Vec<NodeBlame<'a>>,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct NodeBlame<'a> {
+	kind: &'a str,
+	start_byte: u32,
+	end_byte: u32,
+	start_row: u32,
+	end_row: u32,
+	last_commit: Option<BlameCommit<'a>>,
+	commits: Vec<BlameCommit<'a>>,
+	children: Option<Vec<NodeBlame<'a>>>,
+}
+
+impl<'a> From<fb::NodeBlame<'a>> for NodeBlame<'a> {
+	fn from(node: fb::NodeBlame<'a>) -> Self {
+		let (start_byte, end_byte) = node.location().map_or((0, 0), |location| {
+			(location.start_byte(), location.end_byte())
+		});
+		NodeBlame {
+			kind: node.kind(),
+			start_byte,
+			end_byte,
+			start_row: node.start_row(),
+			end_row: node.end_row(),
+			last_commit: node.last_commit().map(BlameCommit::from),
+			commits: node.commits().iter().map(BlameCommit::from).collect(),
+			children: node
+				.children()
+				.map(|children| children.iter().map(NodeBlame::from).collect()),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct BlameCommit<'a> {
+	commit: &'a str,
+	author: &'a str,
+	time: u64,
+	summary: &'a str,
+	lines: u32,
+}
+
+impl<'a> From<fb::BlameCommit<'a>> for BlameCommit<'a> {
+	fn from(commit: fb::BlameCommit<'a>) -> Self {
+		BlameCommit {
+			commit: commit.commit(),
+			author: commit.author().unwrap_or_default(),
+			time: commit.time(),
+			summary: commit.summary().unwrap_or_default(),
+			lines: commit.lines(),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct HistoryResponse<'a> {
+	commits: Vec<HistoryCommit<'a>>,
+}
+
+#[derive(Serialize)]
+struct HistoryCommit<'a> {
+	commit: &'a str,
+	author: &'a str,
+	time: u64,
+	summary: &'a str,
+	deleted: bool,
+	syntax_errors: u32,
+	changes: Vec<DefinitionChange<'a>>,
+}
+
+#[derive(Serialize)]
+struct DefinitionChange<'a> {
+	kind: &'a str,
+	name: &'a str,
+	symbol_kind: &'a str,
+	start_byte: u32,
+	end_byte: u32,
+	start_row: u32,
+	end_row: u32,
+}
+
+#[derive(Serialize)]
+struct SymbolsResponse<'a> {
+	symbols: Vec<Symbol<'a>>,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct Symbol<'a> {
+	name: &'a str,
+	kind: &'a str,
+	tags_kind: &'a str,
+	start_byte: u32,
+	end_byte: u32,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	start_point: Option<Point>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	end_point: Option<Point>,
+	name_start_byte: u32,
+	name_end_byte: u32,
+	children: Vec<Symbol<'a>>,
+}
+
+impl<'a> From<fb::Symbol<'a>> for Symbol<'a> {
+	fn from(symbol: fb::Symbol<'a>) -> Self {
+		let (start_byte, end_byte) = symbol.location().map_or((0, 0), |location| {
+			(location.start_byte(), location.end_byte())
+		});
+		let (name_start_byte, name_end_byte) = symbol.name_location().map_or((0, 0), |location| {
+			(location.start_byte(), location.end_byte())
+		});
+		Symbol {
+			name: symbol.name(),
+			kind: symbol.kind().variant_name().unwrap_or_default(),
+			tags_kind: symbol.tags_kind(),
+			start_byte,
+			end_byte,
+			start_point: symbol.start_point().map(Point::from),
+			end_point: symbol.end_point().map(Point::from),
+			name_start_byte,
+			name_end_byte,
+			children: symbol
+				.children()
+				.into_iter()
+				.flatten()
+				.map(Symbol::from)
+				.collect(),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct ClonesResponse<'a> {
+	groups: Vec<CloneGroup<'a>>,
+}
+
+#[derive(Serialize)]
+struct CloneGroup<'a> {
+	hash: u64,
+	node_count: u32,
+	instances: Vec<CloneInstance<'a>>,
+}
+
+#[derive(Serialize)]
+struct CloneInstance<'a> {
+	path: &'a str,
+	start_byte: u32,
+	end_byte: u32,
+	kind: &'a str,
+	start_row: u32,
+	end_row: u32,
+}
+
+#[derive(Serialize)]
+struct DirectoryListing<'a> {
+	entries: Vec<DirectoryEntry<'a>>,
+	truncated: bool,
+}
+
+#[derive(Serialize)]
+struct DirectoryEntry<'a> {
+	path: &'a str,
+	language: &'a str,
+	file_size: u64,
+}
+
+#[derive(Serialize)]
+struct PluginResponse<'a> {
+	#[serde(serialize_with = "bytes")]
+	payload: &'a [u8],
+}
+
+#[cfg(feature = "wasm")]
+#[derive(Serialize)]
+struct RunPluginResponse {
+	version: u32,
+	content_hash: u64,
+	results: ciborium::Value,
+}
+
+#[cfg(feature = "scripting")]
+#[derive(Serialize)]
+struct ScriptResponse<'a> {
+	version: u32,
+	content_hash: u64,
+	output: Vec<&'a str>,
+	result: ciborium::Value,
+}
+
+#[derive(Serialize)]
+struct QueryResults<'a> {
+	matches: Vec<QueryMatch<'a>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	cursor: Option<&'a str>,
+	done: bool,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	counts: Option<Vec<QueryFileCount<'a>>>,
+}
+
+#[derive(Serialize)]
+struct QueryFileCount<'a> {
+	path: &'a str,
+	matches: u32,
+	captures: Vec<QueryCaptureCount<'a>>,
+}
+
+#[derive(Serialize)]
+struct QueryCaptureCount<'a> {
+	name: &'a str,
+	count: u32,
+}
+
+#[derive(Serialize)]
+struct QueryMatch<'a> {
+	path: &'a str,
+	pattern_index: u32,
+	captures: Vec<QueryCapture<'a>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	groups: Option<Vec<QueryCaptureGroup<'a>>>,
+}
+
+#[derive(Serialize)]
+struct QueryCaptureGroup<'a> {
+	name: &'a str,
+	quantifier: &'a str,
+	captures: Vec<QueryCapture<'a>>,
+}
+
+#[derive(Serialize)]
+struct QueryCapture<'a> {
+	name: &'a str,
+	start_byte: u32,
+	end_byte: u32,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	start_point: Option<Point>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	end_point: Option<Point>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	text: Option<String>,
+}
+
+impl<'a> From<fb::QueryCapture<'a>> for QueryCapture<'a> {
+	fn from(capture: fb::QueryCapture<'a>) -> Self {
+		let (start_byte, end_byte) = capture.location().map_or((0, 0), |location| {
+			(location.start_byte(), location.end_byte())
+		});
+		QueryCapture {
+			name: capture.name(),
+			start_byte,
+			end_byte,
+			start_point: capture.start_point().map(Point::from),
+			end_point: capture.end_point().map(Point::from),
+			text: capture
+				.text()
+				.map(|text| String::from_utf16_lossy(&text.iter().collect::<Vec<_>>())),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct CancelResponse {
+	running: bool,
+}
+
+#[derive(Serialize)]
+struct ResumeResponse<'a> {
+	token: &'a str,
+	resumed: bool,
+	documents: Vec<OpenDocument<'a>>,
+	changed: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+struct OpenDocument<'a> {
+	path: &'a str,
+	language: &'a str,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct TransactionResponse<'a> {
+	documents: Vec<EditedDocument<'a>>,
+}
+
+#[derive(Serialize)]
+struct EditedDocument<'a> {
+	path: &'a str,
+	version: u32,
+	content_hash: u64,
+}
+
+#[derive(Serialize)]
+struct NodeEditResponse<'a> {
+	edits: Vec<TextEdit>,
+	file: FileResponse<'a>,
+}
+
+#[derive(Serialize)]
+struct PlaygroundResponse<'a> {
+	file: FileResponse<'a>,
+	diagnostics: Vec<Diagnostic<'a>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	highlights: Option<Vec<HighlightSpan<'a>>>,
+}
+
+#[derive(Serialize)]
+struct Diagnostic<'a> {
+	start_byte: u32,
+	end_byte: u32,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	start_point: Option<Point>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	end_point: Option<Point>,
+	message: &'a str,
+}
+
+impl<'a> From<fb::Diagnostic<'a>> for Diagnostic<'a> {
+	fn from(diagnostic: fb::Diagnostic<'a>) -> Self {
+		let (start_byte, end_byte) = diagnostic.location().map_or((0, 0), |location| {
+			(location.start_byte(), location.end_byte())
+		});
+		Diagnostic {
+			start_byte,
+			end_byte,
+			start_point: diagnostic.start_point().map(Point::from),
+			end_point: diagnostic.end_point().map(Point::from),
+			message: diagnostic.message(),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct HighlightSpan<'a> {
+	capture: &'a str,
+	start_byte: u32,
+	end_byte: u32,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	start_point: Option<Point>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	end_point: Option<Point>,
+}
+
+#[derive(Serialize)]
+struct TextEdit {
+	start_byte: u32,
+	old_end_byte: u32,
+	text: String,
+}
+
+/// Serializes as a byte string rather than a sequence of numbers, since MessagePack and CBOR have
+/// a type for those.
+fn bytes<S: serde::Serializer>(
+	bytes: &&[u8],
+	serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+	serializer.serialize_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use axum::body::HttpBody;
+
+	use super::*;
+	use crate::{
+		message_generated::asted::interface::SerializationProfile,
+		text::Text,
+		tree_serialize::{self, ParseInfo},
+	};
+
+	#[tokio::test]
+	async fn streams_large_trees_in_chunks() {
+		let source = (0..5_000)
+			.map(|i| format!("int f{}(int x) {{ return x + {}; }}\n", i, i))
+			.collect::<String>();
+		let text = Text::from_utf16(&source.encode_utf16().collect::<Vec<_>>()).unwrap();
+		let mut parser = tree_sitter::Parser::new();
+		parser.set_language(tree_sitter_cpp::language()).unwrap();
+		let tree = text.parse(&mut parser, None).unwrap();
+		let parse_info = ParseInfo {
+			duration: Duration::ZERO,
+			reused_old_tree: false,
+			cached_tree: false,
+			changed_ranges: Vec::new(),
+			version: 1,
+			content_hash: text.content_hash(),
+			mtime: None,
+		};
+		let options = tree_serialize::Options::new(SerializationProfile::Default, false);
+		let serialized = tree_serialize::serialize(&text, &tree, &parse_info, options);
+		assert!(serialized.len() > 4 * STREAM_CHUNK);
+
+		let res = Encoding::Flatbuffers
+			.file_response(serialized.clone())
+			.unwrap();
+		assert_eq!(
+			res.headers()[CONTENT_LENGTH],
+			serialized.len().to_string().as_str()
+		);
+		let mut body = res.into_body();
+		let mut received = Vec::new();
+		let mut chunks = 0;
+		while let Some(chunk) = body.data().await {
+			let chunk = chunk.unwrap();
+			assert!(chunk.len() <= STREAM_CHUNK);
+			received.extend_from_slice(&chunk);
+			chunks += 1;
+		}
+		assert!(chunks > 4);
+		assert_eq!(received, serialized);
+	}
+}
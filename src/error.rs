@@ -0,0 +1,116 @@
+use axum::{
+	http::{header::RETRY_AFTER, HeaderValue, StatusCode},
+	response::{IntoResponse, Response},
+};
+
+use crate::message_generated::asted::interface::{ErrorCode, ErrorResponse, ErrorResponseArgs};
+
+#[derive(Debug)]
+pub enum Error {
+	Cancelled(String),
+	Conflict(String),
+	Forbidden(String),
+	Internal(String),
+	InvalidRequest(String),
+	PayloadTooLarge(String),
+	/// Carries the number of seconds the client should wait before retrying
+	TooManyRequests(String, u64),
+	/// Work on the request took longer than allowed
+	Timeout(String),
+	Unauthorized(String),
+	UnknownCommand(String),
+	UnknownLanguage(String),
+	UnknownFile(String),
+}
+
+impl Error {
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Error::Cancelled(_) => ErrorCode::Cancelled,
+			Error::Conflict(_) => ErrorCode::Conflict,
+			Error::Forbidden(_) => ErrorCode::Forbidden,
+			Error::Internal(_) => ErrorCode::Internal,
+			Error::InvalidRequest(_) => ErrorCode::InvalidRequest,
+			Error::PayloadTooLarge(_) => ErrorCode::PayloadTooLarge,
+			Error::TooManyRequests(..) => ErrorCode::TooManyRequests,
+			Error::Timeout(_) => ErrorCode::Timeout,
+			Error::Unauthorized(_) => ErrorCode::Unauthorized,
+			Error::UnknownCommand(_) => ErrorCode::UnknownCommand,
+			Error::UnknownLanguage(_) => ErrorCode::UnknownLanguage,
+			Error::UnknownFile(_) => ErrorCode::UnknownFile,
+		}
+	}
+}
+
+impl std::fmt::Display for Error {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Error::Cancelled(s) => write!(f, "{}", s),
+			Error::Conflict(s) => write!(f, "{}", s),
+			Error::Forbidden(s) => write!(f, "{}", s),
+			Error::Internal(s) => write!(f, "{}", s),
+			Error::InvalidRequest(s) => write!(f, "{}", s),
+			Error::PayloadTooLarge(s) => write!(f, "{}", s),
+			Error::TooManyRequests(s, _) => write!(f, "{}", s),
+			Error::Timeout(s) => write!(f, "{}", s),
+			Error::Unauthorized(s) => write!(f, "{}", s),
+			Error::UnknownCommand(s) => write!(f, "{}", s),
+			Error::UnknownLanguage(s) => write!(f, "{}", s),
+			Error::UnknownFile(s) => write!(f, "{}", s),
+		}
+	}
+}
+
+impl IntoResponse for Error {
+	fn into_response(self) -> Response {
+		let retry_after = match self {
+			Error::TooManyRequests(_, secs) => Some(secs),
+			_ => None,
+		};
+		let status = match self {
+			// what Google's APIs answer cancelled requests with
+			Error::Cancelled(_) => StatusCode::from_u16(499).unwrap(),
+			Error::Conflict(_) => StatusCode::CONFLICT,
+			Error::Forbidden(_) => StatusCode::FORBIDDEN,
+			Error::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+			Error::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+			Error::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+			Error::TooManyRequests(..) => StatusCode::TOO_MANY_REQUESTS,
+			Error::Timeout(_) => StatusCode::GATEWAY_TIMEOUT,
+			Error::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+			Error::UnknownCommand(_) => StatusCode::BAD_REQUEST,
+			Error::UnknownLanguage(_) => StatusCode::BAD_REQUEST,
+			Error::UnknownFile(_) => StatusCode::BAD_REQUEST,
+		};
+
+		let mut builder = flatbuffers::FlatBufferBuilder::new();
+		let message = builder.create_string(&self.to_string());
+		let resp = ErrorResponse::create(
+			&mut builder,
+			&ErrorResponseArgs {
+				code: self.code(),
+				message: Some(message),
+			},
+		);
+		builder.finish(resp, None);
+
+		let mut res = (status, builder.finished_data().to_vec()).into_response();
+		if let Some(secs) = retry_after {
+			res.headers_mut()
+				.insert(RETRY_AFTER, HeaderValue::from(secs));
+		}
+		res
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<anyhow::Error> for Error {
+	/// Keeps errors raised as an `Error` intact and treats anything else as an internal error.
+	fn from(e: anyhow::Error) -> Self {
+		match e.downcast::<Error>() {
+			Ok(e) => e,
+			Err(e) => Error::Internal(format!("{:#}", e)),
+		}
+	}
+}
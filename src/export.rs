@@ -0,0 +1,590 @@
+use std::{
+	fs::File,
+	io::{self, BufWriter, Write},
+	ops::ControlFlow,
+	path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use tree_sitter::{Point, Tree};
+
+use crate::{
+	languages,
+	text::Text,
+	todos,
+	workspace::{self, Filter},
+	workspace_config,
+};
+
+// Subcommands that parse a whole directory without a server and write the trees out for other
+// tools. (Not a doc comment, which clap would use to describe the whole program.)
+#[derive(clap::Subcommand)]
+pub enum ExportCommand {
+	/// Write one JSON line per node of every file under a directory
+	Export {
+		dir: PathBuf,
+		/// The file to write to, instead of stdout
+		#[arg(long)]
+		out: Option<PathBuf>,
+		/// Only write the top-level constructs of each file (with their full text), instead of
+		/// every node
+		#[arg(long)]
+		top_level: bool,
+		#[command(flatten)]
+		options: Options,
+	},
+	/// Print the TODO, FIXME and HACK comments of every file under a directory
+	Todos {
+		dir: PathBuf,
+		/// A marker to look for instead of the default ones; can be given more than once
+		#[arg(long)]
+		marker: Vec<String>,
+		#[command(flatten)]
+		options: Options,
+	},
+	/// Write the node table of every file under a directory as an Arrow IPC file
+	#[cfg(feature = "arrow")]
+	ExportArrow {
+		dir: PathBuf,
+		out: PathBuf,
+		#[command(flatten)]
+		options: Options,
+	},
+	/// Index every file under a directory into a SQLite database, with `files`, `nodes`,
+	/// `symbols` and `refs` (references) tables
+	#[cfg(feature = "sqlite")]
+	ExportSqlite {
+		dir: PathBuf,
+		out: PathBuf,
+		#[command(flatten)]
+		options: Options,
+	},
+}
+
+#[derive(clap::Args)]
+pub struct Options {
+	/// The language to parse every file as, instead of picking one by file extension
+	#[arg(long)]
+	lang: Option<String>,
+	/// Only take in files matching this glob, or in a directory that does; can be given more than
+	/// once. A glob without a / matches names at any depth, like in .gitignore
+	#[arg(long)]
+	include: Vec<String>,
+	/// Leave out files and directories matching this glob; can be given more than once
+	#[arg(long)]
+	exclude: Vec<String>,
+	/// Take in files ignored by a .gitignore
+	#[arg(long)]
+	include_ignored: bool,
+}
+
+pub fn run(command: ExportCommand) -> Result<()> {
+	match command {
+		ExportCommand::Export {
+			dir,
+			out,
+			top_level,
+			options,
+		} => {
+			let count = match &out {
+				Some(out) => {
+					let file = File::create(out)
+						.with_context(|| format!("Error creating {}", out.display()))?;
+					jsonl(&dir, &options, top_level, BufWriter::new(file))?
+				}
+				None => jsonl(
+					&dir,
+					&options,
+					top_level,
+					BufWriter::new(io::stdout().lock()),
+				)?,
+			};
+			// stdout may be the export itself
+			eprintln!("exported {} files", count);
+			Ok(())
+		}
+		ExportCommand::Todos {
+			dir,
+			mut marker,
+			options,
+		} => {
+			if marker.is_empty() {
+				marker = todos::DEFAULT_MARKERS
+					.iter()
+					.map(|m| m.to_string())
+					.collect();
+			}
+			let mut out = BufWriter::new(io::stdout().lock());
+			walk(&dir, &options, |parsed| {
+				for todo in todos::scan(&parsed.lang, &parsed.tree, &parsed.text, &marker) {
+					write!(
+						out,
+						"{}:{}: {}",
+						parsed.path.display(),
+						todo.row + 1,
+						todo.marker
+					)?;
+					if let Some(author) = &todo.author {
+						write!(out, "({})", author)?;
+					}
+					if let Some(context) = &todo.context {
+						write!(out, " [{}]", context)?;
+					}
+					writeln!(out, " {}", todo.message)?;
+				}
+				Ok(())
+			})?;
+			out.flush()?;
+			Ok(())
+		}
+		#[cfg(feature = "arrow")]
+		ExportCommand::ExportArrow { dir, out, options } => {
+			let count = arrow::export(&dir, &out, &options)?;
+			println!("exported {} files to {}", count, out.display());
+			Ok(())
+		}
+		#[cfg(feature = "sqlite")]
+		ExportCommand::ExportSqlite { dir, out, options } => {
+			let count = sqlite::export(&dir, &out, &options)?;
+			println!("exported {} files to {}", count, out.display());
+			Ok(())
+		}
+	}
+}
+
+/// A file under the exported directory, parsed.
+pub struct ParsedFile {
+	/// Relative to the exported directory
+	pub path: PathBuf,
+	pub lang: String,
+	pub text: Text,
+	pub tree: Tree,
+}
+
+/// Parses every file under `dir` that has a grammar and that the options' filter takes in, on the
+/// worker threads, and hands them to `f` one at a time. Returns how many files were parsed.
+pub fn walk(
+	dir: &Path,
+	options: &Options,
+	mut f: impl FnMut(ParsedFile) -> Result<()>,
+) -> Result<usize> {
+	let files = files(dir, options)?;
+	workspace::parse_all(&files, |parsed| {
+		let parsed = parsed?;
+		f(ParsedFile {
+			path: parsed
+				.path
+				.strip_prefix(dir)
+				.unwrap_or(&parsed.path)
+				.to_path_buf(),
+			lang: parsed.lang,
+			text: parsed.text,
+			tree: parsed.tree,
+		})
+	})?;
+	Ok(files.len())
+}
+
+/// The files under `dir` that have a grammar and that the options' filter takes in, with their
+/// languages.
+pub fn files(dir: &Path, options: &Options) -> Result<Vec<(PathBuf, String)>> {
+	let filter = Filter::new(&options.include, &options.exclude, options.include_ignored)?;
+	let mut files = Vec::new();
+	// never stopped early
+	let _ = filter.walk(dir, None, |path, _| {
+		if let Some(lang) = language_for(path, options) {
+			files.push((path.to_path_buf(), lang));
+		}
+		Ok(ControlFlow::Continue(()))
+	})?;
+	Ok(files)
+}
+
+/// The language to parse the file at `path` as, if it has a grammar.
+pub fn language_for(path: &Path, options: &Options) -> Option<String> {
+	let lang = match &options.lang {
+		Some(lang) => lang.clone(),
+		None => workspace_config::language_for(path)?,
+	};
+	languages::get(&lang)?;
+	Some(lang)
+}
+
+/// A row of the flat node table: nodes in pre-order, each pointing at its parent's index.
+pub struct NodeRow {
+	pub id: u32,
+	/// tree-sitter's ID for the node, for finding the rows of query captures
+	#[cfg_attr(not(feature = "sqlite"), allow(dead_code))]
+	pub node_id: usize,
+	pub parent: Option<u32>,
+	pub kind: &'static str,
+	pub named: bool,
+	/// An ERROR or MISSING node
+	pub error: bool,
+	/// Byte offsets into the UTF-16 text, like the HTTP interface reports
+	pub start_byte: u32,
+	pub end_byte: u32,
+	pub start_point: Point,
+	pub end_point: Point,
+	pub leaf: bool,
+}
+
+pub fn nodes(tree: &Tree) -> Vec<NodeRow> {
+	let mut rows = Vec::new();
+	let mut parents = Vec::new();
+	let mut cursor = tree.walk();
+	loop {
+		let node = cursor.node();
+		let id = rows.len() as u32;
+		rows.push(NodeRow {
+			id,
+			node_id: node.id(),
+			parent: parents.last().copied(),
+			kind: node.kind(),
+			named: node.is_named(),
+			error: node.is_error() || node.is_missing(),
+			start_byte: node.start_byte() as u32,
+			end_byte: node.end_byte() as u32,
+			start_point: node.start_position(),
+			end_point: node.end_position(),
+			leaf: node.child_count() == 0,
+		});
+
+		if cursor.goto_first_child() {
+			parents.push(id);
+			continue;
+		}
+		while !cursor.goto_next_sibling() {
+			if !cursor.goto_parent() {
+				return rows;
+			}
+			parents.pop();
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct Line<'a> {
+	file: &'a str,
+	lang: &'a str,
+	/// Only set when exporting every node, for finding a node's parent (in the same file)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	id: Option<u32>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	parent: Option<u32>,
+	kind: &'a str,
+	named: bool,
+	error: bool,
+	start_byte: u32,
+	end_byte: u32,
+	start_point: LinePoint,
+	end_point: LinePoint,
+	/// Every node would repeat the text of all of its children, so only leaves and top-level
+	/// constructs carry it
+	#[serde(skip_serializing_if = "Option::is_none")]
+	text: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct LinePoint {
+	row: usize,
+	column: usize,
+}
+
+impl From<Point> for LinePoint {
+	fn from(point: Point) -> Self {
+		LinePoint {
+			row: point.row,
+			column: point.column,
+		}
+	}
+}
+
+/// Writes a JSON line for every node (or just the top-level constructs) of every file under
+/// `dir`, returning how many files were exported.
+fn jsonl(dir: &Path, options: &Options, top_level: bool, mut out: impl Write) -> Result<usize> {
+	let count = walk(dir, options, |parsed| {
+		let file = parsed.path.to_string_lossy();
+		let text = |start: u32, end: u32| {
+			String::from_utf16_lossy(&parsed.text.slice(start as usize / 2..end as usize / 2))
+		};
+
+		if top_level {
+			let root = parsed.tree.root_node();
+			let mut cursor = root.walk();
+			for node in root.named_children(&mut cursor) {
+				let line = Line {
+					file: &file,
+					lang: &parsed.lang,
+					id: None,
+					parent: None,
+					kind: node.kind(),
+					named: true,
+					error: node.is_error() || node.is_missing(),
+					start_byte: node.start_byte() as u32,
+					end_byte: node.end_byte() as u32,
+					start_point: node.start_position().into(),
+					end_point: node.end_position().into(),
+					text: Some(text(node.start_byte() as u32, node.end_byte() as u32)),
+				};
+				write_line(&mut out, &line)?;
+			}
+			return Ok(());
+		}
+
+		for row in nodes(&parsed.tree) {
+			let line = Line {
+				file: &file,
+				lang: &parsed.lang,
+				id: Some(row.id),
+				parent: row.parent,
+				kind: row.kind,
+				named: row.named,
+				error: row.error,
+				start_byte: row.start_byte,
+				end_byte: row.end_byte,
+				start_point: row.start_point.into(),
+				end_point: row.end_point.into(),
+				text: row.leaf.then(|| text(row.start_byte, row.end_byte)),
+			};
+			write_line(&mut out, &line)?;
+		}
+		Ok(())
+	})?;
+	out.flush()?;
+	Ok(count)
+}
+
+fn write_line(out: &mut impl Write, line: &Line) -> Result<()> {
+	serde_json::to_writer(&mut *out, line)?;
+	Ok(out.write_all(b"\n")?)
+}
+
+#[cfg(feature = "arrow")]
+mod arrow {
+	use std::{fs::File, io::BufWriter, path::Path, sync::Arc};
+
+	use anyhow::{Context, Result};
+	use arrow_array::{
+		builder::{BooleanBuilder, StringBuilder, UInt32Builder},
+		ArrayRef, RecordBatch,
+	};
+	use arrow_ipc::writer::FileWriter;
+	use arrow_schema::{DataType, Field, Schema};
+
+	use super::Options;
+
+	/// Rows are written in batches of about this many, so memory use doesn't grow with the
+	/// workspace.
+	const BATCH_ROWS: usize = 64 * 1024;
+
+	struct Builders {
+		file: StringBuilder,
+		lang: StringBuilder,
+		id: UInt32Builder,
+		parent: UInt32Builder,
+		kind: StringBuilder,
+		named: BooleanBuilder,
+		error: BooleanBuilder,
+		start_byte: UInt32Builder,
+		end_byte: UInt32Builder,
+		rows: usize,
+	}
+
+	impl Builders {
+		fn new() -> Self {
+			Builders {
+				file: StringBuilder::new(),
+				lang: StringBuilder::new(),
+				id: UInt32Builder::new(),
+				parent: UInt32Builder::new(),
+				kind: StringBuilder::new(),
+				named: BooleanBuilder::new(),
+				error: BooleanBuilder::new(),
+				start_byte: UInt32Builder::new(),
+				end_byte: UInt32Builder::new(),
+				rows: 0,
+			}
+		}
+
+		fn finish(&mut self, schema: &Arc<Schema>) -> Result<RecordBatch> {
+			self.rows = 0;
+			let columns: Vec<ArrayRef> = vec![
+				Arc::new(self.file.finish()),
+				Arc::new(self.lang.finish()),
+				Arc::new(self.id.finish()),
+				Arc::new(self.parent.finish()),
+				Arc::new(self.kind.finish()),
+				Arc::new(self.named.finish()),
+				Arc::new(self.error.finish()),
+				Arc::new(self.start_byte.finish()),
+				Arc::new(self.end_byte.finish()),
+			];
+			Ok(RecordBatch::try_new(schema.clone(), columns)?)
+		}
+	}
+
+	fn schema() -> Schema {
+		// not dictionary-encoded, since IPC files can't grow a dictionary from batch to batch
+		Schema::new(vec![
+			Field::new("file", DataType::Utf8, false),
+			Field::new("lang", DataType::Utf8, false),
+			Field::new("id", DataType::UInt32, false),
+			Field::new("parent", DataType::UInt32, true),
+			Field::new("kind", DataType::Utf8, false),
+			Field::new("named", DataType::Boolean, false),
+			Field::new("error", DataType::Boolean, false),
+			Field::new("start_byte", DataType::UInt32, false),
+			Field::new("end_byte", DataType::UInt32, false),
+		])
+	}
+
+	/// Writes one row per node of every file under `dir` to `out`. Node IDs are only unique
+	/// within a file, so joining a node to its parent takes both `file` and `id`.
+	pub fn export(dir: &Path, out: &Path, options: &Options) -> Result<usize> {
+		let schema = Arc::new(schema());
+		let file =
+			File::create(out).with_context(|| format!("Error creating {}", out.display()))?;
+		let mut writer = FileWriter::try_new(BufWriter::new(file), &schema)?;
+		let mut builders = Builders::new();
+
+		let count = super::walk(dir, options, |parsed| {
+			let path = parsed.path.to_string_lossy();
+			for row in super::nodes(&parsed.tree) {
+				builders.file.append_value(&path);
+				builders.lang.append_value(&parsed.lang);
+				builders.id.append_value(row.id);
+				builders.parent.append_option(row.parent);
+				builders.kind.append_value(row.kind);
+				builders.named.append_value(row.named);
+				builders.error.append_value(row.error);
+				builders.start_byte.append_value(row.start_byte);
+				builders.end_byte.append_value(row.end_byte);
+				builders.rows += 1;
+			}
+			if builders.rows >= BATCH_ROWS {
+				writer.write(&builders.finish(&schema)?)?;
+			}
+			Ok(())
+		})?;
+
+		if builders.rows > 0 {
+			writer.write(&builders.finish(&schema)?)?;
+		}
+		writer.finish()?;
+		Ok(count)
+	}
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+	use std::{collections::HashMap, fs, io, path::Path};
+
+	use anyhow::{Context, Result};
+	use rusqlite::{params, Connection};
+
+	use super::Options;
+	use crate::queries;
+
+	/// Node IDs are only unique within a file, so nodes are identified by `file_id` and `id`.
+	/// Symbols and references point at the node of the whole construct (like a function
+	/// declaration or a call), and carry the name they define or use.
+	const SCHEMA: &str = "
+		CREATE TABLE files (
+			id INTEGER PRIMARY KEY,
+			path TEXT NOT NULL UNIQUE,
+			lang TEXT NOT NULL
+		);
+		CREATE TABLE nodes (
+			file_id INTEGER NOT NULL REFERENCES files (id),
+			id INTEGER NOT NULL,
+			parent INTEGER,
+			kind TEXT NOT NULL,
+			named INTEGER NOT NULL,
+			error INTEGER NOT NULL,
+			start_byte INTEGER NOT NULL,
+			end_byte INTEGER NOT NULL,
+			start_row INTEGER NOT NULL,
+			start_column INTEGER NOT NULL,
+			end_row INTEGER NOT NULL,
+			end_column INTEGER NOT NULL,
+			PRIMARY KEY (file_id, id)
+		);
+		CREATE TABLE symbols (
+			file_id INTEGER NOT NULL REFERENCES files (id),
+			node_id INTEGER NOT NULL,
+			name TEXT NOT NULL,
+			kind TEXT NOT NULL
+		);
+		CREATE TABLE refs (
+			file_id INTEGER NOT NULL REFERENCES files (id),
+			node_id INTEGER NOT NULL,
+			name TEXT NOT NULL,
+			kind TEXT NOT NULL
+		);
+		CREATE INDEX symbols_name ON symbols (name);
+		CREATE INDEX refs_name ON refs (name);
+	";
+
+	/// Writes every file under `dir` to a new database at `out`, replacing any file there.
+	pub fn export(dir: &Path, out: &Path, options: &Options) -> Result<usize> {
+		match fs::remove_file(out) {
+			Err(e) if e.kind() != io::ErrorKind::NotFound => {
+				return Err(e).with_context(|| format!("Error replacing {}", out.display()))
+			}
+			_ => {}
+		}
+		let mut db =
+			Connection::open(out).with_context(|| format!("Error creating {}", out.display()))?;
+		db.execute_batch(SCHEMA)?;
+
+		// one transaction for the whole export, since committing every row is very slow
+		let tx = db.transaction()?;
+		let count = {
+			let mut insert_file = tx.prepare("INSERT INTO files (path, lang) VALUES (?1, ?2)")?;
+			let mut insert_node = tx.prepare(
+				"INSERT INTO nodes VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+			)?;
+			let mut insert_symbol = tx.prepare("INSERT INTO symbols VALUES (?1, ?2, ?3, ?4)")?;
+			let mut insert_ref = tx.prepare("INSERT INTO refs VALUES (?1, ?2, ?3, ?4)")?;
+
+			super::walk(dir, options, |parsed| {
+				insert_file.execute(params![parsed.path.to_string_lossy(), parsed.lang])?;
+				let file_id = tx.last_insert_rowid();
+
+				let mut ids = HashMap::new();
+				for row in super::nodes(&parsed.tree) {
+					ids.insert(row.node_id, row.id);
+					insert_node.execute(params![
+						file_id,
+						row.id,
+						row.parent,
+						row.kind,
+						row.named,
+						row.error,
+						row.start_byte,
+						row.end_byte,
+						row.start_point.row,
+						row.start_point.column,
+						row.end_point.row,
+						row.end_point.column,
+					])?;
+				}
+
+				for tag in queries::tags(&parsed.lang, &parsed.tree, &parsed.text) {
+					let insert = if tag.definition {
+						&mut insert_symbol
+					} else {
+						&mut insert_ref
+					};
+					let name = tag.name_text(&parsed.text);
+					insert.execute(params![file_id, ids[&tag.node.id()], name, tag.kind])?;
+				}
+				Ok(())
+			})?
+		};
+		tx.commit()?;
+		Ok(count)
+	}
+}
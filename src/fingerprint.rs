@@ -0,0 +1,84 @@
+use std::{
+	collections::{hash_map::DefaultHasher, HashMap},
+	hash::Hasher,
+};
+
+use tree_sitter::Tree;
+
+use crate::text::Text;
+
+/// What structural hashes leave out, so code that only differs in those parts hashes the same.
+#[derive(Clone, Copy, Default)]
+pub struct Normalize {
+	pub identifiers: bool,
+	pub literals: bool,
+}
+
+#[derive(Clone, Copy)]
+pub struct Fingerprint {
+	/// Combines the node's kind with the hashes of its children, or its text for leaves. Like
+	/// content hashes, these are only meant to be compared against others from the same server.
+	pub hash: u64,
+	/// How many nodes the subtree has, counting the node itself
+	pub size: u32,
+}
+
+fn is_identifier(kind: &str) -> bool {
+	kind.contains("identifier")
+}
+
+fn is_literal(kind: &str) -> bool {
+	kind.contains("string")
+		|| kind.contains("number")
+		|| kind.ends_with("literal")
+		|| matches!(kind, "true" | "false")
+}
+
+/// The fingerprints of the nodes of `tree`, by node ID. Nodes inside a normalized-away literal
+/// (like the fragments of a string) have none, since the literal is hashed as a whole.
+pub fn fingerprints(tree: &Tree, text: &Text, normalize: Normalize) -> HashMap<usize, Fingerprint> {
+	let mut fingerprints = HashMap::new();
+	// the hashers of the nodes being descended into, and the sizes of their subtrees so far
+	let mut stack = Vec::<(DefaultHasher, u32)>::new();
+	let mut cursor = tree.walk();
+	loop {
+		let node = cursor.node();
+		let mut hasher = DefaultHasher::new();
+		hasher.write_u16(node.kind_id());
+		let opaque = (normalize.identifiers && is_identifier(node.kind()))
+			|| (normalize.literals && is_literal(node.kind()));
+		if !opaque && cursor.goto_first_child() {
+			stack.push((hasher, 1));
+			continue;
+		}
+		if !opaque {
+			for chunk in text.chunks(node.start_byte() / 2..node.end_byte() / 2) {
+				hasher.write(chunk);
+			}
+		}
+
+		// finish the leaf, and every parent whose last child it was
+		let mut fingerprint = Fingerprint {
+			hash: hasher.finish(),
+			size: 1,
+		};
+		loop {
+			fingerprints.insert(cursor.node().id(), fingerprint);
+			if let Some((parent, size)) = stack.last_mut() {
+				parent.write_u64(fingerprint.hash);
+				*size += fingerprint.size;
+			}
+			if cursor.goto_next_sibling() {
+				break;
+			}
+			if !cursor.goto_parent() {
+				return fingerprints;
+			}
+			let (hasher, size) = stack.pop().unwrap();
+			fingerprint = Fingerprint {
+				hash: hasher.finish(),
+				size,
+			};
+		}
+	}
+}
@@ -0,0 +1,30 @@
+use std::hash::Hasher;
+
+/// The 64-bit FNV-1a hash, which unlike `DefaultHasher` hashes the same in every build of the
+/// server, and is simple enough for clients to check against.
+pub struct Fnv(u64);
+
+impl Default for Fnv {
+	fn default() -> Self {
+		Fnv(0xcbf29ce484222325)
+	}
+}
+
+impl Hasher for Fnv {
+	fn write(&mut self, bytes: &[u8]) {
+		for &byte in bytes {
+			self.0 = (self.0 ^ byte as u64).wrapping_mul(0x100000001b3);
+		}
+	}
+
+	fn finish(&self) -> u64 {
+		self.0
+	}
+}
+
+/// The FNV-1a hash of `bytes`.
+pub fn hash(bytes: &[u8]) -> u64 {
+	let mut hasher = Fnv::default();
+	hasher.write(bytes);
+	hasher.finish()
+}
@@ -0,0 +1,225 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use flatbuffers::FlatBufferBuilder;
+use tree_sitter::{Node, Query, QueryCursor};
+
+use crate::{
+	document::Document,
+	message_generated::asted::interface::{
+		FoldKind, FoldingRange, FoldingRangeArgs, FoldingRangesResponse, FoldingRangesResponseArgs,
+		Location,
+	},
+	queries::{self, Kind},
+};
+
+/// A range of lines to fold, from the line after `start_line` to `end_line`.
+pub struct Fold {
+	pub start_line: usize,
+	pub end_line: usize,
+	pub kind: FoldKind,
+	/// How many of the other folds it's within
+	pub level: usize,
+	pub start_byte: usize,
+	pub end_byte: usize,
+}
+
+impl Fold {
+	fn new(start: Node, end: Node, kind: FoldKind, end_line: usize) -> Self {
+		Fold {
+			start_line: start.start_position().row,
+			end_line,
+			kind,
+			level: 0,
+			start_byte: start.start_byte(),
+			end_byte: end.end_byte(),
+		}
+	}
+}
+
+/// The folds of `document`, found by the folds query of its language, or else one for every named
+/// node (and comment) spanning several lines. They're ordered by start line, with only the
+/// outermost of those starting on the same line, since editors fold by line.
+pub fn folds(document: &Document) -> Vec<Fold> {
+	let mut folds = match queries::get(&document.language, document.tree.language(), Kind::Folds) {
+		Some(query) => captured_folds(document, query),
+		None => node_folds(document),
+	};
+	folds.retain(|fold| fold.end_line > fold.start_line);
+	folds.sort_by_key(|fold| (fold.start_line, Reverse(fold.end_line)));
+	folds.dedup_by_key(|fold| fold.start_line);
+
+	// the last lines of the folds around the current one, innermost last
+	let mut around = Vec::<usize>::new();
+	for fold in &mut folds {
+		while around
+			.last()
+			.is_some_and(|&end_line| end_line < fold.start_line)
+		{
+			around.pop();
+		}
+		fold.level = around.len();
+		around.push(fold.end_line);
+	}
+	folds
+}
+
+fn captured_folds(document: &Document, query: &Query) -> Vec<Fold> {
+	let text = &document.text;
+	let names = query.capture_names();
+	let mut cursor = QueryCursor::new();
+	let matches = cursor.matches(query, document.tree.root_node(), |node: Node| {
+		text.chunks(node.start_byte() / 2..node.end_byte() / 2)
+	});
+	let mut captured = Vec::new();
+	for m in matches {
+		for capture in m.captures {
+			let name = names[capture.index as usize].as_str();
+			if name == "fold" || name.starts_with("fold.") {
+				captured.push((capture.node, name));
+			}
+		}
+	}
+	captured.sort_by_key(|(node, _)| node.start_byte());
+	// region markers are usually comments, which the comment pattern captures too
+	let markers = captured
+		.iter()
+		.filter(|(_, name)| name.starts_with("fold.region."))
+		.map(|(node, _)| node.id())
+		.collect::<Vec<_>>();
+
+	let mut folds = Vec::<Fold>::new();
+	// the starts of the regions the current node is in, innermost last
+	let mut regions = Vec::new();
+	// the index of the last comment fold and of the last imports fold, to add the next ones to
+	let mut runs = HashMap::new();
+	for (node, name) in captured {
+		let kind = match name {
+			"fold.region.start" => {
+				regions.push(node);
+				continue;
+			}
+			"fold.region.end" => {
+				if let Some(start) = regions.pop() {
+					folds.push(Fold::new(start, node, FoldKind::Region, last_row(node)));
+				}
+				continue;
+			}
+			"fold.comment" if markers.contains(&node.id()) => continue,
+			"fold.comment" => FoldKind::Comment,
+			"fold.imports" => FoldKind::Imports,
+			_ => FoldKind::Other,
+		};
+		if kind == FoldKind::Other {
+			folds.push(Fold::new(node, node, kind, construct_end_line(node)));
+			continue;
+		}
+
+		// added to the run right before it, unless there's something else in between
+		let run = runs
+			.get(&kind)
+			.map(|&i: &usize| &mut folds[i])
+			.filter(|run| {
+				node.start_position().row <= run.end_line + 1
+					&& node
+						.prev_sibling()
+						.is_some_and(|prev| prev.end_byte() == run.end_byte)
+			});
+		match run {
+			Some(run) => {
+				run.end_line = last_row(node);
+				run.end_byte = node.end_byte();
+			}
+			None => {
+				runs.insert(kind, folds.len());
+				folds.push(Fold::new(node, node, kind, last_row(node)));
+			}
+		}
+	}
+	folds
+}
+
+fn node_folds(document: &Document) -> Vec<Fold> {
+	let mut folds = Vec::new();
+
+	// walk the tree iteratively, since it can be deeper than the stack allows
+	let mut cursor = document.tree.walk();
+	'walk: loop {
+		let node = cursor.node();
+		let is_comment = node.kind() == "comment";
+		if node.parent().is_some() && (is_comment || (node.is_named() && node.child_count() > 0)) {
+			let kind = match is_comment {
+				true => FoldKind::Comment,
+				false => FoldKind::Other,
+			};
+			folds.push(Fold::new(node, node, kind, construct_end_line(node)));
+		}
+
+		if cursor.goto_first_child() {
+			continue;
+		}
+		while !cursor.goto_next_sibling() {
+			if !cursor.goto_parent() {
+				break 'walk;
+			}
+		}
+	}
+
+	folds
+}
+
+/// The last row with any of `node` on it, which isn't its end row if it ends with a newline.
+pub fn last_row(node: Node) -> usize {
+	let (start, end) = (node.start_position(), node.end_position());
+	match end.column == 0 && end.row > start.row {
+		true => end.row - 1,
+		false => end.row,
+	}
+}
+
+/// The last row to fold `node` to, leaving the line with its closing bracket visible.
+fn construct_end_line(node: Node) -> usize {
+	let end_line = last_row(node);
+	let mut last = node;
+	while let Some(child) = last.child(last.child_count().saturating_sub(1)) {
+		last = child;
+	}
+	if last != node && !last.is_named() && last.start_position().row == end_line {
+		return end_line.saturating_sub(1);
+	}
+	end_line
+}
+
+/// Finds the folds of `document` and returns a serialized `FoldingRangesResponse` of them.
+pub fn folding_ranges(document: &Document) -> Vec<u8> {
+	let _span = tracing::info_span!("folding_ranges").entered();
+	let folds = folds(document);
+
+	let mut builder = FlatBufferBuilder::new();
+	let ranges = folds
+		.iter()
+		.map(|fold| {
+			FoldingRange::create(
+				&mut builder,
+				&FoldingRangeArgs {
+					start_line: fold.start_line as u32,
+					end_line: fold.end_line as u32,
+					kind: fold.kind,
+					level: fold.level as u32,
+					location: Some(&Location::new(fold.start_byte as u32, fold.end_byte as u32)),
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let ranges = builder.create_vector(&ranges);
+	let resp = FoldingRangesResponse::create(
+		&mut builder,
+		&FoldingRangesResponseArgs {
+			ranges: Some(ranges),
+			version: document.version,
+			content_hash: document.text.content_hash(),
+		},
+	);
+	builder.finish(resp, None);
+
+	builder.finished_data().to_vec()
+}
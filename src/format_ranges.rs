@@ -0,0 +1,143 @@
+use anyhow::Result;
+use tree_sitter::Node;
+
+use crate::{
+	document::Document,
+	error::Error,
+	message_generated::asted::interface::{
+		FormatRange, FormatRangeArgs, FormatRangesResponse, FormatRangesResponseArgs, Location,
+		Point,
+	},
+	text::Text,
+};
+
+/// A node a formatter can be given on its own, through the tokens trailing it on its last line
+/// (like the `;` after a C++ class).
+struct Unit<'tree> {
+	node: Node<'tree>,
+	last: Node<'tree>,
+}
+
+/// Finds the formatting units around `start..end` (byte offsets into the UTF-16 text) of
+/// `document` and returns a serialized `FormatRangesResponse`.
+pub fn format_ranges(document: &Document, start: usize, end: usize) -> Result<Vec<u8>> {
+	let _span = tracing::info_span!("format_ranges").entered();
+	if start > end || end > document.text.len() * 2 {
+		return Err(Error::InvalidRequest(format!("Invalid range {}..{}", start, end)).into());
+	}
+	let units = units(&document.text, document.tree.root_node(), start, end);
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let ranges = units
+		.iter()
+		.map(|unit| {
+			let kind = builder.create_string(unit.node.kind());
+			let (start_point, end_point) = (unit.node.start_position(), unit.last.end_position());
+			FormatRange::create(
+				&mut builder,
+				&FormatRangeArgs {
+					location: Some(&Location::new(
+						unit.node.start_byte() as u32,
+						unit.last.end_byte() as u32,
+					)),
+					start_point: Some(&Point::new(
+						start_point.row as u32,
+						start_point.column as u32,
+					)),
+					end_point: Some(&Point::new(end_point.row as u32, end_point.column as u32)),
+					kind: Some(kind),
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let ranges = builder.create_vector(&ranges);
+	let resp = FormatRangesResponse::create(
+		&mut builder,
+		&FormatRangesResponseArgs {
+			ranges: Some(ranges),
+			version: document.version,
+			content_hash: document.text.content_hash(),
+		},
+	);
+	builder.finish(resp, None);
+
+	Ok(builder.finished_data().to_vec())
+}
+
+/// The units a range touching several of the named children of the node spanning it (like the
+/// statements of a block) is formatted in, if they all are units, or else the innermost unit
+/// spanning the range.
+fn units<'tree>(text: &Text, root: Node<'tree>, start: usize, end: usize) -> Vec<Unit<'tree>> {
+	let spanning = root.descendant_for_byte_range(start, end).unwrap_or(root);
+
+	if start < end {
+		let mut cursor = spanning.walk();
+		let touched = spanning
+			.named_children(&mut cursor)
+			.filter(|child| !child.is_extra())
+			.filter(|child| child.start_byte() < end && start < child.end_byte())
+			.collect::<Vec<_>>();
+		if touched.len() > 1 {
+			if let Some(units) = touched.into_iter().map(|child| unit(text, child)).collect() {
+				return units;
+			}
+		}
+	}
+
+	let mut node = Some(spanning);
+	while let Some(outer) = node {
+		if let Some(mut found) = unit(text, outer) {
+			// named for the outermost node of the range, like the statement rather than its call
+			while let Some(parent) = found.node.parent().and_then(|parent| unit(text, parent)) {
+				if parent.node.start_byte() != found.node.start_byte()
+					|| parent.last.end_byte() != found.last.end_byte()
+				{
+					break;
+				}
+				found = parent;
+			}
+			return vec![found];
+		}
+		node = outer.parent();
+	}
+	Vec::new()
+}
+
+/// `node` as a unit, if it's a named node without syntax errors that starts its first line and,
+/// along with any tokens and comments after it, ends its last one.
+fn unit<'tree>(text: &Text, node: Node<'tree>) -> Option<Unit<'tree>> {
+	if !node.is_named() || node.is_extra() || node.has_error() {
+		return None;
+	}
+	let start = node.start_byte() / 2;
+	let line_start = text.offset_at(node.start_position().row, 0);
+	if !blank(&text.slice(line_start..start)) {
+		return None;
+	}
+
+	let same_line = |next: &Node, last: Node| next.start_position().row == last.end_position().row;
+	let mut last = node;
+	while let Some(next) = last
+		.next_sibling()
+		.filter(|next| !next.is_named() && !next.is_extra() && same_line(next, last))
+	{
+		last = next;
+	}
+	// comments trailing the unit aren't part of it, but don't stop it from ending its line
+	let mut trailing = last;
+	while let Some(next) = trailing
+		.next_sibling()
+		.filter(|next| next.is_extra() && same_line(next, trailing))
+	{
+		trailing = next;
+	}
+	let end = trailing.end_byte() / 2;
+	let line_end = text.offset_at(trailing.end_position().row, usize::MAX);
+	blank(&text.slice(end..line_end)).then_some(Unit { node, last })
+}
+
+fn blank(units: &[u16]) -> bool {
+	units
+		.iter()
+		.all(|&c| c == ' ' as u16 || c == '\t' as u16 || c == '\r' as u16)
+}
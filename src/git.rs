@@ -0,0 +1,192 @@
+use std::{
+	collections::HashMap,
+	io::Write,
+	path::{Path, PathBuf},
+	process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result};
+
+use crate::error::Error;
+
+/// A file in a git repository, named the way git commands run in its directory can take it.
+pub struct RepoFile {
+	dir: PathBuf,
+	/// Relative to `dir`, like `./main.ts`, which git resolves against the working directory even
+	/// in `<commit>:<path>` revisions
+	name: String,
+}
+
+/// A commit that changed a file.
+pub struct Commit {
+	/// The full hash
+	pub id: String,
+	pub author: String,
+	/// Seconds since the Unix epoch
+	pub time: u64,
+	/// The first line of the message
+	pub summary: String,
+}
+
+/// Which commit last changed each line of a version of a file.
+pub struct Blame {
+	/// With the all-zero hash for lines that aren't committed yet
+	pub commits: Vec<Commit>,
+	/// The index in `commits` of each line's
+	pub lines: Vec<usize>,
+}
+
+impl RepoFile {
+	/// The file at `path`, which doesn't have to exist (anymore), as long as its directory does and
+	/// is in a git repository.
+	pub fn new(path: &Path) -> Result<Self> {
+		let invalid = || Error::InvalidRequest(format!("{} isn't a file", path.display()));
+		let dir = path.parent().ok_or_else(invalid)?;
+		let name = path.file_name().ok_or_else(invalid)?.to_string_lossy();
+		let file = RepoFile {
+			dir: dir.to_path_buf(),
+			name: format!("./{}", name),
+		};
+		file.git(&["rev-parse", "--git-dir"])?;
+		Ok(file)
+	}
+
+	/// The commits in `range` (like `a..b`, or a single commit for every one up to it) that changed
+	/// the file, oldest first, or only the latest `max` of them.
+	pub fn log(&self, range: &str, max: Option<usize>) -> Result<Vec<Commit>> {
+		let max = max.map(|max| format!("--max-count={}", max));
+		let mut args = vec!["log", "--reverse", "--format=%H%x00%an%x00%at%x00%s"];
+		args.extend(max.as_deref());
+		args.extend(["--end-of-options", range, "--", &self.name]);
+		let out = self.git(&args)?;
+		out.lines()
+			.map(|line| {
+				let mut fields = line.splitn(4, '\0');
+				let mut field = || fields.next().unwrap_or_default().to_string();
+				Ok(Commit {
+					id: field(),
+					author: field(),
+					time: field()
+						.parse()
+						.with_context(|| format!("Unexpected git log line {:?}", line))?,
+					summary: field(),
+				})
+			})
+			.collect()
+	}
+
+	/// The file's contents as of `commit`, or `None` if it didn't exist then (as before the commit
+	/// adding it, or in one deleting it).
+	pub fn show(&self, commit: &str) -> Result<Option<String>> {
+		let exists = Command::new("git")
+			.arg("-C")
+			.arg(&self.dir)
+			.args(["cat-file", "-e", &format!("{}:{}", commit, self.name)])
+			.output()
+			.context("Error running git")?
+			.status
+			.success();
+		if !exists {
+			return Ok(None);
+		}
+		let revision = format!("{}:{}", commit, self.name);
+		self.git(&["show", &revision]).map(Some)
+	}
+
+	/// Blames the lines of `contents`, as a version of the file that may have changes not
+	/// committed yet, on the commits that last changed them.
+	pub fn blame(&self, contents: &str) -> Result<Blame> {
+		let out = self.run(
+			&["blame", "--porcelain", "--contents", "-", "--", &self.name],
+			Some(contents),
+		)?;
+		let mut blame = Blame {
+			commits: Vec::new(),
+			lines: Vec::new(),
+		};
+		let mut indices = HashMap::new();
+		// the commit and line of the entry being read, until its line of contents
+		let mut entry = None;
+		for line in out.lines() {
+			if line.starts_with('\t') {
+				if let Some((i, row)) = entry.take() {
+					if blame.lines.len() <= row {
+						blame.lines.resize(row + 1, i);
+					}
+					blame.lines[row] = i;
+				}
+				continue;
+			}
+			let Some((i, _)) = entry else {
+				// the header of an entry: the commit, its line in it and the line in `contents`
+				let mut fields = line.split(' ');
+				let id = fields.next().unwrap_or_default();
+				let row = fields
+					.nth(1)
+					.and_then(|row| row.parse::<usize>().ok())
+					.and_then(|row| row.checked_sub(1))
+					.with_context(|| format!("Unexpected git blame line {:?}", line))?;
+				let i = *indices.entry(id.to_string()).or_insert_with(|| {
+					blame.commits.push(Commit {
+						id: id.to_string(),
+						author: String::new(),
+						time: 0,
+						summary: String::new(),
+					});
+					blame.commits.len() - 1
+				});
+				entry = Some((i, row));
+				continue;
+			};
+			// the commit's details, the first time it comes up
+			let commit = &mut blame.commits[i];
+			match line.split_once(' ') {
+				Some(("author", author)) => commit.author = author.to_string(),
+				Some(("author-time", time)) => commit.time = time.parse().unwrap_or_default(),
+				Some(("summary", summary)) => commit.summary = summary.to_string(),
+				_ => {}
+			}
+		}
+		Ok(blame)
+	}
+
+	/// Runs git in the file's directory, failing with git's own message if it does.
+	fn git(&self, args: &[&str]) -> Result<String> {
+		self.run(args, None)
+	}
+
+	/// Runs git like `git`, writing `input` to it.
+	fn run(&self, args: &[&str], input: Option<&str>) -> Result<String> {
+		let mut child = Command::new("git")
+			.arg("-C")
+			.arg(&self.dir)
+			.args(args)
+			.stdin(match input {
+				Some(_) => Stdio::piped(),
+				None => Stdio::null(),
+			})
+			.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.spawn()
+			.context("Error running git")?;
+		if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+			// git reads all of it before writing anything back, so this can't fill up both pipes
+			stdin
+				.write_all(input.as_bytes())
+				.context("Error writing to git")?;
+		}
+		let out = child.wait_with_output().context("Error running git")?;
+		if !out.status.success() {
+			let message = String::from_utf8_lossy(&out.stderr);
+			return Err(Error::InvalidRequest(format!(
+				"git {} failed: {}",
+				args[0],
+				message.trim()
+			))
+			.into());
+		}
+		String::from_utf8(out.stdout).map_err(|_| {
+			Error::InvalidRequest(format!("git {} printed text that isn't UTF-8", args[0])).into()
+		})
+	}
+}
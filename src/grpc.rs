@@ -0,0 +1,582 @@
+// tonic's interceptors and services have to return `Status` as the error
+#![allow(clippy::result_large_err)]
+
+use std::{net::SocketAddr, pin::pin, sync::Arc};
+
+use anyhow::Result;
+use axum::body::{BoxBody, Bytes, HttpBody};
+use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
+use tokio::sync::{broadcast::error::RecvError, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{service::interceptor::InterceptedService, Request, Response, Status};
+
+use crate::{
+	auth, cancel, changes, daemon,
+	encoding::Encoding,
+	error::Error,
+	grpc_generated::{
+		tree_sitter_server::{TreeSitter, TreeSitterServer},
+		CaptureQuantifier, DocumentChanged, EditRequest, FileResponse, InitRequest, InitResponse,
+		LineEnding, Node, OpenRequest, ParseStats, Point, QueryCapture, QueryCaptureCount,
+		QueryCaptureGroup, QueryFileCount, QueryMatch, QueryRequest, QueryResults, Range,
+		SubscribeRequest,
+	},
+	heartbeat::Heartbeat,
+	idle::Activity,
+	limits,
+	message_generated::asted::interface as fb,
+	tenants::Tenant,
+	uri,
+};
+
+/// Serves the gRPC interface on `addr` until the server shuts down. Like the HTTP interface, it
+/// works on the default session, or the session of the tenant whose key the client sends, and
+/// its calls are handled (limits, permissions and all) like HTTP requests. With a `heartbeat`,
+/// connections are kept alive with HTTP/2 pings, and closed when they go unanswered.
+pub async fn serve(
+	addr: SocketAddr,
+	keys: Arc<auth::Keys>,
+	max_message_size: usize,
+	heartbeat: Option<Heartbeat>,
+	activity: Arc<Activity>,
+) -> Result<()> {
+	let service =
+		TreeSitterServer::new(Service { activity }).max_decoding_message_size(max_message_size);
+	let service = InterceptedService::new(service, move |req| authenticate(&keys, req));
+
+	tonic::transport::Server::builder()
+		.http2_keepalive_interval(heartbeat.map(|heartbeat| heartbeat.interval))
+		// hyper times the answer to a ping, rather than the silence since the client was last heard
+		.http2_keepalive_timeout(heartbeat.map(|heartbeat| heartbeat.timeout - heartbeat.interval))
+		.add_service(service)
+		.serve_with_shutdown(addr, daemon::shutdown_signal())
+		.await?;
+	Ok(())
+}
+
+/// Rejects requests without the `authorization` metadata `--auth` or the tenants ask for, like
+/// `auth::authenticate`. Requests from a tenant carry the tenant as an extension.
+fn authenticate(keys: &auth::Keys, mut req: Request<()>) -> Result<Request<()>, Status> {
+	let header = req
+		.metadata()
+		.get("authorization")
+		.and_then(|value| value.to_str().ok());
+	if let Some(tenant) = auth::identify(keys, header).map_err(|e| status(e.into()))? {
+		req.extensions_mut().insert(tenant);
+	}
+	Ok(req)
+}
+
+/// The key in `STATE_MAP` of the session a request works on.
+fn session<T>(request: &Request<T>) -> String {
+	request
+		.extensions()
+		.get::<Arc<Tenant>>()
+		.map_or("global".to_string(), |tenant| tenant.session.clone())
+}
+
+struct Service {
+	activity: Arc<Activity>,
+}
+
+impl Service {
+	/// Handles the flatbuffers `Request` in `body` the way requests over HTTP are handled (with
+	/// `handle`, once the client's limits let it through), returning the body of the response.
+	/// The request is cancelled if the client gives up on it before it's answered.
+	async fn handle<T>(&self, request: &Request<T>, body: Vec<u8>) -> Result<BoxBody, Status> {
+		let session = session(request);
+		let _permit = match request.remote_addr() {
+			Some(addr) => limits::admit(addr.ip()).map_err(|e| status(e.into()))?,
+			None => None,
+		};
+		let registration = cancel::anonymous();
+		let cancel_on_drop = CancelOnDrop(Some(registration.clone()));
+
+		// `handle` fills in the rest as it learns about the request
+		let span = tracing::info_span!(
+			"request",
+			request_type = tracing::field::Empty,
+			session = %session,
+			uri = tracing::field::Empty,
+			parse = tracing::field::Empty,
+		);
+		let res = tokio::task::spawn_blocking(move || {
+			span.in_scope(|| {
+				cancel::scope(Some(registration), || {
+					crate::handle(Bytes::from(body), Encoding::Flatbuffers, &session)
+				})
+			})
+		})
+		.await;
+		cancel_on_drop.disarm();
+		match res {
+			Ok(res) => res.map(|res| res.into_body()).map_err(status),
+			Err(e) if e.is_panic() => Err(Status::internal("Request handler panicked")),
+			Err(_) => Err(Status::internal("Request handler was cancelled")),
+		}
+	}
+}
+
+/// Cancels a request when dropped, unless it was answered (and the guard disarmed) first.
+struct CancelOnDrop(Option<Arc<cancel::Registration>>);
+
+impl CancelOnDrop {
+	fn disarm(mut self) {
+		self.0 = None;
+	}
+}
+
+impl Drop for CancelOnDrop {
+	fn drop(&mut self) {
+		if let Some(registration) = &self.0 {
+			registration.cancel();
+		}
+	}
+}
+
+/// A flatbuffers `Request` carrying the request `build` adds to the builder.
+fn request(
+	request_type: fb::RequestUnion,
+	build: impl FnOnce(&mut FlatBufferBuilder<'static>) -> WIPOffset<UnionWIPOffset>,
+) -> Vec<u8> {
+	let mut builder = FlatBufferBuilder::new();
+	let request = build(&mut builder);
+	let request = fb::Request::create(
+		&mut builder,
+		&fb::RequestArgs {
+			request_type,
+			request: Some(request),
+			id: 0,
+		},
+	);
+	builder.finish(request, None);
+	builder.finished_data().to_vec()
+}
+
+/// The whole of a response body, which big trees are streamed in.
+async fn read_body(body: BoxBody) -> Result<Bytes, Status> {
+	hyper::body::to_bytes(body)
+		.await
+		.map_err(|e| Status::internal(format!("Error reading response: {}", e)))
+}
+
+#[tonic::async_trait]
+impl TreeSitter for Service {
+	async fn init(&self, request: Request<InitRequest>) -> Result<Response<InitResponse>, Status> {
+		let _busy = self.activity.busy();
+		let req = request.get_ref();
+		let body = self::request(fb::RequestUnion::InitRequest, |builder| {
+			let lang = builder.create_string(&req.lang);
+			fb::InitRequest::create(
+				builder,
+				&fb::InitRequestArgs {
+					lang: Some(lang),
+					plain_text_fallback: req.plain_text_fallback,
+				},
+			)
+			.as_union_value()
+		});
+		let res = read_body(self.handle(&request, body).await?).await?;
+		let res = flatbuffers::root::<fb::InitResponse>(&res).map_err(|e| status(e.into()))?;
+		let language = res.language();
+		Ok(Response::new(InitResponse {
+			abi_version: language.map_or(0, |language| language.abi_version()),
+			grammar_version: language
+				.and_then(|language| language.grammar_version())
+				.map(str::to_string),
+		}))
+	}
+
+	async fn open(&self, request: Request<OpenRequest>) -> Result<Response<FileResponse>, Status> {
+		let _busy = self.activity.busy();
+		let req = request.get_ref();
+		let body = self::request(fb::RequestUnion::FileRequest, |builder| {
+			let path = builder.create_string(&req.path);
+			let content = req
+				.content
+				.as_ref()
+				.map(|content| builder.create_vector(&content.encode_utf16().collect::<Vec<_>>()));
+			let lang = req.lang.as_ref().map(|lang| builder.create_string(lang));
+			fb::FileRequest::create(
+				builder,
+				&fb::FileRequestArgs {
+					path: Some(path),
+					if_hash: req.if_hash,
+					content,
+					force: req.force,
+					no_cache: req.no_cache,
+					normalize_line_endings: req.normalize_line_endings,
+					lang,
+					..Default::default()
+				},
+			)
+			.as_union_value()
+		});
+		let res = read_body(self.handle(&request, body).await?).await?;
+		Ok(Response::new(file_response(&res)))
+	}
+
+	async fn edit(&self, request: Request<EditRequest>) -> Result<Response<FileResponse>, Status> {
+		let _busy = self.activity.busy();
+		let req = request.get_ref();
+		let body = self::request(fb::RequestUnion::EditRequest, |builder| {
+			let path = builder.create_string(&req.path);
+			let edits = req
+				.edits
+				.iter()
+				.map(|edit| {
+					let text = builder.create_vector(&edit.text.encode_utf16().collect::<Vec<_>>());
+					fb::TextEdit::create(
+						builder,
+						&fb::TextEditArgs {
+							start_byte: edit.start_byte,
+							old_end_byte: edit.old_end_byte,
+							text: Some(text),
+						},
+					)
+				})
+				.collect::<Vec<_>>();
+			let edits = builder.create_vector(&edits);
+			fb::EditRequest::create(
+				builder,
+				&fb::EditRequestArgs {
+					path: Some(path),
+					base_version: req.base_version,
+					edits: Some(edits),
+					if_hash: req.if_hash,
+					..Default::default()
+				},
+			)
+			.as_union_value()
+		});
+		let res = read_body(self.handle(&request, body).await?).await?;
+		Ok(Response::new(file_response(&res)))
+	}
+
+	type QueryStream = ReceiverStream<Result<QueryResults, Status>>;
+
+	async fn query(
+		&self,
+		request: Request<QueryRequest>,
+	) -> Result<Response<Self::QueryStream>, Status> {
+		let busy = self.activity.busy();
+		let req = request.get_ref();
+		let body = self::request(fb::RequestUnion::QueryRequest, |builder| {
+			let path = builder.create_string(&req.path);
+			let query = builder.create_string(&req.query);
+			let lang = req.lang.as_ref().map(|lang| builder.create_string(lang));
+			let filter = req.filter.as_ref().map(|filter| {
+				let mut strings = |strings: &[String]| {
+					let strings = strings
+						.iter()
+						.map(|string| builder.create_string(string))
+						.collect::<Vec<_>>();
+					builder.create_vector(&strings)
+				};
+				let include = strings(&filter.include);
+				let exclude = strings(&filter.exclude);
+				fb::WorkspaceFilter::create(
+					builder,
+					&fb::WorkspaceFilterArgs {
+						include: Some(include),
+						exclude: Some(exclude),
+						include_ignored: filter.include_ignored,
+					},
+				)
+			});
+			let cursor = builder.create_string(&req.cursor);
+			fb::QueryRequest::create(
+				builder,
+				&fb::QueryRequestArgs {
+					path: Some(path),
+					query: Some(query),
+					lang,
+					filter,
+					cursor: Some(cursor),
+					max_matches: req.max_matches,
+					with_text: req.with_text,
+					count_only: req.count_only,
+					grouped: req.grouped,
+				},
+			)
+			.as_union_value()
+		});
+		let mut body = self.handle(&request, body).await?;
+
+		let (tx, rx) = mpsc::channel(16);
+		tokio::spawn(async move {
+			// like subscribers, searches keep the server from going idle while they run
+			let _busy = busy;
+			// the batches come size-prefixed, like over HTTP
+			let mut frames = Vec::new();
+			while let Some(chunk) = body.data().await {
+				let chunk = match chunk {
+					Ok(chunk) => chunk,
+					Err(e) => {
+						let message = format!("Error searching: {}", e);
+						let _ = tx.send(Err(Status::internal(message))).await;
+						return;
+					}
+				};
+				frames.extend_from_slice(&chunk);
+				while let Some(frame) = next_frame(&mut frames) {
+					let results = match flatbuffers::size_prefixed_root::<fb::QueryResults>(&frame)
+					{
+						Ok(results) => query_results(results),
+						Err(e) => {
+							let _ = tx.send(Err(status(e.into()))).await;
+							return;
+						}
+					};
+					// the search stops once the body is dropped
+					if tx.send(Ok(results)).await.is_err() {
+						return;
+					}
+				}
+			}
+		});
+
+		Ok(Response::new(ReceiverStream::new(rx)))
+	}
+
+	type SubscribeStream = ReceiverStream<Result<DocumentChanged, Status>>;
+
+	async fn subscribe(
+		&self,
+		request: Request<SubscribeRequest>,
+	) -> Result<Response<Self::SubscribeStream>, Status> {
+		// tenants only hear about their own documents
+		let tenant = request
+			.extensions()
+			.get::<Arc<Tenant>>()
+			.map(|tenant| tenant.session.clone());
+		let req = request.into_inner();
+		let filter = if req.path.is_empty() {
+			None
+		} else {
+			Some(uri::key(&req.path).map_err(status)?)
+		};
+
+		let (missed, mut changes) = if req.resume_token.is_empty() {
+			(Vec::new(), changes::subscribe())
+		} else {
+			changes::resume(&req.resume_token)
+				.map_err(status)?
+				.ok_or_else(|| {
+					Status::failed_precondition(
+						"Changes since the resume token are no longer known; open the documents again",
+					)
+				})?
+		};
+		let (tx, rx) = mpsc::channel(16);
+		// subscribers keep the server from going idle for as long as they listen
+		let busy = self.activity.busy();
+		tokio::spawn(async move {
+			let _busy = busy;
+			// streams have to end for the server to shut down gracefully
+			let mut shutdown = pin!(daemon::shutdown_signal());
+			let mut missed = missed.into_iter();
+			loop {
+				let change = match missed.next() {
+					Some(change) => Ok(change),
+					None => tokio::select! {
+						_ = &mut shutdown => break,
+						_ = tx.closed() => break,
+						change = changes.recv() => change,
+					},
+				};
+				let (seq, change) = match change {
+					Ok(change) => change,
+					// the changes that were missed are gone either way
+					Err(RecvError::Lagged(_)) => continue,
+					Err(RecvError::Closed) => break,
+				};
+				if filter.as_ref().is_some_and(|key| key != &change.key)
+					|| tenant
+						.as_ref()
+						.is_some_and(|session| session != &change.session)
+				{
+					continue;
+				}
+
+				let notification = DocumentChanged {
+					path: change.key.uri(),
+					version: change.version,
+					content_hash: change.content_hash,
+					resume_token: changes::resume_token(seq),
+				};
+				if tx.send(Ok(notification)).await.is_err() {
+					break;
+				}
+			}
+		});
+
+		Ok(Response::new(ReceiverStream::new(rx)))
+	}
+}
+
+fn status(e: anyhow::Error) -> Status {
+	tracing::error!("Error handling gRPC request: {:#}", e);
+	let message = format!("{:#}", e);
+	match e.downcast_ref::<Error>() {
+		Some(Error::Cancelled(_)) => Status::cancelled(message),
+		Some(Error::Conflict(_)) => Status::failed_precondition(message),
+		Some(Error::InvalidRequest(_)) | Some(Error::UnknownLanguage(_)) => {
+			Status::invalid_argument(message)
+		}
+		Some(Error::PayloadTooLarge(_)) | Some(Error::TooManyRequests(..)) => {
+			Status::resource_exhausted(message)
+		}
+		Some(Error::Timeout(_)) => Status::deadline_exceeded(message),
+		Some(Error::Unauthorized(_)) => Status::unauthenticated(message),
+		Some(Error::Forbidden(_)) => Status::permission_denied(message),
+		Some(Error::UnknownCommand(_)) => Status::unimplemented(message),
+		Some(Error::UnknownFile(_)) => Status::not_found(message),
+		Some(Error::Internal(_)) | None => Status::internal(message),
+	}
+}
+
+/// Converts a `FileResponse` built by the tree (or plain-text) serializer, so both interfaces
+/// describe documents the same way.
+fn file_response(res: &[u8]) -> FileResponse {
+	// Safety: the buffer was just built by our own serializer. Verifying it would also limit how
+	// deep the tree can be.
+	let res = unsafe { flatbuffers::root_unchecked::<fb::FileResponse>(res) };
+	FileResponse {
+		tree: Some(node(res.tree())),
+		stats: res.stats().map(|stats| ParseStats {
+			parse_duration_us: stats.parse_duration_us(),
+			reused_old_tree: stats.reused_old_tree(),
+			node_count: stats.node_count(),
+			error_count: stats.error_count(),
+			cached_tree: stats.cached_tree(),
+			changed_ranges: stats
+				.changed_ranges()
+				.into_iter()
+				.flatten()
+				.map(|range| Range {
+					start_byte: range.start_byte(),
+					end_byte: range.end_byte(),
+				})
+				.collect(),
+		}),
+		version: res.version(),
+		content_hash: res.content_hash(),
+		mtime_ms: res.mtime_ms(),
+		line_ending: match res.line_ending() {
+			fb::LineEnding::Crlf => LineEnding::Crlf,
+			fb::LineEnding::Mixed => LineEnding::Mixed,
+			_ => LineEnding::Lf,
+		} as i32,
+	}
+}
+
+/// Takes the first whole size-prefixed batch off `frames`, prefix and all, if it has arrived yet.
+fn next_frame(frames: &mut Vec<u8>) -> Option<Vec<u8>> {
+	let prefix = frames.get(..4)?;
+	let len = 4 + u32::from_le_bytes(prefix.try_into().unwrap()) as usize;
+	if frames.len() < len {
+		return None;
+	}
+	let rest = frames.split_off(len);
+	Some(std::mem::replace(frames, rest))
+}
+
+/// Converts a batch of a streamed query response, like `Encoding::query_results_frame`.
+fn query_results(res: fb::QueryResults) -> QueryResults {
+	QueryResults {
+		matches: res
+			.matches()
+			.into_iter()
+			.flatten()
+			.map(|m| QueryMatch {
+				path: m.path().to_string(),
+				pattern_index: m.pattern_index(),
+				captures: m
+					.captures()
+					.into_iter()
+					.flatten()
+					.map(query_capture)
+					.collect(),
+				groups: m
+					.groups()
+					.into_iter()
+					.flatten()
+					.map(|group| QueryCaptureGroup {
+						name: group.name().to_string(),
+						quantifier: match group.quantifier() {
+							fb::CaptureQuantifier::ZeroOrOne => CaptureQuantifier::ZeroOrOne,
+							fb::CaptureQuantifier::ZeroOrMore => CaptureQuantifier::ZeroOrMore,
+							fb::CaptureQuantifier::One => CaptureQuantifier::One,
+							fb::CaptureQuantifier::OneOrMore => CaptureQuantifier::OneOrMore,
+							_ => CaptureQuantifier::Zero,
+						} as i32,
+						captures: group.captures().iter().map(query_capture).collect(),
+					})
+					.collect(),
+			})
+			.collect(),
+		cursor: res.cursor().unwrap_or_default().to_string(),
+		done: res.done(),
+		counts: res
+			.counts()
+			.into_iter()
+			.flatten()
+			.map(|count| QueryFileCount {
+				path: count.path().to_string(),
+				matches: count.matches(),
+				captures: count
+					.captures()
+					.into_iter()
+					.flatten()
+					.map(|capture| QueryCaptureCount {
+						name: capture.name().to_string(),
+						count: capture.count(),
+					})
+					.collect(),
+			})
+			.collect(),
+	}
+}
+
+fn query_capture(capture: fb::QueryCapture) -> QueryCapture {
+	let point = |point: &fb::Point| Point {
+		row: point.row(),
+		column: point.column(),
+	};
+	QueryCapture {
+		name: capture.name().to_string(),
+		range: capture.location().map(|location| Range {
+			start_byte: location.start_byte(),
+			end_byte: location.end_byte(),
+		}),
+		start_point: capture.start_point().map(point),
+		end_point: capture.end_point().map(point),
+		text: capture
+			.text()
+			.map(|text| String::from_utf16_lossy(&text.iter().collect::<Vec<_>>()))
+			.unwrap_or_default(),
+	}
+}
+
+fn node(node: fb::Node) -> Node {
+	let (start_byte, end_byte) = node.location().map_or((0, 0), |location| {
+		(location.start_byte(), location.end_byte())
+	});
+	Node {
+		kind: node.kind().to_string(),
+		start_byte,
+		end_byte,
+		children: node
+			.children()
+			.into_iter()
+			.flatten()
+			.map(self::node)
+			.collect(),
+		named: node.named(),
+		text: node
+			.text()
+			.map(|text| String::from_utf16_lossy(&text.iter().collect::<Vec<_>>()))
+			.unwrap_or_default(),
+	}
+}
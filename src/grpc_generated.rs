@@ -0,0 +1,722 @@
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitRequest {
+	#[prost(string, tag = "1")]
+	pub lang: ::prost::alloc::string::String,
+	/// serve line/whitespace-delimited tokens instead of failing when lang has no grammar
+	#[prost(bool, tag = "2")]
+	pub plain_text_fallback: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitResponse {
+	/// the tree-sitter ABI version the language's grammar was generated for; 0 when the session fell
+	/// back to plain text
+	#[prost(uint32, tag = "1")]
+	pub abi_version: u32,
+	/// the version of the grammar crate a built-in language comes from; unset for shared-library
+	/// grammars
+	#[prost(string, optional, tag = "2")]
+	pub grammar_version: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenRequest {
+	/// a URI; only file:// URIs can be read from disk, but any URI can be used with content
+	#[prost(string, tag = "1")]
+	pub path: ::prost::alloc::string::String,
+	/// fail with FAILED_PRECONDITION unless the file's contents still have this content_hash
+	#[prost(uint64, optional, tag = "2")]
+	pub if_hash: ::core::option::Option<u64>,
+	/// the document's text (e.g. an unsaved buffer), used instead of reading path from disk
+	#[prost(string, optional, tag = "3")]
+	pub content: ::core::option::Option<::prost::alloc::string::String>,
+	/// parse from scratch, ignoring the document's cached tree and any shared with other documents
+	#[prost(bool, tag = "4")]
+	pub force: bool,
+	/// don't cache the document or its tree, e.g. for batch tools reading many files once; the
+	/// response's version is 0
+	#[prost(bool, tag = "5")]
+	pub no_cache: bool,
+	/// parse (and keep) the text with every \r\n turned into \n
+	#[prost(bool, tag = "6")]
+	pub normalize_line_endings: bool,
+	/// parse the document in this language instead of the session's; it stays in it until opened
+	/// in another
+	#[prost(string, optional, tag = "7")]
+	pub lang: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextEdit {
+	/// byte offsets into the UTF-16 text, like Node
+	#[prost(uint32, tag = "1")]
+	pub start_byte: u32,
+	#[prost(uint32, tag = "2")]
+	pub old_end_byte: u32,
+	#[prost(string, tag = "3")]
+	pub text: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditRequest {
+	#[prost(string, tag = "1")]
+	pub path: ::prost::alloc::string::String,
+	#[prost(uint32, tag = "2")]
+	pub base_version: u32,
+	#[prost(message, repeated, tag = "3")]
+	pub edits: ::prost::alloc::vec::Vec<TextEdit>,
+	/// fail with FAILED_PRECONDITION unless the document's text still has this content_hash
+	#[prost(uint64, optional, tag = "4")]
+	pub if_hash: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryRequest {
+	/// a file:// URI of the directory
+	#[prost(string, tag = "1")]
+	pub path: ::prost::alloc::string::String,
+	/// in the syntax of tree-sitter's .scm query files
+	#[prost(string, tag = "2")]
+	pub query: ::prost::alloc::string::String,
+	/// the language the query is written for, and of the files searched; the session's if not set
+	#[prost(string, optional, tag = "3")]
+	pub lang: ::core::option::Option<::prost::alloc::string::String>,
+	/// which files under the directory are searched
+	#[prost(message, optional, tag = "4")]
+	pub filter: ::core::option::Option<WorkspaceFilter>,
+	/// the cursor of a batch already received, to carry on after its last match
+	#[prost(string, tag = "5")]
+	pub cursor: ::prost::alloc::string::String,
+	/// stop after this many matches (the last batch's cursor carries on from there); 0 for no limit
+	#[prost(uint32, tag = "6")]
+	pub max_matches: u32,
+	/// also return each capture's text
+	#[prost(bool, tag = "7")]
+	pub with_text: bool,
+	/// return how many matches (and captures) each file has, in QueryResults.counts, instead of the
+	/// matches themselves; max_matches and cursors work the same
+	#[prost(bool, tag = "8")]
+	pub count_only: bool,
+	/// return each match's captures in QueryMatch.groups, by name, instead of in
+	/// QueryMatch.captures
+	#[prost(bool, tag = "9")]
+	pub grouped: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WorkspaceFilter {
+	/// take in only the files matching one of these, or in a directory that does; every file if
+	/// empty
+	#[prost(string, repeated, tag = "1")]
+	pub include: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+	/// leave out the files and directories matching one of these
+	#[prost(string, repeated, tag = "2")]
+	pub exclude: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+	/// take in files ignored by a .gitignore in the directory or further up its repository
+	#[prost(bool, tag = "3")]
+	pub include_ignored: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryResults {
+	/// in order of path, then of position in the file
+	#[prost(message, repeated, tag = "1")]
+	pub matches: ::prost::alloc::vec::Vec<QueryMatch>,
+	/// where this batch ends, for QueryRequest.cursor
+	#[prost(string, tag = "2")]
+	pub cursor: ::prost::alloc::string::String,
+	/// whether this is the last batch because every file was searched, rather than because
+	/// max_matches was reached
+	#[prost(bool, tag = "3")]
+	pub done: bool,
+	/// for count_only queries, in order of path and leaving out files without matches
+	#[prost(message, repeated, tag = "4")]
+	pub counts: ::prost::alloc::vec::Vec<QueryFileCount>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryMatch {
+	/// a file:// URI to open with Open
+	#[prost(string, tag = "1")]
+	pub path: ::prost::alloc::string::String,
+	/// which of the query's patterns matched, counting from 0
+	#[prost(uint32, tag = "2")]
+	pub pattern_index: u32,
+	/// in the order they were captured, unless the request asked for groups
+	#[prost(message, repeated, tag = "3")]
+	pub captures: ::prost::alloc::vec::Vec<QueryCapture>,
+	/// for grouped requests, a group for every name the pattern captures with, in the order of the
+	/// query's capture names
+	#[prost(message, repeated, tag = "4")]
+	pub groups: ::prost::alloc::vec::Vec<QueryCaptureGroup>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryCapture {
+	/// the capture's name in the query, without the @
+	#[prost(string, tag = "1")]
+	pub name: ::prost::alloc::string::String,
+	#[prost(message, optional, tag = "2")]
+	pub range: ::core::option::Option<Range>,
+	#[prost(message, optional, tag = "3")]
+	pub start_point: ::core::option::Option<Point>,
+	#[prost(message, optional, tag = "4")]
+	pub end_point: ::core::option::Option<Point>,
+	/// only set if the request asked for with_text
+	#[prost(string, tag = "5")]
+	pub text: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryCaptureGroup {
+	/// the capture's name in the query, without the @
+	#[prost(string, tag = "1")]
+	pub name: ::prost::alloc::string::String,
+	#[prost(enumeration = "CaptureQuantifier", tag = "2")]
+	pub quantifier: i32,
+	/// empty if no node was captured, which only optional and repeated captures allow
+	#[prost(message, repeated, tag = "3")]
+	pub captures: ::prost::alloc::vec::Vec<QueryCapture>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFileCount {
+	/// a file:// URI to open with Open
+	#[prost(string, tag = "1")]
+	pub path: ::prost::alloc::string::String,
+	#[prost(uint32, tag = "2")]
+	pub matches: u32,
+	/// in the order of the query's captures, leaving out ones with no captured nodes
+	#[prost(message, repeated, tag = "3")]
+	pub captures: ::prost::alloc::vec::Vec<QueryCaptureCount>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryCaptureCount {
+	/// the capture's name in the query, without the @
+	#[prost(string, tag = "1")]
+	pub name: ::prost::alloc::string::String,
+	#[prost(uint32, tag = "2")]
+	pub count: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Point {
+	#[prost(uint32, tag = "1")]
+	pub row: u32,
+	/// in UTF-16 bytes, like Node's offsets
+	#[prost(uint32, tag = "2")]
+	pub column: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeRequest {
+	/// only notify about this document (a URI, like OpenRequest.path); all documents if empty
+	#[prost(string, tag = "1")]
+	pub path: ::prost::alloc::string::String,
+	/// carry on after the change this token came with (a DocumentChanged.resume_token, or a
+	/// ResumeResponse.token), starting with the changes missed since; fails with FAILED_PRECONDITION
+	/// if some are no longer known
+	#[prost(string, tag = "2")]
+	pub resume_token: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DocumentChanged {
+	#[prost(string, tag = "1")]
+	pub path: ::prost::alloc::string::String,
+	#[prost(uint32, tag = "2")]
+	pub version: u32,
+	#[prost(uint64, tag = "3")]
+	pub content_hash: u64,
+	/// to subscribe again with after a reconnect, to carry on after this change
+	#[prost(string, tag = "4")]
+	pub resume_token: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParseStats {
+	#[prost(uint64, tag = "1")]
+	pub parse_duration_us: u64,
+	/// whether the previously cached tree was handed to the parser
+	#[prost(bool, tag = "2")]
+	pub reused_old_tree: bool,
+	#[prost(uint32, tag = "3")]
+	pub node_count: u32,
+	/// ERROR and MISSING nodes
+	#[prost(uint32, tag = "4")]
+	pub error_count: u32,
+	/// whether the tree wasn't parsed: it was shared from another document with the same text, or
+	/// the response came from the disk cache
+	#[prost(bool, tag = "5")]
+	pub cached_tree: bool,
+	/// where the tree differs from the old one, if reused_old_tree; empty if the edits didn't change
+	/// the syntax
+	#[prost(message, repeated, tag = "6")]
+	pub changed_ranges: ::prost::alloc::vec::Vec<Range>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Range {
+	/// byte offsets into the UTF-16 text, like Node
+	#[prost(uint32, tag = "1")]
+	pub start_byte: u32,
+	#[prost(uint32, tag = "2")]
+	pub end_byte: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Node {
+	#[prost(string, tag = "1")]
+	pub kind: ::prost::alloc::string::String,
+	/// byte offsets into the UTF-16 text, matching what tree-sitter reports
+	#[prost(uint32, tag = "2")]
+	pub start_byte: u32,
+	#[prost(uint32, tag = "3")]
+	pub end_byte: u32,
+	#[prost(message, repeated, tag = "4")]
+	pub children: ::prost::alloc::vec::Vec<Node>,
+	#[prost(bool, tag = "5")]
+	pub named: bool,
+	/// only set on leaves
+	#[prost(string, tag = "6")]
+	pub text: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FileResponse {
+	#[prost(message, optional, tag = "1")]
+	pub tree: ::core::option::Option<Node>,
+	#[prost(message, optional, tag = "2")]
+	pub stats: ::core::option::Option<ParseStats>,
+	/// the document version the tree was parsed from, to be passed as the base_version of edits
+	#[prost(uint32, tag = "3")]
+	pub version: u32,
+	/// identifies the text the tree was parsed from, for use in if_hash preconditions
+	#[prost(uint64, tag = "4")]
+	pub content_hash: u64,
+	/// when the file was last read from disk, in milliseconds since the Unix epoch (0 if unknown)
+	#[prost(uint64, tag = "5")]
+	pub mtime_ms: u64,
+	/// how the lines of the text the tree was parsed from end
+	#[prost(enumeration = "LineEnding", tag = "6")]
+	pub line_ending: i32,
+}
+/// How many nodes a pattern captures with a name, going by the capture's quantifier
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CaptureQuantifier {
+	Zero = 0,
+	ZeroOrOne = 1,
+	ZeroOrMore = 2,
+	One = 3,
+	OneOrMore = 4,
+}
+impl CaptureQuantifier {
+	/// String value of the enum field names used in the ProtoBuf definition.
+	///
+	/// The values are not transformed in any way and thus are considered stable
+	/// (if the ProtoBuf definition does not change) and safe for programmatic use.
+	pub fn as_str_name(&self) -> &'static str {
+		match self {
+			CaptureQuantifier::Zero => "CAPTURE_QUANTIFIER_ZERO",
+			CaptureQuantifier::ZeroOrOne => "CAPTURE_QUANTIFIER_ZERO_OR_ONE",
+			CaptureQuantifier::ZeroOrMore => "CAPTURE_QUANTIFIER_ZERO_OR_MORE",
+			CaptureQuantifier::One => "CAPTURE_QUANTIFIER_ONE",
+			CaptureQuantifier::OneOrMore => "CAPTURE_QUANTIFIER_ONE_OR_MORE",
+		}
+	}
+	/// Creates an enum from field names used in the ProtoBuf definition.
+	pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+		match value {
+			"CAPTURE_QUANTIFIER_ZERO" => Some(Self::Zero),
+			"CAPTURE_QUANTIFIER_ZERO_OR_ONE" => Some(Self::ZeroOrOne),
+			"CAPTURE_QUANTIFIER_ZERO_OR_MORE" => Some(Self::ZeroOrMore),
+			"CAPTURE_QUANTIFIER_ONE" => Some(Self::One),
+			"CAPTURE_QUANTIFIER_ONE_OR_MORE" => Some(Self::OneOrMore),
+			_ => None,
+		}
+	}
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LineEnding {
+	/// \n (or there's only one line)
+	Lf = 0,
+	Crlf = 1,
+	/// some \n and some \r\n
+	Mixed = 2,
+}
+impl LineEnding {
+	/// String value of the enum field names used in the ProtoBuf definition.
+	///
+	/// The values are not transformed in any way and thus are considered stable
+	/// (if the ProtoBuf definition does not change) and safe for programmatic use.
+	pub fn as_str_name(&self) -> &'static str {
+		match self {
+			LineEnding::Lf => "LINE_ENDING_LF",
+			LineEnding::Crlf => "LINE_ENDING_CRLF",
+			LineEnding::Mixed => "LINE_ENDING_MIXED",
+		}
+	}
+	/// Creates an enum from field names used in the ProtoBuf definition.
+	pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+		match value {
+			"LINE_ENDING_LF" => Some(Self::Lf),
+			"LINE_ENDING_CRLF" => Some(Self::Crlf),
+			"LINE_ENDING_MIXED" => Some(Self::Mixed),
+			_ => None,
+		}
+	}
+}
+/// Generated server implementations.
+pub mod tree_sitter_server {
+	#![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+	use tonic::codegen::*;
+	/// Generated trait containing gRPC methods that should be implemented for use with TreeSitterServer.
+	#[async_trait]
+	pub trait TreeSitter: Send + Sync + 'static {
+		/// Switches the session to a language, dropping its cached documents
+		async fn init(
+			&self,
+			request: tonic::Request<super::InitRequest>,
+		) -> std::result::Result<tonic::Response<super::InitResponse>, tonic::Status>;
+		/// Parses a document, reusing its cached tree if there is one
+		async fn open(
+			&self,
+			request: tonic::Request<super::OpenRequest>,
+		) -> std::result::Result<tonic::Response<super::FileResponse>, tonic::Status>;
+		/// Applies edits (in order, each against the result of the previous one) to an open document
+		async fn edit(
+			&self,
+			request: tonic::Request<super::EditRequest>,
+		) -> std::result::Result<tonic::Response<super::FileResponse>, tonic::Status>;
+		/// Server streaming response type for the Query method.
+		type QueryStream: futures_core::Stream<Item = std::result::Result<super::QueryResults, tonic::Status>>
+			+ Send
+			+ 'static;
+		/// Runs a query over the files under a directory, streaming batches of matches as they're found
+		async fn query(
+			&self,
+			request: tonic::Request<super::QueryRequest>,
+		) -> std::result::Result<tonic::Response<Self::QueryStream>, tonic::Status>;
+		/// Server streaming response type for the Subscribe method.
+		type SubscribeStream: futures_core::Stream<Item = std::result::Result<super::DocumentChanged, tonic::Status>>
+			+ Send
+			+ 'static;
+		/// Streams a notification whenever a document changes, no matter which client changed it
+		async fn subscribe(
+			&self,
+			request: tonic::Request<super::SubscribeRequest>,
+		) -> std::result::Result<tonic::Response<Self::SubscribeStream>, tonic::Status>;
+	}
+	#[derive(Debug)]
+	pub struct TreeSitterServer<T: TreeSitter> {
+		inner: _Inner<T>,
+		accept_compression_encodings: EnabledCompressionEncodings,
+		send_compression_encodings: EnabledCompressionEncodings,
+		max_decoding_message_size: Option<usize>,
+		max_encoding_message_size: Option<usize>,
+	}
+	struct _Inner<T>(Arc<T>);
+	impl<T: TreeSitter> TreeSitterServer<T> {
+		pub fn new(inner: T) -> Self {
+			Self::from_arc(Arc::new(inner))
+		}
+		pub fn from_arc(inner: Arc<T>) -> Self {
+			let inner = _Inner(inner);
+			Self {
+				inner,
+				accept_compression_encodings: Default::default(),
+				send_compression_encodings: Default::default(),
+				max_decoding_message_size: None,
+				max_encoding_message_size: None,
+			}
+		}
+		pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+		where
+			F: tonic::service::Interceptor,
+		{
+			InterceptedService::new(Self::new(inner), interceptor)
+		}
+		/// Enable decompressing requests with the given encoding.
+		#[must_use]
+		pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+			self.accept_compression_encodings.enable(encoding);
+			self
+		}
+		/// Compress responses with the given encoding, if the client supports it.
+		#[must_use]
+		pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+			self.send_compression_encodings.enable(encoding);
+			self
+		}
+		/// Limits the maximum size of a decoded message.
+		///
+		/// Default: `4MB`
+		#[must_use]
+		pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+			self.max_decoding_message_size = Some(limit);
+			self
+		}
+		/// Limits the maximum size of an encoded message.
+		///
+		/// Default: `usize::MAX`
+		#[must_use]
+		pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+			self.max_encoding_message_size = Some(limit);
+			self
+		}
+	}
+	impl<T, B> tonic::codegen::Service<http::Request<B>> for TreeSitterServer<T>
+	where
+		T: TreeSitter,
+		B: Body + Send + 'static,
+		B::Error: Into<StdError> + Send + 'static,
+	{
+		type Response = http::Response<tonic::body::BoxBody>;
+		type Error = std::convert::Infallible;
+		type Future = BoxFuture<Self::Response, Self::Error>;
+		fn poll_ready(
+			&mut self,
+			_cx: &mut Context<'_>,
+		) -> Poll<std::result::Result<(), Self::Error>> {
+			Poll::Ready(Ok(()))
+		}
+		fn call(&mut self, req: http::Request<B>) -> Self::Future {
+			let inner = self.inner.clone();
+			match req.uri().path() {
+				"/asted.interface.TreeSitter/Init" => {
+					#[allow(non_camel_case_types)]
+					struct InitSvc<T: TreeSitter>(pub Arc<T>);
+					impl<T: TreeSitter> tonic::server::UnaryService<super::InitRequest> for InitSvc<T> {
+						type Response = super::InitResponse;
+						type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+						fn call(
+							&mut self,
+							request: tonic::Request<super::InitRequest>,
+						) -> Self::Future {
+							let inner = Arc::clone(&self.0);
+							let fut = async move { (*inner).init(request).await };
+							Box::pin(fut)
+						}
+					}
+					let accept_compression_encodings = self.accept_compression_encodings;
+					let send_compression_encodings = self.send_compression_encodings;
+					let max_decoding_message_size = self.max_decoding_message_size;
+					let max_encoding_message_size = self.max_encoding_message_size;
+					let inner = self.inner.clone();
+					let fut = async move {
+						let inner = inner.0;
+						let method = InitSvc(inner);
+						let codec = tonic::codec::ProstCodec::default();
+						let mut grpc = tonic::server::Grpc::new(codec)
+							.apply_compression_config(
+								accept_compression_encodings,
+								send_compression_encodings,
+							)
+							.apply_max_message_size_config(
+								max_decoding_message_size,
+								max_encoding_message_size,
+							);
+						let res = grpc.unary(method, req).await;
+						Ok(res)
+					};
+					Box::pin(fut)
+				}
+				"/asted.interface.TreeSitter/Open" => {
+					#[allow(non_camel_case_types)]
+					struct OpenSvc<T: TreeSitter>(pub Arc<T>);
+					impl<T: TreeSitter> tonic::server::UnaryService<super::OpenRequest> for OpenSvc<T> {
+						type Response = super::FileResponse;
+						type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+						fn call(
+							&mut self,
+							request: tonic::Request<super::OpenRequest>,
+						) -> Self::Future {
+							let inner = Arc::clone(&self.0);
+							let fut = async move { (*inner).open(request).await };
+							Box::pin(fut)
+						}
+					}
+					let accept_compression_encodings = self.accept_compression_encodings;
+					let send_compression_encodings = self.send_compression_encodings;
+					let max_decoding_message_size = self.max_decoding_message_size;
+					let max_encoding_message_size = self.max_encoding_message_size;
+					let inner = self.inner.clone();
+					let fut = async move {
+						let inner = inner.0;
+						let method = OpenSvc(inner);
+						let codec = tonic::codec::ProstCodec::default();
+						let mut grpc = tonic::server::Grpc::new(codec)
+							.apply_compression_config(
+								accept_compression_encodings,
+								send_compression_encodings,
+							)
+							.apply_max_message_size_config(
+								max_decoding_message_size,
+								max_encoding_message_size,
+							);
+						let res = grpc.unary(method, req).await;
+						Ok(res)
+					};
+					Box::pin(fut)
+				}
+				"/asted.interface.TreeSitter/Edit" => {
+					#[allow(non_camel_case_types)]
+					struct EditSvc<T: TreeSitter>(pub Arc<T>);
+					impl<T: TreeSitter> tonic::server::UnaryService<super::EditRequest> for EditSvc<T> {
+						type Response = super::FileResponse;
+						type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+						fn call(
+							&mut self,
+							request: tonic::Request<super::EditRequest>,
+						) -> Self::Future {
+							let inner = Arc::clone(&self.0);
+							let fut = async move { (*inner).edit(request).await };
+							Box::pin(fut)
+						}
+					}
+					let accept_compression_encodings = self.accept_compression_encodings;
+					let send_compression_encodings = self.send_compression_encodings;
+					let max_decoding_message_size = self.max_decoding_message_size;
+					let max_encoding_message_size = self.max_encoding_message_size;
+					let inner = self.inner.clone();
+					let fut = async move {
+						let inner = inner.0;
+						let method = EditSvc(inner);
+						let codec = tonic::codec::ProstCodec::default();
+						let mut grpc = tonic::server::Grpc::new(codec)
+							.apply_compression_config(
+								accept_compression_encodings,
+								send_compression_encodings,
+							)
+							.apply_max_message_size_config(
+								max_decoding_message_size,
+								max_encoding_message_size,
+							);
+						let res = grpc.unary(method, req).await;
+						Ok(res)
+					};
+					Box::pin(fut)
+				}
+				"/asted.interface.TreeSitter/Query" => {
+					#[allow(non_camel_case_types)]
+					struct QuerySvc<T: TreeSitter>(pub Arc<T>);
+					impl<T: TreeSitter> tonic::server::ServerStreamingService<super::QueryRequest> for QuerySvc<T> {
+						type Response = super::QueryResults;
+						type ResponseStream = T::QueryStream;
+						type Future =
+							BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+						fn call(
+							&mut self,
+							request: tonic::Request<super::QueryRequest>,
+						) -> Self::Future {
+							let inner = Arc::clone(&self.0);
+							let fut = async move { (*inner).query(request).await };
+							Box::pin(fut)
+						}
+					}
+					let accept_compression_encodings = self.accept_compression_encodings;
+					let send_compression_encodings = self.send_compression_encodings;
+					let max_decoding_message_size = self.max_decoding_message_size;
+					let max_encoding_message_size = self.max_encoding_message_size;
+					let inner = self.inner.clone();
+					let fut = async move {
+						let inner = inner.0;
+						let method = QuerySvc(inner);
+						let codec = tonic::codec::ProstCodec::default();
+						let mut grpc = tonic::server::Grpc::new(codec)
+							.apply_compression_config(
+								accept_compression_encodings,
+								send_compression_encodings,
+							)
+							.apply_max_message_size_config(
+								max_decoding_message_size,
+								max_encoding_message_size,
+							);
+						let res = grpc.server_streaming(method, req).await;
+						Ok(res)
+					};
+					Box::pin(fut)
+				}
+				"/asted.interface.TreeSitter/Subscribe" => {
+					#[allow(non_camel_case_types)]
+					struct SubscribeSvc<T: TreeSitter>(pub Arc<T>);
+					impl<T: TreeSitter>
+						tonic::server::ServerStreamingService<super::SubscribeRequest> for SubscribeSvc<T>
+					{
+						type Response = super::DocumentChanged;
+						type ResponseStream = T::SubscribeStream;
+						type Future =
+							BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+						fn call(
+							&mut self,
+							request: tonic::Request<super::SubscribeRequest>,
+						) -> Self::Future {
+							let inner = Arc::clone(&self.0);
+							let fut = async move { (*inner).subscribe(request).await };
+							Box::pin(fut)
+						}
+					}
+					let accept_compression_encodings = self.accept_compression_encodings;
+					let send_compression_encodings = self.send_compression_encodings;
+					let max_decoding_message_size = self.max_decoding_message_size;
+					let max_encoding_message_size = self.max_encoding_message_size;
+					let inner = self.inner.clone();
+					let fut = async move {
+						let inner = inner.0;
+						let method = SubscribeSvc(inner);
+						let codec = tonic::codec::ProstCodec::default();
+						let mut grpc = tonic::server::Grpc::new(codec)
+							.apply_compression_config(
+								accept_compression_encodings,
+								send_compression_encodings,
+							)
+							.apply_max_message_size_config(
+								max_decoding_message_size,
+								max_encoding_message_size,
+							);
+						let res = grpc.server_streaming(method, req).await;
+						Ok(res)
+					};
+					Box::pin(fut)
+				}
+				_ => Box::pin(async move {
+					Ok(http::Response::builder()
+						.status(200)
+						.header("grpc-status", "12")
+						.header("content-type", "application/grpc")
+						.body(empty_body())
+						.unwrap())
+				}),
+			}
+		}
+	}
+	impl<T: TreeSitter> Clone for TreeSitterServer<T> {
+		fn clone(&self) -> Self {
+			let inner = self.inner.clone();
+			Self {
+				inner,
+				accept_compression_encodings: self.accept_compression_encodings,
+				send_compression_encodings: self.send_compression_encodings,
+				max_decoding_message_size: self.max_decoding_message_size,
+				max_encoding_message_size: self.max_encoding_message_size,
+			}
+		}
+	}
+	impl<T: TreeSitter> Clone for _Inner<T> {
+		fn clone(&self) -> Self {
+			Self(Arc::clone(&self.0))
+		}
+	}
+	impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+		fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+			write!(f, "{:?}", self.0)
+		}
+	}
+	impl<T: TreeSitter> tonic::server::NamedService for TreeSitterServer<T> {
+		const NAME: &'static str = "asted.interface.TreeSitter";
+	}
+}
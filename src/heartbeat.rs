@@ -0,0 +1,22 @@
+use std::time::Duration;
+
+/// How connections that stay open (raw TCP and gRPC) check that the peer is still there: after
+/// `interval` without hearing from it, the server pings it, and once `timeout` goes by without
+/// anything coming back, the connection is given up on.
+#[derive(Clone, Copy, Debug)]
+pub struct Heartbeat {
+	pub interval: Duration,
+	pub timeout: Duration,
+}
+
+impl Heartbeat {
+	/// The heartbeat the `--heartbeat-interval` and `--heartbeat-timeout` seconds ask for, if an
+	/// interval of 0 doesn't turn it off.
+	pub fn from_secs(interval: u64, timeout: u64) -> Option<Self> {
+		(interval != 0).then(|| Heartbeat {
+			interval: Duration::from_secs(interval),
+			// a timeout no longer than the interval would give up before the ping could be answered
+			timeout: Duration::from_secs(timeout.max(interval + 1)),
+		})
+	}
+}
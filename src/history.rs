@@ -0,0 +1,231 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use anyhow::Result;
+use flatbuffers::FlatBufferBuilder;
+
+use crate::{
+	cancel,
+	error::Error,
+	fingerprint, git,
+	message_generated::asted::interface::{
+		DefinitionChange, DefinitionChangeArgs, DefinitionChangeKind, HistoryCommit,
+		HistoryCommitArgs, HistoryRequest, HistoryResponse, HistoryResponseArgs, Location,
+		SymbolKind,
+	},
+	queries, symbols, syntax_errors,
+	text::Text,
+	uri::{self, DocumentKey},
+	workspace_config, State,
+};
+
+/// A function (or method) of a version of the file.
+struct Function {
+	/// Qualified with the names of the definitions it's in
+	name: String,
+	kind: SymbolKind,
+	start_byte: usize,
+	end_byte: usize,
+	start_row: usize,
+	end_row: usize,
+	/// The structural hash of its subtree, which only changes with its tokens
+	hash: u64,
+}
+
+/// What's compared of a version of the file.
+#[derive(Default)]
+struct Version {
+	functions: Vec<Function>,
+	syntax_errors: usize,
+}
+
+/// Parses each version of the file in `req` over its commit range and returns a serialized
+/// `HistoryResponse` of how its functions changed from one to the next.
+pub fn history(state: &State, req: HistoryRequest) -> Result<Vec<u8>> {
+	let path = match uri::key(req.path())? {
+		DocumentKey::File(path) => path,
+		DocumentKey::Uri(_) => {
+			return Err(Error::UnknownFile(format!("{} is not a file:// URI", req.path())).into())
+		}
+	};
+	state.check_root(&path)?;
+	let lang = req
+		.lang()
+		.map(str::to_string)
+		.or_else(|| workspace_config::language_for(&path))
+		.or_else(|| state.lang.clone())
+		.ok_or_else(|| {
+			Error::InvalidRequest(
+				"HistoryRequest needs a lang, a file with a known extension or a session with a \
+				 language"
+					.to_string(),
+			)
+		})?;
+	let _span = tracing::info_span!("history", path = %path.display(), lang).entered();
+
+	let file = git::RepoFile::new(&path)?;
+	let to = req.to().unwrap_or("HEAD");
+	let range = match req.from() {
+		Some(from) => format!("{}..{}", from, to),
+		None => to.to_string(),
+	};
+	let max_commits = match req.max_commits() {
+		0 => None,
+		max => Some(max as usize),
+	};
+	let commits = file.log(&range, max_commits)?;
+
+	// the session's own parser for its language, so asking for it doesn't set up another one
+	let parser_lang =
+		(Some(lang.as_str()) != state.lang.as_deref() || state.plain_text).then_some(lang.as_str());
+	let parse = |source: Option<String>| -> Result<Option<Version>> {
+		cancel::check()?;
+		let source = match source {
+			Some(source) => source,
+			None => return Ok(None),
+		};
+		let text = Text::from(source.as_str());
+		let (tree, _) = state.parse_tree(parser_lang, &text, None, false)?;
+		let fingerprints = fingerprint::fingerprints(&tree, &text, Default::default());
+		let mut definitions = queries::tags(&lang, &tree, &text)
+			.into_iter()
+			.filter(|tag| tag.definition)
+			.collect::<Vec<_>>();
+		// parents before their children
+		definitions.sort_by_key(|tag| (tag.node.start_byte(), Reverse(tag.node.end_byte())));
+
+		let mut functions = Vec::new();
+		// the end and name of each definition the current one is in, innermost last
+		let mut around = Vec::<(usize, String)>::new();
+		for tag in definitions {
+			while around
+				.last()
+				.is_some_and(|(end_byte, _)| *end_byte <= tag.node.start_byte())
+			{
+				around.pop();
+			}
+			let mut name = around
+				.iter()
+				.map(|(_, name)| format!("{}.", name))
+				.collect::<String>();
+			name.push_str(&tag.name_text(&text));
+			if tag.is_function() {
+				functions.push(Function {
+					name: name.clone(),
+					kind: symbols::kind(&lang, tag.kind),
+					start_byte: tag.node.start_byte(),
+					end_byte: tag.node.end_byte(),
+					start_row: tag.node.start_position().row,
+					end_row: tag.node.end_position().row,
+					hash: fingerprints
+						.get(&tag.node.id())
+						.map_or(0, |fingerprint| fingerprint.hash),
+				});
+			}
+			around.push((tag.node.end_byte(), tag.name_text(&text)));
+		}
+		Ok(Some(Version {
+			functions,
+			syntax_errors: syntax_errors::syntax_errors(&text, &tree).len(),
+		}))
+	};
+
+	let mut before = match commits.first() {
+		Some(first) => parse(file.show(&format!("{}^", first.id))?)?.unwrap_or_default(),
+		None => Version::default(),
+	};
+	let mut builder = FlatBufferBuilder::new();
+	let mut built = Vec::new();
+	for commit in &commits {
+		let version = parse(file.show(&commit.id)?)?;
+		let deleted = version.is_none();
+		let after = version.unwrap_or_default();
+
+		let changes = changes(&before, &after)
+			.into_iter()
+			.map(|(kind, function)| {
+				let name = builder.create_string(&function.name);
+				DefinitionChange::create(
+					&mut builder,
+					&DefinitionChangeArgs {
+						kind,
+						name: Some(name),
+						symbol_kind: function.kind,
+						location: Some(&Location::new(
+							function.start_byte as u32,
+							function.end_byte as u32,
+						)),
+						start_row: function.start_row as u32,
+						end_row: function.end_row as u32,
+					},
+				)
+			})
+			.collect::<Vec<_>>();
+		let changes = builder.create_vector(&changes);
+		let id = builder.create_string(&commit.id);
+		let author = builder.create_string(&commit.author);
+		let summary = builder.create_string(&commit.summary);
+		built.push(HistoryCommit::create(
+			&mut builder,
+			&HistoryCommitArgs {
+				commit: Some(id),
+				author: Some(author),
+				time: commit.time,
+				summary: Some(summary),
+				deleted,
+				syntax_errors: after.syntax_errors as u32,
+				changes: Some(changes),
+			},
+		));
+		before = after;
+	}
+	let commits = builder.create_vector(&built);
+	let resp = HistoryResponse::create(
+		&mut builder,
+		&HistoryResponseArgs {
+			commits: Some(commits),
+		},
+	);
+	builder.finish(resp, None);
+
+	Ok(builder.finished_data().to_vec())
+}
+
+/// The functions `after` added or modified, in its order, and then the ones it removed from
+/// `before`. Functions with the same name (like overloads) are told apart by their order.
+fn changes<'a>(
+	before: &'a Version,
+	after: &'a Version,
+) -> Vec<(DefinitionChangeKind, &'a Function)> {
+	let keyed = |version: &'a Version| {
+		let mut seen = HashMap::<&str, usize>::new();
+		version
+			.functions
+			.iter()
+			.map(|function| {
+				let occurrence = seen.entry(&function.name).or_default();
+				*occurrence += 1;
+				((function.name.as_str(), *occurrence), function)
+			})
+			.collect::<Vec<_>>()
+	};
+	let (before, after) = (keyed(before), keyed(after));
+	let before_keys = before.iter().copied().collect::<HashMap<_, _>>();
+	let after_keys = after.iter().copied().collect::<HashMap<_, _>>();
+
+	let mut changes = Vec::new();
+	for (key, function) in &after {
+		match before_keys.get(key) {
+			None => changes.push((DefinitionChangeKind::Added, *function)),
+			Some(old) if old.hash != function.hash => {
+				changes.push((DefinitionChangeKind::Modified, *function))
+			}
+			Some(_) => {}
+		}
+	}
+	for (key, function) in &before {
+		if !after_keys.contains_key(key) {
+			changes.push((DefinitionChangeKind::Removed, *function));
+		}
+	}
+	changes
+}
@@ -0,0 +1,71 @@
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc, Mutex,
+	},
+	time::{Duration, Instant},
+};
+
+use axum::{extract::State, http::Request, middleware::Next, response::Response};
+
+/// When the server last handled a request, and how many it's handling now.
+pub struct Activity {
+	last: Mutex<Instant>,
+	/// Requests being handled, and clients holding a connection open
+	in_flight: AtomicUsize,
+}
+
+/// Counts as activity for as long as it's held.
+pub struct Busy(Arc<Activity>);
+
+impl Drop for Busy {
+	fn drop(&mut self) {
+		*self.0.last.lock().unwrap() = Instant::now();
+		self.0.in_flight.fetch_sub(1, Ordering::SeqCst);
+	}
+}
+
+impl Activity {
+	pub fn new() -> Self {
+		Activity {
+			last: Mutex::new(Instant::now()),
+			in_flight: AtomicUsize::new(0),
+		}
+	}
+
+	/// Keeps the server from going idle until the returned guard is dropped, for a request being
+	/// handled or a client that stays connected between requests (raw TCP and pipe connections,
+	/// gRPC streams).
+	pub fn busy(self: &Arc<Self>) -> Busy {
+		self.in_flight.fetch_add(1, Ordering::SeqCst);
+		Busy(self.clone())
+	}
+
+	fn idle_for(&self) -> Option<Duration> {
+		if self.in_flight.load(Ordering::SeqCst) > 0 {
+			return None;
+		}
+		Some(self.last.lock().unwrap().elapsed())
+	}
+}
+
+/// Middleware recording every request as activity.
+pub async fn track<B>(
+	State(activity): State<Arc<Activity>>,
+	req: Request<B>,
+	next: Next<B>,
+) -> Response {
+	let _busy = activity.busy();
+	next.run(req).await
+}
+
+/// Resolves once the server has gone `timeout` without a request, while no client was connected.
+pub async fn wait(activity: Arc<Activity>, timeout: Duration) {
+	loop {
+		let idle_for = activity.idle_for().unwrap_or_default();
+		if idle_for >= timeout {
+			return;
+		}
+		tokio::time::sleep(timeout.saturating_sub(idle_for).max(Duration::from_secs(1))).await;
+	}
+}
@@ -0,0 +1,119 @@
+use std::path::{Path, PathBuf};
+
+use tree_sitter::{Node, QueryCursor};
+
+use crate::{
+	document::Document,
+	message_generated::asted::interface::{
+		Import, ImportArgs, ImportsResponse, ImportsResponseArgs, Location,
+	},
+	queries::{self, Kind},
+	uri::DocumentKey,
+};
+
+/// What TypeScript tries appending to a relative module to find its file, in order.
+const TYPESCRIPT_SUFFIXES: &[&str] = &[
+	"",
+	".ts",
+	".tsx",
+	".d.ts",
+	".js",
+	".jsx",
+	"/index.ts",
+	"/index.tsx",
+	"/index.js",
+];
+
+/// Finds the imports of the document at `key` and returns a serialized `ImportsResponse`.
+pub fn imports(key: &DocumentKey, document: &Document) -> Vec<u8> {
+	let _span = tracing::info_span!("imports").entered();
+	let text = &document.text;
+	let dir = match key {
+		DocumentKey::File(path) => path.parent(),
+		DocumentKey::Uri(_) => None,
+	};
+
+	let mut found = Vec::new();
+	if let Some(query) = queries::get(&document.language, document.tree.language(), Kind::Imports) {
+		let names = query.capture_names();
+		let mut cursor = QueryCursor::new();
+		let matches = cursor.matches(query, document.tree.root_node(), |node: Node| {
+			text.chunks(node.start_byte() / 2..node.end_byte() / 2)
+		});
+		for m in matches {
+			let mut import = None;
+			let mut module = None;
+			for capture in m.captures {
+				let capture_name = names[capture.index as usize].as_str();
+				if let Some(kind) = capture_name.strip_prefix("import.") {
+					import = Some((capture.node, kind));
+				} else if capture_name == "module" {
+					module = Some(capture.node);
+				}
+			}
+			if let (Some((node, kind)), Some(module)) = (import, module) {
+				let module = String::from_utf16_lossy(
+					&text.slice(module.start_byte() / 2..module.end_byte() / 2),
+				);
+				let module = module.trim_matches(['"', '\'', '`', '<', '>']).to_string();
+				found.push((node, kind, module));
+			}
+		}
+	}
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let imports = found
+		.iter()
+		.map(|(node, kind, module)| {
+			let resolved = dir
+				.and_then(|dir| resolve(&document.language, kind, dir, module))
+				.map(|path| builder.create_string(&DocumentKey::file(&path).uri()));
+			let kind = builder.create_string(kind);
+			let module = builder.create_string(module);
+			Import::create(
+				&mut builder,
+				&ImportArgs {
+					location: Some(&Location::new(
+						node.start_byte() as u32,
+						node.end_byte() as u32,
+					)),
+					kind: Some(kind),
+					module: Some(module),
+					resolved,
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let imports = builder.create_vector(&imports);
+	let resp = ImportsResponse::create(
+		&mut builder,
+		&ImportsResponseArgs {
+			imports: Some(imports),
+			version: document.version,
+			content_hash: text.content_hash(),
+		},
+	);
+	builder.finish(resp, None);
+
+	builder.finished_data().to_vec()
+}
+
+/// The file `module` refers to, relative to `dir` (the directory of the importing file).
+fn resolve(lang: &str, kind: &str, dir: &Path, module: &str) -> Option<PathBuf> {
+	match lang {
+		"typescript" | "tsx" => {
+			// anything else is a package, which would take node_modules lookups and package.json
+			// exports to resolve
+			if !module.starts_with("./") && !module.starts_with("../") {
+				return None;
+			}
+			TYPESCRIPT_SUFFIXES
+				.iter()
+				.map(|suffix| dir.join(format!("{}{}", module, suffix)))
+				.find(|path| path.is_file())
+		}
+		// system headers are found on the compiler's include path
+		"cpp" if kind == "include" => Some(dir.join(module)).filter(|path| path.is_file()),
+		_ => None,
+	}
+}
@@ -0,0 +1,68 @@
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+use tree_sitter::{LANGUAGE_VERSION, MIN_COMPATIBLE_LANGUAGE_VERSION};
+
+use crate::{
+	languages::{self, LanguageInfo},
+	message_generated::asted::interface::{
+		self as fb, InitResponse, InitResponseArgs, LanguageInfoArgs, ListLanguagesResponse,
+		ListLanguagesResponseArgs,
+	},
+	State,
+};
+
+/// A serialized `InitResponse` for `state` once it's switched to its language.
+pub fn init_response(state: &State) -> Vec<u8> {
+	let info = match (&state.lang, state.plain_text) {
+		(Some(lang), false) => languages::info(lang),
+		_ => None,
+	};
+
+	let mut builder = FlatBufferBuilder::new();
+	let language = info.map(|info| create(&mut builder, &info));
+	let resp = InitResponse::create(&mut builder, &InitResponseArgs { language });
+	builder.finish(resp, None);
+
+	builder.finished_data().to_vec()
+}
+
+/// Lists every language with a grammar and returns a serialized `ListLanguagesResponse`.
+pub fn list_languages() -> Vec<u8> {
+	let _span = tracing::info_span!("list_languages").entered();
+	let mut builder = FlatBufferBuilder::new();
+	let languages = languages::names()
+		.iter()
+		// a grammar reload may have dropped it meanwhile
+		.filter_map(|name| languages::info(name))
+		.map(|info| create(&mut builder, &info))
+		.collect::<Vec<_>>();
+	let languages = builder.create_vector(&languages);
+	let resp = ListLanguagesResponse::create(
+		&mut builder,
+		&ListLanguagesResponseArgs {
+			languages: Some(languages),
+			min_abi_version: MIN_COMPATIBLE_LANGUAGE_VERSION as u32,
+			max_abi_version: LANGUAGE_VERSION as u32,
+		},
+	);
+	builder.finish(resp, None);
+
+	builder.finished_data().to_vec()
+}
+
+fn create<'a>(
+	builder: &mut FlatBufferBuilder<'a>,
+	info: &LanguageInfo,
+) -> WIPOffset<fb::LanguageInfo<'a>> {
+	let name = builder.create_string(&info.name);
+	let grammar_version = info
+		.grammar_version
+		.map(|version| builder.create_string(version));
+	fb::LanguageInfo::create(
+		builder,
+		&LanguageInfoArgs {
+			name: Some(name),
+			abi_version: info.abi_version as u32,
+			grammar_version,
+		},
+	)
+}
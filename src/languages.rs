@@ -0,0 +1,445 @@
+use std::{
+	collections::HashMap,
+	fs,
+	path::{Path, PathBuf},
+	sync::{mpsc, PoisonError, RwLock},
+	thread,
+	time::Duration,
+};
+
+use anyhow::{bail, Context, Result};
+use libloading::Library;
+use notify::{RecursiveMode, Watcher};
+use once_cell::sync::Lazy;
+use serde::{de, Deserialize, Deserializer};
+use tree_sitter::{Language, LANGUAGE_VERSION, MIN_COMPATIBLE_LANGUAGE_VERSION};
+
+use crate::{
+	fnv,
+	message_generated::asted::interface::{SerializationProfile, SymbolKind},
+	symbols,
+};
+
+/// Settings for a language, from a `[languages.<name>]` table of the config file.
+#[derive(Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LanguageConfig {
+	/// File extensions (without the dot) of files in the language, besides the built-in ones
+	pub extensions: Vec<String>,
+	/// How long parsing a document can take before it's abandoned
+	pub parse_timeout_ms: Option<u64>,
+	/// The largest file, in bytes, that can be read from disk
+	pub max_file_size: Option<u64>,
+	/// What trees are serialized with when requests ask for the default profile, by its name in
+	/// kebab-case (like `structure-only`)
+	#[serde(deserialize_with = "profile")]
+	pub profile: Option<SerializationProfile>,
+	/// A directory of queries for the language, like the subdirectories of `--queries-dir`, which
+	/// it takes precedence over
+	pub queries: Option<PathBuf>,
+	/// Contexts for `ParseSnippetRequest`s by name, as scaffolding code with `$SNIPPET` where the
+	/// snippet goes (like `function _() { $SNIPPET }`), besides the built-in ones (which they
+	/// replace if named the same)
+	pub snippet_contexts: HashMap<String, String>,
+	/// Kinds to serialize nodes as instead of the grammar's, by the grammar's kind, so that trees of
+	/// languages naming the same construct differently (like `function_item` and
+	/// `function_declaration`) come out alike
+	pub kind_aliases: HashMap<String, String>,
+	/// What definitions found by the tags query as `@definition.<kind>` are, by `<kind>`, as the
+	/// name of a symbol kind (like `method` or `enum_member`), for kinds that aren't already named
+	/// like one
+	#[serde(deserialize_with = "symbol_kinds")]
+	pub symbol_kinds: HashMap<String, SymbolKind>,
+	/// The `node-types.json` of a shared-library grammar, for the supertypes of its nodes, which
+	/// built-in grammars come with
+	pub node_types: Option<PathBuf>,
+}
+
+fn profile<'de, D: Deserializer<'de>>(
+	deserializer: D,
+) -> Result<Option<SerializationProfile>, D::Error> {
+	const NAMES: &[&str] = &["default", "full", "structure-only", "outline", "ranges"];
+	let name = String::deserialize(deserializer)?;
+	let profile = match name.as_str() {
+		"default" => SerializationProfile::Default,
+		"full" => SerializationProfile::Full,
+		"structure-only" => SerializationProfile::StructureOnly,
+		"outline" => SerializationProfile::Outline,
+		"ranges" => SerializationProfile::Ranges,
+		_ => return Err(de::Error::unknown_variant(&name, NAMES)),
+	};
+	Ok(Some(profile))
+}
+
+fn symbol_kinds<'de, D: Deserializer<'de>>(
+	deserializer: D,
+) -> Result<HashMap<String, SymbolKind>, D::Error> {
+	HashMap::<String, String>::deserialize(deserializer)?
+		.into_iter()
+		.map(|(tags_kind, name)| match symbols::kind_named(&name) {
+			Some(kind) => Ok((tags_kind, kind)),
+			// `unknown_variant` wants names that live as long as the deserializer
+			None => {
+				let names = symbols::KIND_NAMES
+					.iter()
+					.map(|(name, _)| *name)
+					.collect::<Vec<_>>();
+				Err(de::Error::custom(format_args!(
+					"unknown symbol kind `{}`, expected one of {}",
+					name,
+					names.join(", ")
+				)))
+			}
+		})
+		.collect()
+}
+
+/// The versions of the grammar crates the built-in languages come from, as in Cargo.toml.
+const BUILT_IN_VERSIONS: &[(&str, &str)] = &[
+	("typescript", "0.20.2"),
+	("tsx", "0.20.2"),
+	("cpp", "0.20.2"),
+];
+
+/// A language with a grammar, and the versions of it.
+pub struct LanguageInfo {
+	pub name: String,
+	/// The tree-sitter ABI version the grammar was generated for
+	pub abi_version: usize,
+	/// The version of the grammar crate a built-in language comes from, which shared-library
+	/// grammars don't have
+	pub grammar_version: Option<&'static str>,
+}
+
+// Poisoning is ignored: a panic mid-reload leaves every entry pointing at a loaded grammar.
+static REGISTRY: Lazy<RwLock<Registry>> = Lazy::new(|| RwLock::new(Registry::new()));
+
+struct Registry {
+	languages: HashMap<String, Language>,
+	/// The languages still served by their built-in grammar, by the grammar's crate version
+	built_in_versions: HashMap<String, &'static str>,
+	/// Hashes of the shared libraries the other languages' grammars were loaded from
+	library_hashes: HashMap<String, u64>,
+	configs: HashMap<String, LanguageConfig>,
+	grammar_dir: Option<PathBuf>,
+	// Libraries are never unloaded, since parsers and trees created from an older copy of a
+	// grammar keep pointing into its parse tables.
+	libraries: Vec<Library>,
+	generation: usize,
+}
+
+impl Registry {
+	fn new() -> Self {
+		let mut languages = HashMap::new();
+		languages.insert(
+			"typescript".to_string(),
+			tree_sitter_typescript::language_typescript(),
+		);
+		languages.insert("tsx".to_string(), tree_sitter_typescript::language_tsx());
+		languages.insert("cpp".to_string(), tree_sitter_cpp::language());
+
+		Registry {
+			languages,
+			built_in_versions: BUILT_IN_VERSIONS
+				.iter()
+				.map(|&(name, version)| (name.to_string(), version))
+				.collect(),
+			library_hashes: HashMap::new(),
+			configs: HashMap::new(),
+			grammar_dir: None,
+			libraries: Vec::new(),
+			generation: 0,
+		}
+	}
+}
+
+pub fn get(name: &str) -> Option<Language> {
+	REGISTRY
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.languages
+		.get(name)
+		.copied()
+}
+
+pub fn names() -> Vec<String> {
+	let mut names = REGISTRY
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.languages
+		.keys()
+		.cloned()
+		.collect::<Vec<_>>();
+	names.sort();
+	names
+}
+
+/// The versions of the language called `name`, if it has a grammar.
+pub fn info(name: &str) -> Option<LanguageInfo> {
+	let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+	let language = registry.languages.get(name)?;
+	Some(LanguageInfo {
+		name: name.to_string(),
+		abi_version: language.version(),
+		grammar_version: registry.built_in_versions.get(name).copied(),
+	})
+}
+
+/// Replaces the settings of every language.
+pub fn set_configs(configs: HashMap<String, LanguageConfig>) {
+	REGISTRY
+		.write()
+		.unwrap_or_else(PoisonError::into_inner)
+		.configs = configs;
+}
+
+/// The settings of the language called `name`, which are all left out if it has none.
+pub fn config(name: &str) -> LanguageConfig {
+	REGISTRY
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.configs
+		.get(name)
+		.cloned()
+		.unwrap_or_default()
+}
+
+/// The kind aliases of the language `language` is the grammar of, which are empty if it has none.
+pub fn kind_aliases(language: Language) -> HashMap<String, String> {
+	let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+	registry
+		.languages
+		.iter()
+		.find(|(_, l)| **l == language)
+		.and_then(|(name, _)| registry.configs.get(name))
+		.map(|config| config.kind_aliases.clone())
+		.unwrap_or_default()
+}
+
+/// Tells grammars apart: a hash of the shared library a grammar was loaded from, or of the crate
+/// version of a built-in one.
+pub fn grammar_hash(language: Language) -> u64 {
+	let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+	let name = match registry.languages.iter().find(|(_, l)| **l == language) {
+		Some((name, _)) => name,
+		None => return 0,
+	};
+	match registry.library_hashes.get(name) {
+		Some(hash) => *hash,
+		None => fnv::hash(
+			registry
+				.built_in_versions
+				.get(name)
+				.copied()
+				.unwrap_or_default()
+				.as_bytes(),
+		),
+	}
+}
+
+/// The `node-types.json` of the grammar `language`, if it's a built-in one or one configured with
+/// `node_types`.
+pub fn node_types(language: Language) -> Result<Option<String>> {
+	let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+	let name = match registry.languages.iter().find(|(_, l)| **l == language) {
+		Some((name, _)) => name,
+		None => return Ok(None),
+	};
+	if registry.built_in_versions.contains_key(name) {
+		let node_types = match name.as_str() {
+			"typescript" => tree_sitter_typescript::TYPESCRIPT_NODE_TYPES,
+			"tsx" => tree_sitter_typescript::TSX_NODE_TYPES,
+			"cpp" => tree_sitter_cpp::NODE_TYPES,
+			_ => return Ok(None),
+		};
+		return Ok(Some(node_types.to_string()));
+	}
+	match registry
+		.configs
+		.get(name)
+		.and_then(|config| config.node_types.as_ref())
+	{
+		Some(path) => fs::read_to_string(path)
+			.map(Some)
+			.with_context(|| format!("Error reading node types {}", path.display())),
+		None => Ok(None),
+	}
+}
+
+/// The settings of every language that has some.
+pub fn configs() -> HashMap<String, LanguageConfig> {
+	REGISTRY
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.configs
+		.clone()
+}
+
+/// The language files with the extension `ext` are in, if it's a built-in or configured one.
+pub fn for_extension(ext: &str) -> Option<String> {
+	let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+	let configured = registry
+		.configs
+		.iter()
+		.find(|(_, config)| config.extensions.iter().any(|e| e == ext));
+	if let Some((name, _)) = configured {
+		return Some(name.clone());
+	}
+	let name = match ext {
+		"ts" | "mts" | "cts" => "typescript",
+		"tsx" => "tsx",
+		"cc" | "cpp" | "cxx" | "h" | "hh" | "hpp" | "hxx" => "cpp",
+		_ => return None,
+	};
+	Some(name.to_string())
+}
+
+/// Sets the directory shared-library grammars are loaded from and loads them.
+pub fn set_grammar_dir(dir: PathBuf) -> Result<Reloaded> {
+	REGISTRY
+		.write()
+		.unwrap_or_else(PoisonError::into_inner)
+		.grammar_dir = Some(dir);
+	reload()
+}
+
+/// The languages a reload of the grammar directory loaded grammars for, and the ones whose
+/// grammars failed to load, which keep the grammar they had before.
+pub struct Reloaded {
+	pub loaded: Vec<String>,
+	pub failed: Vec<String>,
+}
+
+/// Re-loads every shared-library grammar in the grammar directory. The registry only switches to
+/// the new grammars once they've all been tried, so it's never left half reloaded.
+pub fn reload() -> Result<Reloaded> {
+	let (dir, generation) = {
+		let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+		let dir = match &registry.grammar_dir {
+			Some(dir) => dir.clone(),
+			None => {
+				return Ok(Reloaded {
+					loaded: Vec::new(),
+					failed: Vec::new(),
+				})
+			}
+		};
+		registry.generation += 1;
+		(dir, registry.generation)
+	};
+
+	let mut grammars = HashMap::new();
+	let mut failed = Vec::new();
+	for entry in fs::read_dir(&dir)
+		.with_context(|| format!("Error reading grammar directory {}", dir.display()))?
+	{
+		let path = entry?.path();
+		if path.extension().and_then(|ext| ext.to_str()) != Some(std::env::consts::DLL_EXTENSION) {
+			continue;
+		}
+		let name = match grammar_name(&path) {
+			Some(name) => name,
+			None => continue,
+		};
+
+		match load_grammar(&path, &name, generation) {
+			Ok(grammar) => {
+				tracing::info!("loaded grammar {} from {}", name, path.display());
+				grammars.insert(name, grammar);
+			}
+			Err(e) => {
+				tracing::error!("Error loading grammar {}: {:#}", path.display(), e);
+				failed.push(name);
+			}
+		}
+	}
+
+	let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+	let mut loaded = Vec::new();
+	for (name, (library, language, hash)) in grammars {
+		registry.libraries.push(library);
+		registry.languages.insert(name.clone(), language);
+		registry.built_in_versions.remove(&name);
+		registry.library_hashes.insert(name.clone(), hash);
+		loaded.push(name);
+	}
+
+	loaded.sort();
+	failed.sort();
+	Ok(Reloaded { loaded, failed })
+}
+
+/// Calls `on_change` whenever the contents of `dir` change, until the process exits.
+pub fn watch(dir: PathBuf, on_change: impl Fn() + Send + 'static) -> Result<()> {
+	let (tx, rx) = mpsc::channel();
+	let mut watcher = notify::recommended_watcher(tx).context("Error creating file watcher")?;
+	watcher
+		.watch(&dir, RecursiveMode::NonRecursive)
+		.with_context(|| format!("Error watching {}", dir.display()))?;
+
+	thread::spawn(move || {
+		// keep the watcher alive for as long as the thread runs
+		let _watcher = watcher;
+		while rx.recv().is_ok() {
+			// builds usually touch a grammar several times in a row, so wait for things to settle
+			while rx.recv_timeout(Duration::from_millis(200)).is_ok() {}
+			on_change();
+		}
+	});
+
+	Ok(())
+}
+
+/// Maps `libtree-sitter-foo.so` (or `foo.so`) to `foo`.
+fn grammar_name(path: &Path) -> Option<String> {
+	let stem = path.file_stem()?.to_str()?;
+	let stem = stem.strip_prefix("lib").unwrap_or(stem);
+	let stem = stem.strip_prefix("tree-sitter-").unwrap_or(stem);
+	if stem.is_empty() {
+		None
+	} else {
+		Some(stem.to_string())
+	}
+}
+
+/// Loads the grammar for `name` from the shared library at `path`, along with the library's hash.
+fn load_grammar(path: &Path, name: &str, generation: usize) -> Result<(Library, Language, u64)> {
+	// The dynamic loader hands back the already-loaded library when asked to open the same path
+	// twice, so load a fresh copy to pick up a rebuilt grammar.
+	let copy_dir = std::env::temp_dir().join(format!("asted-grammars-{}", std::process::id()));
+	fs::create_dir_all(&copy_dir).context("Error creating grammar copy directory")?;
+	let copy = copy_dir.join(format!(
+		"{}-{}",
+		generation,
+		path.file_name().unwrap().to_string_lossy()
+	));
+	let bytes = fs::read(path).context("Error reading grammar")?;
+	fs::write(&copy, &bytes).context("Error copying grammar")?;
+
+	let library = unsafe { Library::new(&copy) }.context("Error opening shared library");
+	// the copy is no longer needed once it's mapped (this fails harmlessly on Windows)
+	let _ = fs::remove_file(&copy);
+	let library = library?;
+
+	let symbol = format!("tree_sitter_{}", name.replace('-', "_"));
+	let language = unsafe {
+		let constructor = library
+			.get::<unsafe extern "C" fn() -> Language>(symbol.as_bytes())
+			.with_context(|| format!("Missing symbol {}", symbol))?;
+		constructor()
+	};
+
+	// parsing with a grammar of another ABI reads its tables wrong, so refuse it up front
+	let abi_version = language.version();
+	if !(MIN_COMPATIBLE_LANGUAGE_VERSION..=LANGUAGE_VERSION).contains(&abi_version) {
+		bail!(
+			"The grammar has ABI version {}, but this server only supports versions {} to {}; \
+			 regenerate it with a tree-sitter CLI of a compatible version",
+			abi_version,
+			MIN_COMPATIBLE_LANGUAGE_VERSION,
+			LANGUAGE_VERSION
+		);
+	}
+
+	Ok((library, language, fnv::hash(&bytes)))
+}
@@ -0,0 +1,220 @@
+use std::{
+	hash::Hash,
+	net::{IpAddr, SocketAddr},
+	sync::{Arc, Mutex, PoisonError, RwLock},
+	time::{Duration, Instant},
+};
+
+use axum::{
+	extract::ConnectInfo,
+	http::Request,
+	middleware::Next,
+	response::{IntoResponse, Response},
+};
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::error::Error;
+
+/// Caps on how much of the server a client can take up at once.
+pub struct Limits {
+	concurrency: Option<Arc<Semaphore>>,
+	/// Requests per second allowed from each client address
+	rate: Option<RateLimiter<IpAddr>>,
+}
+
+/// Allows each client (as told apart by `K`) a number of requests per second.
+pub struct RateLimiter<K> {
+	rate: f64,
+	buckets: DashMap<K, Bucket>,
+	/// When buckets were last swept for ones that have filled up again
+	swept: Mutex<Instant>,
+}
+
+/// How often buckets that have filled up are dropped, so clients that have gone away aren't kept
+/// track of forever. A full bucket is no different from a new one.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A token bucket holding up to one second's worth of requests
+struct Bucket {
+	tokens: f64,
+	updated: Instant,
+}
+
+impl Limits {
+	pub fn new(max_concurrent_requests: Option<usize>, rate_limit: Option<u32>) -> Self {
+		Limits {
+			concurrency: max_concurrent_requests.map(|max| Arc::new(Semaphore::new(max))),
+			rate: rate_limit.map(RateLimiter::new),
+		}
+	}
+}
+
+static LIMITS: Lazy<RwLock<Arc<Limits>>> =
+	Lazy::new(|| RwLock::new(Arc::new(Limits::new(None, None))));
+
+/// Holds clients to `limits` from now on, starting their rate limits afresh. Requests already
+/// being handled still count towards the old concurrency limit until they're done.
+pub fn set(limits: Limits) {
+	*LIMITS.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(limits);
+}
+
+fn current() -> Arc<Limits> {
+	LIMITS
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.clone()
+}
+
+impl<K: Eq + Hash> RateLimiter<K> {
+	pub fn new(rate_limit: u32) -> Self {
+		RateLimiter {
+			rate: f64::from(rate_limit),
+			buckets: DashMap::new(),
+			swept: Mutex::new(Instant::now()),
+		}
+	}
+
+	/// Takes a token from the client's bucket, returning how many seconds to wait if it's empty.
+	pub fn take_token(&self, client: K) -> Result<(), u64> {
+		self.take_token_at(client, Instant::now())
+	}
+
+	fn take_token_at(&self, client: K, now: Instant) -> Result<(), u64> {
+		let rate = self.rate;
+		self.sweep(now);
+		let mut bucket = self.buckets.entry(client).or_insert(Bucket {
+			tokens: rate,
+			updated: now,
+		});
+		let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+		bucket.tokens = (bucket.tokens + elapsed * rate).min(rate);
+		bucket.updated = now;
+
+		if bucket.tokens >= 1.0 {
+			bucket.tokens -= 1.0;
+			Ok(())
+		} else {
+			Err(((1.0 - bucket.tokens) / rate).ceil() as u64)
+		}
+	}
+
+	/// Drops the buckets that have filled up again, if they haven't been swept for a while.
+	fn sweep(&self, now: Instant) {
+		{
+			let mut swept = self.swept.lock().unwrap_or_else(PoisonError::into_inner);
+			if now.duration_since(*swept) < SWEEP_INTERVAL {
+				return;
+			}
+			*swept = now;
+		}
+		let rate = self.rate;
+		self.buckets.retain(|_, bucket| {
+			bucket.tokens + now.duration_since(bucket.updated).as_secs_f64() * rate < rate
+		});
+	}
+}
+
+/// Holds a request from `client` to the limits, failing with a too-many-requests error if the
+/// client is over its rate limit or the server is already handling as many requests as it's
+/// allowed to. The request counts towards the concurrency limit until the permit is dropped.
+pub fn admit(client: IpAddr) -> Result<Option<OwnedSemaphorePermit>, Error> {
+	let limits = current();
+	if let Some(Err(retry_after)) = limits.rate.as_ref().map(|rate| rate.take_token(client)) {
+		tracing::warn!("rate limiting {}", client);
+		return Err(Error::TooManyRequests(
+			format!("Too many requests from {}", client),
+			retry_after,
+		));
+	}
+
+	match &limits.concurrency {
+		Some(semaphore) => match semaphore.clone().try_acquire_owned() {
+			Ok(permit) => Ok(Some(permit)),
+			Err(_) => {
+				tracing::warn!(
+					"too many concurrent requests, rejecting request from {}",
+					client
+				);
+				Err(Error::TooManyRequests(
+					"Too many concurrent requests".to_string(),
+					1,
+				))
+			}
+		},
+		None => Ok(None),
+	}
+}
+
+/// Middleware rejecting requests with a 429 when `admit` turns them away.
+pub async fn limit<B>(
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	req: Request<B>,
+	next: Next<B>,
+) -> Response {
+	let _permit = match admit(addr.ip()) {
+		Ok(permit) => permit,
+		Err(e) => return e.into_response(),
+	};
+	next.run(req).await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn refills_buckets_over_time() {
+		let limiter = RateLimiter::new(2);
+		let start = Instant::now();
+		assert_eq!(limiter.take_token_at("a", start), Ok(()));
+		assert_eq!(limiter.take_token_at("a", start), Ok(()));
+		assert!(limiter.take_token_at("a", start).is_err());
+		// other clients have buckets of their own
+		assert_eq!(limiter.take_token_at("b", start), Ok(()));
+
+		let later = start + Duration::from_millis(500);
+		assert_eq!(limiter.take_token_at("a", later), Ok(()));
+		assert!(limiter.take_token_at("a", later).is_err());
+
+		// never past a second's worth of requests
+		let much_later = later + Duration::from_secs(10);
+		assert_eq!(limiter.take_token_at("a", much_later), Ok(()));
+		assert_eq!(limiter.take_token_at("a", much_later), Ok(()));
+		assert!(limiter.take_token_at("a", much_later).is_err());
+	}
+
+	#[test]
+	fn tells_clients_how_long_to_wait() {
+		let limiter = RateLimiter::new(1);
+		let start = Instant::now();
+		assert_eq!(limiter.take_token_at((), start), Ok(()));
+		assert_eq!(limiter.take_token_at((), start), Err(1));
+		// a rounded-up wait for what's left of the token
+		let later = start + Duration::from_millis(900);
+		assert_eq!(limiter.take_token_at((), later), Err(1));
+	}
+
+	#[test]
+	fn sweeps_full_buckets() {
+		let limiter = RateLimiter::new(10);
+		let start = Instant::now();
+		limiter.take_token_at("idle", start).unwrap();
+		limiter.take_token_at("busy", start).unwrap();
+
+		// too soon to sweep
+		limiter.sweep(start + Duration::from_secs(1));
+		assert_eq!(limiter.buckets.len(), 2);
+
+		let later = start + SWEEP_INTERVAL;
+		for _ in 0..10 {
+			limiter
+				.take_token_at("busy", later - Duration::from_millis(1))
+				.unwrap();
+		}
+		limiter.sweep(later);
+		assert!(!limiter.buckets.contains_key("idle"));
+		assert!(limiter.buckets.contains_key("busy"));
+	}
+}
@@ -0,0 +1,52 @@
+use anyhow::{Context, Result};
+use once_cell::sync::OnceCell;
+use tracing_subscriber::{
+	filter::LevelFilter, fmt::writer::BoxMakeWriter, layer::SubscriberExt, reload,
+	util::SubscriberInitExt, Layer, Registry,
+};
+
+/// For changing the level of the log lines written once logging is set up.
+static LEVEL: OnceCell<reload::Handle<LevelFilter, Registry>> = OnceCell::new();
+
+/// Writes log lines at `level` and above to stdout, or to stderr if stdout carries the Language
+/// Server Protocol. Requests are timed for `timings` whatever the level, and their spans are
+/// exported to `otlp_endpoint` too, if there is one.
+pub fn init(
+	level: LevelFilter,
+	lsp: bool,
+	#[cfg(feature = "otel")] otlp_endpoint: Option<&str>,
+) -> Result<()> {
+	let writer = match lsp {
+		true => BoxMakeWriter::new(std::io::stderr),
+		false => BoxMakeWriter::new(std::io::stdout),
+	};
+	let (level, handle) = reload::Layer::new(level);
+	let registry = tracing_subscriber::registry()
+		.with(
+			tracing_subscriber::fmt::layer()
+				.with_writer(writer)
+				.with_filter(level),
+		)
+		// whatever is logged, for GET /admin/profile
+		.with(crate::timings::Layer.with_filter(LevelFilter::INFO));
+
+	#[cfg(feature = "otel")]
+	let registry = registry.with(match otlp_endpoint {
+		Some(endpoint) => Some(crate::telemetry::layer(endpoint)?),
+		None => None,
+	});
+
+	registry
+		.try_init()
+		.context("Error installing tracing subscriber")?;
+	let _ = LEVEL.set(handle);
+	Ok(())
+}
+
+/// Writes log lines at `level` and above from now on.
+pub fn set_level(level: LevelFilter) -> Result<()> {
+	match LEVEL.get() {
+		Some(handle) => handle.reload(level).context("Error changing the log level"),
+		None => Ok(()),
+	}
+}
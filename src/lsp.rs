@@ -0,0 +1,677 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use anyhow::{Context, Result};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+	notification::{
+		DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+		Notification as LspNotification, PublishDiagnostics, ShowMessage,
+	},
+	request::{
+		DocumentSymbolRequest, FoldingRangeRequest, Request as LspRequest, SelectionRangeRequest,
+		SemanticTokensFullRequest,
+	},
+	Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, DidChangeTextDocumentParams,
+	DidCloseTextDocumentParams, DidOpenTextDocumentParams, DocumentSymbol, DocumentSymbolParams,
+	DocumentSymbolResponse, FoldingRange, FoldingRangeKind, FoldingRangeParams,
+	FoldingRangeProviderCapability, InitializeParams, Location, MessageType, OneOf, Position,
+	PublishDiagnosticsParams, Range, SelectionRange, SelectionRangeParams,
+	SelectionRangeProviderCapability, SemanticToken, SemanticTokenModifier, SemanticTokenType,
+	SemanticTokens, SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions,
+	SemanticTokensParams, SemanticTokensResult, SemanticTokensServerCapabilities,
+	ServerCapabilities, ShowMessageParams, SymbolKind, TextDocumentSyncCapability,
+	TextDocumentSyncKind, Url,
+};
+use tree_sitter::{Node, Point, QueryCursor};
+
+use crate::{
+	document::Document,
+	error::Error,
+	folding, languages,
+	message_generated::asted::interface as fb,
+	queries::{self, Kind},
+	selection_ranges, symbols,
+	syntax_errors::{self, ErrorGroup, SyntaxError},
+	text::Text,
+	uri, State, STATE_MAP,
+};
+
+/// Semantic token types, in the order of the legend sent to the client.
+const TOKEN_TYPES: &[SemanticTokenType] = &[
+	SemanticTokenType::COMMENT,
+	SemanticTokenType::STRING,
+	SemanticTokenType::REGEXP,
+	SemanticTokenType::NUMBER,
+	SemanticTokenType::KEYWORD,
+	SemanticTokenType::OPERATOR,
+	SemanticTokenType::FUNCTION,
+	SemanticTokenType::METHOD,
+	SemanticTokenType::MACRO,
+	SemanticTokenType::TYPE,
+	SemanticTokenType::NAMESPACE,
+	SemanticTokenType::PARAMETER,
+	SemanticTokenType::VARIABLE,
+	SemanticTokenType::PROPERTY,
+];
+
+/// Semantic token modifiers, in the order of the legend sent to the client.
+const TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
+	SemanticTokenModifier::READONLY,
+	SemanticTokenModifier::DEFAULT_LIBRARY,
+];
+
+/// Serves the Language Server Protocol over stdin and stdout until the client exits.
+///
+/// Documents are kept in one session per language (`lsp:<language>`), so they're parsed and
+/// edited incrementally just like documents sent over HTTP. Their syntax errors are published as
+/// diagnostics whenever they change.
+pub fn run() -> Result<()> {
+	let (connection, io_threads) = Connection::stdio();
+	let capabilities = serde_json::to_value(capabilities())?;
+	let params = connection
+		.initialize(capabilities)
+		.context("Error initializing LSP connection")?;
+	// capabilities the client leaves out (or that don't parse) are taken as unsupported
+	let params = serde_json::from_value::<InitializeParams>(params).ok();
+
+	let mut server = Server {
+		related_information: params
+			.and_then(|params| params.capabilities.text_document)
+			.and_then(|capabilities| capabilities.publish_diagnostics)
+			.and_then(|capabilities| capabilities.related_information)
+			.unwrap_or(false),
+		..Default::default()
+	};
+	for message in &connection.receiver {
+		match message {
+			Message::Request(req) => {
+				if connection
+					.handle_shutdown(&req)
+					.context("Error shutting down")?
+				{
+					break;
+				}
+				connection
+					.sender
+					.send(Message::Response(server.request(req)))?;
+			}
+			Message::Notification(notification) => {
+				// nothing can be sent back for a notification, so errors are shown to the user
+				let res = server
+					.notification(notification)
+					.and_then(|changed| changed.map(|uri| server.diagnostics(uri)).transpose());
+				let notification = match res {
+					Ok(Some(diagnostics)) => {
+						Notification::new(PublishDiagnostics::METHOD.to_string(), diagnostics)
+					}
+					Ok(None) => continue,
+					Err(e) => Notification::new(
+						ShowMessage::METHOD.to_string(),
+						ShowMessageParams {
+							typ: MessageType::ERROR,
+							message: format!("{:#}", e),
+						},
+					),
+				};
+				connection
+					.sender
+					.send(Message::Notification(notification))?;
+			}
+			Message::Response(_) => {}
+		}
+	}
+
+	drop(connection);
+	io_threads.join().context("Error closing LSP connection")?;
+	Ok(())
+}
+
+fn capabilities() -> ServerCapabilities {
+	ServerCapabilities {
+		text_document_sync: Some(TextDocumentSyncCapability::Kind(
+			TextDocumentSyncKind::INCREMENTAL,
+		)),
+		document_symbol_provider: Some(OneOf::Left(true)),
+		folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+		selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+		semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensOptions(
+			SemanticTokensOptions {
+				legend: SemanticTokensLegend {
+					token_types: TOKEN_TYPES.to_vec(),
+					token_modifiers: TOKEN_MODIFIERS.to_vec(),
+				},
+				full: Some(SemanticTokensFullOptions::Bool(true)),
+				..Default::default()
+			},
+		)),
+		..Default::default()
+	}
+}
+
+/// Maps LSP language identifiers to grammar names.
+fn language(language_id: &str) -> &str {
+	match language_id {
+		"typescriptreact" => "tsx",
+		// TypeScript's grammar is a superset of JavaScript's
+		"javascript" => "typescript",
+		"javascriptreact" => "tsx",
+		language_id => language_id,
+	}
+}
+
+#[derive(Default)]
+struct Server {
+	/// The session each open document is kept in, by URI. Documents in languages without a
+	/// grammar aren't tracked, and get empty results.
+	sessions: HashMap<Url, String>,
+	/// Whether the client shows the related information of diagnostics
+	related_information: bool,
+}
+
+impl Server {
+	/// Handles a notification, returning the document it changed, if any.
+	fn notification(&mut self, notification: Notification) -> Result<Option<Url>> {
+		let uri = match notification.method.as_str() {
+			DidOpenTextDocument::METHOD => {
+				let params = params::<DidOpenTextDocumentParams>(notification.params)?;
+				let uri = params.text_document.uri.clone();
+				self.did_open(params)?;
+				uri
+			}
+			DidChangeTextDocument::METHOD => {
+				let params = params::<DidChangeTextDocumentParams>(notification.params)?;
+				let uri = params.text_document.uri.clone();
+				self.did_change(params)?;
+				uri
+			}
+			DidCloseTextDocument::METHOD => {
+				let params = params::<DidCloseTextDocumentParams>(notification.params)?;
+				let uri = params.text_document.uri.clone();
+				self.did_close(params)?;
+				uri
+			}
+			_ => return Ok(None),
+		};
+		Ok(Some(uri))
+	}
+
+	/// The syntax errors in the document at `uri`, or none if it isn't open (anymore).
+	fn diagnostics(&self, uri: Url) -> Result<PublishDiagnosticsParams> {
+		let diagnostics = match self.sessions.get(&uri) {
+			Some(session) => {
+				let state = STATE_MAP
+					.get(session)
+					.ok_or_else(|| Error::Internal(format!("Session {} is gone", session)))?;
+				let key = uri::key(uri.as_str())?;
+				match state.documents.get(&key) {
+					Some(document) => error_diagnostics(
+						&uri,
+						syntax_errors::syntax_errors(&document.text, &document.tree),
+						self.related_information,
+					),
+					None => Vec::new(),
+				}
+			}
+			None => Vec::new(),
+		};
+		Ok(PublishDiagnosticsParams::new(uri, diagnostics, None))
+	}
+
+	fn request(&self, req: Request) -> Response {
+		let res = match req.method.as_str() {
+			DocumentSymbolRequest::METHOD => self
+				.respond::<DocumentSymbolRequest>(req.params, |document, _| {
+					Some(DocumentSymbolResponse::Nested(document_symbols(document)))
+				}),
+			FoldingRangeRequest::METHOD => self
+				.respond::<FoldingRangeRequest>(req.params, |document, _| {
+					Some(folding_ranges(document))
+				}),
+			SelectionRangeRequest::METHOD => self
+				.respond::<SelectionRangeRequest>(req.params, |document, params| {
+					Some(selection_ranges(document, &params.positions))
+				}),
+			SemanticTokensFullRequest::METHOD => self
+				.respond::<SemanticTokensFullRequest>(req.params, |document, _| {
+					Some(SemanticTokensResult::Tokens(semantic_tokens(document)))
+				}),
+			method => {
+				return Response::new_err(
+					req.id,
+					ErrorCode::MethodNotFound as i32,
+					format!("Unsupported method {}", method),
+				)
+			}
+		};
+
+		match res {
+			Ok(value) => Response::new_ok(req.id, value),
+			Err(e) => {
+				let code = match e.downcast_ref::<Error>() {
+					Some(Error::Internal(_)) | None => ErrorCode::InternalError,
+					Some(_) => ErrorCode::InvalidParams,
+				};
+				Response::new_err(req.id, code as i32, format!("{:#}", e))
+			}
+		}
+	}
+
+	/// Answers a request about a single document with `f`, or with `null` if the document's
+	/// language has no grammar.
+	fn respond<R>(
+		&self,
+		params: serde_json::Value,
+		f: impl FnOnce(&Document, R::Params) -> R::Result,
+	) -> Result<serde_json::Value>
+	where
+		R: LspRequest,
+		R::Params: TextDocumentParams,
+	{
+		let params = self::params::<R::Params>(params)?;
+		let uri = params.uri().clone();
+		let session = match self.sessions.get(&uri) {
+			Some(session) => session,
+			None => return Ok(serde_json::Value::Null),
+		};
+
+		let state = STATE_MAP
+			.get(session)
+			.ok_or_else(|| Error::Internal(format!("Session {} is gone", session)))?;
+		let key = uri::key(uri.as_str())?;
+		let document = state
+			.documents
+			.get(&key)
+			.ok_or_else(|| Error::UnknownFile(format!("{} is not open", key)))?;
+
+		Ok(serde_json::to_value(f(document, params))?)
+	}
+
+	fn did_open(&mut self, params: DidOpenTextDocumentParams) -> Result<()> {
+		let document = params.text_document;
+		let lang = language(&document.language_id);
+		if languages::get(lang).is_none() {
+			return Ok(());
+		}
+
+		let session = format!("lsp:{}", lang);
+		if !STATE_MAP.contains_key(&session) {
+			let mut state = State::new(&session);
+			state.set_language(lang, false)?;
+			STATE_MAP.insert(session.clone(), state);
+		}
+
+		let mut state = STATE_MAP.get_mut(&session).unwrap();
+		state.parse(
+			&uri::key(document.uri.as_str())?,
+			Text::from(document.text.as_str()),
+			None,
+			false,
+		)?;
+		self.sessions.insert(document.uri, session);
+		Ok(())
+	}
+
+	fn did_change(&mut self, params: DidChangeTextDocumentParams) -> Result<()> {
+		let uri = params.text_document.uri;
+		let session = match self.sessions.get(&uri) {
+			Some(session) => session,
+			None => return Ok(()),
+		};
+		let mut state = STATE_MAP
+			.get_mut(session)
+			.ok_or_else(|| Error::Internal(format!("Session {} is gone", session)))?;
+		let key = uri::key(uri.as_str())?;
+		let document = state
+			.documents
+			.get(&key)
+			.ok_or_else(|| Error::UnknownFile(format!("{} is not open", key)))?;
+
+		// each change's range is relative to the text after the ones before it, so they're
+		// tracked on a copy of the text
+		let mut text = document.text.clone();
+		let version = document.version;
+		let mut edits = Vec::new();
+		let mut replaced = false;
+		for change in params.content_changes {
+			let range = match change.range {
+				Some(range) => range,
+				None => {
+					text = Text::from(change.text.as_str());
+					replaced = true;
+					continue;
+				}
+			};
+			let start = offset(&text, range.start);
+			let end = offset(&text, range.end).max(start);
+			let new_text = change.text.encode_utf16().collect::<Vec<_>>();
+			text.replace(start..end, &new_text)?;
+			edits.push((start * 2, end * 2, new_text));
+		}
+
+		if replaced {
+			// the edits no longer apply to the cached tree, but parse works out the difference
+			state.parse(&key, text, None, false)?;
+		} else {
+			state.edit(&key, version, None, edits)?;
+		}
+		Ok(())
+	}
+
+	fn did_close(&mut self, params: DidCloseTextDocumentParams) -> Result<()> {
+		let uri = params.text_document.uri;
+		if let Some(session) = self.sessions.remove(&uri) {
+			if let Some(mut state) = STATE_MAP.get_mut(&session) {
+				state.documents.remove(&uri::key(uri.as_str())?);
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Request parameters that name the document they're about.
+trait TextDocumentParams {
+	fn uri(&self) -> &Url;
+}
+
+impl TextDocumentParams for DocumentSymbolParams {
+	fn uri(&self) -> &Url {
+		&self.text_document.uri
+	}
+}
+
+impl TextDocumentParams for FoldingRangeParams {
+	fn uri(&self) -> &Url {
+		&self.text_document.uri
+	}
+}
+
+impl TextDocumentParams for SelectionRangeParams {
+	fn uri(&self) -> &Url {
+		&self.text_document.uri
+	}
+}
+
+impl TextDocumentParams for SemanticTokensParams {
+	fn uri(&self) -> &Url {
+		&self.text_document.uri
+	}
+}
+
+fn params<P: serde::de::DeserializeOwned>(params: serde_json::Value) -> Result<P> {
+	Ok(serde_json::from_value(params)
+		.map_err(|e| Error::InvalidRequest(format!("Invalid parameters: {}", e)))?)
+}
+
+fn offset(text: &Text, position: Position) -> usize {
+	text.offset_at(position.line as usize, position.character as usize)
+}
+
+/// LSP positions count UTF-16 code units, so the columns of a UTF-16 parse are twice that.
+fn position(point: Point) -> Position {
+	Position::new(point.row as u32, (point.column / 2) as u32)
+}
+
+fn range(node: Node) -> Range {
+	Range::new(
+		position(node.start_position()),
+		position(node.end_position()),
+	)
+}
+
+fn symbol_kind(kind: fb::SymbolKind) -> SymbolKind {
+	match kind {
+		fb::SymbolKind::Module => SymbolKind::MODULE,
+		fb::SymbolKind::Namespace => SymbolKind::NAMESPACE,
+		fb::SymbolKind::Class => SymbolKind::CLASS,
+		fb::SymbolKind::Struct => SymbolKind::STRUCT,
+		fb::SymbolKind::Interface => SymbolKind::INTERFACE,
+		fb::SymbolKind::Enum => SymbolKind::ENUM,
+		fb::SymbolKind::EnumMember => SymbolKind::ENUM_MEMBER,
+		fb::SymbolKind::Function => SymbolKind::FUNCTION,
+		fb::SymbolKind::Method => SymbolKind::METHOD,
+		fb::SymbolKind::Field => SymbolKind::FIELD,
+		fb::SymbolKind::Property => SymbolKind::PROPERTY,
+		fb::SymbolKind::Constant => SymbolKind::CONSTANT,
+		_ => SymbolKind::VARIABLE,
+	}
+}
+
+/// The definitions found by the language's tags query, nested by where they are in the document.
+fn document_symbols(document: &Document) -> Vec<DocumentSymbol> {
+	let mut symbols = Vec::new();
+	for tag in queries::tags(&document.language, &document.tree, &document.text) {
+		if !tag.definition {
+			continue;
+		}
+
+		#[allow(deprecated)]
+		symbols.push(DocumentSymbol {
+			name: tag.name_text(&document.text),
+			detail: None,
+			kind: symbol_kind(symbols::kind(&document.language, tag.kind)),
+			tags: None,
+			deprecated: None,
+			range: range(tag.node),
+			selection_range: range(tag.name),
+			children: None,
+		});
+	}
+
+	nest(symbols)
+}
+
+/// Turns a list of symbols into a tree, with each symbol under the innermost one containing it.
+fn nest(mut symbols: Vec<DocumentSymbol>) -> Vec<DocumentSymbol> {
+	// parents before their children
+	symbols.sort_by_key(|symbol| (symbol.range.start, Reverse(symbol.range.end)));
+
+	fn pop(stack: &mut Vec<DocumentSymbol>, roots: &mut Vec<DocumentSymbol>) {
+		let symbol = stack.pop().unwrap();
+		match stack.last_mut() {
+			Some(parent) => parent.children.get_or_insert_with(Vec::new).push(symbol),
+			None => roots.push(symbol),
+		}
+	}
+
+	let mut roots = Vec::new();
+	let mut stack = Vec::<DocumentSymbol>::new();
+	for symbol in symbols {
+		while stack
+			.last()
+			.is_some_and(|parent| parent.range.end <= symbol.range.start)
+		{
+			pop(&mut stack, &mut roots);
+		}
+		stack.push(symbol);
+	}
+	while !stack.is_empty() {
+		pop(&mut stack, &mut roots);
+	}
+	roots
+}
+
+/// Turns `errors` into diagnostics, one for each group of them, with the rest of the group as
+/// its related information, or as hints for clients that don't show related information.
+fn error_diagnostics(
+	uri: &Url,
+	errors: Vec<SyntaxError>,
+	related_information: bool,
+) -> Vec<Diagnostic> {
+	let diagnostic = |error: SyntaxError, severity| Diagnostic {
+		range: range(error.node),
+		severity: Some(severity),
+		source: Some("asted-tree-sitter".to_string()),
+		message: error.message,
+		..Default::default()
+	};
+	let mut diagnostics = Vec::new();
+	for ErrorGroup { first, rest } in syntax_errors::merge_errors(errors) {
+		if related_information {
+			let mut diagnostic = diagnostic(first, DiagnosticSeverity::ERROR);
+			if !rest.is_empty() {
+				diagnostic.related_information = Some(
+					rest.into_iter()
+						.map(|error| DiagnosticRelatedInformation {
+							location: Location::new(uri.clone(), range(error.node)),
+							message: error.message,
+						})
+						.collect(),
+				);
+			}
+			diagnostics.push(diagnostic);
+		} else {
+			diagnostics.push(diagnostic(first, DiagnosticSeverity::ERROR));
+			diagnostics.extend(
+				rest.into_iter()
+					.map(|error| diagnostic(error, DiagnosticSeverity::HINT)),
+			);
+		}
+	}
+	diagnostics
+}
+
+/// The folds of the document's folds query (or of its named nodes), as LSP ranges, which don't
+/// have levels since clients work them out from the ranges themselves.
+fn folding_ranges(document: &Document) -> Vec<FoldingRange> {
+	folding::folds(document)
+		.into_iter()
+		.map(|fold| FoldingRange {
+			start_line: fold.start_line as u32,
+			end_line: fold.end_line as u32,
+			kind: match fold.kind {
+				fb::FoldKind::Comment => Some(FoldingRangeKind::Comment),
+				fb::FoldKind::Imports => Some(FoldingRangeKind::Imports),
+				fb::FoldKind::Region => Some(FoldingRangeKind::Region),
+				_ => None,
+			},
+			..Default::default()
+		})
+		.collect()
+}
+
+/// For each position, the node at it and all of its ancestors, innermost first.
+fn selection_ranges(document: &Document, positions: &[Position]) -> Vec<SelectionRange> {
+	let root = document.tree.root_node();
+	positions
+		.iter()
+		.map(|&position| {
+			let point = document.text.point_at(offset(&document.text, position));
+			let mut selection = None;
+			for node in selection_ranges::nodes_around(root, point)
+				.into_iter()
+				.rev()
+			{
+				selection = Some(SelectionRange {
+					range: range(node),
+					parent: selection.map(Box::new),
+				});
+			}
+			selection.unwrap_or(SelectionRange {
+				range: Range::new(position, position),
+				parent: None,
+			})
+		})
+		.collect()
+}
+
+/// The legend index and modifier bits for a highlight capture name like `function.method`.
+fn token_type(capture_name: &str) -> Option<(u32, u32)> {
+	let readonly = 1;
+	let default_library = 2;
+	let (token_type, modifiers) = match capture_name {
+		"function.method" | "method" => (SemanticTokenType::METHOD, 0),
+		"function.macro" => (SemanticTokenType::MACRO, 0),
+		"variable.parameter" => (SemanticTokenType::PARAMETER, 0),
+		"variable.builtin" | "constant.builtin" => (SemanticTokenType::KEYWORD, 0),
+		"string.special" => (SemanticTokenType::REGEXP, 0),
+		"type.builtin" => (SemanticTokenType::TYPE, default_library),
+		name => match name.split('.').next().unwrap() {
+			"comment" => (SemanticTokenType::COMMENT, 0),
+			"string" => (SemanticTokenType::STRING, 0),
+			"number" => (SemanticTokenType::NUMBER, 0),
+			"keyword" => (SemanticTokenType::KEYWORD, 0),
+			"operator" => (SemanticTokenType::OPERATOR, 0),
+			"function" | "constructor" => (SemanticTokenType::FUNCTION, 0),
+			"type" => (SemanticTokenType::TYPE, 0),
+			"namespace" => (SemanticTokenType::NAMESPACE, 0),
+			"variable" => (SemanticTokenType::VARIABLE, 0),
+			"constant" => (SemanticTokenType::VARIABLE, readonly),
+			"property" => (SemanticTokenType::PROPERTY, 0),
+			_ => return None,
+		},
+	};
+	let index = TOKEN_TYPES.iter().position(|t| *t == token_type).unwrap();
+	Some((index as u32, modifiers))
+}
+
+/// Tokens for the language's highlight query captures. Where captures overlap, the first one
+/// wins, and tokens spanning several lines are split since not every client supports those.
+fn semantic_tokens(document: &Document) -> SemanticTokens {
+	let mut data = Vec::new();
+	let query = match queries::get(
+		&document.language,
+		document.tree.language(),
+		Kind::Highlights,
+	) {
+		Some(query) => query,
+		None => return SemanticTokens::default(),
+	};
+	let names = query.capture_names();
+	let text = &document.text;
+
+	let mut cursor = QueryCursor::new();
+	let captures = cursor.captures(query, document.tree.root_node(), |node: Node| {
+		text.chunks(node.start_byte() / 2..node.end_byte() / 2)
+	});
+	let mut covered_until = 0;
+	let (mut last_line, mut last_start) = (0, 0);
+	for (m, index) in captures {
+		let node = m.captures[index].node;
+		if node.start_byte() < covered_until {
+			continue;
+		}
+		let (token_type, modifiers) = match token_type(&names[m.captures[index].index as usize]) {
+			Some(token_type) => token_type,
+			None => continue,
+		};
+		covered_until = node.end_byte();
+
+		let start = position(node.start_position());
+		let end = position(node.end_position());
+		for line in start.line..=end.line {
+			let from = if line == start.line {
+				start.character
+			} else {
+				0
+			};
+			let to = if line == end.line {
+				end.character
+			} else {
+				text.line_len(line as usize) as u32
+			};
+			if to <= from {
+				continue;
+			}
+
+			let delta_line = line - last_line;
+			let delta_start = if delta_line == 0 {
+				from - last_start
+			} else {
+				from
+			};
+			data.push(SemanticToken {
+				delta_line,
+				delta_start,
+				length: to - from,
+				token_type,
+				token_modifiers_bitset: modifiers,
+			});
+			(last_line, last_start) = (line, from);
+		}
+	}
+
+	SemanticTokens {
+		result_id: None,
+		data,
+	}
+}
@@ -1,145 +1,1471 @@
 use std::{
-	collections::HashMap,
+	any::Any,
+	collections::{hash_map::Entry, HashMap, VecDeque},
 	fs,
-	net::SocketAddr,
-	path::PathBuf,
-	sync::{Mutex, RwLock},
+	net::{IpAddr, SocketAddr},
+	path::{Path, PathBuf},
+	sync::{Arc, Mutex, MutexGuard},
+	time::{Duration, Instant, SystemTime},
 };
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use axum::{
-	body::Bytes,
-	http::StatusCode,
+	body::{Bytes, HttpBody},
+	extract::{rejection::BytesRejection, DefaultBodyLimit},
+	http::{
+		header::{AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER},
+		HeaderMap, HeaderValue, Method, StatusCode,
+	},
+	middleware,
 	response::{IntoResponse, Response},
-	routing::post,
-	Router,
+	routing::{get, post},
+	Extension, Router,
 };
 use clap::Parser as ClapParser;
 use dashmap::DashMap;
-use message_generated::asted::interface::{FileRequest, InitRequest, RequestUnion};
+use document::Document;
+use encoding::Encoding;
+use error::Error;
+use hyper::server::conn::AddrIncoming;
+use message_generated::asted::interface::{
+	CancelResponse, CancelResponseArgs, DirectoryMode, PluginResponse, PluginResponseArgs,
+	ReloadGrammarsResponse, ReloadGrammarsResponseArgs, RequestUnion, SerializationProfile,
+	TextEdit,
+};
+#[cfg(feature = "wasm")]
+use message_generated::asted::interface::{RunPluginResponse, RunPluginResponseArgs};
+#[cfg(feature = "scripting")]
+use message_generated::asted::interface::{ScriptResponse, ScriptResponseArgs};
 use once_cell::sync::Lazy;
+use text::Text;
+use tokio::task::JoinSet;
+use tower_http::{catch_panic::CatchPanicLayer, cors::CorsLayer};
+use tracing_subscriber::filter::LevelFilter;
 use tree_sitter::Parser;
-use url::Url;
+use uri::DocumentKey;
 
-#[allow(dead_code, unused_imports)]
+mod admin;
+mod auth;
+mod bench;
+mod blame;
+mod cancel;
+// only subscribed to over gRPC
+#[cfg_attr(not(feature = "grpc"), allow(dead_code))]
+mod changes;
+mod check;
+mod chunk;
+mod clones;
+mod complexity;
+mod config;
+mod daemon;
+mod debug_parse;
+mod deterministic;
+mod directory;
+mod disk_cache;
+mod document;
+mod edit;
+mod encoding;
+mod error;
+mod export;
+mod fingerprint;
+mod fnv;
+mod folding;
+mod format_ranges;
+mod git;
+#[cfg(feature = "grpc")]
+mod grpc;
+#[cfg(feature = "grpc")]
+#[allow(clippy::all)]
+mod grpc_generated;
+mod heartbeat;
+mod history;
+mod idle;
+mod imports;
+mod language_info;
+mod languages;
+mod limits;
+mod logging;
+mod lsp;
+#[allow(dead_code, unused_imports, clippy::all, mismatched_lifetime_syntaxes)]
+#[rustfmt::skip]
 mod message_generated;
+mod metrics;
+mod node_edit;
+mod node_types;
+mod permissions;
+mod pipe;
+mod plain_text;
+mod playground;
+mod plugins;
+mod queries;
+mod raw_tcp;
+mod recovery;
+mod replay;
+mod resume;
+mod retries;
+mod sarif;
+#[cfg(feature = "scripting")]
+mod scripting;
+mod search;
+mod selection_ranges;
+mod snippet;
+mod symbols;
+mod syntax_errors;
+#[cfg(feature = "otel")]
+mod telemetry;
+mod tenants;
+mod text;
+mod timings;
+mod todos;
+mod transaction;
+mod traverse;
+mod tree_cache;
 mod tree_serialize;
+mod uri;
+mod verify_rewrite;
+#[cfg(feature = "wasm")]
+mod wasm_plugins;
+mod watch;
+mod webhooks;
+mod workspace;
+mod workspace_config;
 
 struct State {
+	/// The session's key in `STATE_MAP`, which its changes are published under
+	session: String,
 	parser: Mutex<Parser>,
-	files: HashMap<PathBuf, RwLock<tree_sitter::Tree>>,
+	lang: Option<String>,
+	/// Set when `lang` has no grammar and the client asked for the plain-text fallback
+	plain_text: bool,
+	/// The languages documents were opened in instead of `lang`, by a `FileRequest`'s lang
+	overrides: HashMap<DocumentKey, String>,
+	/// Parsers for the languages in `overrides`, by name
+	override_parsers: Mutex<HashMap<String, Parser>>,
+	documents: HashMap<DocumentKey, Document>,
+	/// Documents answered from `disk_cache` whose text hasn't been parsed yet, which it is once a
+	/// request needs the tree. They're at version 1, like any document just opened.
+	unparsed: HashMap<DocumentKey, Unparsed>,
+	/// Labelled copies of documents to roll back to, oldest first
+	snapshots: VecDeque<Snapshot>,
+	/// How many documents to keep before dropping the least recently used one
+	max_documents: Option<usize>,
+	/// The directories files can be read from; anywhere if empty
+	roots: Vec<PathBuf>,
+}
+
+/// How a `FileRequest` uses the caches.
+#[derive(Clone, Copy, Default)]
+pub struct Caching {
+	/// Parse from scratch, without the document's cached tree or one shared by another document
+	pub force: bool,
+	/// Leave both the session's documents and the shared trees as they were
+	pub no_cache: bool,
 }
 
-static STATE_MAP: Lazy<DashMap<String, State>> = Lazy::new(|| DashMap::new());
+/// How a `FileRequest` has the document's text taken in.
+#[derive(Clone, Copy, Default)]
+pub struct Opening<'a> {
+	/// Parse it in this language instead of the session's (see `State::override_language`)
+	pub lang: Option<&'a str>,
+	/// Turn its `\r\n`s into `\n`s
+	pub normalize_line_endings: bool,
+}
 
-#[derive(Debug)]
-enum Error {
-	UnknownCommand(String),
-	UnknownLanguage(String),
-	UnknownFile(String),
+struct Unparsed {
+	text: Text,
+	mtime: Option<SystemTime>,
+	accessed: SystemTime,
 }
 
-impl std::fmt::Display for Error {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		match self {
-			Error::UnknownCommand(s) => write!(f, "{}", s),
-			Error::UnknownLanguage(s) => write!(f, "{}", s),
-			Error::UnknownFile(s) => write!(f, "{}", s),
+struct Snapshot {
+	key: DocumentKey,
+	label: String,
+	document: Document,
+}
+
+/// How many snapshots a session keeps before dropping the oldest.
+const MAX_SNAPSHOTS: usize = 16;
+
+impl State {
+	fn new(session: &str) -> Self {
+		State {
+			session: session.to_string(),
+			parser: Mutex::new(Parser::new()),
+			lang: None,
+			plain_text: false,
+			overrides: HashMap::new(),
+			override_parsers: Mutex::new(HashMap::new()),
+			documents: HashMap::new(),
+			unparsed: HashMap::new(),
+			snapshots: VecDeque::new(),
+			max_documents: None,
+			roots: Vec::new(),
 		}
 	}
-}
 
-impl IntoResponse for Error {
-	fn into_response(self) -> Response {
-		let status = match self {
-			Error::UnknownCommand(_) => StatusCode::BAD_REQUEST,
-			Error::UnknownLanguage(_) => StatusCode::BAD_REQUEST,
-			Error::UnknownFile(_) => StatusCode::BAD_REQUEST,
+	/// A session for `tenant`, held to its limits.
+	fn for_tenant(tenant: &tenants::TenantConfig) -> Self {
+		State {
+			max_documents: tenant.max_documents,
+			roots: tenant.roots.iter().map(|root| uri::resolve(root)).collect(),
+			..State::new(&tenant.session())
+		}
+	}
+
+	/// Fails with a forbidden error unless `path` is inside one of the session's roots. Symlinks
+	/// are followed whatever the symlink policy, since reading the file follows them too.
+	fn check_root(&self, path: &Path) -> Result<()> {
+		if uri::is_within(path, &self.roots) {
+			return Ok(());
+		}
+		Err(Error::Forbidden(format!(
+			"{} is outside the directories this session can read",
+			path.display()
+		))
+		.into())
+	}
+
+	/// Drops the least recently used documents other than `key` until the session is within its
+	/// budget.
+	fn evict(&mut self, key: &DocumentKey) {
+		let max = match self.max_documents {
+			Some(max) => max.max(1),
+			None => return,
 		};
+		while self.documents.len() + self.unparsed.len() > max {
+			let oldest = self
+				.documents
+				.iter()
+				.map(|(k, document)| (k, document.accessed))
+				.chain(
+					self.unparsed
+						.iter()
+						.map(|(k, unparsed)| (k, unparsed.accessed)),
+				)
+				.filter(|(k, _)| *k != key)
+				.min_by_key(|(_, accessed)| *accessed)
+				.map(|(k, _)| k.clone());
+			match oldest {
+				Some(oldest) => {
+					self.documents.remove(&oldest);
+					self.unparsed.remove(&oldest);
+					self.overrides.remove(&oldest);
+				}
+				None => break,
+			};
+		}
+	}
 
-		(status, self.to_string()).into_response()
+	/// Locks the parser. If a request panicked while holding it, the parser is reset first since
+	/// it may have been left mid-parse.
+	fn parser(&self) -> MutexGuard<'_, Parser> {
+		match self.parser.lock() {
+			Ok(parser) => parser,
+			Err(e) => {
+				let mut parser = e.into_inner();
+				parser.reset();
+				self.parser.clear_poison();
+				parser
+			}
+		}
 	}
-}
 
-impl std::error::Error for Error {}
+	/// Locks the parsers for the languages documents were opened in instead of the session's,
+	/// resetting them first like `parser` if a request panicked while holding them.
+	fn override_parsers(&self) -> MutexGuard<'_, HashMap<String, Parser>> {
+		self.override_parsers.lock().unwrap_or_else(|e| {
+			let mut parsers = e.into_inner();
+			for parser in parsers.values_mut() {
+				parser.reset();
+			}
+			self.override_parsers.clear_poison();
+			parsers
+		})
+	}
 
-async fn handle(body: Bytes) -> Result<Response> {
-	let req = message_generated::asted::interface::root_as_request(&body)
-		.context("Failed to parse request")?;
+	/// The language of the document at `key`: the one it was opened in, or the session's.
+	fn language_of(&self, key: &DocumentKey) -> Option<&str> {
+		self.overrides
+			.get(key)
+			.or(self.lang.as_ref())
+			.map(String::as_str)
+	}
 
-	let mut state = STATE_MAP.get_mut("global").unwrap();
+	/// Has the document at `key` parsed in `lang` from now on, instead of in its language so far.
+	fn override_language(&mut self, key: &DocumentKey, lang: &str) -> Result<()> {
+		if languages::get(lang).is_none() {
+			return Err(Error::UnknownLanguage(format!("Unsupported language: {}", lang)).into());
+		}
+		if self.language_of(key) == Some(lang) {
+			return Ok(());
+		}
+		// its disk cache entry is for the other language
+		self.unparsed.remove(key);
+		match self.lang.as_deref() == Some(lang) {
+			true => self.overrides.remove(key),
+			false => self.overrides.insert(key.clone(), lang.to_string()),
+		};
+		Ok(())
+	}
 
-	println!("handling request: {:?}", req);
+	/// Switches the session to `lang`, dropping cached trees since they can't be reused across
+	/// grammars.
+	fn set_language(&mut self, lang: &str, plain_text_fallback: bool) -> Result<()> {
+		match languages::get(lang) {
+			Some(language) => {
+				self.parser()
+					.set_language(language)
+					.with_context(|| format!("Error loading tree-sitter {} language", lang))?;
+				self.plain_text = false;
+			}
+			None if plain_text_fallback => {
+				tracing::info!("no grammar for {}, falling back to plain text", lang);
+				self.plain_text = true;
+			}
+			None => {
+				return Err(
+					Error::UnknownLanguage(format!("Unsupported language: {}", lang)).into(),
+				)
+			}
+		}
 
-	match req.request_type() {
-		RequestUnion::InitRequest => {
-			let req = unsafe { InitRequest::init_from_table(req.request()) };
-
-			match req.lang() {
-				"typescript" => {
-					state
-						.parser
-						.lock()
-						.unwrap()
-						.set_language(tree_sitter_typescript::language_typescript())
-						.context("Error loading tree-sitter typescript language")?;
-					return Ok("".into_response());
+		self.lang = Some(lang.to_string());
+		self.documents.clear();
+		self.unparsed.clear();
+		self.snapshots.clear();
+		self.overrides.clear();
+		self.override_parsers().clear();
+		Ok(())
+	}
+
+	/// How trees are serialized for a request asking for `profile`, and `text` unless it leaves
+	/// that to the profile. The default profile is the one configured for the session's language,
+	/// if there is one.
+	fn serialize_options(
+		&self,
+		profile: SerializationProfile,
+		structural_hashes: bool,
+		dual_offsets: bool,
+		text: Option<tree_serialize::TextPolicy>,
+	) -> tree_serialize::Options {
+		let profile = match (profile, &self.lang) {
+			(SerializationProfile::Default, Some(lang)) => {
+				languages::config(lang).profile.unwrap_or(profile)
+			}
+			_ => profile,
+		};
+		let options = tree_serialize::Options::new(profile, structural_hashes);
+		tree_serialize::Options {
+			dual_offsets,
+			text: text.unwrap_or(options.text),
+			..options
+		}
+	}
+
+	/// Parses `text` as the contents of `key`, reusing the cached tree for it if there is one
+	/// (unless `force`).
+	fn parse(
+		&mut self,
+		key: &DocumentKey,
+		text: Text,
+		mtime: Option<SystemTime>,
+		force: bool,
+	) -> Result<(&Document, tree_serialize::ParseInfo)> {
+		let language = self.language_of(key);
+		let old = self.documents.get(key).map(|document| {
+			match Some(document.language.as_str()) == language {
+				true => {
+					let (tree, version) = document.tree_for(&text);
+					(Some(tree), version)
 				}
-				lang => {
-					Err(Error::UnknownLanguage(format!("Unsupported language: {}", lang)).into())
+				// parsed in another language, so its tree can't be reused and it changes even if
+				// the text doesn't
+				false => (None, document.version + 1),
+			}
+		});
+		let old = match (old, force) {
+			// the version still has to move on if the text changed
+			(Some((_, version)), true) => Some((None, version)),
+			(old, _) => old,
+		};
+
+		let (document, parse_info) = self.reparse(key, text, mtime, old, force)?;
+		replay::record(key, document, &parse_info, replay::Input::Text);
+		Ok((document, parse_info))
+	}
+
+	/// Parses `text` as the contents of `key` without caching it, sharing the tree of another
+	/// document with the same text unless `force`.
+	fn parse_uncached(
+		&self,
+		key: &DocumentKey,
+		text: Text,
+		mtime: Option<SystemTime>,
+		force: bool,
+	) -> Result<(Document, tree_serialize::ParseInfo)> {
+		let _span = tracing::info_span!("parse", document = %key).entered();
+		let start = Instant::now();
+		let lang = self.overrides.get(key).map(String::as_str);
+		let (tree, cached_tree) = self.parse_tree(lang, &text, None, force)?;
+		let parse_info = tree_serialize::ParseInfo {
+			duration: start.elapsed(),
+			reused_old_tree: false,
+			cached_tree,
+			changed_ranges: Vec::new(),
+			version: 0,
+			content_hash: text.content_hash(),
+			mtime,
+		};
+		let document = Document {
+			text,
+			tree,
+			version: 0,
+			language: self.language_of(key).unwrap_or_default().to_string(),
+			mtime,
+			accessed: SystemTime::now(),
+		};
+		Ok((document, parse_info))
+	}
+
+	/// Parses `text` with `old` in `lang` (or the session's language), or takes the tree of another
+	/// document with the same text unless `force`. Returns whether the tree was taken.
+	fn parse_tree(
+		&self,
+		lang: Option<&str>,
+		text: &Text,
+		old: Option<&tree_sitter::Tree>,
+		force: bool,
+	) -> Result<(tree_sitter::Tree, bool)> {
+		let mut session_parser;
+		let mut override_parsers;
+		let parser = match lang {
+			None => {
+				session_parser = self.parser();
+				&mut *session_parser
+			}
+			Some(lang) => {
+				override_parsers = self.override_parsers();
+				match override_parsers.entry(lang.to_string()) {
+					Entry::Occupied(entry) => entry.into_mut(),
+					Entry::Vacant(entry) => {
+						let language = languages::get(lang).ok_or_else(|| {
+							Error::UnknownLanguage(format!("Unsupported language: {}", lang))
+						})?;
+						let mut parser = Parser::new();
+						parser.set_language(language).with_context(|| {
+							format!("Error loading tree-sitter {} language", lang)
+						})?;
+						entry.insert(parser)
+					}
 				}
 			}
+		};
+
+		let cached = match force {
+			true => None,
+			false => parser
+				.language()
+				.and_then(|language| tree_cache::get(language, text)),
+		};
+		if let Some(tree) = cached {
+			return Ok((tree, true));
 		}
-		RequestUnion::FileRequest => {
-			let req = unsafe { FileRequest::init_from_table(req.request()) };
+		// like a document edited after it was opened in another language and without caching
+		let old = old.filter(|old| Some(old.language()) == parser.language());
 
-			let uri = Url::parse(req.path()).context("Failed to parse URI")?;
-			if uri.scheme() != "file" {
-				return Err(Error::UnknownFile(format!(
-					"Unsupported URI scheme: {:?}",
-					uri.scheme()
+		let timeout = lang
+			.or(self.lang.as_deref())
+			.and_then(|lang| languages::config(lang).parse_timeout_ms);
+		parser.set_timeout_micros(timeout.map_or(0, |timeout| timeout * 1000));
+		let registration = cancel::current();
+		let tree = cancel::parse(parser, registration.as_deref(), |parser| {
+			text.parse(parser, old)
+		});
+		match (tree, timeout) {
+			(Some(tree), _) => Ok((tree, false)),
+			(None, _) if registration.as_ref().is_some_and(|r| r.cancelled()) => {
+				parser.reset();
+				Err(registration.unwrap().error().into())
+			}
+			(None, Some(timeout)) => {
+				// an abandoned parse would otherwise be resumed by the next one
+				parser.reset();
+				Err(Error::Timeout(format!(
+					"Parsing took longer than the {} ms allowed",
+					timeout
 				))
-				.into());
+				.into())
 			}
-			let path = uri
-				.to_file_path()
-				.map_err(|_| Error::UnknownFile(format!("Invalid file path: {}", uri.path())))?;
+			(None, None) => Err(anyhow::anyhow!("Error parsing file")),
+		}
+	}
 
-			if path.is_dir() {
-				return Err(
-					Error::UnknownFile(format!("{} is a directory!", path.display())).into(),
-				);
+	/// The cached document at `key`, which requests that work on open documents need.
+	fn document(&mut self, key: &DocumentKey) -> Result<&Document> {
+		if let Some(unparsed) = self.unparsed.remove(key) {
+			let (document, parse_info) =
+				self.reparse(key, unparsed.text, unparsed.mtime, None, false)?;
+			replay::record(key, document, &parse_info, replay::Input::Text);
+		}
+		Ok(self
+			.documents
+			.get(key)
+			.ok_or_else(|| Error::UnknownFile(format!("{} is not open", key)))?)
+	}
+
+	/// Parses every document answered from the disk cache, for requests that look at all of them.
+	fn parse_unparsed(&mut self) -> Result<()> {
+		let keys = self.unparsed.keys().cloned().collect::<Vec<_>>();
+		for key in keys {
+			self.document(&key)?;
+		}
+		Ok(())
+	}
+
+	/// Applies `edits` (start byte, old end byte, new text) in order to the cached document at
+	/// `key`, which must still be at `base_version` (and have the content hash `if_hash`, if
+	/// given).
+	fn edit(
+		&mut self,
+		key: &DocumentKey,
+		base_version: u32,
+		if_hash: Option<u64>,
+		edits: Vec<(usize, usize, Vec<u16>)>,
+	) -> Result<(&Document, tree_serialize::ParseInfo)> {
+		let logged = replay::enabled().then(|| edits.clone());
+		let (text, tree, mtime) = self.edited(key, base_version, if_hash, edits)?;
+		let (document, parse_info) = self.reparse(
+			key,
+			text,
+			mtime,
+			Some((Some(tree), base_version + 1)),
+			false,
+		)?;
+		if let Some(edits) = logged {
+			let input = replay::Input::Edits {
+				base_version,
+				edits: &edits,
+			};
+			replay::record(key, document, &parse_info, input);
+		}
+		Ok((document, parse_info))
+	}
+
+	/// The text and (edited) tree of the cached document at `key` with `edits` applied, and its
+	/// modification time, checking it like `edit` does but leaving the document as it is.
+	fn edited(
+		&mut self,
+		key: &DocumentKey,
+		base_version: u32,
+		if_hash: Option<u64>,
+		edits: Vec<(usize, usize, Vec<u16>)>,
+	) -> Result<(Text, tree_sitter::Tree, Option<SystemTime>)> {
+		let document = self.edited_document(key, base_version, if_hash)?;
+		let (text, tree) = document.edited(edits)?;
+		Ok((text, tree, document.mtime))
+	}
+
+	/// The cached document at `key`, which edits against `base_version` (and `if_hash`, if given)
+	/// can be applied to.
+	fn edited_document(
+		&mut self,
+		key: &DocumentKey,
+		base_version: u32,
+		if_hash: Option<u64>,
+	) -> Result<&Document> {
+		let document = self.document(key)?;
+		if document.version != base_version {
+			return Err(Error::Conflict(format!(
+				"Edits are against version {} of {}, but it is at version {}",
+				base_version, key, document.version
+			))
+			.into());
+		}
+		check_hash(key, document.text.content_hash(), if_hash)?;
+		Ok(document)
+	}
+
+	/// Saves the current tree and text of the document at `key` as `label`, replacing any
+	/// earlier snapshot of it with the same label.
+	fn snapshot(&mut self, key: &DocumentKey, label: &str) -> Result<()> {
+		let document = self.document(key)?;
+		let snapshot = Snapshot {
+			key: key.clone(),
+			label: label.to_string(),
+			document: document.clone(),
+		};
+
+		self.snapshots
+			.retain(|snapshot| &snapshot.key != key || snapshot.label != label);
+		if self.snapshots.len() >= MAX_SNAPSHOTS {
+			self.snapshots.pop_front();
+		}
+		self.snapshots.push_back(snapshot);
+		Ok(())
+	}
+
+	/// Rolls the document at `key` back to the snapshot labelled `label`. The snapshot is kept,
+	/// so it can be restored again.
+	fn restore(
+		&mut self,
+		key: &DocumentKey,
+		label: &str,
+	) -> Result<(&Document, tree_serialize::ParseInfo)> {
+		let snapshot = self
+			.snapshots
+			.iter()
+			.find(|snapshot| &snapshot.key == key && snapshot.label == label)
+			.ok_or_else(|| {
+				Error::InvalidRequest(format!("No snapshot of {} labelled {:?}", key, label))
+			})?;
+
+		let version = match self.documents.get(key) {
+			Some(document) if document.text == snapshot.document.text => document.version,
+			Some(document) => document.version + 1,
+			None => 1,
+		};
+		let document = Document {
+			version,
+			accessed: SystemTime::now(),
+			..snapshot.document.clone()
+		};
+		let parse_info = tree_serialize::ParseInfo {
+			duration: Duration::ZERO,
+			reused_old_tree: true,
+			cached_tree: false,
+			// the snapshot's tree wasn't edited from the current one, so there's nothing to compare
+			changed_ranges: Vec::new(),
+			version,
+			content_hash: document.text.content_hash(),
+			mtime: document.mtime,
+		};
+
+		if Some(version) != self.documents.get(key).map(|document| document.version) {
+			changes::publish(changes::Change {
+				session: self.session.clone(),
+				key: key.clone(),
+				version,
+				content_hash: parse_info.content_hash,
+			});
+		}
+		self.documents.insert(key.clone(), document);
+		self.evict(key);
+		replay::record(
+			key,
+			&self.documents[key],
+			&parse_info,
+			replay::Input::Snapshot,
+		);
+		Ok((&self.documents[key], parse_info))
+	}
+
+	/// Parses `text` with `old` (an already edited tree, if there is one to reuse, and the new
+	/// version number) and caches the result for `key`. If any document already had the same
+	/// text, its tree is shared instead, unless `force`.
+	fn reparse(
+		&mut self,
+		key: &DocumentKey,
+		text: Text,
+		mtime: Option<SystemTime>,
+		old: Option<(Option<tree_sitter::Tree>, u32)>,
+		force: bool,
+	) -> Result<(&Document, tree_serialize::ParseInfo)> {
+		let (text, tree, parse_info) = self.parse_document(key, text, mtime, old, force)?;
+		self.insert(key, text, tree, parse_info)
+	}
+
+	/// Parses `text` as the contents of `key` like `reparse`, but leaves caching the document to
+	/// the caller.
+	fn parse_document(
+		&self,
+		key: &DocumentKey,
+		text: Text,
+		mtime: Option<SystemTime>,
+		old: Option<(Option<tree_sitter::Tree>, u32)>,
+		force: bool,
+	) -> Result<(Text, tree_sitter::Tree, tree_serialize::ParseInfo)> {
+		let _span = tracing::info_span!("parse", document = %key).entered();
+		let start = Instant::now();
+		let old_tree = old.as_ref().and_then(|(tree, _)| tree.as_ref());
+		let lang = self.overrides.get(key).map(String::as_str);
+		let (tree, cached_tree) = self.parse_tree(lang, &text, old_tree, force)?;
+		if !cached_tree {
+			tree_cache::insert(&text, &tree);
+		}
+		let duration = start.elapsed();
+		let changed_ranges = match old_tree {
+			Some(old_tree) if !cached_tree => old_tree
+				.changed_ranges(&tree)
+				.map(|range| range.start_byte..range.end_byte)
+				.collect(),
+			_ => Vec::new(),
+		};
+		let parse_info = tree_serialize::ParseInfo {
+			duration,
+			reused_old_tree: old_tree.is_some() && !cached_tree,
+			cached_tree,
+			changed_ranges,
+			version: old.map_or(1, |(_, version)| version),
+			content_hash: text.content_hash(),
+			mtime,
+		};
+		Ok((text, tree, parse_info))
+	}
+
+	/// Caches `tree`, parsed from `text`, as the document at `key`, at the version in
+	/// `parse_info`.
+	fn insert(
+		&mut self,
+		key: &DocumentKey,
+		text: Text,
+		tree: tree_sitter::Tree,
+		parse_info: tree_serialize::ParseInfo,
+	) -> Result<(&Document, tree_serialize::ParseInfo)> {
+		if Some(parse_info.version) != self.documents.get(key).map(|document| document.version) {
+			changes::publish(changes::Change {
+				session: self.session.clone(),
+				key: key.clone(),
+				version: parse_info.version,
+				content_hash: parse_info.content_hash,
+			});
+		}
+		let language = self.language_of(key).unwrap_or_default().to_string();
+		webhooks::emit(webhooks::Payload {
+			event: webhooks::Event::Parsed,
+			subject: webhooks::Subject::Document {
+				uri: key.uri(),
+				version: parse_info.version,
+				content_hash: parse_info.content_hash,
+				language: language.clone(),
+			},
+		});
+		if webhooks::enabled() {
+			let syntax_errors = syntax_errors::syntax_errors(&text, &tree).len();
+			let before = self.documents.get(key).map_or(0, |document| {
+				syntax_errors::syntax_errors(&document.text, &document.tree).len()
+			});
+			if syntax_errors != before {
+				webhooks::emit(webhooks::Payload {
+					event: webhooks::Event::DiagnosticsChanged,
+					subject: webhooks::Subject::Diagnostics {
+						uri: key.uri(),
+						version: parse_info.version,
+						syntax_errors,
+					},
+				});
 			}
-			if !path.is_file() {
-				return Err(
-					Error::UnknownFile(format!("File not found: {}", path.display())).into(),
+		}
+		self.documents.insert(
+			key.clone(),
+			Document {
+				text,
+				tree,
+				version: parse_info.version,
+				language,
+				mtime: parse_info.mtime,
+				accessed: SystemTime::now(),
+			},
+		);
+		self.evict(key);
+
+		Ok((&self.documents[key], parse_info))
+	}
+}
+
+static STATE_MAP: Lazy<DashMap<String, State>> = Lazy::new(DashMap::new);
+
+/// Files at least this big are memory-mapped instead of being read into a `String` first.
+const MMAP_THRESHOLD: u64 = 1 << 20;
+
+fn read_text(path: &Path) -> Result<Text> {
+	let _span = tracing::info_span!("read", path = %path.display()).entered();
+	let file = fs::File::open(path).context("Error reading file")?;
+	let len = file.metadata().context("Error reading file")?.len();
+	if len < MMAP_THRESHOLD {
+		let text = fs::read_to_string(path).context("Error reading file")?;
+		return Ok(Text::from(text.as_str()));
+	}
+
+	// Safety: the map is only read while copying it into the rope below. Another process
+	// truncating the file in the meantime would make that fault, which is the usual caveat with
+	// mapping files.
+	let map = unsafe { memmap2::Mmap::map(&file) }.context("Error mapping file")?;
+	let text = std::str::from_utf8(&map).context("Error reading file")?;
+	Ok(Text::from(text))
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+	fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Fails with a conflict if the client expected a document to have a different content hash.
+fn check_hash(key: &DocumentKey, hash: u64, if_hash: Option<u64>) -> Result<()> {
+	match if_hash {
+		Some(expected) if expected != hash => Err(Error::Conflict(format!(
+			"Expected {} to have content hash {:016x}, but it has {:016x}",
+			key, expected, hash
+		))
+		.into()),
+		_ => Ok(()),
+	}
+}
+
+/// The start byte, old end byte and new text of each of `edits`.
+fn text_edits(
+	edits: Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<TextEdit>>>,
+) -> Vec<(usize, usize, Vec<u16>)> {
+	edits
+		.into_iter()
+		.flatten()
+		.map(|edit| {
+			(
+				edit.start_byte() as usize,
+				edit.old_end_byte() as usize,
+				edit.text().map_or(Vec::new(), |text| text.iter().collect()),
+			)
+		})
+		.collect()
+}
+
+/// The key, text and modification time of the document at `uri`: `content` if the client sent
+/// it, or else the file read from disk (if `state` can read it).
+fn document_text(
+	state: &State,
+	uri: &str,
+	content: Option<Text>,
+) -> Result<(DocumentKey, Text, Option<SystemTime>)> {
+	match content {
+		Some(content) => Ok((uri::key(uri)?, content, None)),
+		None => {
+			let path = uri::file_path(uri)?;
+			state.check_root(&path)?;
+			let max_file_size = state
+				.lang
+				.as_deref()
+				.and_then(|lang| languages::config(lang).max_file_size);
+			if let Some(max) = max_file_size {
+				let len = fs::metadata(&path).map_or(0, |metadata| metadata.len());
+				if len > max {
+					return Err(Error::PayloadTooLarge(format!(
+						"{} is {} bytes, more than the {} allowed",
+						path.display(),
+						len,
+						max
+					))
+					.into());
+				}
+			}
+			let mtime = modified(&path);
+			Ok((DocumentKey::file(&path), read_text(&path)?, mtime))
+		}
+	}
+}
+
+/// Parses the document at `uri` (see `document_text`) as `opening` says, and serializes it to a
+/// `FileResponse`.
+fn open(
+	state: &mut State,
+	uri: &str,
+	content: Option<Text>,
+	opening: Opening,
+	if_hash: Option<u64>,
+	caching: Caching,
+	options: tree_serialize::Options,
+) -> Result<Bytes> {
+	let (key, mut text, mtime) = document_text(state, uri, content)?;
+	if opening.normalize_line_endings {
+		text = text.normalize_line_endings();
+	}
+	check_hash(&key, text.content_hash(), if_hash)?;
+	let lang = opening.lang.map(str::to_string).or_else(|| match &key {
+		DocumentKey::File(path) => workspace_config::language_override(path),
+		DocumentKey::Uri(_) => None,
+	});
+	if let Some(lang) = lang {
+		state.override_language(&key, &lang)?;
+	}
+
+	if state.plain_text && !state.overrides.contains_key(&key) {
+		tracing::Span::current().record("parse", "plain_text");
+		return Ok(plain_text::serialize(&text, mtime));
+	}
+
+	if caching.no_cache {
+		let (document, parse_info) = state.parse_uncached(&key, text, mtime, caching.force)?;
+		tracing::Span::current().record("parse", parse_info.outcome());
+		// not through the shared cache of serialized responses either
+		return Ok(tree_serialize::serialize(
+			&document.text,
+			&document.tree,
+			&parse_info,
+			options,
+		));
+	}
+
+	// a document that isn't open yet can be answered from the disk cache, which leaves parsing it
+	// for when a request needs the tree
+	let cold = !caching.force && !state.documents.contains_key(&key);
+	let language = state
+		.language_of(&key)
+		.map(str::to_string)
+		.and_then(|lang| Some((languages::get(&lang)?, lang)));
+	if let (true, Some((language, lang))) = (cold, &language) {
+		let unchanged = state
+			.unparsed
+			.get(&key)
+			.is_none_or(|unparsed| unparsed.text == text);
+		if unchanged && tree_cache::get(*language, &text).is_none() {
+			if let Some(mut res) = disk_cache::get(lang, *language, &text, options) {
+				tracing::Span::current().record("parse", "disk_cache");
+				let parse_info = tree_serialize::ParseInfo {
+					duration: Duration::ZERO,
+					reused_old_tree: false,
+					cached_tree: true,
+					changed_ranges: Vec::new(),
+					version: 1,
+					content_hash: text.content_hash(),
+					mtime,
+				};
+				tree_serialize::restamp(&mut res, &parse_info);
+				state.unparsed.insert(
+					key.clone(),
+					Unparsed {
+						text,
+						mtime,
+						accessed: SystemTime::now(),
+					},
 				);
+				state.evict(&key);
+				return Ok(Bytes::from(res));
+			}
+		}
+	}
+	if state.unparsed.contains_key(&key) {
+		// so the version moves on from the one already handed out
+		state.document(&key)?;
+	}
+
+	let (document, parse_info) = state.parse(&key, text, mtime, caching.force)?;
+	tracing::Span::current().record("parse", parse_info.outcome());
+	let res = document.serialize(&parse_info, options);
+	if let (true, Some((language, lang))) = (cold, &language) {
+		if parse_info.changed_ranges.is_empty() {
+			disk_cache::insert(lang, *language, &document.text, options, &res);
+		}
+	}
+	Ok(res)
+}
+
+/// The document a request is about, if it's about one.
+fn request_uri<'a>(req: &message_generated::asted::interface::Request<'a>) -> Option<&'a str> {
+	match req.request_type() {
+		RequestUnion::FileRequest => req.request_as_file_request().map(|req| req.path()),
+		RequestUnion::EditRequest => req.request_as_edit_request().map(|req| req.path()),
+		RequestUnion::NodeEditRequest => req.request_as_node_edit_request().map(|req| req.path()),
+		RequestUnion::DebugParseRequest => {
+			req.request_as_debug_parse_request().map(|req| req.path())
+		}
+		RequestUnion::SnapshotRequest => req.request_as_snapshot_request().map(|req| req.path()),
+		RequestUnion::RestoreRequest => req.request_as_restore_request().map(|req| req.path()),
+		RequestUnion::ChunkRequest => req.request_as_chunk_request().map(|req| req.path()),
+		RequestUnion::MetricsRequest => req.request_as_metrics_request().map(|req| req.path()),
+		RequestUnion::ComplexityRequest => {
+			req.request_as_complexity_request().map(|req| req.path())
+		}
+		RequestUnion::ImportsRequest => req.request_as_imports_request().map(|req| req.path()),
+		RequestUnion::TodosRequest => req.request_as_todos_request().map(|req| req.path()),
+		RequestUnion::FormatRangesRequest => {
+			req.request_as_format_ranges_request().map(|req| req.path())
+		}
+		RequestUnion::TraverseRequest => req.request_as_traverse_request().map(|req| req.path()),
+		RequestUnion::SelectionRangesRequest => req
+			.request_as_selection_ranges_request()
+			.map(|req| req.path()),
+		RequestUnion::SymbolsRequest => req.request_as_symbols_request().map(|req| req.path()),
+		RequestUnion::FoldingRangesRequest => req
+			.request_as_folding_ranges_request()
+			.map(|req| req.path()),
+		RequestUnion::HistoryRequest => req.request_as_history_request().map(|req| req.path()),
+		RequestUnion::BlameRequest => req.request_as_blame_request().map(|req| req.path()),
+		RequestUnion::QueryRequest => req.request_as_query_request().map(|req| req.path()),
+		RequestUnion::RunPluginRequest => req.request_as_run_plugin_request().map(|req| req.path()),
+		RequestUnion::ScriptRequest => req.request_as_script_request().map(|req| req.path()),
+		_ => None,
+	}
+}
+
+/// Handles a request. Everything here (file reads, parsing, serialization) blocks, so it runs on
+/// tokio's blocking pool rather than a reactor thread.
+fn handle(body: Bytes, encoding: Encoding, session: &str) -> Result<Response> {
+	let req = tracing::info_span!("decode")
+		.in_scope(|| message_generated::asted::interface::root_as_request(&body))
+		.map_err(|e| Error::InvalidRequest(format!("Failed to parse request: {}", e)))?;
+
+	let span = tracing::Span::current();
+	span.record("request_type", tracing::field::debug(req.request_type()));
+	if let Some(uri) = request_uri(&req) {
+		span.record("uri", uri);
+	}
+	if let Some(capability) = permissions::required(req.request_type()) {
+		permissions::check(capability)?;
+	}
+	// these reach past the tenant's own session
+	if session.starts_with(tenants::SESSION_PREFIX)
+		&& matches!(
+			req.request_type(),
+			RequestUnion::ReloadGrammarsRequest | RequestUnion::ShutdownRequest
+		) {
+		return Err(Error::Forbidden(format!(
+			"Tenants can't send {:?} requests",
+			req.request_type()
+		))
+		.into());
+	}
+
+	if encoding == Encoding::Sarif
+		&& !matches!(
+			req.request_type(),
+			RequestUnion::ClonesRequest | RequestUnion::QueryRequest
+		) {
+		return Err(Error::InvalidRequest(format!(
+			"{:?} responses can't be encoded as SARIF",
+			req.request_type()
+		))
+		.into());
+	}
+
+	// answered without waiting for the session, which is likely busy with the request
+	if let Some(req) = req.request_as_cancel_request() {
+		let running = cancel::cancel(session, req.id());
+		let mut builder = flatbuffers::FlatBufferBuilder::new();
+		let resp = CancelResponse::create(&mut builder, &CancelResponseArgs { running });
+		builder.finish(resp, None);
+		return encoding.cancel_response(builder.finished_data().to_vec());
+	}
+	let attempt = match permissions::required(req.request_type()) {
+		Some(permissions::Capability::Edit) if req.id() != 0 => {
+			match retries::begin(session, req.id(), &body, encoding)? {
+				retries::Retry::Replay(res) => return Ok(res),
+				retries::Retry::First(attempt) => Some(attempt),
+			}
+		}
+		_ => None,
+	};
+	let registration = match req.id() {
+		// gRPC calls come registered, to be cancelled when their client gives up on them
+		0 => cancel::current(),
+		id => Some(cancel::register(session, id)),
+	};
+	// a session's roots never change, so they can be looked at before it's free
+	let roots = STATE_MAP
+		.get(session)
+		.map(|state| state.roots.clone())
+		.unwrap_or_default();
+	let path = request_uri(&req).and_then(|uri| uri::file_path(uri).ok());
+	let res = cancel::scope(registration, || {
+		workspace_config::scope(roots, path.as_deref(), || dispatch(req, encoding, session))
+	})?;
+	match attempt {
+		Some(attempt) => attempt.finish(res),
+		None => Ok(res),
+	}
+}
+
+/// Handles a decoded request, once it has the session to itself.
+fn dispatch(
+	req: message_generated::asted::interface::Request,
+	encoding: Encoding,
+	session: &str,
+) -> Result<Response> {
+	let mut state = STATE_MAP.get_mut(session).unwrap();
+	// it may have been cancelled while waiting
+	cancel::check()?;
+
+	// the verifier has already checked that the request table matches its union type, so the
+	// request_as_* accessors below can't fail
+	match req.request_type() {
+		RequestUnion::InitRequest => {
+			let req = req.request_as_init_request().unwrap();
+
+			state.set_language(req.lang(), req.plain_text_fallback())?;
+			encoding.init_response(language_info::init_response(&state))
+		}
+		RequestUnion::FileRequest => {
+			let req = req.request_as_file_request().unwrap();
+
+			if req.directory_mode() == DirectoryMode::List && req.content().is_none() {
+				if let DocumentKey::File(path) = uri::key(req.path())? {
+					if path.is_dir() {
+						let filter = workspace::Filter::from_request(req.directory_filter())?;
+						let res = directory::list(&state, &path, req.directory_depth(), &filter)?;
+						return encoding.directory_listing_response(res);
+					}
+				}
 			}
+			let content = match req.content() {
+				Some(content) => Some(Text::from_utf16(&content.iter().collect::<Vec<_>>())?),
+				None => None,
+			};
+			let options = state.serialize_options(
+				req.profile(),
+				req.structural_hashes(),
+				req.dual_offsets(),
+				tree_serialize::TextPolicy::from_request(
+					req.text_inclusion(),
+					req.text_max_bytes(),
+				),
+			);
+			let caching = Caching {
+				force: req.force(),
+				no_cache: req.no_cache(),
+			};
+			let res = open(
+				&mut state,
+				req.path(),
+				content,
+				Opening {
+					lang: req.lang().filter(|lang| !lang.is_empty()),
+					normalize_line_endings: req.normalize_line_endings(),
+				},
+				req.if_hash(),
+				caching,
+				options,
+			)?;
+			encoding.file_response(res)
+		}
+		RequestUnion::EditRequest => {
+			let req = req.request_as_edit_request().unwrap();
+
+			let key = uri::key(req.path())?;
+			let edits = text_edits(req.edits());
+
+			let options = state.serialize_options(
+				req.profile(),
+				req.structural_hashes(),
+				req.dual_offsets(),
+				tree_serialize::TextPolicy::from_request(
+					req.text_inclusion(),
+					req.text_max_bytes(),
+				),
+			);
+			let (document, parse_info) =
+				state.edit(&key, req.base_version(), req.if_hash(), edits)?;
+			tracing::Span::current().record("parse", parse_info.outcome());
+			let res = document.serialize(&parse_info, options);
+
+			encoding.file_response(res)
+		}
+		RequestUnion::NodeEditRequest => {
+			let req = req.request_as_node_edit_request().unwrap();
+
+			encoding.node_edit_response(node_edit::node_edit(&mut state, req)?)
+		}
+		RequestUnion::SnapshotRequest => {
+			let req = req.request_as_snapshot_request().unwrap();
+
+			state.snapshot(&uri::key(req.path())?, req.label())?;
+			Ok("".into_response())
+		}
+		RequestUnion::RestoreRequest => {
+			let req = req.request_as_restore_request().unwrap();
+
+			let key = uri::key(req.path())?;
+			let options = state.serialize_options(
+				req.profile(),
+				req.structural_hashes(),
+				req.dual_offsets(),
+				tree_serialize::TextPolicy::from_request(
+					req.text_inclusion(),
+					req.text_max_bytes(),
+				),
+			);
+			let (document, parse_info) = state.restore(&key, req.label())?;
+			tracing::Span::current().record("parse", "snapshot");
+			let res = document.serialize(&parse_info, options);
+
+			encoding.file_response(res)
+		}
+		RequestUnion::ChunkRequest => {
+			let req = req.request_as_chunk_request().unwrap();
+
+			let document = state.document(&uri::key(req.path())?)?;
+			let workspace = workspace_config::current();
+			let chunking = workspace
+				.as_ref()
+				.map(|workspace| &workspace.config.chunking);
+			let tokens = Some(req.max_tokens())
+				.filter(|&tokens| tokens > 0)
+				.or_else(|| chunking.and_then(|chunking| chunking.max_tokens));
+			let budget = match (tokens, req.max_chars()) {
+				(Some(tokens), _) => tokens as usize * chunk::CHARS_PER_TOKEN,
+				(None, Some(chars)) => chars as usize,
+				(None, None) => chunking
+					.and_then(|chunking| chunking.max_chars)
+					.unwrap_or(chunk::DEFAULT_MAX_CHARS) as usize,
+			};
+			encoding.chunk_response(chunk::chunk(document, budget, req.with_text()))
+		}
+		RequestUnion::MetricsRequest => {
+			let req = req.request_as_metrics_request().unwrap();
+
+			let document = state.document(&uri::key(req.path())?)?;
+			encoding.metrics_response(metrics::metrics(document))
+		}
+		RequestUnion::ComplexityRequest => {
+			let req = req.request_as_complexity_request().unwrap();
+
+			let document = state.document(&uri::key(req.path())?)?;
+			encoding.complexity_response(complexity::complexity(document))
+		}
+		RequestUnion::ImportsRequest => {
+			let req = req.request_as_imports_request().unwrap();
+
+			let key = uri::key(req.path())?;
+			let document = state.document(&key)?;
+			encoding.imports_response(imports::imports(&key, document))
+		}
+		RequestUnion::TodosRequest => {
+			let req = req.request_as_todos_request().unwrap();
+
+			let document = state.document(&uri::key(req.path())?)?;
+			let mut markers = req
+				.markers()
+				.into_iter()
+				.flatten()
+				.map(str::to_string)
+				.collect::<Vec<_>>();
+			if markers.is_empty() {
+				markers = todos::DEFAULT_MARKERS
+					.iter()
+					.map(|m| m.to_string())
+					.collect();
+			}
+			encoding.todos_response(todos::todos(document, &markers))
+		}
+		RequestUnion::FormatRangesRequest => {
+			let req = req.request_as_format_ranges_request().unwrap();
+
+			let document = state.document(&uri::key(req.path())?)?;
+			let location = req.location();
+			encoding.format_ranges_response(format_ranges::format_ranges(
+				document,
+				location.start_byte() as usize,
+				location.end_byte() as usize,
+			)?)
+		}
+		RequestUnion::TraverseRequest => {
+			let req = req.request_as_traverse_request().unwrap();
 
-			let text = fs::read_to_string(&path).context("Error reading file")?;
-			let utf16_text = text.encode_utf16().collect::<Vec<u16>>();
+			let document = state.document(&uri::key(req.path())?)?;
+			encoding.traverse_response(traverse::traverse(document, req)?)
+		}
+		RequestUnion::SelectionRangesRequest => {
+			let req = req.request_as_selection_ranges_request().unwrap();
+
+			let document = state.document(&uri::key(req.path())?)?;
+			encoding.selection_ranges_response(selection_ranges::selection_ranges(document, req))
+		}
+		RequestUnion::SymbolsRequest => {
+			let req = req.request_as_symbols_request().unwrap();
+
+			let document = state.document(&uri::key(req.path())?)?;
+			encoding.symbols_response(symbols::symbols(document))
+		}
+		RequestUnion::FoldingRangesRequest => {
+			let req = req.request_as_folding_ranges_request().unwrap();
+
+			let document = state.document(&uri::key(req.path())?)?;
+			encoding.folding_ranges_response(folding::folding_ranges(document))
+		}
+		RequestUnion::HistoryRequest => {
+			let req = req.request_as_history_request().unwrap();
+
+			encoding.history_response(history::history(&state, req)?)
+		}
+		RequestUnion::BlameRequest => {
+			let req = req.request_as_blame_request().unwrap();
+			let key = uri::key(req.path())?;
+
+			encoding.blame_response(blame::blame(&mut state, &key, req)?)
+		}
+		RequestUnion::VerifyRewriteRequest => {
+			let req = req.request_as_verify_rewrite_request().unwrap();
+
+			encoding.verify_rewrite_response(verify_rewrite::verify_rewrite(&state, req)?)
+		}
+		RequestUnion::ClonesRequest => {
+			let req = req.request_as_clones_request().unwrap();
 
-			let tree = {
-				let old_tree = state.files.get(&path).map(|v| v.read().unwrap());
-				state
-					.parser
-					.lock()
-					.unwrap()
-					.parse_utf16(&utf16_text, old_tree.as_deref())
-					.context("Error parsing file")?
+			let normalize = fingerprint::Normalize {
+				identifiers: req.ignore_identifiers(),
+				literals: req.ignore_literals(),
 			};
+			state.parse_unparsed()?;
+			encoding.clones_response(clones::clones(&state.documents, req.min_nodes(), normalize))
+		}
+		RequestUnion::PluginRequest => {
+			let req = req.request_as_plugin_request().unwrap();
 
-			let res = tree_serialize::serialize(&utf16_text, &tree);
+			state.parse_unparsed()?;
+			let payload = plugins::call(
+				&state.documents,
+				req.plugin(),
+				req.method().unwrap_or_default(),
+				req.payload().map_or(&[], |payload| payload.bytes()),
+			)?;
+			let mut builder = flatbuffers::FlatBufferBuilder::new();
+			let payload = builder.create_vector(&payload);
+			let resp = PluginResponse::create(
+				&mut builder,
+				&PluginResponseArgs {
+					payload: Some(payload),
+				},
+			);
+			builder.finish(resp, None);
 
-			let file_resp =
-				flatbuffers::root::<message_generated::asted::interface::FileResponse>(&res);
-			println!("file_resp: {:?}", file_resp);
+			encoding.plugin_response(builder.finished_data().to_vec())
+		}
+		#[cfg(feature = "wasm")]
+		RequestUnion::RunPluginRequest => {
+			let req = req.request_as_run_plugin_request().unwrap();
 
-			state.files.insert(path.into(), RwLock::new(tree));
+			let document = state.document(&uri::key(req.path())?)?;
+			let results = wasm_plugins::run(
+				document,
+				req.plugin(),
+				req.args().map_or(&[], |args| args.bytes()),
+			)?;
+			let mut builder = flatbuffers::FlatBufferBuilder::new();
+			let results = builder.create_vector(&results);
+			let resp = RunPluginResponse::create(
+				&mut builder,
+				&RunPluginResponseArgs {
+					version: document.version,
+					content_hash: document.text.content_hash(),
+					results: Some(results),
+				},
+			);
+			builder.finish(resp, None);
 
-			println!("sending buffer");
+			encoding.run_plugin_response(builder.finished_data().to_vec())
+		}
+		#[cfg(not(feature = "wasm"))]
+		RequestUnion::RunPluginRequest => Err(Error::UnknownCommand(
+			"This server was built without WASM plugins".to_string(),
+		)
+		.into()),
+		#[cfg(feature = "scripting")]
+		RequestUnion::ScriptRequest => {
+			let req = req.request_as_script_request().unwrap();
+
+			let document = state.document(&uri::key(req.path())?)?;
+			let output = scripting::run(document, req.path(), req.script())?;
+			let mut builder = flatbuffers::FlatBufferBuilder::new();
+			let lines = output
+				.lines
+				.iter()
+				.map(|line| builder.create_string(line))
+				.collect::<Vec<_>>();
+			let lines = builder.create_vector(&lines);
+			let result = builder.create_vector(&output.result);
+			let resp = ScriptResponse::create(
+				&mut builder,
+				&ScriptResponseArgs {
+					version: document.version,
+					content_hash: document.text.content_hash(),
+					output: Some(lines),
+					result: Some(result),
+				},
+			);
+			builder.finish(resp, None);
 
-			Ok(res.into_response())
+			encoding.script_response(builder.finished_data().to_vec())
+		}
+		#[cfg(not(feature = "scripting"))]
+		RequestUnion::ScriptRequest => {
+			Err(Error::UnknownCommand("This server was built without scripting".to_string()).into())
+		}
+		RequestUnion::QueryRequest => {
+			let req = req.request_as_query_request().unwrap();
+
+			search::start(&state, req, encoding)
+		}
+		RequestUnion::ResumeRequest => {
+			let req = req.request_as_resume_request().unwrap();
+
+			encoding.resume_response(resume::resume(&state, req)?)
+		}
+		RequestUnion::ParseSnippetRequest => {
+			let req = req.request_as_parse_snippet_request().unwrap();
+
+			encoding.file_response(snippet::parse_snippet(&state, req)?)
+		}
+		RequestUnion::TransactionRequest => {
+			let req = req.request_as_transaction_request().unwrap();
+
+			encoding.transaction_response(transaction::transaction(&mut state, req)?)
+		}
+		RequestUnion::ShutdownRequest => {
+			tracing::info!("shutting down on request");
+			daemon::shutdown();
+			Ok("".into_response())
+		}
+		RequestUnion::DebugParseRequest => {
+			let req = req.request_as_debug_parse_request().unwrap();
+
+			let path = uri::file_path(req.path())?;
+			state.check_root(&path)?;
+			let text = read_text(&path)?.to_utf16();
+			if state.plain_text {
+				return Err(Error::UnknownLanguage(format!(
+					"No grammar to debug for plain-text language {}",
+					state.lang.as_deref().unwrap_or_default()
+				))
+				.into());
+			}
+			let language = state.parser().language().ok_or_else(|| {
+				Error::UnknownLanguage("No language has been initialized".to_string())
+			})?;
+
+			encoding.debug_parse_response(debug_parse::debug_parse(
+				language,
+				&text,
+				req.dot_graphs(),
+			)?)
+		}
+		RequestUnion::PlaygroundRequest => {
+			let req = req.request_as_playground_request().unwrap();
+
+			encoding.playground_response(playground::playground(&state, req)?)
+		}
+		RequestUnion::ListLanguagesRequest => {
+			encoding.list_languages_response(language_info::list_languages())
+		}
+		RequestUnion::ReloadGrammarsRequest => {
+			// reloading touches every session, including this one
+			drop(state);
+			let reloaded = reload_grammars()?;
+
+			let mut builder = flatbuffers::FlatBufferBuilder::new();
+			let mut names = |names: &[String]| {
+				let names = names
+					.iter()
+					.map(|name| builder.create_string(name))
+					.collect::<Vec<_>>();
+				builder.create_vector(&names)
+			};
+			let languages = names(&reloaded.loaded);
+			let failed = names(&reloaded.failed);
+			let resp = ReloadGrammarsResponse::create(
+				&mut builder,
+				&ReloadGrammarsResponseArgs {
+					languages: Some(languages),
+					failed: Some(failed),
+				},
+			);
+			builder.finish(resp, None);
+
+			encoding.reload_grammars_response(builder.finished_data().to_vec())
 		}
 		_ => Err(
 			Error::UnknownCommand("The server does not understand this command!".to_string())
@@ -148,55 +1474,817 @@ async fn handle(body: Bytes) -> Result<Response> {
 	}
 }
 
-async fn handler(body: Bytes) -> Response {
-	println!("got request to /");
-	match handle(body).await {
-		Ok(r) => r,
-		Err(e) => {
-			println!("Error handling request: {}", e);
-			println!(
-				"Underlying error: {}",
-				e.source().map_or("None".to_string(), |e| e.to_string())
-			);
-			(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+/// Re-loads the grammar directory and points every session using a reloaded language at the new
+/// grammar. Cached trees of those sessions are dropped since they can't be reused across grammars.
+/// Languages whose grammars fail to load keep the old ones. The queries directory is reread too.
+fn reload_grammars() -> Result<languages::Reloaded> {
+	let reloaded = languages::reload()?;
+	let loaded = &reloaded.loaded;
+	tree_cache::clear();
+	// the queries have to be compiled again for the new grammars
+	queries::reload()?;
+	workspace_config::forget();
+
+	for mut state in STATE_MAP.iter_mut() {
+		// made again with the new grammar when next needed
+		state
+			.override_parsers()
+			.retain(|lang, _| !loaded.contains(lang));
+		let overridden = state
+			.overrides
+			.iter()
+			.filter(|(_, lang)| loaded.contains(lang))
+			.map(|(key, _)| key.clone())
+			.collect::<Vec<_>>();
+		for key in overridden {
+			state.documents.remove(&key);
+			state.unparsed.remove(&key);
+		}
+
+		let lang = match &state.lang {
+			Some(lang) if loaded.contains(lang) => lang.clone(),
+			_ => continue,
+		};
+		state
+			.parser()
+			.set_language(languages::get(&lang).unwrap())
+			.with_context(|| format!("Error loading tree-sitter {} language", lang))?;
+		// a plain-text session switches over once its grammar shows up
+		state.plain_text = false;
+		state.documents.clear();
+		state.unparsed.clear();
+	}
+
+	Ok(reloaded)
+}
+
+/// Initializes the global session with `lang` and parses the files matching `patterns` into its
+/// cache (on the worker threads), so the first client requests don't have to wait for it.
+fn preload(lang: Option<&str>, patterns: &[String]) -> Result<()> {
+	let mut state = STATE_MAP.get_mut("global").unwrap();
+	if let Some(lang) = lang {
+		state.set_language(lang, false)?;
+	}
+	if patterns.is_empty() {
+		return Ok(());
+	}
+	if state.lang.is_none() {
+		anyhow::bail!("Preloading paths requires a preload language");
+	}
+
+	let cwd = std::env::current_dir().context("Error getting current directory")?;
+	let lang = state.lang.clone().unwrap_or_default();
+	let mut files = Vec::new();
+	for pattern in patterns {
+		for path in glob::glob(pattern).with_context(|| format!("Invalid pattern {}", pattern))? {
+			let path = cwd.join(path?);
+			if path.is_file() {
+				files.push((path, lang.clone()));
+			}
 		}
 	}
+	let count = files.len();
+	workspace::parse_all(&files, |parsed| {
+		let parsed = parsed?;
+		let key = DocumentKey::file(&parsed.path);
+		tree_cache::insert(&parsed.text, &parsed.tree);
+		let parse_info = tree_serialize::ParseInfo {
+			duration: parsed.duration,
+			reused_old_tree: false,
+			cached_tree: false,
+			changed_ranges: Vec::new(),
+			version: 1,
+			content_hash: parsed.text.content_hash(),
+			mtime: parsed.mtime,
+		};
+		let (document, parse_info) = state.insert(&key, parsed.text, parsed.tree, parse_info)?;
+		replay::record(&key, document, &parse_info, replay::Input::Text);
+		Ok(())
+	})
+	.context("Error preloading")?;
+	tracing::info!("preloaded {} files", count);
+	webhooks::emit(webhooks::Payload {
+		event: webhooks::Event::IndexCompleted,
+		subject: webhooks::Subject::Index { files: count },
+	});
+
+	Ok(())
+}
+
+/// Turns a panic in a request handler into an internal error response. The session state it
+/// held stays in place, so other requests (and clients) are unaffected.
+fn panic_response(err: Box<dyn Any + Send + 'static>) -> Response {
+	panic_error(err).into_response()
+}
+
+fn panic_error(err: Box<dyn Any + Send + 'static>) -> Error {
+	let message = if let Some(s) = err.downcast_ref::<&str>() {
+		s.to_string()
+	} else if let Some(s) = err.downcast_ref::<String>() {
+		s.clone()
+	} else {
+		"unknown panic".to_string()
+	};
+	tracing::error!("Request handler panicked: {}", message);
+
+	Error::Internal(format!("Request handler panicked: {}", message))
+}
+
+async fn handler(
+	tenant: Option<Extension<Arc<tenants::Tenant>>>,
+	headers: HeaderMap,
+	body: Result<Bytes, BytesRejection>,
+) -> Response {
+	let start = Instant::now();
+	let encoding = Encoding::negotiate(&headers);
+	let session = tenant.map_or("global".to_string(), |Extension(tenant)| {
+		tenant.session.clone()
+	});
+	// handle fills in the rest as it learns about the request
+	let span = tracing::info_span!(
+		"request",
+		request_type = tracing::field::Empty,
+		session = %session,
+		uri = tracing::field::Empty,
+		parse = tracing::field::Empty,
+	);
+
+	let res = match body {
+		Ok(body) => {
+			let span = span.clone();
+			match tokio::task::spawn_blocking(move || {
+				span.in_scope(|| handle(body, encoding, &session))
+			})
+			.await
+			{
+				Ok(res) => res,
+				Err(e) if e.is_panic() => Err(panic_error(e.into_panic()).into()),
+				Err(e) => Err(e).context("Request handler was cancelled"),
+			}
+		}
+		Err(rejection) => Err(if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE {
+			Error::PayloadTooLarge(rejection.body_text())
+		} else {
+			Error::InvalidRequest(rejection.body_text())
+		}
+		.into()),
+	};
+
+	let (res, error) = match res {
+		Ok(res) => (res, None),
+		Err(e) => {
+			let error = format!("{:#}", e);
+			(encoding.error_response(Error::from(e)), Some(error))
+		}
+	};
+	span.in_scope(|| {
+		tracing::info!(
+			target: "access",
+			status = res.status().as_u16(),
+			size = HttpBody::size_hint(res.body()).exact(),
+			duration_us = start.elapsed().as_micros() as u64,
+			error,
+		)
+	});
+	res
 }
 
 #[derive(ClapParser)]
 struct Args {
+	/// A TOML config file; flags given on the command line or through the environment take
+	/// precedence over it. Sending the server SIGHUP rereads it
+	#[arg(short, long)]
+	config: Option<PathBuf>,
+	/// Reread the config file whenever it changes
+	#[arg(long, requires = "config")]
+	watch_config: bool,
 	/// The host to listen on
-	#[arg(short = 'H', long, default_value = "127.0.0.1")]
+	#[arg(short = 'H', long, env = "ASTED_HOST", default_value = "127.0.0.1")]
 	host: String,
-	/// The port to listen on
-	#[arg(short, long, default_value = "44790")]
+	/// The port to listen on; 0 picks a free one, which is published in the discovery file (give
+	/// each such server a --discovery-file of its own)
+	#[arg(short, long, env = "ASTED_PORT", default_value = "44790")]
 	port: u16,
+	/// Where the server publishes its address (and token), and where start, stop and status look
+	/// for it; defaults to daemon.json under $XDG_RUNTIME_DIR/asted-tree-sitter
+	#[arg(long, env = "ASTED_DISCOVERY_FILE")]
+	discovery_file: Option<PathBuf>,
+	/// An address to listen on, like 127.0.0.1:44790 or [::1]:44790; can be given more than once,
+	/// and replaces --host and --port (which may come from the environment, so they don't
+	/// conflict)
+	#[arg(long)]
+	listen: Vec<SocketAddr>,
+	/// A local endpoint to serve HTTP on as well: a named pipe on Windows (a name, or a full
+	/// `\\.\pipe\...` path), and a Unix domain socket path elsewhere
+	#[arg(long)]
+	pipe: Option<String>,
+	/// An address to serve length-prefixed flatbuffers frames on over plain TCP, bypassing HTTP;
+	/// can be given more than once
+	#[arg(long)]
+	raw_tcp: Vec<SocketAddr>,
+	/// Ping raw TCP and gRPC clients after this many seconds without hearing from them; 0 turns
+	/// pinging off
+	#[arg(long, default_value = "30")]
+	heartbeat_interval: u64,
+	/// Close raw TCP and gRPC connections after this many seconds without hearing from the client,
+	/// pings unanswered
+	#[arg(long, default_value = "90")]
+	heartbeat_timeout: u64,
+	/// Require clients to send a randomly generated token (published in the discovery file) as
+	/// `Authorization: Bearer <token>`
+	#[arg(long)]
+	auth: bool,
+	/// Require clients to send this token instead of a generated one, like --auth
+	#[arg(long, env = "ASTED_TOKEN", hide_env_values = true)]
+	token: Option<String>,
+	/// An origin (like http://localhost:3000) browsers may call the server from; can be given
+	/// more than once. Without any, browsers are held to the same-origin policy
+	#[arg(long)]
+	cors_origin: Vec<HeaderValue>,
+	/// Exit after this many seconds without a request, while no raw TCP, pipe or gRPC streaming
+	/// client is connected
+	#[arg(long)]
+	idle_timeout: Option<u64>,
+	/// The maximum size of a request body in bytes
+	#[arg(long, default_value = "2097152")]
+	max_body_size: usize,
+	/// The maximum number of requests handled at once
+	#[arg(long)]
+	max_concurrent_requests: Option<usize>,
+	/// The maximum number of requests per second from each client address
+	#[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+	rate_limit: Option<u32>,
+	/// A directory of shared-library tree-sitter grammars to load
+	#[arg(long, env = "ASTED_GRAMMAR_DIR")]
+	grammar_dir: Option<PathBuf>,
+	/// Reload grammars whenever the grammar directory changes
+	#[arg(long, requires = "grammar_dir")]
+	watch_grammars: bool,
+	/// A directory of queries to use instead of the bundled ones, like <dir>/typescript/tags.scm;
+	/// reread whenever grammars are reloaded
+	#[arg(long)]
+	queries_dir: Option<PathBuf>,
+	/// The language to initialize the default session with at startup
+	#[arg(long)]
+	preload_lang: Option<String>,
+	/// A file (or glob pattern) to parse into the default session's cache at startup
+	#[arg(long)]
+	preload_path: Vec<String>,
+	/// How many threads to parse files on when parsing many at once, like when preloading or
+	/// exporting; one per core by default
+	#[arg(long)]
+	workers: Option<usize>,
+	/// A file to keep the list of every session's open documents in, rewritten every few seconds,
+	/// so they're opened (and reread from disk) again when the server restarts
+	#[arg(long)]
+	state_file: Option<PathBuf>,
+	/// Keep serialized trees on disk across restarts, so files that haven't changed since are
+	/// answered without being parsed until a request needs their tree
+	#[arg(long)]
+	disk_cache: bool,
+	/// Where the disk cache is kept, instead of $XDG_CACHE_HOME/asted-tree-sitter/trees
+	#[arg(long, requires = "disk_cache")]
+	disk_cache_dir: Option<PathBuf>,
+	/// How many bytes the disk cache can take before the least recently used trees are deleted
+	#[arg(long, default_value = "1073741824")]
+	disk_cache_size: u64,
+	/// The least severe messages to log: off, error, warn, info (the default), debug or trace
+	#[arg(long, env = "ASTED_LOG_LEVEL")]
+	log_level: Option<LevelFilter>,
+	/// Export request spans to this OTLP (gRPC) collector, e.g. http://localhost:4317
+	#[cfg(feature = "otel")]
+	#[arg(long)]
+	otlp_endpoint: Option<String>,
+	/// Speak the Language Server Protocol over stdin and stdout instead of serving HTTP. Only the
+	/// built-in grammars are available, and the HTTP options are ignored
+	#[arg(long)]
+	lsp: bool,
+	/// An address to serve the gRPC interface on, like 127.0.0.1:44791; can be given more than
+	/// once
+	#[cfg(feature = "grpc")]
+	#[arg(long)]
+	grpc_listen: Vec<SocketAddr>,
+	/// Whether to resolve symlinks in file paths, or treat each link as a separate document
+	#[arg(long, value_enum, default_value_t)]
+	symlinks: uri::SymlinkPolicy,
+	/// Keep clients from changing open documents, flushing caches, reloading grammars, running
+	/// plugins and shutting the server down, e.g. when exposing the server to tooling that should
+	/// only read
+	#[arg(long)]
+	read_only: bool,
+	/// Keep clients from doing one of the things --read-only denies; can be given more than once
+	#[arg(long, value_enum, conflicts_with = "read_only")]
+	deny: Vec<permissions::Capability>,
+	/// A native plugin (shared library) to load, for handling `PluginRequest`s; can be given more
+	/// than once
+	#[arg(long)]
+	plugin: Vec<PathBuf>,
+	/// A WASM plugin to load, for handling `RunPluginRequest`s, named after the file; can be given
+	/// more than once
+	#[cfg(feature = "wasm")]
+	#[arg(long)]
+	wasm_plugin: Vec<PathBuf>,
+	/// A URL to POST a JSON payload to for every event; can be given more than once. Webhooks
+	/// listening for only some events can be set up in the config file
+	#[arg(long)]
+	webhook: Vec<String>,
+	/// Keep responses the same from run to run, so client test suites can snapshot them: timings
+	/// and file modification times are zeroed, resume tokens don't tell server instances apart,
+	/// and files are parsed on one thread
+	#[arg(long)]
+	deterministic: bool,
+	/// Record every edit and reparse of each open document to a log for it in this directory, for
+	/// the replay subcommand to re-execute when a client's tree drifts from the server's
+	#[arg(long)]
+	replay_dir: Option<PathBuf>,
+	#[command(subcommand)]
+	command: Option<Command>,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+	#[command(flatten)]
+	Bench(bench::BenchCommand),
+	#[command(flatten)]
+	Check(check::CheckCommand),
+	#[command(flatten)]
+	Daemon(daemon::DaemonCommand),
+	#[command(flatten)]
+	Export(export::ExportCommand),
+	#[command(flatten)]
+	Replay(replay::ReplayCommand),
+	#[command(flatten)]
+	Watch(watch::WatchCommand),
+}
+
+/// Sets up the language registry for the subcommands that parse files without a server.
+fn load_languages(args: &Args) -> Result<()> {
+	// for the extensions configured for languages
+	if let Some(path) = &args.config {
+		languages::set_configs(config::Config::load(path)?.languages);
+	}
+	if let Some(dir) = &args.grammar_dir {
+		let reloaded = languages::set_grammar_dir(dir.clone())?;
+		if !reloaded.failed.is_empty() {
+			bail!("Error loading grammars for {}", reloaded.failed.join(", "));
+		}
+	}
+	Ok(())
 }
 
 #[tokio::main]
 async fn main() {
-	let args = Args::parse();
+	let mut args = Args::parse();
+	deterministic::set(args.deterministic);
+	if args.deterministic {
+		workspace::set_workers(1);
+	} else if let Some(workers) = args.workers {
+		workspace::set_workers(workers);
+	}
+	if let Some(path) = args.discovery_file.clone() {
+		daemon::set_discovery_file(path);
+	}
+	match args.command.take() {
+		Some(Command::Daemon(command)) => {
+			if let Err(e) = daemon::run(command).await {
+				println!("{:#}", e);
+				std::process::exit(1);
+			}
+			return;
+		}
+		Some(Command::Bench(command)) => {
+			// before the languages are loaded, in case loading them allocates
+			bench::count_allocations();
+			if let Err(e) = load_languages(&args).and_then(|_| bench::run(command)) {
+				println!("{:#}", e);
+				std::process::exit(1);
+			}
+			return;
+		}
+		Some(Command::Check(command)) => {
+			match load_languages(&args).and_then(|_| check::run(command)) {
+				Ok(true) => {}
+				// there were syntax errors, which were printed
+				Ok(false) => std::process::exit(1),
+				Err(e) => {
+					println!("{:#}", e);
+					std::process::exit(2);
+				}
+			}
+			return;
+		}
+		Some(Command::Export(command)) => {
+			if let Err(e) = load_languages(&args).and_then(|_| export::run(command)) {
+				println!("{:#}", e);
+				std::process::exit(1);
+			}
+			return;
+		}
+		Some(Command::Replay(command)) => {
+			if let Err(e) = load_languages(&args).and_then(|_| replay::run(command)) {
+				println!("{:#}", e);
+				std::process::exit(1);
+			}
+			return;
+		}
+		Some(Command::Watch(command)) => {
+			if let Err(e) = load_languages(&args).and_then(|_| watch::run(command)) {
+				println!("{:#}", e);
+				std::process::exit(1);
+			}
+			return;
+		}
+		None => {}
+	}
 
-	STATE_MAP.insert(
-		"global".to_string(),
-		State {
-			parser: Mutex::new(Parser::new()),
-			files: HashMap::new(),
+	let config = match &args.config {
+		Some(path) => match config::Config::load(path) {
+			Ok(config) => config,
+			Err(e) => {
+				println!("Failed to load config: {:#}", e);
+				std::process::exit(1);
+			}
 		},
+		None => config::Config::default(),
+	};
+
+	let flags = config::Flags {
+		read_only: args.read_only,
+		deny: args.deny.clone(),
+		log_level: args.log_level,
+		rate_limit: args.rate_limit,
+		max_concurrent_requests: args.max_concurrent_requests,
+	};
+	let res = logging::init(
+		flags.log_level(&config),
+		args.lsp,
+		#[cfg(feature = "otel")]
+		args.otlp_endpoint.as_deref(),
 	);
+	if let Err(e) = res {
+		println!("{:#}", e);
+		std::process::exit(1);
+	}
+
+	let (max_concurrent_requests, rate_limit) = flags.limits(&config);
+	limits::set(limits::Limits::new(max_concurrent_requests, rate_limit));
+	flags.deny(&config);
+
+	uri::set_symlink_policy(args.symlinks);
+	if let Some(dir) = args.replay_dir.clone() {
+		if let Err(e) = replay::set_dir(dir) {
+			println!("Failed to set up replay logs: {:#}", e);
+			std::process::exit(1);
+		}
+	}
+	languages::set_configs(config.languages.clone());
+	let reloader = args
+		.config
+		.as_ref()
+		.map(|path| Arc::new(config::Reloader::new(path.clone(), config.clone(), flags)));
 
-	let app = Router::new().route("/", post(handler));
+	if args.lsp {
+		if let Err(e) = queries::set_dir(args.queries_dir) {
+			tracing::error!("Failed to load queries: {:#}", e);
+			std::process::exit(1);
+		}
+		if let Err(e) = lsp::run() {
+			tracing::error!("{:#}", e);
+			std::process::exit(1);
+		}
+		return;
+	}
+
+	STATE_MAP.insert("global".to_string(), State::new("global"));
 
-	let addr = match format!("{}:{}", args.host, args.port).parse::<SocketAddr>() {
-		Ok(addr) => addr,
+	if let Some(dir) = args.grammar_dir {
+		match languages::set_grammar_dir(dir.clone()) {
+			Ok(reloaded) if reloaded.failed.is_empty() => {}
+			Ok(reloaded) => {
+				tracing::error!("Failed to load grammars for {}", reloaded.failed.join(", "));
+				std::process::exit(1);
+			}
+			Err(e) => {
+				tracing::error!("Failed to load grammars: {:#}", e);
+				std::process::exit(1);
+			}
+		}
+
+		if args.watch_grammars {
+			let res = languages::watch(dir, || {
+				if let Err(e) = reload_grammars() {
+					tracing::error!("Failed to reload grammars: {:#}", e);
+				}
+			});
+			if let Err(e) = res {
+				tracing::error!("Failed to watch grammar directory: {:#}", e);
+				std::process::exit(1);
+			}
+		}
+	}
+
+	match queries::set_dir(args.queries_dir) {
+		Ok(langs) => {
+			for lang in langs {
+				tracing::info!("loaded queries for {}", lang);
+			}
+		}
 		Err(e) => {
-			println!("Failed to parse address: {}", e);
+			tracing::error!("Failed to load queries: {:#}", e);
+			std::process::exit(1);
+		}
+	}
+
+	if let Some(reloader) = &reloader {
+		#[cfg(unix)]
+		{
+			use tokio::signal::unix::{signal, SignalKind};
+
+			let mut hangups = match signal(SignalKind::hangup()) {
+				Ok(hangups) => hangups,
+				Err(e) => {
+					tracing::error!("Failed to listen for SIGHUP: {}", e);
+					std::process::exit(1);
+				}
+			};
+			let reloader = reloader.clone();
+			tokio::spawn(async move {
+				while hangups.recv().await.is_some() {
+					let reloader = reloader.clone();
+					let _ = tokio::task::spawn_blocking(move || reloader.reload()).await;
+				}
+			});
+		}
+
+		if args.watch_config {
+			// editors often replace the file rather than writing to it, so watch its directory
+			let path = args.config.as_deref().unwrap();
+			let dir = match path.parent() {
+				Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+				_ => PathBuf::from("."),
+			};
+			let reloader = reloader.clone();
+			if let Err(e) = languages::watch(dir, move || reloader.reload()) {
+				tracing::error!("Failed to watch config file: {:#}", e);
+				std::process::exit(1);
+			}
+		}
+	}
+
+	for path in &args.plugin {
+		match plugins::load(path) {
+			Ok(name) => tracing::info!("loaded plugin {} from {}", name, path.display()),
+			Err(e) => {
+				tracing::error!("Failed to load plugin: {:#}", e);
+				std::process::exit(1);
+			}
+		}
+	}
+	#[cfg(feature = "wasm")]
+	for path in &args.wasm_plugin {
+		match wasm_plugins::load(path) {
+			Ok(name) => tracing::info!("loaded WASM plugin {} from {}", name, path.display()),
+			Err(e) => {
+				tracing::error!("Failed to load WASM plugin: {:#}", e);
+				std::process::exit(1);
+			}
+		}
+	}
+
+	if args.disk_cache {
+		let res = args
+			.disk_cache_dir
+			.clone()
+			.or_else(disk_cache::default_dir)
+			.context("No cache directory: set XDG_CACHE_HOME or pass --disk-cache-dir")
+			.and_then(|dir| disk_cache::enable(dir, args.disk_cache_size));
+		if let Err(e) = res {
+			tracing::error!("Failed to open the disk cache: {:#}", e);
+			std::process::exit(1);
+		}
+	}
+
+	// before preloading, so the webhooks hear of it
+	let mut hooks = config.webhooks;
+	hooks.extend(args.webhook.into_iter().map(|url| webhooks::Webhook {
+		url,
+		events: Vec::new(),
+	}));
+	if !hooks.is_empty() {
+		if let Err(e) = webhooks::start(hooks) {
+			tracing::error!("{:#}", e);
+			std::process::exit(1);
+		}
+	}
+
+	let preload_lang = args.preload_lang.or(config.preload_lang);
+	let preload_paths = if args.preload_path.is_empty() {
+		config.preload_paths
+	} else {
+		args.preload_path
+	};
+	if let Err(e) = preload(preload_lang.as_deref(), &preload_paths) {
+		tracing::error!("Failed to preload: {:#}", e);
+		std::process::exit(1);
+	}
+
+	let activity = Arc::new(idle::Activity::new());
+
+	let token = if args.token.is_some() {
+		args.token.clone()
+	} else if args.auth {
+		match auth::generate_token() {
+			Ok(token) => Some(token),
+			Err(e) => {
+				tracing::error!("{:#}", e);
+				std::process::exit(1);
+			}
+		}
+	} else {
+		None
+	};
+
+	let mut app = Router::new()
+		.route("/", post(handler))
+		.route("/admin/state", get(admin::state))
+		.route("/admin/flush", post(admin::flush))
+		.route("/admin/profile", get(admin::profile));
+	let mut tenants = Vec::<Arc<tenants::Tenant>>::new();
+	for config in &config.tenants {
+		let tenant = tenants::Tenant::new(config);
+		if tenants
+			.iter()
+			.any(|other| other.name == tenant.name || other.key == tenant.key)
+		{
+			tracing::error!("Tenant {} has the same name or key as another", tenant.name);
 			std::process::exit(1);
 		}
+		STATE_MAP.insert(tenant.session.clone(), State::for_tenant(config));
+		tenants.push(Arc::new(tenant));
+	}
+	if let Some(path) = &args.state_file {
+		match recovery::restore(path) {
+			Ok(0) => {}
+			Ok(count) => tracing::info!("restored {} documents from {}", count, path.display()),
+			Err(e) => tracing::error!("Failed to restore documents: {:#}", e),
+		}
+	}
+	let keys = Arc::new(auth::Keys {
+		token: token.clone(),
+		tenants,
+	});
+	if keys.token.is_some() || !keys.tenants.is_empty() {
+		app = app.route_layer(middleware::from_fn_with_state(
+			keys.clone(),
+			auth::authenticate,
+		));
+	}
+	// health checks don't need the token, so status can be checked without it
+	let app = app
+		.route("/healthz", get(|| async { "ok" }))
+		.layer(middleware::from_fn(limits::limit))
+		.layer(DefaultBodyLimit::max(args.max_body_size))
+		.layer(CatchPanicLayer::custom(panic_response))
+		.layer(middleware::from_fn_with_state(
+			activity.clone(),
+			idle::track,
+		));
+	// outermost, so preflight requests are answered before auth and rate limiting see them
+	let app = if args.cors_origin.is_empty() {
+		app
+	} else {
+		app.layer(
+			CorsLayer::new()
+				.allow_origin(args.cors_origin)
+				.allow_methods([Method::GET, Method::POST])
+				.allow_headers([AUTHORIZATION, CONTENT_TYPE])
+				.expose_headers([RETRY_AFTER]),
+		)
+	};
+
+	let addrs = if args.listen.is_empty() {
+		// IPv6 hosts can be given with or without brackets
+		let host = args.host.trim_start_matches('[').trim_end_matches(']');
+		match host.parse::<IpAddr>() {
+			Ok(ip) => vec![SocketAddr::new(ip, args.port)],
+			Err(e) => {
+				tracing::error!("Failed to parse host {}: {}", args.host, e);
+				std::process::exit(1);
+			}
+		}
+	} else {
+		args.listen
 	};
 
-	axum::Server::bind(&addr)
-		.serve(app.into_make_service())
-		.await
-		.unwrap();
+	let mut servers = JoinSet::<Result<()>>::new();
+	let mut bound = Vec::new();
+	for addr in addrs {
+		let incoming = match AddrIncoming::bind(&addr) {
+			Ok(incoming) => incoming,
+			Err(e) => {
+				tracing::error!("Failed to listen on {}: {}", addr, e);
+				std::process::exit(1);
+			}
+		};
+		// differs from addr when binding port 0
+		let addr = incoming.local_addr();
+		tracing::info!("listening on {}", addr);
+		bound.push(addr);
+		let server = axum::Server::builder(incoming)
+			.serve(
+				app.clone()
+					.into_make_service_with_connect_info::<SocketAddr>(),
+			)
+			.with_graceful_shutdown(daemon::shutdown_signal());
+		servers.spawn(async move { Ok(server.await?) });
+	}
+	if let Some(name) = args.pipe {
+		servers.spawn(pipe::serve(name, app.clone(), activity.clone()));
+	}
+	let heartbeat =
+		heartbeat::Heartbeat::from_secs(args.heartbeat_interval, args.heartbeat_timeout);
+	for addr in args.raw_tcp {
+		let listener = match tokio::net::TcpListener::bind(addr).await {
+			Ok(listener) => listener,
+			Err(e) => {
+				tracing::error!("Failed to listen on {}: {}", addr, e);
+				std::process::exit(1);
+			}
+		};
+		if let Ok(addr) = listener.local_addr() {
+			tracing::info!("serving raw TCP on {}", addr);
+		}
+		servers.spawn(raw_tcp::serve(
+			listener,
+			app.clone(),
+			args.max_body_size,
+			heartbeat,
+			activity.clone(),
+		));
+	}
+	#[cfg(feature = "grpc")]
+	for addr in args.grpc_listen {
+		tracing::info!("serving gRPC on {}", addr);
+		servers.spawn(grpc::serve(
+			addr,
+			keys.clone(),
+			args.max_body_size,
+			heartbeat,
+			activity.clone(),
+		));
+	}
+	if let Err(e) = daemon::write_discovery(&bound, token) {
+		tracing::error!("{:#}", e);
+	}
+
+	if let Some(secs) = args.idle_timeout {
+		tokio::spawn(async move {
+			idle::wait(activity, Duration::from_secs(secs)).await;
+			tracing::info!("no requests for {}s, shutting down", secs);
+			daemon::shutdown();
+		});
+	}
+
+	if let Some(path) = args.state_file.clone() {
+		tokio::spawn(async move {
+			let mut interval = tokio::time::interval(recovery::SAVE_INTERVAL);
+			loop {
+				interval.tick().await;
+				let path = path.clone();
+				// reading the sessions waits for the requests holding them
+				if let Ok(Err(e)) = tokio::task::spawn_blocking(move || recovery::save(&path)).await
+				{
+					tracing::error!("Failed to save open documents: {:#}", e);
+				}
+			}
+		});
+	}
+
+	while let Some(res) = servers.join_next().await {
+		if let Ok(Err(e)) = res {
+			tracing::error!("Server error: {:#}", e);
+			daemon::remove_discovery();
+			std::process::exit(1);
+		}
+	}
+	if let Some(path) = &args.state_file {
+		if let Err(e) = recovery::save(path) {
+			tracing::error!("Failed to save open documents: {:#}", e);
+		}
+	}
+	daemon::remove_discovery();
+	#[cfg(feature = "otel")]
+	telemetry::shutdown();
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn rejects_a_rate_limit_of_zero() {
+		assert!(Args::try_parse_from(["asted-tree-sitter", "--rate-limit", "0"]).is_err());
+		let args = Args::try_parse_from(["asted-tree-sitter", "--rate-limit", "5"]).unwrap();
+		assert_eq!(args.rate_limit, Some(5));
+	}
 }
@@ -27,44 +27,52 @@ pub mod interface {
   use self::flatbuffers::{EndianScalar, Follow};
 
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MIN_REQUEST_UNION: u8 = 0;
+pub const ENUM_MIN_SERIALIZATION_PROFILE: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_REQUEST_UNION: u8 = 2;
+pub const ENUM_MAX_SERIALIZATION_PROFILE: u8 = 4;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_REQUEST_UNION: [RequestUnion; 3] = [
-  RequestUnion::NONE,
-  RequestUnion::InitRequest,
-  RequestUnion::FileRequest,
+pub const ENUM_VALUES_SERIALIZATION_PROFILE: [SerializationProfile; 5] = [
+  SerializationProfile::Default,
+  SerializationProfile::Full,
+  SerializationProfile::StructureOnly,
+  SerializationProfile::Outline,
+  SerializationProfile::Ranges,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct RequestUnion(pub u8);
+pub struct SerializationProfile(pub u8);
 #[allow(non_upper_case_globals)]
-impl RequestUnion {
-  pub const NONE: Self = Self(0);
-  pub const InitRequest: Self = Self(1);
-  pub const FileRequest: Self = Self(2);
+impl SerializationProfile {
+  pub const Default: Self = Self(0);
+  pub const Full: Self = Self(1);
+  pub const StructureOnly: Self = Self(2);
+  pub const Outline: Self = Self(3);
+  pub const Ranges: Self = Self(4);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_MAX: u8 = 4;
   pub const ENUM_VALUES: &'static [Self] = &[
-    Self::NONE,
-    Self::InitRequest,
-    Self::FileRequest,
+    Self::Default,
+    Self::Full,
+    Self::StructureOnly,
+    Self::Outline,
+    Self::Ranges,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
     match self {
-      Self::NONE => Some("NONE"),
-      Self::InitRequest => Some("InitRequest"),
-      Self::FileRequest => Some("FileRequest"),
+      Self::Default => Some("Default"),
+      Self::Full => Some("Full"),
+      Self::StructureOnly => Some("StructureOnly"),
+      Self::Outline => Some("Outline"),
+      Self::Ranges => Some("Ranges"),
       _ => None,
     }
   }
 }
-impl core::fmt::Debug for RequestUnion {
+impl core::fmt::Debug for SerializationProfile {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
     if let Some(name) = self.variant_name() {
       f.write_str(name)
@@ -73,7 +81,7 @@ impl core::fmt::Debug for RequestUnion {
     }
   }
 }
-impl<'a> flatbuffers::Follow<'a> for RequestUnion {
+impl<'a> flatbuffers::Follow<'a> for SerializationProfile {
   type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -82,15 +90,15 @@ impl<'a> flatbuffers::Follow<'a> for RequestUnion {
   }
 }
 
-impl flatbuffers::Push for RequestUnion {
-    type Output = RequestUnion;
+impl flatbuffers::Push for SerializationProfile {
+    type Output = SerializationProfile;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for RequestUnion {
+impl flatbuffers::EndianScalar for SerializationProfile {
   type Scalar = u8;
   #[inline]
   fn to_little_endian(self) -> u8 {
@@ -104,7 +112,7 @@ impl flatbuffers::EndianScalar for RequestUnion {
   }
 }
 
-impl<'a> flatbuffers::Verifiable for RequestUnion {
+impl<'a> flatbuffers::Verifiable for SerializationProfile {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
@@ -114,485 +122,16645 @@ impl<'a> flatbuffers::Verifiable for RequestUnion {
   }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for RequestUnion {}
-pub struct RequestUnionUnionTableOffset {}
+impl flatbuffers::SimpleToVerifyInSlice for SerializationProfile {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_TEXT_INCLUSION: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_TEXT_INCLUSION: u8 = 4;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TEXT_INCLUSION: [TextInclusion; 5] = [
+  TextInclusion::Profile,
+  TextInclusion::None,
+  TextInclusion::Leaves,
+  TextInclusion::Short,
+  TextInclusion::All,
+];
 
-// struct Location, aligned to 4
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-#[derive(Clone, Copy, PartialEq)]
-pub struct Location(pub [u8; 8]);
-impl Default for Location { 
-  fn default() -> Self { 
-    Self([0; 8])
+pub struct TextInclusion(pub u8);
+#[allow(non_upper_case_globals)]
+impl TextInclusion {
+  pub const Profile: Self = Self(0);
+  pub const None: Self = Self(1);
+  pub const Leaves: Self = Self(2);
+  pub const Short: Self = Self(3);
+  pub const All: Self = Self(4);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 4;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Profile,
+    Self::None,
+    Self::Leaves,
+    Self::Short,
+    Self::All,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Profile => Some("Profile"),
+      Self::None => Some("None"),
+      Self::Leaves => Some("Leaves"),
+      Self::Short => Some("Short"),
+      Self::All => Some("All"),
+      _ => None,
+    }
   }
 }
-impl core::fmt::Debug for Location {
+impl core::fmt::Debug for TextInclusion {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    f.debug_struct("Location")
-      .field("start_byte", &self.start_byte())
-      .field("end_byte", &self.end_byte())
-      .finish()
-  }
-}
-
-impl flatbuffers::SimpleToVerifyInSlice for Location {}
-impl<'a> flatbuffers::Follow<'a> for Location {
-  type Inner = &'a Location;
-  #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    <&'a Location>::follow(buf, loc)
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
   }
 }
-impl<'a> flatbuffers::Follow<'a> for &'a Location {
-  type Inner = &'a Location;
+impl<'a> flatbuffers::Follow<'a> for TextInclusion {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    flatbuffers::follow_cast_ref::<Location>(buf, loc)
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
   }
 }
-impl<'b> flatbuffers::Push for Location {
-    type Output = Location;
+
+impl flatbuffers::Push for TextInclusion {
+    type Output = TextInclusion;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        let src = ::core::slice::from_raw_parts(self as *const Location as *const u8, Self::size());
-        dst.copy_from_slice(src);
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
 
-impl<'a> flatbuffers::Verifiable for Location {
+impl flatbuffers::EndianScalar for TextInclusion {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for TextInclusion {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.in_buffer::<Self>(pos)
+    u8::run_verifier(v, pos)
   }
 }
 
-impl<'a> Location {
-  #[allow(clippy::too_many_arguments)]
-  pub fn new(
-    start_byte: u32,
-    end_byte: u32,
-  ) -> Self {
-    let mut s = Self([0; 8]);
-    s.set_start_byte(start_byte);
-    s.set_end_byte(end_byte);
-    s
-  }
+impl flatbuffers::SimpleToVerifyInSlice for TextInclusion {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_LINE_ENDING: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_LINE_ENDING: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_LINE_ENDING: [LineEnding; 3] = [
+  LineEnding::Lf,
+  LineEnding::Crlf,
+  LineEnding::Mixed,
+];
 
-  pub fn start_byte(&self) -> u32 {
-    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    EndianScalar::from_little_endian(unsafe {
-      core::ptr::copy_nonoverlapping(
-        self.0[0..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
-  }
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct LineEnding(pub u8);
+#[allow(non_upper_case_globals)]
+impl LineEnding {
+  pub const Lf: Self = Self(0);
+  pub const Crlf: Self = Self(1);
+  pub const Mixed: Self = Self(2);
 
-  pub fn set_start_byte(&mut self, x: u32) {
-    let x_le = x.to_little_endian();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[0..].as_mut_ptr(),
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Lf,
+    Self::Crlf,
+    Self::Mixed,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Lf => Some("Lf"),
+      Self::Crlf => Some("Crlf"),
+      Self::Mixed => Some("Mixed"),
+      _ => None,
     }
   }
-
-  pub fn end_byte(&self) -> u32 {
-    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    EndianScalar::from_little_endian(unsafe {
-      core::ptr::copy_nonoverlapping(
-        self.0[4..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
-  }
-
-  pub fn set_end_byte(&mut self, x: u32) {
-    let x_le = x.to_little_endian();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[4..].as_mut_ptr(),
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
+}
+impl core::fmt::Debug for LineEnding {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
     }
   }
-
-}
-
-pub enum InitRequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct InitRequest<'a> {
-  pub _tab: flatbuffers::Table<'a>,
 }
-
-impl<'a> flatbuffers::Follow<'a> for InitRequest<'a> {
-  type Inner = InitRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for LineEnding {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
   }
 }
 
-impl<'a> InitRequest<'a> {
-  pub const VT_LANG: flatbuffers::VOffsetT = 4;
+impl flatbuffers::Push for LineEnding {
+    type Output = LineEnding;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
 
+impl flatbuffers::EndianScalar for LineEnding {
+  type Scalar = u8;
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    InitRequest { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args InitRequestArgs<'args>
-  ) -> flatbuffers::WIPOffset<InitRequest<'bldr>> {
-    let mut builder = InitRequestBuilder::new(_fbb);
-    if let Some(x) = args.lang { builder.add_lang(x); }
-    builder.finish()
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
   }
-
-
   #[inline]
-  pub fn lang(&self) -> &'a str {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InitRequest::VT_LANG, None).unwrap()}
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
   }
 }
 
-impl flatbuffers::Verifiable for InitRequest<'_> {
+impl<'a> flatbuffers::Verifiable for LineEnding {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("lang", Self::VT_LANG, true)?
-     .finish();
-    Ok(())
-  }
-}
-pub struct InitRequestArgs<'a> {
-    pub lang: Option<flatbuffers::WIPOffset<&'a str>>,
-}
-impl<'a> Default for InitRequestArgs<'a> {
-  #[inline]
-  fn default() -> Self {
-    InitRequestArgs {
-      lang: None, // required field
-    }
+    u8::run_verifier(v, pos)
   }
 }
 
-pub struct InitRequestBuilder<'a: 'b, 'b> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b> InitRequestBuilder<'a, 'b> {
-  #[inline]
-  pub fn add_lang(&mut self, lang: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InitRequest::VT_LANG, lang);
-  }
-  #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> InitRequestBuilder<'a, 'b> {
-    let start = _fbb.start_table();
-    InitRequestBuilder {
-      fbb_: _fbb,
-      start_: start,
+impl flatbuffers::SimpleToVerifyInSlice for LineEnding {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_ERROR_CODE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_ERROR_CODE: u8 = 11;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ERROR_CODE: [ErrorCode; 12] = [
+  ErrorCode::Internal,
+  ErrorCode::InvalidRequest,
+  ErrorCode::PayloadTooLarge,
+  ErrorCode::UnknownCommand,
+  ErrorCode::UnknownLanguage,
+  ErrorCode::UnknownFile,
+  ErrorCode::TooManyRequests,
+  ErrorCode::Conflict,
+  ErrorCode::Unauthorized,
+  ErrorCode::Forbidden,
+  ErrorCode::Cancelled,
+  ErrorCode::Timeout,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ErrorCode(pub u8);
+#[allow(non_upper_case_globals)]
+impl ErrorCode {
+  pub const Internal: Self = Self(0);
+  pub const InvalidRequest: Self = Self(1);
+  pub const PayloadTooLarge: Self = Self(2);
+  pub const UnknownCommand: Self = Self(3);
+  pub const UnknownLanguage: Self = Self(4);
+  pub const UnknownFile: Self = Self(5);
+  pub const TooManyRequests: Self = Self(6);
+  pub const Conflict: Self = Self(7);
+  pub const Unauthorized: Self = Self(8);
+  pub const Forbidden: Self = Self(9);
+  pub const Cancelled: Self = Self(10);
+  pub const Timeout: Self = Self(11);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 11;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Internal,
+    Self::InvalidRequest,
+    Self::PayloadTooLarge,
+    Self::UnknownCommand,
+    Self::UnknownLanguage,
+    Self::UnknownFile,
+    Self::TooManyRequests,
+    Self::Conflict,
+    Self::Unauthorized,
+    Self::Forbidden,
+    Self::Cancelled,
+    Self::Timeout,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Internal => Some("Internal"),
+      Self::InvalidRequest => Some("InvalidRequest"),
+      Self::PayloadTooLarge => Some("PayloadTooLarge"),
+      Self::UnknownCommand => Some("UnknownCommand"),
+      Self::UnknownLanguage => Some("UnknownLanguage"),
+      Self::UnknownFile => Some("UnknownFile"),
+      Self::TooManyRequests => Some("TooManyRequests"),
+      Self::Conflict => Some("Conflict"),
+      Self::Unauthorized => Some("Unauthorized"),
+      Self::Forbidden => Some("Forbidden"),
+      Self::Cancelled => Some("Cancelled"),
+      Self::Timeout => Some("Timeout"),
+      _ => None,
     }
   }
-  #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<InitRequest<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, InitRequest::VT_LANG,"lang");
-    flatbuffers::WIPOffset::new(o.value())
-  }
 }
-
-impl core::fmt::Debug for InitRequest<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("InitRequest");
-      ds.field("lang", &self.lang());
-      ds.finish()
+impl core::fmt::Debug for ErrorCode {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
   }
 }
-pub enum FileRequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct FileRequest<'a> {
-  pub _tab: flatbuffers::Table<'a>,
-}
-
-impl<'a> flatbuffers::Follow<'a> for FileRequest<'a> {
-  type Inner = FileRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for ErrorCode {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
   }
 }
 
-impl<'a> FileRequest<'a> {
-  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+impl flatbuffers::Push for ErrorCode {
+    type Output = ErrorCode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
 
+impl flatbuffers::EndianScalar for ErrorCode {
+  type Scalar = u8;
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    FileRequest { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args FileRequestArgs<'args>
-  ) -> flatbuffers::WIPOffset<FileRequest<'bldr>> {
-    let mut builder = FileRequestBuilder::new(_fbb);
-    if let Some(x) = args.path { builder.add_path(x); }
-    builder.finish()
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
   }
-
-
   #[inline]
-  pub fn path(&self) -> &'a str {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileRequest::VT_PATH, None).unwrap()}
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
   }
 }
 
-impl flatbuffers::Verifiable for FileRequest<'_> {
+impl<'a> flatbuffers::Verifiable for ErrorCode {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
-     .finish();
-    Ok(())
+    u8::run_verifier(v, pos)
   }
 }
-pub struct FileRequestArgs<'a> {
-    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+
+impl flatbuffers::SimpleToVerifyInSlice for ErrorCode {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_DIRECTORY_MODE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_DIRECTORY_MODE: u8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_DIRECTORY_MODE: [DirectoryMode; 2] = [
+  DirectoryMode::Reject,
+  DirectoryMode::List,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct DirectoryMode(pub u8);
+#[allow(non_upper_case_globals)]
+impl DirectoryMode {
+  pub const Reject: Self = Self(0);
+  pub const List: Self = Self(1);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Reject,
+    Self::List,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Reject => Some("Reject"),
+      Self::List => Some("List"),
+      _ => None,
+    }
+  }
 }
-impl<'a> Default for FileRequestArgs<'a> {
-  #[inline]
-  fn default() -> Self {
-    FileRequestArgs {
-      path: None, // required field
+impl core::fmt::Debug for DirectoryMode {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
     }
   }
 }
+impl<'a> flatbuffers::Follow<'a> for DirectoryMode {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
 
-pub struct FileRequestBuilder<'a: 'b, 'b> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+impl flatbuffers::Push for DirectoryMode {
+    type Output = DirectoryMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
 }
-impl<'a: 'b, 'b> FileRequestBuilder<'a, 'b> {
+
+impl flatbuffers::EndianScalar for DirectoryMode {
+  type Scalar = u8;
   #[inline]
-  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileRequest::VT_PATH, path);
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FileRequestBuilder<'a, 'b> {
-    let start = _fbb.start_table();
-    FileRequestBuilder {
-      fbb_: _fbb,
-      start_: start,
-    }
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
   }
+}
+
+impl<'a> flatbuffers::Verifiable for DirectoryMode {
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<FileRequest<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, FileRequest::VT_PATH,"path");
-    flatbuffers::WIPOffset::new(o.value())
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
   }
 }
 
-impl core::fmt::Debug for FileRequest<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("FileRequest");
-      ds.field("path", &self.path());
-      ds.finish()
+impl flatbuffers::SimpleToVerifyInSlice for DirectoryMode {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_NODE_OPERATION: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_NODE_OPERATION: u8 = 4;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_NODE_OPERATION: [NodeOperation; 5] = [
+  NodeOperation::Delete,
+  NodeOperation::Replace,
+  NodeOperation::Swap,
+  NodeOperation::MoveBefore,
+  NodeOperation::MoveAfter,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct NodeOperation(pub u8);
+#[allow(non_upper_case_globals)]
+impl NodeOperation {
+  pub const Delete: Self = Self(0);
+  pub const Replace: Self = Self(1);
+  pub const Swap: Self = Self(2);
+  pub const MoveBefore: Self = Self(3);
+  pub const MoveAfter: Self = Self(4);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 4;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Delete,
+    Self::Replace,
+    Self::Swap,
+    Self::MoveBefore,
+    Self::MoveAfter,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Delete => Some("Delete"),
+      Self::Replace => Some("Replace"),
+      Self::Swap => Some("Swap"),
+      Self::MoveBefore => Some("MoveBefore"),
+      Self::MoveAfter => Some("MoveAfter"),
+      _ => None,
+    }
   }
 }
-pub enum FileResponseOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct FileResponse<'a> {
-  pub _tab: flatbuffers::Table<'a>,
+impl core::fmt::Debug for NodeOperation {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
 }
-
-impl<'a> flatbuffers::Follow<'a> for FileResponse<'a> {
-  type Inner = FileResponse<'a>;
+impl<'a> flatbuffers::Follow<'a> for NodeOperation {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
   }
 }
 
-impl<'a> FileResponse<'a> {
-  pub const VT_TREE: flatbuffers::VOffsetT = 4;
+impl flatbuffers::Push for NodeOperation {
+    type Output = NodeOperation;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
 
+impl flatbuffers::EndianScalar for NodeOperation {
+  type Scalar = u8;
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    FileResponse { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args FileResponseArgs<'args>
-  ) -> flatbuffers::WIPOffset<FileResponse<'bldr>> {
-    let mut builder = FileResponseBuilder::new(_fbb);
-    if let Some(x) = args.tree { builder.add_tree(x); }
-    builder.finish()
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
   }
-
-
   #[inline]
-  pub fn tree(&self) -> Node<'a> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Node>>(FileResponse::VT_TREE, None).unwrap()}
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
   }
 }
 
-impl flatbuffers::Verifiable for FileResponse<'_> {
+impl<'a> flatbuffers::Verifiable for NodeOperation {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<Node>>("tree", Self::VT_TREE, true)?
-     .finish();
-    Ok(())
-  }
-}
-pub struct FileResponseArgs<'a> {
-    pub tree: Option<flatbuffers::WIPOffset<Node<'a>>>,
-}
-impl<'a> Default for FileResponseArgs<'a> {
-  #[inline]
-  fn default() -> Self {
-    FileResponseArgs {
-      tree: None, // required field
-    }
+    u8::run_verifier(v, pos)
   }
 }
 
-pub struct FileResponseBuilder<'a: 'b, 'b> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b> FileResponseBuilder<'a, 'b> {
+impl flatbuffers::SimpleToVerifyInSlice for NodeOperation {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_CAPTURE_QUANTIFIER: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_CAPTURE_QUANTIFIER: u8 = 4;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_CAPTURE_QUANTIFIER: [CaptureQuantifier; 5] = [
+  CaptureQuantifier::Zero,
+  CaptureQuantifier::ZeroOrOne,
+  CaptureQuantifier::ZeroOrMore,
+  CaptureQuantifier::One,
+  CaptureQuantifier::OneOrMore,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct CaptureQuantifier(pub u8);
+#[allow(non_upper_case_globals)]
+impl CaptureQuantifier {
+  pub const Zero: Self = Self(0);
+  pub const ZeroOrOne: Self = Self(1);
+  pub const ZeroOrMore: Self = Self(2);
+  pub const One: Self = Self(3);
+  pub const OneOrMore: Self = Self(4);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 4;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Zero,
+    Self::ZeroOrOne,
+    Self::ZeroOrMore,
+    Self::One,
+    Self::OneOrMore,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Zero => Some("Zero"),
+      Self::ZeroOrOne => Some("ZeroOrOne"),
+      Self::ZeroOrMore => Some("ZeroOrMore"),
+      Self::One => Some("One"),
+      Self::OneOrMore => Some("OneOrMore"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for CaptureQuantifier {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for CaptureQuantifier {
+  type Inner = Self;
   #[inline]
-  pub fn add_tree(&mut self, tree: flatbuffers::WIPOffset<Node<'b >>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Node>>(FileResponse::VT_TREE, tree);
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
   }
+}
+
+impl flatbuffers::Push for CaptureQuantifier {
+    type Output = CaptureQuantifier;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for CaptureQuantifier {
+  type Scalar = u8;
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FileResponseBuilder<'a, 'b> {
-    let start = _fbb.start_table();
-    FileResponseBuilder {
-      fbb_: _fbb,
-      start_: start,
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for CaptureQuantifier {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for CaptureQuantifier {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_TRAVERSAL_ORDER: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_TRAVERSAL_ORDER: u8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TRAVERSAL_ORDER: [TraversalOrder; 2] = [
+  TraversalOrder::Pre,
+  TraversalOrder::Post,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct TraversalOrder(pub u8);
+#[allow(non_upper_case_globals)]
+impl TraversalOrder {
+  pub const Pre: Self = Self(0);
+  pub const Post: Self = Self(1);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Pre,
+    Self::Post,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Pre => Some("Pre"),
+      Self::Post => Some("Post"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for TraversalOrder {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
     }
   }
+}
+impl<'a> flatbuffers::Follow<'a> for TraversalOrder {
+  type Inner = Self;
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<FileResponse<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, FileResponse::VT_TREE,"tree");
-    flatbuffers::WIPOffset::new(o.value())
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
   }
 }
 
-impl core::fmt::Debug for FileResponse<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("FileResponse");
-      ds.field("tree", &self.tree());
-      ds.finish()
+impl flatbuffers::Push for TraversalOrder {
+    type Output = TraversalOrder;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for TraversalOrder {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
   }
 }
-pub enum RequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
 
-pub struct Request<'a> {
-  pub _tab: flatbuffers::Table<'a>,
+impl<'a> flatbuffers::Verifiable for TraversalOrder {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
 }
 
-impl<'a> flatbuffers::Follow<'a> for Request<'a> {
-  type Inner = Request<'a>;
+impl flatbuffers::SimpleToVerifyInSlice for TraversalOrder {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_SYMBOL_KIND: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_SYMBOL_KIND: u8 = 13;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_SYMBOL_KIND: [SymbolKind; 14] = [
+  SymbolKind::Other,
+  SymbolKind::Module,
+  SymbolKind::Namespace,
+  SymbolKind::Class,
+  SymbolKind::Struct,
+  SymbolKind::Interface,
+  SymbolKind::Enum,
+  SymbolKind::EnumMember,
+  SymbolKind::Function,
+  SymbolKind::Method,
+  SymbolKind::Field,
+  SymbolKind::Property,
+  SymbolKind::Constant,
+  SymbolKind::Variable,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct SymbolKind(pub u8);
+#[allow(non_upper_case_globals)]
+impl SymbolKind {
+  pub const Other: Self = Self(0);
+  pub const Module: Self = Self(1);
+  pub const Namespace: Self = Self(2);
+  pub const Class: Self = Self(3);
+  pub const Struct: Self = Self(4);
+  pub const Interface: Self = Self(5);
+  pub const Enum: Self = Self(6);
+  pub const EnumMember: Self = Self(7);
+  pub const Function: Self = Self(8);
+  pub const Method: Self = Self(9);
+  pub const Field: Self = Self(10);
+  pub const Property: Self = Self(11);
+  pub const Constant: Self = Self(12);
+  pub const Variable: Self = Self(13);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 13;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Other,
+    Self::Module,
+    Self::Namespace,
+    Self::Class,
+    Self::Struct,
+    Self::Interface,
+    Self::Enum,
+    Self::EnumMember,
+    Self::Function,
+    Self::Method,
+    Self::Field,
+    Self::Property,
+    Self::Constant,
+    Self::Variable,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Other => Some("Other"),
+      Self::Module => Some("Module"),
+      Self::Namespace => Some("Namespace"),
+      Self::Class => Some("Class"),
+      Self::Struct => Some("Struct"),
+      Self::Interface => Some("Interface"),
+      Self::Enum => Some("Enum"),
+      Self::EnumMember => Some("EnumMember"),
+      Self::Function => Some("Function"),
+      Self::Method => Some("Method"),
+      Self::Field => Some("Field"),
+      Self::Property => Some("Property"),
+      Self::Constant => Some("Constant"),
+      Self::Variable => Some("Variable"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for SymbolKind {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for SymbolKind {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
   }
 }
 
-impl<'a> Request<'a> {
-  pub const VT_REQUEST_TYPE: flatbuffers::VOffsetT = 4;
-  pub const VT_REQUEST: flatbuffers::VOffsetT = 6;
+impl flatbuffers::Push for SymbolKind {
+    type Output = SymbolKind;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
 
+impl flatbuffers::EndianScalar for SymbolKind {
+  type Scalar = u8;
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Request { _tab: table }
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
   }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args RequestArgs
-  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
-    let mut builder = RequestBuilder::new(_fbb);
-    if let Some(x) = args.request { builder.add_request(x); }
-    builder.add_request_type(args.request_type);
-    builder.finish()
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for SymbolKind {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for SymbolKind {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_FOLD_KIND: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_FOLD_KIND: u8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_FOLD_KIND: [FoldKind; 4] = [
+  FoldKind::Other,
+  FoldKind::Comment,
+  FoldKind::Imports,
+  FoldKind::Region,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct FoldKind(pub u8);
+#[allow(non_upper_case_globals)]
+impl FoldKind {
+  pub const Other: Self = Self(0);
+  pub const Comment: Self = Self(1);
+  pub const Imports: Self = Self(2);
+  pub const Region: Self = Self(3);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Other,
+    Self::Comment,
+    Self::Imports,
+    Self::Region,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Other => Some("Other"),
+      Self::Comment => Some("Comment"),
+      Self::Imports => Some("Imports"),
+      Self::Region => Some("Region"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for FoldKind {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for FoldKind {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for FoldKind {
+    type Output = FoldKind;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for FoldKind {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for FoldKind {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for FoldKind {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_DEFINITION_CHANGE_KIND: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_DEFINITION_CHANGE_KIND: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_DEFINITION_CHANGE_KIND: [DefinitionChangeKind; 3] = [
+  DefinitionChangeKind::Added,
+  DefinitionChangeKind::Removed,
+  DefinitionChangeKind::Modified,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct DefinitionChangeKind(pub u8);
+#[allow(non_upper_case_globals)]
+impl DefinitionChangeKind {
+  pub const Added: Self = Self(0);
+  pub const Removed: Self = Self(1);
+  pub const Modified: Self = Self(2);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Added,
+    Self::Removed,
+    Self::Modified,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Added => Some("Added"),
+      Self::Removed => Some("Removed"),
+      Self::Modified => Some("Modified"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for DefinitionChangeKind {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for DefinitionChangeKind {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for DefinitionChangeKind {
+    type Output = DefinitionChangeKind;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for DefinitionChangeKind {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for DefinitionChangeKind {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for DefinitionChangeKind {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_INVARIANT_EXPECTATION: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_INVARIANT_EXPECTATION: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_INVARIANT_EXPECTATION: [InvariantExpectation; 3] = [
+  InvariantExpectation::Unchanged,
+  InvariantExpectation::Absent,
+  InvariantExpectation::Present,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct InvariantExpectation(pub u8);
+#[allow(non_upper_case_globals)]
+impl InvariantExpectation {
+  pub const Unchanged: Self = Self(0);
+  pub const Absent: Self = Self(1);
+  pub const Present: Self = Self(2);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Unchanged,
+    Self::Absent,
+    Self::Present,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Unchanged => Some("Unchanged"),
+      Self::Absent => Some("Absent"),
+      Self::Present => Some("Present"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for InvariantExpectation {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for InvariantExpectation {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for InvariantExpectation {
+    type Output = InvariantExpectation;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for InvariantExpectation {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for InvariantExpectation {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for InvariantExpectation {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_REQUEST_UNION: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_REQUEST_UNION: u8 = 33;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_REQUEST_UNION: [RequestUnion; 34] = [
+  RequestUnion::NONE,
+  RequestUnion::InitRequest,
+  RequestUnion::FileRequest,
+  RequestUnion::ReloadGrammarsRequest,
+  RequestUnion::DebugParseRequest,
+  RequestUnion::EditRequest,
+  RequestUnion::SnapshotRequest,
+  RequestUnion::RestoreRequest,
+  RequestUnion::ShutdownRequest,
+  RequestUnion::ChunkRequest,
+  RequestUnion::MetricsRequest,
+  RequestUnion::ComplexityRequest,
+  RequestUnion::ImportsRequest,
+  RequestUnion::TodosRequest,
+  RequestUnion::ClonesRequest,
+  RequestUnion::PluginRequest,
+  RequestUnion::QueryRequest,
+  RequestUnion::CancelRequest,
+  RequestUnion::ResumeRequest,
+  RequestUnion::ParseSnippetRequest,
+  RequestUnion::TransactionRequest,
+  RequestUnion::NodeEditRequest,
+  RequestUnion::ListLanguagesRequest,
+  RequestUnion::PlaygroundRequest,
+  RequestUnion::FormatRangesRequest,
+  RequestUnion::TraverseRequest,
+  RequestUnion::SelectionRangesRequest,
+  RequestUnion::SymbolsRequest,
+  RequestUnion::FoldingRangesRequest,
+  RequestUnion::HistoryRequest,
+  RequestUnion::BlameRequest,
+  RequestUnion::VerifyRewriteRequest,
+  RequestUnion::RunPluginRequest,
+  RequestUnion::ScriptRequest,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct RequestUnion(pub u8);
+#[allow(non_upper_case_globals)]
+impl RequestUnion {
+  pub const NONE: Self = Self(0);
+  pub const InitRequest: Self = Self(1);
+  pub const FileRequest: Self = Self(2);
+  pub const ReloadGrammarsRequest: Self = Self(3);
+  pub const DebugParseRequest: Self = Self(4);
+  pub const EditRequest: Self = Self(5);
+  pub const SnapshotRequest: Self = Self(6);
+  pub const RestoreRequest: Self = Self(7);
+  pub const ShutdownRequest: Self = Self(8);
+  pub const ChunkRequest: Self = Self(9);
+  pub const MetricsRequest: Self = Self(10);
+  pub const ComplexityRequest: Self = Self(11);
+  pub const ImportsRequest: Self = Self(12);
+  pub const TodosRequest: Self = Self(13);
+  pub const ClonesRequest: Self = Self(14);
+  pub const PluginRequest: Self = Self(15);
+  pub const QueryRequest: Self = Self(16);
+  pub const CancelRequest: Self = Self(17);
+  pub const ResumeRequest: Self = Self(18);
+  pub const ParseSnippetRequest: Self = Self(19);
+  pub const TransactionRequest: Self = Self(20);
+  pub const NodeEditRequest: Self = Self(21);
+  pub const ListLanguagesRequest: Self = Self(22);
+  pub const PlaygroundRequest: Self = Self(23);
+  pub const FormatRangesRequest: Self = Self(24);
+  pub const TraverseRequest: Self = Self(25);
+  pub const SelectionRangesRequest: Self = Self(26);
+  pub const SymbolsRequest: Self = Self(27);
+  pub const FoldingRangesRequest: Self = Self(28);
+  pub const HistoryRequest: Self = Self(29);
+  pub const BlameRequest: Self = Self(30);
+  pub const VerifyRewriteRequest: Self = Self(31);
+  pub const RunPluginRequest: Self = Self(32);
+  pub const ScriptRequest: Self = Self(33);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 33;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::InitRequest,
+    Self::FileRequest,
+    Self::ReloadGrammarsRequest,
+    Self::DebugParseRequest,
+    Self::EditRequest,
+    Self::SnapshotRequest,
+    Self::RestoreRequest,
+    Self::ShutdownRequest,
+    Self::ChunkRequest,
+    Self::MetricsRequest,
+    Self::ComplexityRequest,
+    Self::ImportsRequest,
+    Self::TodosRequest,
+    Self::ClonesRequest,
+    Self::PluginRequest,
+    Self::QueryRequest,
+    Self::CancelRequest,
+    Self::ResumeRequest,
+    Self::ParseSnippetRequest,
+    Self::TransactionRequest,
+    Self::NodeEditRequest,
+    Self::ListLanguagesRequest,
+    Self::PlaygroundRequest,
+    Self::FormatRangesRequest,
+    Self::TraverseRequest,
+    Self::SelectionRangesRequest,
+    Self::SymbolsRequest,
+    Self::FoldingRangesRequest,
+    Self::HistoryRequest,
+    Self::BlameRequest,
+    Self::VerifyRewriteRequest,
+    Self::RunPluginRequest,
+    Self::ScriptRequest,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::InitRequest => Some("InitRequest"),
+      Self::FileRequest => Some("FileRequest"),
+      Self::ReloadGrammarsRequest => Some("ReloadGrammarsRequest"),
+      Self::DebugParseRequest => Some("DebugParseRequest"),
+      Self::EditRequest => Some("EditRequest"),
+      Self::SnapshotRequest => Some("SnapshotRequest"),
+      Self::RestoreRequest => Some("RestoreRequest"),
+      Self::ShutdownRequest => Some("ShutdownRequest"),
+      Self::ChunkRequest => Some("ChunkRequest"),
+      Self::MetricsRequest => Some("MetricsRequest"),
+      Self::ComplexityRequest => Some("ComplexityRequest"),
+      Self::ImportsRequest => Some("ImportsRequest"),
+      Self::TodosRequest => Some("TodosRequest"),
+      Self::ClonesRequest => Some("ClonesRequest"),
+      Self::PluginRequest => Some("PluginRequest"),
+      Self::QueryRequest => Some("QueryRequest"),
+      Self::CancelRequest => Some("CancelRequest"),
+      Self::ResumeRequest => Some("ResumeRequest"),
+      Self::ParseSnippetRequest => Some("ParseSnippetRequest"),
+      Self::TransactionRequest => Some("TransactionRequest"),
+      Self::NodeEditRequest => Some("NodeEditRequest"),
+      Self::ListLanguagesRequest => Some("ListLanguagesRequest"),
+      Self::PlaygroundRequest => Some("PlaygroundRequest"),
+      Self::FormatRangesRequest => Some("FormatRangesRequest"),
+      Self::TraverseRequest => Some("TraverseRequest"),
+      Self::SelectionRangesRequest => Some("SelectionRangesRequest"),
+      Self::SymbolsRequest => Some("SymbolsRequest"),
+      Self::FoldingRangesRequest => Some("FoldingRangesRequest"),
+      Self::HistoryRequest => Some("HistoryRequest"),
+      Self::BlameRequest => Some("BlameRequest"),
+      Self::VerifyRewriteRequest => Some("VerifyRewriteRequest"),
+      Self::RunPluginRequest => Some("RunPluginRequest"),
+      Self::ScriptRequest => Some("ScriptRequest"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for RequestUnion {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for RequestUnion {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for RequestUnion {
+    type Output = RequestUnion;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for RequestUnion {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for RequestUnion {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for RequestUnion {}
+pub struct RequestUnionUnionTableOffset {}
+
+// struct Location, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Location(pub [u8; 8]);
+impl Default for Location { 
+  fn default() -> Self { 
+    Self([0; 8])
+  }
+}
+impl core::fmt::Debug for Location {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Location")
+      .field("start_byte", &self.start_byte())
+      .field("end_byte", &self.end_byte())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Location {}
+impl<'a> flatbuffers::Follow<'a> for Location {
+  type Inner = &'a Location;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Location>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Location {
+  type Inner = &'a Location;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Location>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Location {
+    type Output = Location;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const Location as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Location {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Location {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    start_byte: u32,
+    end_byte: u32,
+  ) -> Self {
+    let mut s = Self([0; 8]);
+    s.set_start_byte(start_byte);
+    s.set_end_byte(end_byte);
+    s
+  }
+
+  pub fn start_byte(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_start_byte(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn end_byte(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_end_byte(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+// struct Point, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Point(pub [u8; 8]);
+impl Default for Point { 
+  fn default() -> Self { 
+    Self([0; 8])
+  }
+}
+impl core::fmt::Debug for Point {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Point")
+      .field("row", &self.row())
+      .field("column", &self.column())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Point {}
+impl<'a> flatbuffers::Follow<'a> for Point {
+  type Inner = &'a Point;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Point>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Point {
+  type Inner = &'a Point;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Point>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Point {
+    type Output = Point;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const Point as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Point {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Point {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    row: u32,
+    column: u32,
+  ) -> Self {
+    let mut s = Self([0; 8]);
+    s.set_row(row);
+    s.set_column(column);
+    s
+  }
+
+  pub fn row(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_row(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn column(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_column(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+// struct Offsets, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Offsets(pub [u8; 32]);
+impl Default for Offsets { 
+  fn default() -> Self { 
+    Self([0; 32])
+  }
+}
+impl core::fmt::Debug for Offsets {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Offsets")
+      .field("utf8_start", &self.utf8_start())
+      .field("utf8_end", &self.utf8_end())
+      .field("utf16_start", &self.utf16_start())
+      .field("utf16_end", &self.utf16_end())
+      .field("utf8_start_column", &self.utf8_start_column())
+      .field("utf8_end_column", &self.utf8_end_column())
+      .field("utf16_start_column", &self.utf16_start_column())
+      .field("utf16_end_column", &self.utf16_end_column())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Offsets {}
+impl<'a> flatbuffers::Follow<'a> for Offsets {
+  type Inner = &'a Offsets;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Offsets>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Offsets {
+  type Inner = &'a Offsets;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Offsets>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Offsets {
+    type Output = Offsets;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const Offsets as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Offsets {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Offsets {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    utf8_start: u32,
+    utf8_end: u32,
+    utf16_start: u32,
+    utf16_end: u32,
+    utf8_start_column: u32,
+    utf8_end_column: u32,
+    utf16_start_column: u32,
+    utf16_end_column: u32,
+  ) -> Self {
+    let mut s = Self([0; 32]);
+    s.set_utf8_start(utf8_start);
+    s.set_utf8_end(utf8_end);
+    s.set_utf16_start(utf16_start);
+    s.set_utf16_end(utf16_end);
+    s.set_utf8_start_column(utf8_start_column);
+    s.set_utf8_end_column(utf8_end_column);
+    s.set_utf16_start_column(utf16_start_column);
+    s.set_utf16_end_column(utf16_end_column);
+    s
+  }
+
+  pub fn utf8_start(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_utf8_start(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn utf8_end(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_utf8_end(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn utf16_start(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_utf16_start(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn utf16_end(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[12..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_utf16_end(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[12..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn utf8_start_column(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[16..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_utf8_start_column(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[16..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn utf8_end_column(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[20..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_utf8_end_column(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[20..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn utf16_start_column(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[24..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_utf16_start_column(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[24..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn utf16_end_column(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[28..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_utf16_end_column(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[28..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+pub enum ErrorResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ErrorResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ErrorResponse<'a> {
+  type Inner = ErrorResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ErrorResponse<'a> {
+  pub const VT_CODE: flatbuffers::VOffsetT = 4;
+  pub const VT_MESSAGE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ErrorResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ErrorResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<ErrorResponse<'bldr>> {
+    let mut builder = ErrorResponseBuilder::new(_fbb);
+    if let Some(x) = args.message { builder.add_message(x); }
+    builder.add_code(args.code);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn code(&self) -> ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ErrorCode>(ErrorResponse::VT_CODE, Some(ErrorCode::Internal)).unwrap()}
+  }
+  #[inline]
+  pub fn message(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ErrorResponse::VT_MESSAGE, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ErrorResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<ErrorCode>("code", Self::VT_CODE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ErrorResponseArgs<'a> {
+    pub code: ErrorCode,
+    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ErrorResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ErrorResponseArgs {
+      code: ErrorCode::Internal,
+      message: None, // required field
+    }
+  }
+}
+
+pub struct ErrorResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ErrorResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_code(&mut self, code: ErrorCode) {
+    self.fbb_.push_slot::<ErrorCode>(ErrorResponse::VT_CODE, code, ErrorCode::Internal);
+  }
+  #[inline]
+  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ErrorResponse::VT_MESSAGE, message);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ErrorResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ErrorResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ErrorResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ErrorResponse::VT_MESSAGE,"message");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ErrorResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ErrorResponse");
+      ds.field("code", &self.code());
+      ds.field("message", &self.message());
+      ds.finish()
+  }
+}
+pub enum InitRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct InitRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for InitRequest<'a> {
+  type Inner = InitRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> InitRequest<'a> {
+  pub const VT_LANG: flatbuffers::VOffsetT = 4;
+  pub const VT_PLAIN_TEXT_FALLBACK: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    InitRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args InitRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<InitRequest<'bldr>> {
+    let mut builder = InitRequestBuilder::new(_fbb);
+    if let Some(x) = args.lang { builder.add_lang(x); }
+    builder.add_plain_text_fallback(args.plain_text_fallback);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn lang(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InitRequest::VT_LANG, None).unwrap()}
+  }
+  #[inline]
+  pub fn plain_text_fallback(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(InitRequest::VT_PLAIN_TEXT_FALLBACK, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for InitRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("lang", Self::VT_LANG, true)?
+     .visit_field::<bool>("plain_text_fallback", Self::VT_PLAIN_TEXT_FALLBACK, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct InitRequestArgs<'a> {
+    pub lang: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub plain_text_fallback: bool,
+}
+impl<'a> Default for InitRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    InitRequestArgs {
+      lang: None, // required field
+      plain_text_fallback: false,
+    }
+  }
+}
+
+pub struct InitRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> InitRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_lang(&mut self, lang: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InitRequest::VT_LANG, lang);
+  }
+  #[inline]
+  pub fn add_plain_text_fallback(&mut self, plain_text_fallback: bool) {
+    self.fbb_.push_slot::<bool>(InitRequest::VT_PLAIN_TEXT_FALLBACK, plain_text_fallback, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> InitRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    InitRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<InitRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, InitRequest::VT_LANG,"lang");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for InitRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("InitRequest");
+      ds.field("lang", &self.lang());
+      ds.field("plain_text_fallback", &self.plain_text_fallback());
+      ds.finish()
+  }
+}
+pub enum LanguageInfoOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct LanguageInfo<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LanguageInfo<'a> {
+  type Inner = LanguageInfo<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> LanguageInfo<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_ABI_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_GRAMMAR_VERSION: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    LanguageInfo { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args LanguageInfoArgs<'args>
+  ) -> flatbuffers::WIPOffset<LanguageInfo<'bldr>> {
+    let mut builder = LanguageInfoBuilder::new(_fbb);
+    if let Some(x) = args.grammar_version { builder.add_grammar_version(x); }
+    builder.add_abi_version(args.abi_version);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LanguageInfo::VT_NAME, None).unwrap()}
+  }
+  #[inline]
+  pub fn abi_version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(LanguageInfo::VT_ABI_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn grammar_version(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LanguageInfo::VT_GRAMMAR_VERSION, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for LanguageInfo<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+     .visit_field::<u32>("abi_version", Self::VT_ABI_VERSION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("grammar_version", Self::VT_GRAMMAR_VERSION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LanguageInfoArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub abi_version: u32,
+    pub grammar_version: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for LanguageInfoArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LanguageInfoArgs {
+      name: None, // required field
+      abi_version: 0,
+      grammar_version: None,
+    }
+  }
+}
+
+pub struct LanguageInfoBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> LanguageInfoBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LanguageInfo::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_abi_version(&mut self, abi_version: u32) {
+    self.fbb_.push_slot::<u32>(LanguageInfo::VT_ABI_VERSION, abi_version, 0);
+  }
+  #[inline]
+  pub fn add_grammar_version(&mut self, grammar_version: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LanguageInfo::VT_GRAMMAR_VERSION, grammar_version);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LanguageInfoBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    LanguageInfoBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<LanguageInfo<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, LanguageInfo::VT_NAME,"name");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for LanguageInfo<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("LanguageInfo");
+      ds.field("name", &self.name());
+      ds.field("abi_version", &self.abi_version());
+      ds.field("grammar_version", &self.grammar_version());
+      ds.finish()
+  }
+}
+pub enum InitResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct InitResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for InitResponse<'a> {
+  type Inner = InitResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> InitResponse<'a> {
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    InitResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args InitResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<InitResponse<'bldr>> {
+    let mut builder = InitResponseBuilder::new(_fbb);
+    if let Some(x) = args.language { builder.add_language(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn language(&self) -> Option<LanguageInfo<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<LanguageInfo>>(InitResponse::VT_LANGUAGE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for InitResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<LanguageInfo>>("language", Self::VT_LANGUAGE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct InitResponseArgs<'a> {
+    pub language: Option<flatbuffers::WIPOffset<LanguageInfo<'a>>>,
+}
+impl<'a> Default for InitResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    InitResponseArgs {
+      language: None,
+    }
+  }
+}
+
+pub struct InitResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> InitResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_language(&mut self, language: flatbuffers::WIPOffset<LanguageInfo<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<LanguageInfo>>(InitResponse::VT_LANGUAGE, language);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> InitResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    InitResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<InitResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for InitResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("InitResponse");
+      ds.field("language", &self.language());
+      ds.finish()
+  }
+}
+pub enum ListLanguagesRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ListLanguagesRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ListLanguagesRequest<'a> {
+  type Inner = ListLanguagesRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ListLanguagesRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ListLanguagesRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args ListLanguagesRequestArgs
+  ) -> flatbuffers::WIPOffset<ListLanguagesRequest<'bldr>> {
+    let mut builder = ListLanguagesRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for ListLanguagesRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ListLanguagesRequestArgs {
+}
+impl<'a> Default for ListLanguagesRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    ListLanguagesRequestArgs {
+    }
+  }
+}
+
+pub struct ListLanguagesRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ListLanguagesRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ListLanguagesRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ListLanguagesRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ListLanguagesRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ListLanguagesRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ListLanguagesRequest");
+      ds.finish()
+  }
+}
+pub enum ListLanguagesResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ListLanguagesResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ListLanguagesResponse<'a> {
+  type Inner = ListLanguagesResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ListLanguagesResponse<'a> {
+  pub const VT_LANGUAGES: flatbuffers::VOffsetT = 4;
+  pub const VT_MIN_ABI_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_MAX_ABI_VERSION: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ListLanguagesResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ListLanguagesResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<ListLanguagesResponse<'bldr>> {
+    let mut builder = ListLanguagesResponseBuilder::new(_fbb);
+    builder.add_max_abi_version(args.max_abi_version);
+    builder.add_min_abi_version(args.min_abi_version);
+    if let Some(x) = args.languages { builder.add_languages(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn languages(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LanguageInfo<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LanguageInfo>>>>(ListLanguagesResponse::VT_LANGUAGES, None).unwrap()}
+  }
+  #[inline]
+  pub fn min_abi_version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ListLanguagesResponse::VT_MIN_ABI_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn max_abi_version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ListLanguagesResponse::VT_MAX_ABI_VERSION, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ListLanguagesResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LanguageInfo>>>>("languages", Self::VT_LANGUAGES, true)?
+     .visit_field::<u32>("min_abi_version", Self::VT_MIN_ABI_VERSION, false)?
+     .visit_field::<u32>("max_abi_version", Self::VT_MAX_ABI_VERSION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ListLanguagesResponseArgs<'a> {
+    pub languages: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LanguageInfo<'a>>>>>,
+    pub min_abi_version: u32,
+    pub max_abi_version: u32,
+}
+impl<'a> Default for ListLanguagesResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ListLanguagesResponseArgs {
+      languages: None, // required field
+      min_abi_version: 0,
+      max_abi_version: 0,
+    }
+  }
+}
+
+pub struct ListLanguagesResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ListLanguagesResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_languages(&mut self, languages: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LanguageInfo<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ListLanguagesResponse::VT_LANGUAGES, languages);
+  }
+  #[inline]
+  pub fn add_min_abi_version(&mut self, min_abi_version: u32) {
+    self.fbb_.push_slot::<u32>(ListLanguagesResponse::VT_MIN_ABI_VERSION, min_abi_version, 0);
+  }
+  #[inline]
+  pub fn add_max_abi_version(&mut self, max_abi_version: u32) {
+    self.fbb_.push_slot::<u32>(ListLanguagesResponse::VT_MAX_ABI_VERSION, max_abi_version, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ListLanguagesResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ListLanguagesResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ListLanguagesResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ListLanguagesResponse::VT_LANGUAGES,"languages");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ListLanguagesResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ListLanguagesResponse");
+      ds.field("languages", &self.languages());
+      ds.field("min_abi_version", &self.min_abi_version());
+      ds.field("max_abi_version", &self.max_abi_version());
+      ds.finish()
+  }
+}
+pub enum WorkspaceFilterOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct WorkspaceFilter<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for WorkspaceFilter<'a> {
+  type Inner = WorkspaceFilter<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> WorkspaceFilter<'a> {
+  pub const VT_INCLUDE: flatbuffers::VOffsetT = 4;
+  pub const VT_EXCLUDE: flatbuffers::VOffsetT = 6;
+  pub const VT_INCLUDE_IGNORED: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    WorkspaceFilter { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args WorkspaceFilterArgs<'args>
+  ) -> flatbuffers::WIPOffset<WorkspaceFilter<'bldr>> {
+    let mut builder = WorkspaceFilterBuilder::new(_fbb);
+    if let Some(x) = args.exclude { builder.add_exclude(x); }
+    if let Some(x) = args.include { builder.add_include(x); }
+    builder.add_include_ignored(args.include_ignored);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn include(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(WorkspaceFilter::VT_INCLUDE, None)}
+  }
+  #[inline]
+  pub fn exclude(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(WorkspaceFilter::VT_EXCLUDE, None)}
+  }
+  #[inline]
+  pub fn include_ignored(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(WorkspaceFilter::VT_INCLUDE_IGNORED, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for WorkspaceFilter<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("include", Self::VT_INCLUDE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("exclude", Self::VT_EXCLUDE, false)?
+     .visit_field::<bool>("include_ignored", Self::VT_INCLUDE_IGNORED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct WorkspaceFilterArgs<'a> {
+    pub include: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub exclude: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub include_ignored: bool,
+}
+impl<'a> Default for WorkspaceFilterArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    WorkspaceFilterArgs {
+      include: None,
+      exclude: None,
+      include_ignored: false,
+    }
+  }
+}
+
+pub struct WorkspaceFilterBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> WorkspaceFilterBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_include(&mut self, include: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(WorkspaceFilter::VT_INCLUDE, include);
+  }
+  #[inline]
+  pub fn add_exclude(&mut self, exclude: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(WorkspaceFilter::VT_EXCLUDE, exclude);
+  }
+  #[inline]
+  pub fn add_include_ignored(&mut self, include_ignored: bool) {
+    self.fbb_.push_slot::<bool>(WorkspaceFilter::VT_INCLUDE_IGNORED, include_ignored, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> WorkspaceFilterBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    WorkspaceFilterBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<WorkspaceFilter<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for WorkspaceFilter<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("WorkspaceFilter");
+      ds.field("include", &self.include());
+      ds.field("exclude", &self.exclude());
+      ds.field("include_ignored", &self.include_ignored());
+      ds.finish()
+  }
+}
+pub enum FileRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FileRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FileRequest<'a> {
+  type Inner = FileRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FileRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_IF_HASH: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT: flatbuffers::VOffsetT = 8;
+  pub const VT_STRUCTURAL_HASHES: flatbuffers::VOffsetT = 10;
+  pub const VT_PROFILE: flatbuffers::VOffsetT = 12;
+  pub const VT_FORCE: flatbuffers::VOffsetT = 14;
+  pub const VT_NO_CACHE: flatbuffers::VOffsetT = 16;
+  pub const VT_DIRECTORY_MODE: flatbuffers::VOffsetT = 18;
+  pub const VT_DIRECTORY_DEPTH: flatbuffers::VOffsetT = 20;
+  pub const VT_DIRECTORY_FILTER: flatbuffers::VOffsetT = 22;
+  pub const VT_DUAL_OFFSETS: flatbuffers::VOffsetT = 24;
+  pub const VT_NORMALIZE_LINE_ENDINGS: flatbuffers::VOffsetT = 26;
+  pub const VT_LANG: flatbuffers::VOffsetT = 28;
+  pub const VT_TEXT_INCLUSION: flatbuffers::VOffsetT = 30;
+  pub const VT_TEXT_MAX_BYTES: flatbuffers::VOffsetT = 32;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FileRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FileRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<FileRequest<'bldr>> {
+    let mut builder = FileRequestBuilder::new(_fbb);
+    if let Some(x) = args.if_hash { builder.add_if_hash(x); }
+    builder.add_text_max_bytes(args.text_max_bytes);
+    if let Some(x) = args.lang { builder.add_lang(x); }
+    if let Some(x) = args.directory_filter { builder.add_directory_filter(x); }
+    builder.add_directory_depth(args.directory_depth);
+    if let Some(x) = args.content { builder.add_content(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.add_text_inclusion(args.text_inclusion);
+    builder.add_normalize_line_endings(args.normalize_line_endings);
+    builder.add_dual_offsets(args.dual_offsets);
+    builder.add_directory_mode(args.directory_mode);
+    builder.add_no_cache(args.no_cache);
+    builder.add_force(args.force);
+    builder.add_profile(args.profile);
+    builder.add_structural_hashes(args.structural_hashes);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn if_hash(&self) -> Option<u64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(FileRequest::VT_IF_HASH, None)}
+  }
+  #[inline]
+  pub fn content(&self) -> Option<flatbuffers::Vector<'a, u16>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(FileRequest::VT_CONTENT, None)}
+  }
+  #[inline]
+  pub fn structural_hashes(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(FileRequest::VT_STRUCTURAL_HASHES, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn profile(&self) -> SerializationProfile {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SerializationProfile>(FileRequest::VT_PROFILE, Some(SerializationProfile::Default)).unwrap()}
+  }
+  #[inline]
+  pub fn force(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(FileRequest::VT_FORCE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn no_cache(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(FileRequest::VT_NO_CACHE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn directory_mode(&self) -> DirectoryMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<DirectoryMode>(FileRequest::VT_DIRECTORY_MODE, Some(DirectoryMode::Reject)).unwrap()}
+  }
+  #[inline]
+  pub fn directory_depth(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FileRequest::VT_DIRECTORY_DEPTH, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn directory_filter(&self) -> Option<WorkspaceFilter<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<WorkspaceFilter>>(FileRequest::VT_DIRECTORY_FILTER, None)}
+  }
+  #[inline]
+  pub fn dual_offsets(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(FileRequest::VT_DUAL_OFFSETS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn normalize_line_endings(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(FileRequest::VT_NORMALIZE_LINE_ENDINGS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn lang(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileRequest::VT_LANG, None)}
+  }
+  #[inline]
+  pub fn text_inclusion(&self) -> TextInclusion {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TextInclusion>(FileRequest::VT_TEXT_INCLUSION, Some(TextInclusion::Profile)).unwrap()}
+  }
+  #[inline]
+  pub fn text_max_bytes(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FileRequest::VT_TEXT_MAX_BYTES, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FileRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<u64>("if_hash", Self::VT_IF_HASH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("content", Self::VT_CONTENT, false)?
+     .visit_field::<bool>("structural_hashes", Self::VT_STRUCTURAL_HASHES, false)?
+     .visit_field::<SerializationProfile>("profile", Self::VT_PROFILE, false)?
+     .visit_field::<bool>("force", Self::VT_FORCE, false)?
+     .visit_field::<bool>("no_cache", Self::VT_NO_CACHE, false)?
+     .visit_field::<DirectoryMode>("directory_mode", Self::VT_DIRECTORY_MODE, false)?
+     .visit_field::<u32>("directory_depth", Self::VT_DIRECTORY_DEPTH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<WorkspaceFilter>>("directory_filter", Self::VT_DIRECTORY_FILTER, false)?
+     .visit_field::<bool>("dual_offsets", Self::VT_DUAL_OFFSETS, false)?
+     .visit_field::<bool>("normalize_line_endings", Self::VT_NORMALIZE_LINE_ENDINGS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("lang", Self::VT_LANG, false)?
+     .visit_field::<TextInclusion>("text_inclusion", Self::VT_TEXT_INCLUSION, false)?
+     .visit_field::<u32>("text_max_bytes", Self::VT_TEXT_MAX_BYTES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FileRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub if_hash: Option<u64>,
+    pub content: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+    pub structural_hashes: bool,
+    pub profile: SerializationProfile,
+    pub force: bool,
+    pub no_cache: bool,
+    pub directory_mode: DirectoryMode,
+    pub directory_depth: u32,
+    pub directory_filter: Option<flatbuffers::WIPOffset<WorkspaceFilter<'a>>>,
+    pub dual_offsets: bool,
+    pub normalize_line_endings: bool,
+    pub lang: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub text_inclusion: TextInclusion,
+    pub text_max_bytes: u32,
+}
+impl<'a> Default for FileRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FileRequestArgs {
+      path: None, // required field
+      if_hash: None,
+      content: None,
+      structural_hashes: false,
+      profile: SerializationProfile::Default,
+      force: false,
+      no_cache: false,
+      directory_mode: DirectoryMode::Reject,
+      directory_depth: 0,
+      directory_filter: None,
+      dual_offsets: false,
+      normalize_line_endings: false,
+      lang: None,
+      text_inclusion: TextInclusion::Profile,
+      text_max_bytes: 0,
+    }
+  }
+}
+
+pub struct FileRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FileRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_if_hash(&mut self, if_hash: u64) {
+    self.fbb_.push_slot_always::<u64>(FileRequest::VT_IF_HASH, if_hash);
+  }
+  #[inline]
+  pub fn add_content(&mut self, content: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileRequest::VT_CONTENT, content);
+  }
+  #[inline]
+  pub fn add_structural_hashes(&mut self, structural_hashes: bool) {
+    self.fbb_.push_slot::<bool>(FileRequest::VT_STRUCTURAL_HASHES, structural_hashes, false);
+  }
+  #[inline]
+  pub fn add_profile(&mut self, profile: SerializationProfile) {
+    self.fbb_.push_slot::<SerializationProfile>(FileRequest::VT_PROFILE, profile, SerializationProfile::Default);
+  }
+  #[inline]
+  pub fn add_force(&mut self, force: bool) {
+    self.fbb_.push_slot::<bool>(FileRequest::VT_FORCE, force, false);
+  }
+  #[inline]
+  pub fn add_no_cache(&mut self, no_cache: bool) {
+    self.fbb_.push_slot::<bool>(FileRequest::VT_NO_CACHE, no_cache, false);
+  }
+  #[inline]
+  pub fn add_directory_mode(&mut self, directory_mode: DirectoryMode) {
+    self.fbb_.push_slot::<DirectoryMode>(FileRequest::VT_DIRECTORY_MODE, directory_mode, DirectoryMode::Reject);
+  }
+  #[inline]
+  pub fn add_directory_depth(&mut self, directory_depth: u32) {
+    self.fbb_.push_slot::<u32>(FileRequest::VT_DIRECTORY_DEPTH, directory_depth, 0);
+  }
+  #[inline]
+  pub fn add_directory_filter(&mut self, directory_filter: flatbuffers::WIPOffset<WorkspaceFilter<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<WorkspaceFilter>>(FileRequest::VT_DIRECTORY_FILTER, directory_filter);
+  }
+  #[inline]
+  pub fn add_dual_offsets(&mut self, dual_offsets: bool) {
+    self.fbb_.push_slot::<bool>(FileRequest::VT_DUAL_OFFSETS, dual_offsets, false);
+  }
+  #[inline]
+  pub fn add_normalize_line_endings(&mut self, normalize_line_endings: bool) {
+    self.fbb_.push_slot::<bool>(FileRequest::VT_NORMALIZE_LINE_ENDINGS, normalize_line_endings, false);
+  }
+  #[inline]
+  pub fn add_lang(&mut self, lang: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileRequest::VT_LANG, lang);
+  }
+  #[inline]
+  pub fn add_text_inclusion(&mut self, text_inclusion: TextInclusion) {
+    self.fbb_.push_slot::<TextInclusion>(FileRequest::VT_TEXT_INCLUSION, text_inclusion, TextInclusion::Profile);
+  }
+  #[inline]
+  pub fn add_text_max_bytes(&mut self, text_max_bytes: u32) {
+    self.fbb_.push_slot::<u32>(FileRequest::VT_TEXT_MAX_BYTES, text_max_bytes, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FileRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FileRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FileRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, FileRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FileRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FileRequest");
+      ds.field("path", &self.path());
+      ds.field("if_hash", &self.if_hash());
+      ds.field("content", &self.content());
+      ds.field("structural_hashes", &self.structural_hashes());
+      ds.field("profile", &self.profile());
+      ds.field("force", &self.force());
+      ds.field("no_cache", &self.no_cache());
+      ds.field("directory_mode", &self.directory_mode());
+      ds.field("directory_depth", &self.directory_depth());
+      ds.field("directory_filter", &self.directory_filter());
+      ds.field("dual_offsets", &self.dual_offsets());
+      ds.field("normalize_line_endings", &self.normalize_line_endings());
+      ds.field("lang", &self.lang());
+      ds.field("text_inclusion", &self.text_inclusion());
+      ds.field("text_max_bytes", &self.text_max_bytes());
+      ds.finish()
+  }
+}
+pub enum ParseStatsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ParseStats<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ParseStats<'a> {
+  type Inner = ParseStats<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ParseStats<'a> {
+  pub const VT_PARSE_DURATION_US: flatbuffers::VOffsetT = 4;
+  pub const VT_REUSED_OLD_TREE: flatbuffers::VOffsetT = 6;
+  pub const VT_NODE_COUNT: flatbuffers::VOffsetT = 8;
+  pub const VT_ERROR_COUNT: flatbuffers::VOffsetT = 10;
+  pub const VT_SERIALIZED_SIZE: flatbuffers::VOffsetT = 12;
+  pub const VT_CACHED_TREE: flatbuffers::VOffsetT = 14;
+  pub const VT_CHANGED_RANGES: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ParseStats { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ParseStatsArgs<'args>
+  ) -> flatbuffers::WIPOffset<ParseStats<'bldr>> {
+    let mut builder = ParseStatsBuilder::new(_fbb);
+    builder.add_parse_duration_us(args.parse_duration_us);
+    if let Some(x) = args.changed_ranges { builder.add_changed_ranges(x); }
+    builder.add_serialized_size(args.serialized_size);
+    builder.add_error_count(args.error_count);
+    builder.add_node_count(args.node_count);
+    builder.add_cached_tree(args.cached_tree);
+    builder.add_reused_old_tree(args.reused_old_tree);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn parse_duration_us(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(ParseStats::VT_PARSE_DURATION_US, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn reused_old_tree(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ParseStats::VT_REUSED_OLD_TREE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn node_count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ParseStats::VT_NODE_COUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn error_count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ParseStats::VT_ERROR_COUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn serialized_size(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ParseStats::VT_SERIALIZED_SIZE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn cached_tree(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ParseStats::VT_CACHED_TREE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn changed_ranges(&self) -> Option<flatbuffers::Vector<'a, Location>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Location>>>(ParseStats::VT_CHANGED_RANGES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ParseStats<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("parse_duration_us", Self::VT_PARSE_DURATION_US, false)?
+     .visit_field::<bool>("reused_old_tree", Self::VT_REUSED_OLD_TREE, false)?
+     .visit_field::<u32>("node_count", Self::VT_NODE_COUNT, false)?
+     .visit_field::<u32>("error_count", Self::VT_ERROR_COUNT, false)?
+     .visit_field::<u32>("serialized_size", Self::VT_SERIALIZED_SIZE, false)?
+     .visit_field::<bool>("cached_tree", Self::VT_CACHED_TREE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Location>>>("changed_ranges", Self::VT_CHANGED_RANGES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ParseStatsArgs<'a> {
+    pub parse_duration_us: u64,
+    pub reused_old_tree: bool,
+    pub node_count: u32,
+    pub error_count: u32,
+    pub serialized_size: u32,
+    pub cached_tree: bool,
+    pub changed_ranges: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Location>>>,
+}
+impl<'a> Default for ParseStatsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ParseStatsArgs {
+      parse_duration_us: 0,
+      reused_old_tree: false,
+      node_count: 0,
+      error_count: 0,
+      serialized_size: 0,
+      cached_tree: false,
+      changed_ranges: None,
+    }
+  }
+}
+
+pub struct ParseStatsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ParseStatsBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_parse_duration_us(&mut self, parse_duration_us: u64) {
+    self.fbb_.push_slot::<u64>(ParseStats::VT_PARSE_DURATION_US, parse_duration_us, 0);
+  }
+  #[inline]
+  pub fn add_reused_old_tree(&mut self, reused_old_tree: bool) {
+    self.fbb_.push_slot::<bool>(ParseStats::VT_REUSED_OLD_TREE, reused_old_tree, false);
+  }
+  #[inline]
+  pub fn add_node_count(&mut self, node_count: u32) {
+    self.fbb_.push_slot::<u32>(ParseStats::VT_NODE_COUNT, node_count, 0);
+  }
+  #[inline]
+  pub fn add_error_count(&mut self, error_count: u32) {
+    self.fbb_.push_slot::<u32>(ParseStats::VT_ERROR_COUNT, error_count, 0);
+  }
+  #[inline]
+  pub fn add_serialized_size(&mut self, serialized_size: u32) {
+    self.fbb_.push_slot::<u32>(ParseStats::VT_SERIALIZED_SIZE, serialized_size, 0);
+  }
+  #[inline]
+  pub fn add_cached_tree(&mut self, cached_tree: bool) {
+    self.fbb_.push_slot::<bool>(ParseStats::VT_CACHED_TREE, cached_tree, false);
+  }
+  #[inline]
+  pub fn add_changed_ranges(&mut self, changed_ranges: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Location>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ParseStats::VT_CHANGED_RANGES, changed_ranges);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ParseStatsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ParseStatsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ParseStats<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ParseStats<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ParseStats");
+      ds.field("parse_duration_us", &self.parse_duration_us());
+      ds.field("reused_old_tree", &self.reused_old_tree());
+      ds.field("node_count", &self.node_count());
+      ds.field("error_count", &self.error_count());
+      ds.field("serialized_size", &self.serialized_size());
+      ds.field("cached_tree", &self.cached_tree());
+      ds.field("changed_ranges", &self.changed_ranges());
+      ds.finish()
+  }
+}
+pub enum FileResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FileResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FileResponse<'a> {
+  type Inner = FileResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FileResponse<'a> {
+  pub const VT_TREE: flatbuffers::VOffsetT = 4;
+  pub const VT_STATS: flatbuffers::VOffsetT = 6;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 8;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 10;
+  pub const VT_MTIME_MS: flatbuffers::VOffsetT = 12;
+  pub const VT_LINE_ENDING: flatbuffers::VOffsetT = 14;
+  pub const VT_CHECKSUM: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FileResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FileResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<FileResponse<'bldr>> {
+    let mut builder = FileResponseBuilder::new(_fbb);
+    builder.add_checksum(args.checksum);
+    builder.add_mtime_ms(args.mtime_ms);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.stats { builder.add_stats(x); }
+    if let Some(x) = args.tree { builder.add_tree(x); }
+    builder.add_line_ending(args.line_ending);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn tree(&self) -> Node<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Node>>(FileResponse::VT_TREE, None).unwrap()}
+  }
+  #[inline]
+  pub fn stats(&self) -> Option<ParseStats<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<ParseStats>>(FileResponse::VT_STATS, None)}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FileResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(FileResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn mtime_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(FileResponse::VT_MTIME_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn line_ending(&self) -> LineEnding {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<LineEnding>(FileResponse::VT_LINE_ENDING, Some(LineEnding::Lf)).unwrap()}
+  }
+  #[inline]
+  pub fn checksum(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(FileResponse::VT_CHECKSUM, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FileResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Node>>("tree", Self::VT_TREE, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<ParseStats>>("stats", Self::VT_STATS, false)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .visit_field::<u64>("mtime_ms", Self::VT_MTIME_MS, false)?
+     .visit_field::<LineEnding>("line_ending", Self::VT_LINE_ENDING, false)?
+     .visit_field::<u64>("checksum", Self::VT_CHECKSUM, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FileResponseArgs<'a> {
+    pub tree: Option<flatbuffers::WIPOffset<Node<'a>>>,
+    pub stats: Option<flatbuffers::WIPOffset<ParseStats<'a>>>,
+    pub version: u32,
+    pub content_hash: u64,
+    pub mtime_ms: u64,
+    pub line_ending: LineEnding,
+    pub checksum: u64,
+}
+impl<'a> Default for FileResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FileResponseArgs {
+      tree: None, // required field
+      stats: None,
+      version: 0,
+      content_hash: 0,
+      mtime_ms: 0,
+      line_ending: LineEnding::Lf,
+      checksum: 0,
+    }
+  }
+}
+
+pub struct FileResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FileResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_tree(&mut self, tree: flatbuffers::WIPOffset<Node<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Node>>(FileResponse::VT_TREE, tree);
+  }
+  #[inline]
+  pub fn add_stats(&mut self, stats: flatbuffers::WIPOffset<ParseStats<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<ParseStats>>(FileResponse::VT_STATS, stats);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(FileResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(FileResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn add_mtime_ms(&mut self, mtime_ms: u64) {
+    self.fbb_.push_slot::<u64>(FileResponse::VT_MTIME_MS, mtime_ms, 0);
+  }
+  #[inline]
+  pub fn add_line_ending(&mut self, line_ending: LineEnding) {
+    self.fbb_.push_slot::<LineEnding>(FileResponse::VT_LINE_ENDING, line_ending, LineEnding::Lf);
+  }
+  #[inline]
+  pub fn add_checksum(&mut self, checksum: u64) {
+    self.fbb_.push_slot::<u64>(FileResponse::VT_CHECKSUM, checksum, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FileResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FileResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FileResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, FileResponse::VT_TREE,"tree");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FileResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FileResponse");
+      ds.field("tree", &self.tree());
+      ds.field("stats", &self.stats());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.field("mtime_ms", &self.mtime_ms());
+      ds.field("line_ending", &self.line_ending());
+      ds.field("checksum", &self.checksum());
+      ds.finish()
+  }
+}
+pub enum DirectoryEntryOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DirectoryEntry<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DirectoryEntry<'a> {
+  type Inner = DirectoryEntry<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> DirectoryEntry<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 6;
+  pub const VT_FILE_SIZE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DirectoryEntry { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args DirectoryEntryArgs<'args>
+  ) -> flatbuffers::WIPOffset<DirectoryEntry<'bldr>> {
+    let mut builder = DirectoryEntryBuilder::new(_fbb);
+    builder.add_file_size(args.file_size);
+    if let Some(x) = args.language { builder.add_language(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DirectoryEntry::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn language(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DirectoryEntry::VT_LANGUAGE, None).unwrap()}
+  }
+  #[inline]
+  pub fn file_size(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(DirectoryEntry::VT_FILE_SIZE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for DirectoryEntry<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("language", Self::VT_LANGUAGE, true)?
+     .visit_field::<u64>("file_size", Self::VT_FILE_SIZE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DirectoryEntryArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub file_size: u64,
+}
+impl<'a> Default for DirectoryEntryArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DirectoryEntryArgs {
+      path: None, // required field
+      language: None, // required field
+      file_size: 0,
+    }
+  }
+}
+
+pub struct DirectoryEntryBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DirectoryEntryBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DirectoryEntry::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DirectoryEntry::VT_LANGUAGE, language);
+  }
+  #[inline]
+  pub fn add_file_size(&mut self, file_size: u64) {
+    self.fbb_.push_slot::<u64>(DirectoryEntry::VT_FILE_SIZE, file_size, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DirectoryEntryBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DirectoryEntryBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DirectoryEntry<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, DirectoryEntry::VT_PATH,"path");
+    self.fbb_.required(o, DirectoryEntry::VT_LANGUAGE,"language");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DirectoryEntry<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DirectoryEntry");
+      ds.field("path", &self.path());
+      ds.field("language", &self.language());
+      ds.field("file_size", &self.file_size());
+      ds.finish()
+  }
+}
+pub enum DirectoryListingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DirectoryListing<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DirectoryListing<'a> {
+  type Inner = DirectoryListing<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> DirectoryListing<'a> {
+  pub const VT_ENTRIES: flatbuffers::VOffsetT = 4;
+  pub const VT_TRUNCATED: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DirectoryListing { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args DirectoryListingArgs<'args>
+  ) -> flatbuffers::WIPOffset<DirectoryListing<'bldr>> {
+    let mut builder = DirectoryListingBuilder::new(_fbb);
+    if let Some(x) = args.entries { builder.add_entries(x); }
+    builder.add_truncated(args.truncated);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn entries(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DirectoryEntry<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DirectoryEntry>>>>(DirectoryListing::VT_ENTRIES, None)}
+  }
+  #[inline]
+  pub fn truncated(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(DirectoryListing::VT_TRUNCATED, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for DirectoryListing<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<DirectoryEntry>>>>("entries", Self::VT_ENTRIES, false)?
+     .visit_field::<bool>("truncated", Self::VT_TRUNCATED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DirectoryListingArgs<'a> {
+    pub entries: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DirectoryEntry<'a>>>>>,
+    pub truncated: bool,
+}
+impl<'a> Default for DirectoryListingArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DirectoryListingArgs {
+      entries: None,
+      truncated: false,
+    }
+  }
+}
+
+pub struct DirectoryListingBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DirectoryListingBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_entries(&mut self, entries: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<DirectoryEntry<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DirectoryListing::VT_ENTRIES, entries);
+  }
+  #[inline]
+  pub fn add_truncated(&mut self, truncated: bool) {
+    self.fbb_.push_slot::<bool>(DirectoryListing::VT_TRUNCATED, truncated, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DirectoryListingBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DirectoryListingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DirectoryListing<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DirectoryListing<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DirectoryListing");
+      ds.field("entries", &self.entries());
+      ds.field("truncated", &self.truncated());
+      ds.finish()
+  }
+}
+pub enum TextEditOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TextEdit<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TextEdit<'a> {
+  type Inner = TextEdit<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TextEdit<'a> {
+  pub const VT_START_BYTE: flatbuffers::VOffsetT = 4;
+  pub const VT_OLD_END_BYTE: flatbuffers::VOffsetT = 6;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TextEdit { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TextEditArgs<'args>
+  ) -> flatbuffers::WIPOffset<TextEdit<'bldr>> {
+    let mut builder = TextEditBuilder::new(_fbb);
+    if let Some(x) = args.text { builder.add_text(x); }
+    builder.add_old_end_byte(args.old_end_byte);
+    builder.add_start_byte(args.start_byte);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn start_byte(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TextEdit::VT_START_BYTE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn old_end_byte(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TextEdit::VT_OLD_END_BYTE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn text(&self) -> Option<flatbuffers::Vector<'a, u16>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(TextEdit::VT_TEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TextEdit<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("start_byte", Self::VT_START_BYTE, false)?
+     .visit_field::<u32>("old_end_byte", Self::VT_OLD_END_BYTE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("text", Self::VT_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TextEditArgs<'a> {
+    pub start_byte: u32,
+    pub old_end_byte: u32,
+    pub text: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+}
+impl<'a> Default for TextEditArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TextEditArgs {
+      start_byte: 0,
+      old_end_byte: 0,
+      text: None,
+    }
+  }
+}
+
+pub struct TextEditBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TextEditBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_start_byte(&mut self, start_byte: u32) {
+    self.fbb_.push_slot::<u32>(TextEdit::VT_START_BYTE, start_byte, 0);
+  }
+  #[inline]
+  pub fn add_old_end_byte(&mut self, old_end_byte: u32) {
+    self.fbb_.push_slot::<u32>(TextEdit::VT_OLD_END_BYTE, old_end_byte, 0);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TextEdit::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TextEditBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TextEditBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TextEdit<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TextEdit<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TextEdit");
+      ds.field("start_byte", &self.start_byte());
+      ds.field("old_end_byte", &self.old_end_byte());
+      ds.field("text", &self.text());
+      ds.finish()
+  }
+}
+pub enum EditRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct EditRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for EditRequest<'a> {
+  type Inner = EditRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> EditRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_BASE_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_EDITS: flatbuffers::VOffsetT = 8;
+  pub const VT_IF_HASH: flatbuffers::VOffsetT = 10;
+  pub const VT_STRUCTURAL_HASHES: flatbuffers::VOffsetT = 12;
+  pub const VT_PROFILE: flatbuffers::VOffsetT = 14;
+  pub const VT_DUAL_OFFSETS: flatbuffers::VOffsetT = 16;
+  pub const VT_TEXT_INCLUSION: flatbuffers::VOffsetT = 18;
+  pub const VT_TEXT_MAX_BYTES: flatbuffers::VOffsetT = 20;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    EditRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args EditRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<EditRequest<'bldr>> {
+    let mut builder = EditRequestBuilder::new(_fbb);
+    if let Some(x) = args.if_hash { builder.add_if_hash(x); }
+    builder.add_text_max_bytes(args.text_max_bytes);
+    if let Some(x) = args.edits { builder.add_edits(x); }
+    builder.add_base_version(args.base_version);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.add_text_inclusion(args.text_inclusion);
+    builder.add_dual_offsets(args.dual_offsets);
+    builder.add_profile(args.profile);
+    builder.add_structural_hashes(args.structural_hashes);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(EditRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn base_version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(EditRequest::VT_BASE_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn edits(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit>>>>(EditRequest::VT_EDITS, None)}
+  }
+  #[inline]
+  pub fn if_hash(&self) -> Option<u64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(EditRequest::VT_IF_HASH, None)}
+  }
+  #[inline]
+  pub fn structural_hashes(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(EditRequest::VT_STRUCTURAL_HASHES, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn profile(&self) -> SerializationProfile {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SerializationProfile>(EditRequest::VT_PROFILE, Some(SerializationProfile::Default)).unwrap()}
+  }
+  #[inline]
+  pub fn dual_offsets(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(EditRequest::VT_DUAL_OFFSETS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn text_inclusion(&self) -> TextInclusion {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TextInclusion>(EditRequest::VT_TEXT_INCLUSION, Some(TextInclusion::Profile)).unwrap()}
+  }
+  #[inline]
+  pub fn text_max_bytes(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(EditRequest::VT_TEXT_MAX_BYTES, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for EditRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<u32>("base_version", Self::VT_BASE_VERSION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TextEdit>>>>("edits", Self::VT_EDITS, false)?
+     .visit_field::<u64>("if_hash", Self::VT_IF_HASH, false)?
+     .visit_field::<bool>("structural_hashes", Self::VT_STRUCTURAL_HASHES, false)?
+     .visit_field::<SerializationProfile>("profile", Self::VT_PROFILE, false)?
+     .visit_field::<bool>("dual_offsets", Self::VT_DUAL_OFFSETS, false)?
+     .visit_field::<TextInclusion>("text_inclusion", Self::VT_TEXT_INCLUSION, false)?
+     .visit_field::<u32>("text_max_bytes", Self::VT_TEXT_MAX_BYTES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EditRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub base_version: u32,
+    pub edits: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit<'a>>>>>,
+    pub if_hash: Option<u64>,
+    pub structural_hashes: bool,
+    pub profile: SerializationProfile,
+    pub dual_offsets: bool,
+    pub text_inclusion: TextInclusion,
+    pub text_max_bytes: u32,
+}
+impl<'a> Default for EditRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    EditRequestArgs {
+      path: None, // required field
+      base_version: 0,
+      edits: None,
+      if_hash: None,
+      structural_hashes: false,
+      profile: SerializationProfile::Default,
+      dual_offsets: false,
+      text_inclusion: TextInclusion::Profile,
+      text_max_bytes: 0,
+    }
+  }
+}
+
+pub struct EditRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> EditRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(EditRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_base_version(&mut self, base_version: u32) {
+    self.fbb_.push_slot::<u32>(EditRequest::VT_BASE_VERSION, base_version, 0);
+  }
+  #[inline]
+  pub fn add_edits(&mut self, edits: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TextEdit<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(EditRequest::VT_EDITS, edits);
+  }
+  #[inline]
+  pub fn add_if_hash(&mut self, if_hash: u64) {
+    self.fbb_.push_slot_always::<u64>(EditRequest::VT_IF_HASH, if_hash);
+  }
+  #[inline]
+  pub fn add_structural_hashes(&mut self, structural_hashes: bool) {
+    self.fbb_.push_slot::<bool>(EditRequest::VT_STRUCTURAL_HASHES, structural_hashes, false);
+  }
+  #[inline]
+  pub fn add_profile(&mut self, profile: SerializationProfile) {
+    self.fbb_.push_slot::<SerializationProfile>(EditRequest::VT_PROFILE, profile, SerializationProfile::Default);
+  }
+  #[inline]
+  pub fn add_dual_offsets(&mut self, dual_offsets: bool) {
+    self.fbb_.push_slot::<bool>(EditRequest::VT_DUAL_OFFSETS, dual_offsets, false);
+  }
+  #[inline]
+  pub fn add_text_inclusion(&mut self, text_inclusion: TextInclusion) {
+    self.fbb_.push_slot::<TextInclusion>(EditRequest::VT_TEXT_INCLUSION, text_inclusion, TextInclusion::Profile);
+  }
+  #[inline]
+  pub fn add_text_max_bytes(&mut self, text_max_bytes: u32) {
+    self.fbb_.push_slot::<u32>(EditRequest::VT_TEXT_MAX_BYTES, text_max_bytes, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> EditRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    EditRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<EditRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, EditRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for EditRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("EditRequest");
+      ds.field("path", &self.path());
+      ds.field("base_version", &self.base_version());
+      ds.field("edits", &self.edits());
+      ds.field("if_hash", &self.if_hash());
+      ds.field("structural_hashes", &self.structural_hashes());
+      ds.field("profile", &self.profile());
+      ds.field("dual_offsets", &self.dual_offsets());
+      ds.field("text_inclusion", &self.text_inclusion());
+      ds.field("text_max_bytes", &self.text_max_bytes());
+      ds.finish()
+  }
+}
+pub enum DocumentEditsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DocumentEdits<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DocumentEdits<'a> {
+  type Inner = DocumentEdits<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> DocumentEdits<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_BASE_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_EDITS: flatbuffers::VOffsetT = 8;
+  pub const VT_IF_HASH: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DocumentEdits { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args DocumentEditsArgs<'args>
+  ) -> flatbuffers::WIPOffset<DocumentEdits<'bldr>> {
+    let mut builder = DocumentEditsBuilder::new(_fbb);
+    if let Some(x) = args.if_hash { builder.add_if_hash(x); }
+    if let Some(x) = args.edits { builder.add_edits(x); }
+    builder.add_base_version(args.base_version);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DocumentEdits::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn base_version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DocumentEdits::VT_BASE_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn edits(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit>>>>(DocumentEdits::VT_EDITS, None)}
+  }
+  #[inline]
+  pub fn if_hash(&self) -> Option<u64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(DocumentEdits::VT_IF_HASH, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for DocumentEdits<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<u32>("base_version", Self::VT_BASE_VERSION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TextEdit>>>>("edits", Self::VT_EDITS, false)?
+     .visit_field::<u64>("if_hash", Self::VT_IF_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentEditsArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub base_version: u32,
+    pub edits: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit<'a>>>>>,
+    pub if_hash: Option<u64>,
+}
+impl<'a> Default for DocumentEditsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentEditsArgs {
+      path: None, // required field
+      base_version: 0,
+      edits: None,
+      if_hash: None,
+    }
+  }
+}
+
+pub struct DocumentEditsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DocumentEditsBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DocumentEdits::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_base_version(&mut self, base_version: u32) {
+    self.fbb_.push_slot::<u32>(DocumentEdits::VT_BASE_VERSION, base_version, 0);
+  }
+  #[inline]
+  pub fn add_edits(&mut self, edits: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TextEdit<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DocumentEdits::VT_EDITS, edits);
+  }
+  #[inline]
+  pub fn add_if_hash(&mut self, if_hash: u64) {
+    self.fbb_.push_slot_always::<u64>(DocumentEdits::VT_IF_HASH, if_hash);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DocumentEditsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DocumentEditsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DocumentEdits<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, DocumentEdits::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DocumentEdits<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DocumentEdits");
+      ds.field("path", &self.path());
+      ds.field("base_version", &self.base_version());
+      ds.field("edits", &self.edits());
+      ds.field("if_hash", &self.if_hash());
+      ds.finish()
+  }
+}
+pub enum TransactionRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TransactionRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TransactionRequest<'a> {
+  type Inner = TransactionRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TransactionRequest<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TransactionRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TransactionRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<TransactionRequest<'bldr>> {
+    let mut builder = TransactionRequestBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DocumentEdits<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DocumentEdits>>>>(TransactionRequest::VT_DOCUMENTS, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for TransactionRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<DocumentEdits>>>>("documents", Self::VT_DOCUMENTS, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TransactionRequestArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DocumentEdits<'a>>>>>,
+}
+impl<'a> Default for TransactionRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TransactionRequestArgs {
+      documents: None, // required field
+    }
+  }
+}
+
+pub struct TransactionRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TransactionRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<DocumentEdits<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TransactionRequest::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TransactionRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TransactionRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TransactionRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, TransactionRequest::VT_DOCUMENTS,"documents");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TransactionRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TransactionRequest");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum EditedDocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct EditedDocument<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for EditedDocument<'a> {
+  type Inner = EditedDocument<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> EditedDocument<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    EditedDocument { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args EditedDocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<EditedDocument<'bldr>> {
+    let mut builder = EditedDocumentBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(EditedDocument::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(EditedDocument::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(EditedDocument::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for EditedDocument<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EditedDocumentArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for EditedDocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    EditedDocumentArgs {
+      path: None, // required field
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct EditedDocumentBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> EditedDocumentBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(EditedDocument::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(EditedDocument::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(EditedDocument::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> EditedDocumentBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    EditedDocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<EditedDocument<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, EditedDocument::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for EditedDocument<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("EditedDocument");
+      ds.field("path", &self.path());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum TransactionResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TransactionResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TransactionResponse<'a> {
+  type Inner = TransactionResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TransactionResponse<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TransactionResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TransactionResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<TransactionResponse<'bldr>> {
+    let mut builder = TransactionResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EditedDocument<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EditedDocument>>>>(TransactionResponse::VT_DOCUMENTS, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for TransactionResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<EditedDocument>>>>("documents", Self::VT_DOCUMENTS, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TransactionResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EditedDocument<'a>>>>>,
+}
+impl<'a> Default for TransactionResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TransactionResponseArgs {
+      documents: None, // required field
+    }
+  }
+}
+
+pub struct TransactionResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TransactionResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<EditedDocument<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TransactionResponse::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TransactionResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TransactionResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TransactionResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, TransactionResponse::VT_DOCUMENTS,"documents");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TransactionResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TransactionResponse");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum NodeSelectorOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct NodeSelector<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for NodeSelector<'a> {
+  type Inner = NodeSelector<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> NodeSelector<'a> {
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 4;
+  pub const VT_KIND: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    NodeSelector { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args NodeSelectorArgs<'args>
+  ) -> flatbuffers::WIPOffset<NodeSelector<'bldr>> {
+    let mut builder = NodeSelectorBuilder::new(_fbb);
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    if let Some(x) = args.location { builder.add_location(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(NodeSelector::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn kind(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(NodeSelector::VT_KIND, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for NodeSelector<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct NodeSelectorArgs<'a> {
+    pub location: Option<&'a Location>,
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for NodeSelectorArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    NodeSelectorArgs {
+      location: None,
+      kind: None,
+    }
+  }
+}
+
+pub struct NodeSelectorBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> NodeSelectorBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(NodeSelector::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NodeSelector::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NodeSelectorBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    NodeSelectorBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<NodeSelector<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for NodeSelector<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("NodeSelector");
+      ds.field("location", &self.location());
+      ds.field("kind", &self.kind());
+      ds.finish()
+  }
+}
+pub enum NodeEditRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct NodeEditRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for NodeEditRequest<'a> {
+  type Inner = NodeEditRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> NodeEditRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_BASE_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_IF_HASH: flatbuffers::VOffsetT = 8;
+  pub const VT_OPERATION: flatbuffers::VOffsetT = 10;
+  pub const VT_NODE: flatbuffers::VOffsetT = 12;
+  pub const VT_TARGET: flatbuffers::VOffsetT = 14;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 16;
+  pub const VT_PREVIEW: flatbuffers::VOffsetT = 18;
+  pub const VT_STRUCTURAL_HASHES: flatbuffers::VOffsetT = 20;
+  pub const VT_PROFILE: flatbuffers::VOffsetT = 22;
+  pub const VT_DUAL_OFFSETS: flatbuffers::VOffsetT = 24;
+  pub const VT_TEXT_INCLUSION: flatbuffers::VOffsetT = 26;
+  pub const VT_TEXT_MAX_BYTES: flatbuffers::VOffsetT = 28;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    NodeEditRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args NodeEditRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<NodeEditRequest<'bldr>> {
+    let mut builder = NodeEditRequestBuilder::new(_fbb);
+    if let Some(x) = args.if_hash { builder.add_if_hash(x); }
+    builder.add_text_max_bytes(args.text_max_bytes);
+    if let Some(x) = args.text { builder.add_text(x); }
+    if let Some(x) = args.target { builder.add_target(x); }
+    if let Some(x) = args.node { builder.add_node(x); }
+    builder.add_base_version(args.base_version);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.add_text_inclusion(args.text_inclusion);
+    builder.add_dual_offsets(args.dual_offsets);
+    builder.add_profile(args.profile);
+    builder.add_structural_hashes(args.structural_hashes);
+    builder.add_preview(args.preview);
+    builder.add_operation(args.operation);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(NodeEditRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn base_version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(NodeEditRequest::VT_BASE_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn if_hash(&self) -> Option<u64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(NodeEditRequest::VT_IF_HASH, None)}
+  }
+  #[inline]
+  pub fn operation(&self) -> NodeOperation {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<NodeOperation>(NodeEditRequest::VT_OPERATION, Some(NodeOperation::Delete)).unwrap()}
+  }
+  #[inline]
+  pub fn node(&self) -> NodeSelector<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<NodeSelector>>(NodeEditRequest::VT_NODE, None).unwrap()}
+  }
+  #[inline]
+  pub fn target(&self) -> Option<NodeSelector<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<NodeSelector>>(NodeEditRequest::VT_TARGET, None)}
+  }
+  #[inline]
+  pub fn text(&self) -> Option<flatbuffers::Vector<'a, u16>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(NodeEditRequest::VT_TEXT, None)}
+  }
+  #[inline]
+  pub fn preview(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(NodeEditRequest::VT_PREVIEW, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn structural_hashes(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(NodeEditRequest::VT_STRUCTURAL_HASHES, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn profile(&self) -> SerializationProfile {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SerializationProfile>(NodeEditRequest::VT_PROFILE, Some(SerializationProfile::Default)).unwrap()}
+  }
+  #[inline]
+  pub fn dual_offsets(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(NodeEditRequest::VT_DUAL_OFFSETS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn text_inclusion(&self) -> TextInclusion {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TextInclusion>(NodeEditRequest::VT_TEXT_INCLUSION, Some(TextInclusion::Profile)).unwrap()}
+  }
+  #[inline]
+  pub fn text_max_bytes(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(NodeEditRequest::VT_TEXT_MAX_BYTES, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for NodeEditRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<u32>("base_version", Self::VT_BASE_VERSION, false)?
+     .visit_field::<u64>("if_hash", Self::VT_IF_HASH, false)?
+     .visit_field::<NodeOperation>("operation", Self::VT_OPERATION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<NodeSelector>>("node", Self::VT_NODE, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<NodeSelector>>("target", Self::VT_TARGET, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("text", Self::VT_TEXT, false)?
+     .visit_field::<bool>("preview", Self::VT_PREVIEW, false)?
+     .visit_field::<bool>("structural_hashes", Self::VT_STRUCTURAL_HASHES, false)?
+     .visit_field::<SerializationProfile>("profile", Self::VT_PROFILE, false)?
+     .visit_field::<bool>("dual_offsets", Self::VT_DUAL_OFFSETS, false)?
+     .visit_field::<TextInclusion>("text_inclusion", Self::VT_TEXT_INCLUSION, false)?
+     .visit_field::<u32>("text_max_bytes", Self::VT_TEXT_MAX_BYTES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct NodeEditRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub base_version: u32,
+    pub if_hash: Option<u64>,
+    pub operation: NodeOperation,
+    pub node: Option<flatbuffers::WIPOffset<NodeSelector<'a>>>,
+    pub target: Option<flatbuffers::WIPOffset<NodeSelector<'a>>>,
+    pub text: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+    pub preview: bool,
+    pub structural_hashes: bool,
+    pub profile: SerializationProfile,
+    pub dual_offsets: bool,
+    pub text_inclusion: TextInclusion,
+    pub text_max_bytes: u32,
+}
+impl<'a> Default for NodeEditRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    NodeEditRequestArgs {
+      path: None, // required field
+      base_version: 0,
+      if_hash: None,
+      operation: NodeOperation::Delete,
+      node: None, // required field
+      target: None,
+      text: None,
+      preview: false,
+      structural_hashes: false,
+      profile: SerializationProfile::Default,
+      dual_offsets: false,
+      text_inclusion: TextInclusion::Profile,
+      text_max_bytes: 0,
+    }
+  }
+}
+
+pub struct NodeEditRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> NodeEditRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NodeEditRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_base_version(&mut self, base_version: u32) {
+    self.fbb_.push_slot::<u32>(NodeEditRequest::VT_BASE_VERSION, base_version, 0);
+  }
+  #[inline]
+  pub fn add_if_hash(&mut self, if_hash: u64) {
+    self.fbb_.push_slot_always::<u64>(NodeEditRequest::VT_IF_HASH, if_hash);
+  }
+  #[inline]
+  pub fn add_operation(&mut self, operation: NodeOperation) {
+    self.fbb_.push_slot::<NodeOperation>(NodeEditRequest::VT_OPERATION, operation, NodeOperation::Delete);
+  }
+  #[inline]
+  pub fn add_node(&mut self, node: flatbuffers::WIPOffset<NodeSelector<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<NodeSelector>>(NodeEditRequest::VT_NODE, node);
+  }
+  #[inline]
+  pub fn add_target(&mut self, target: flatbuffers::WIPOffset<NodeSelector<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<NodeSelector>>(NodeEditRequest::VT_TARGET, target);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NodeEditRequest::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn add_preview(&mut self, preview: bool) {
+    self.fbb_.push_slot::<bool>(NodeEditRequest::VT_PREVIEW, preview, false);
+  }
+  #[inline]
+  pub fn add_structural_hashes(&mut self, structural_hashes: bool) {
+    self.fbb_.push_slot::<bool>(NodeEditRequest::VT_STRUCTURAL_HASHES, structural_hashes, false);
+  }
+  #[inline]
+  pub fn add_profile(&mut self, profile: SerializationProfile) {
+    self.fbb_.push_slot::<SerializationProfile>(NodeEditRequest::VT_PROFILE, profile, SerializationProfile::Default);
+  }
+  #[inline]
+  pub fn add_dual_offsets(&mut self, dual_offsets: bool) {
+    self.fbb_.push_slot::<bool>(NodeEditRequest::VT_DUAL_OFFSETS, dual_offsets, false);
+  }
+  #[inline]
+  pub fn add_text_inclusion(&mut self, text_inclusion: TextInclusion) {
+    self.fbb_.push_slot::<TextInclusion>(NodeEditRequest::VT_TEXT_INCLUSION, text_inclusion, TextInclusion::Profile);
+  }
+  #[inline]
+  pub fn add_text_max_bytes(&mut self, text_max_bytes: u32) {
+    self.fbb_.push_slot::<u32>(NodeEditRequest::VT_TEXT_MAX_BYTES, text_max_bytes, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NodeEditRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    NodeEditRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<NodeEditRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, NodeEditRequest::VT_PATH,"path");
+    self.fbb_.required(o, NodeEditRequest::VT_NODE,"node");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for NodeEditRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("NodeEditRequest");
+      ds.field("path", &self.path());
+      ds.field("base_version", &self.base_version());
+      ds.field("if_hash", &self.if_hash());
+      ds.field("operation", &self.operation());
+      ds.field("node", &self.node());
+      ds.field("target", &self.target());
+      ds.field("text", &self.text());
+      ds.field("preview", &self.preview());
+      ds.field("structural_hashes", &self.structural_hashes());
+      ds.field("profile", &self.profile());
+      ds.field("dual_offsets", &self.dual_offsets());
+      ds.field("text_inclusion", &self.text_inclusion());
+      ds.field("text_max_bytes", &self.text_max_bytes());
+      ds.finish()
+  }
+}
+pub enum NodeEditResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct NodeEditResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for NodeEditResponse<'a> {
+  type Inner = NodeEditResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> NodeEditResponse<'a> {
+  pub const VT_EDITS: flatbuffers::VOffsetT = 4;
+  pub const VT_FILE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    NodeEditResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args NodeEditResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<NodeEditResponse<'bldr>> {
+    let mut builder = NodeEditResponseBuilder::new(_fbb);
+    if let Some(x) = args.file { builder.add_file(x); }
+    if let Some(x) = args.edits { builder.add_edits(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn edits(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit>>>>(NodeEditResponse::VT_EDITS, None).unwrap()}
+  }
+  #[inline]
+  pub fn file(&self) -> flatbuffers::Vector<'a, u8> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(NodeEditResponse::VT_FILE, None).unwrap()}
+  }
+  pub fn file_nested_flatbuffer(&'a self) -> FileResponse<'a> {
+    let data = self.file();
+    use flatbuffers::Follow;
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid flatbuffer in this slot
+    unsafe { <flatbuffers::ForwardsUOffset<FileResponse<'a>>>::follow(data.bytes(), 0) }
+  }
+}
+
+impl flatbuffers::Verifiable for NodeEditResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TextEdit>>>>("edits", Self::VT_EDITS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("file", Self::VT_FILE, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct NodeEditResponseArgs<'a> {
+    pub edits: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit<'a>>>>>,
+    pub file: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for NodeEditResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    NodeEditResponseArgs {
+      edits: None, // required field
+      file: None, // required field
+    }
+  }
+}
+
+pub struct NodeEditResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> NodeEditResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_edits(&mut self, edits: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TextEdit<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NodeEditResponse::VT_EDITS, edits);
+  }
+  #[inline]
+  pub fn add_file(&mut self, file: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NodeEditResponse::VT_FILE, file);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NodeEditResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    NodeEditResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<NodeEditResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, NodeEditResponse::VT_EDITS,"edits");
+    self.fbb_.required(o, NodeEditResponse::VT_FILE,"file");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for NodeEditResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("NodeEditResponse");
+      ds.field("edits", &self.edits());
+      ds.field("file", &self.file());
+      ds.finish()
+  }
+}
+pub enum ReloadGrammarsRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReloadGrammarsRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReloadGrammarsRequest<'a> {
+  type Inner = ReloadGrammarsRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ReloadGrammarsRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ReloadGrammarsRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args ReloadGrammarsRequestArgs
+  ) -> flatbuffers::WIPOffset<ReloadGrammarsRequest<'bldr>> {
+    let mut builder = ReloadGrammarsRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for ReloadGrammarsRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ReloadGrammarsRequestArgs {
+}
+impl<'a> Default for ReloadGrammarsRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    ReloadGrammarsRequestArgs {
+    }
+  }
+}
+
+pub struct ReloadGrammarsRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ReloadGrammarsRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReloadGrammarsRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ReloadGrammarsRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ReloadGrammarsRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ReloadGrammarsRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ReloadGrammarsRequest");
+      ds.finish()
+  }
+}
+pub enum ReloadGrammarsResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReloadGrammarsResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReloadGrammarsResponse<'a> {
+  type Inner = ReloadGrammarsResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ReloadGrammarsResponse<'a> {
+  pub const VT_LANGUAGES: flatbuffers::VOffsetT = 4;
+  pub const VT_FAILED: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ReloadGrammarsResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ReloadGrammarsResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<ReloadGrammarsResponse<'bldr>> {
+    let mut builder = ReloadGrammarsResponseBuilder::new(_fbb);
+    if let Some(x) = args.failed { builder.add_failed(x); }
+    if let Some(x) = args.languages { builder.add_languages(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn languages(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(ReloadGrammarsResponse::VT_LANGUAGES, None)}
+  }
+  #[inline]
+  pub fn failed(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(ReloadGrammarsResponse::VT_FAILED, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ReloadGrammarsResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("languages", Self::VT_LANGUAGES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("failed", Self::VT_FAILED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ReloadGrammarsResponseArgs<'a> {
+    pub languages: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub failed: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for ReloadGrammarsResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ReloadGrammarsResponseArgs {
+      languages: None,
+      failed: None,
+    }
+  }
+}
+
+pub struct ReloadGrammarsResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ReloadGrammarsResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_languages(&mut self, languages: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ReloadGrammarsResponse::VT_LANGUAGES, languages);
+  }
+  #[inline]
+  pub fn add_failed(&mut self, failed: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ReloadGrammarsResponse::VT_FAILED, failed);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReloadGrammarsResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ReloadGrammarsResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ReloadGrammarsResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ReloadGrammarsResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ReloadGrammarsResponse");
+      ds.field("languages", &self.languages());
+      ds.field("failed", &self.failed());
+      ds.finish()
+  }
+}
+pub enum DebugParseRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DebugParseRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DebugParseRequest<'a> {
+  type Inner = DebugParseRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> DebugParseRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_DOT_GRAPHS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DebugParseRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args DebugParseRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<DebugParseRequest<'bldr>> {
+    let mut builder = DebugParseRequestBuilder::new(_fbb);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.add_dot_graphs(args.dot_graphs);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DebugParseRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn dot_graphs(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(DebugParseRequest::VT_DOT_GRAPHS, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for DebugParseRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<bool>("dot_graphs", Self::VT_DOT_GRAPHS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DebugParseRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub dot_graphs: bool,
+}
+impl<'a> Default for DebugParseRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DebugParseRequestArgs {
+      path: None, // required field
+      dot_graphs: false,
+    }
+  }
+}
+
+pub struct DebugParseRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DebugParseRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DebugParseRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_dot_graphs(&mut self, dot_graphs: bool) {
+    self.fbb_.push_slot::<bool>(DebugParseRequest::VT_DOT_GRAPHS, dot_graphs, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DebugParseRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DebugParseRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DebugParseRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, DebugParseRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DebugParseRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DebugParseRequest");
+      ds.field("path", &self.path());
+      ds.field("dot_graphs", &self.dot_graphs());
+      ds.finish()
+  }
+}
+pub enum DebugParseResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DebugParseResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DebugParseResponse<'a> {
+  type Inner = DebugParseResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> DebugParseResponse<'a> {
+  pub const VT_LOG: flatbuffers::VOffsetT = 4;
+  pub const VT_DOT_GRAPHS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DebugParseResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args DebugParseResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<DebugParseResponse<'bldr>> {
+    let mut builder = DebugParseResponseBuilder::new(_fbb);
+    if let Some(x) = args.dot_graphs { builder.add_dot_graphs(x); }
+    if let Some(x) = args.log { builder.add_log(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn log(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(DebugParseResponse::VT_LOG, None)}
+  }
+  #[inline]
+  pub fn dot_graphs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(DebugParseResponse::VT_DOT_GRAPHS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for DebugParseResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("log", Self::VT_LOG, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("dot_graphs", Self::VT_DOT_GRAPHS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DebugParseResponseArgs<'a> {
+    pub log: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub dot_graphs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for DebugParseResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DebugParseResponseArgs {
+      log: None,
+      dot_graphs: None,
+    }
+  }
+}
+
+pub struct DebugParseResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DebugParseResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_log(&mut self, log: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DebugParseResponse::VT_LOG, log);
+  }
+  #[inline]
+  pub fn add_dot_graphs(&mut self, dot_graphs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DebugParseResponse::VT_DOT_GRAPHS, dot_graphs);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DebugParseResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DebugParseResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DebugParseResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DebugParseResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DebugParseResponse");
+      ds.field("log", &self.log());
+      ds.field("dot_graphs", &self.dot_graphs());
+      ds.finish()
+  }
+}
+pub enum SnapshotRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SnapshotRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SnapshotRequest<'a> {
+  type Inner = SnapshotRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SnapshotRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_LABEL: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SnapshotRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SnapshotRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<SnapshotRequest<'bldr>> {
+    let mut builder = SnapshotRequestBuilder::new(_fbb);
+    if let Some(x) = args.label { builder.add_label(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SnapshotRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn label(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SnapshotRequest::VT_LABEL, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SnapshotRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("label", Self::VT_LABEL, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SnapshotRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub label: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SnapshotRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SnapshotRequestArgs {
+      path: None, // required field
+      label: None, // required field
+    }
+  }
+}
+
+pub struct SnapshotRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SnapshotRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SnapshotRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_label(&mut self, label: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SnapshotRequest::VT_LABEL, label);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SnapshotRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SnapshotRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SnapshotRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, SnapshotRequest::VT_PATH,"path");
+    self.fbb_.required(o, SnapshotRequest::VT_LABEL,"label");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SnapshotRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SnapshotRequest");
+      ds.field("path", &self.path());
+      ds.field("label", &self.label());
+      ds.finish()
+  }
+}
+pub enum RestoreRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RestoreRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RestoreRequest<'a> {
+  type Inner = RestoreRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RestoreRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_LABEL: flatbuffers::VOffsetT = 6;
+  pub const VT_STRUCTURAL_HASHES: flatbuffers::VOffsetT = 8;
+  pub const VT_PROFILE: flatbuffers::VOffsetT = 10;
+  pub const VT_DUAL_OFFSETS: flatbuffers::VOffsetT = 12;
+  pub const VT_TEXT_INCLUSION: flatbuffers::VOffsetT = 14;
+  pub const VT_TEXT_MAX_BYTES: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RestoreRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RestoreRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<RestoreRequest<'bldr>> {
+    let mut builder = RestoreRequestBuilder::new(_fbb);
+    builder.add_text_max_bytes(args.text_max_bytes);
+    if let Some(x) = args.label { builder.add_label(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.add_text_inclusion(args.text_inclusion);
+    builder.add_dual_offsets(args.dual_offsets);
+    builder.add_profile(args.profile);
+    builder.add_structural_hashes(args.structural_hashes);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(RestoreRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn label(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(RestoreRequest::VT_LABEL, None).unwrap()}
+  }
+  #[inline]
+  pub fn structural_hashes(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(RestoreRequest::VT_STRUCTURAL_HASHES, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn profile(&self) -> SerializationProfile {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SerializationProfile>(RestoreRequest::VT_PROFILE, Some(SerializationProfile::Default)).unwrap()}
+  }
+  #[inline]
+  pub fn dual_offsets(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(RestoreRequest::VT_DUAL_OFFSETS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn text_inclusion(&self) -> TextInclusion {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TextInclusion>(RestoreRequest::VT_TEXT_INCLUSION, Some(TextInclusion::Profile)).unwrap()}
+  }
+  #[inline]
+  pub fn text_max_bytes(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(RestoreRequest::VT_TEXT_MAX_BYTES, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for RestoreRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("label", Self::VT_LABEL, true)?
+     .visit_field::<bool>("structural_hashes", Self::VT_STRUCTURAL_HASHES, false)?
+     .visit_field::<SerializationProfile>("profile", Self::VT_PROFILE, false)?
+     .visit_field::<bool>("dual_offsets", Self::VT_DUAL_OFFSETS, false)?
+     .visit_field::<TextInclusion>("text_inclusion", Self::VT_TEXT_INCLUSION, false)?
+     .visit_field::<u32>("text_max_bytes", Self::VT_TEXT_MAX_BYTES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RestoreRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub label: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub structural_hashes: bool,
+    pub profile: SerializationProfile,
+    pub dual_offsets: bool,
+    pub text_inclusion: TextInclusion,
+    pub text_max_bytes: u32,
+}
+impl<'a> Default for RestoreRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RestoreRequestArgs {
+      path: None, // required field
+      label: None, // required field
+      structural_hashes: false,
+      profile: SerializationProfile::Default,
+      dual_offsets: false,
+      text_inclusion: TextInclusion::Profile,
+      text_max_bytes: 0,
+    }
+  }
+}
+
+pub struct RestoreRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RestoreRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RestoreRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_label(&mut self, label: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RestoreRequest::VT_LABEL, label);
+  }
+  #[inline]
+  pub fn add_structural_hashes(&mut self, structural_hashes: bool) {
+    self.fbb_.push_slot::<bool>(RestoreRequest::VT_STRUCTURAL_HASHES, structural_hashes, false);
+  }
+  #[inline]
+  pub fn add_profile(&mut self, profile: SerializationProfile) {
+    self.fbb_.push_slot::<SerializationProfile>(RestoreRequest::VT_PROFILE, profile, SerializationProfile::Default);
+  }
+  #[inline]
+  pub fn add_dual_offsets(&mut self, dual_offsets: bool) {
+    self.fbb_.push_slot::<bool>(RestoreRequest::VT_DUAL_OFFSETS, dual_offsets, false);
+  }
+  #[inline]
+  pub fn add_text_inclusion(&mut self, text_inclusion: TextInclusion) {
+    self.fbb_.push_slot::<TextInclusion>(RestoreRequest::VT_TEXT_INCLUSION, text_inclusion, TextInclusion::Profile);
+  }
+  #[inline]
+  pub fn add_text_max_bytes(&mut self, text_max_bytes: u32) {
+    self.fbb_.push_slot::<u32>(RestoreRequest::VT_TEXT_MAX_BYTES, text_max_bytes, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RestoreRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RestoreRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RestoreRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, RestoreRequest::VT_PATH,"path");
+    self.fbb_.required(o, RestoreRequest::VT_LABEL,"label");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RestoreRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RestoreRequest");
+      ds.field("path", &self.path());
+      ds.field("label", &self.label());
+      ds.field("structural_hashes", &self.structural_hashes());
+      ds.field("profile", &self.profile());
+      ds.field("dual_offsets", &self.dual_offsets());
+      ds.field("text_inclusion", &self.text_inclusion());
+      ds.field("text_max_bytes", &self.text_max_bytes());
+      ds.finish()
+  }
+}
+pub enum ShutdownRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ShutdownRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ShutdownRequest<'a> {
+  type Inner = ShutdownRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ShutdownRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ShutdownRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args ShutdownRequestArgs
+  ) -> flatbuffers::WIPOffset<ShutdownRequest<'bldr>> {
+    let mut builder = ShutdownRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for ShutdownRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ShutdownRequestArgs {
+}
+impl<'a> Default for ShutdownRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    ShutdownRequestArgs {
+    }
+  }
+}
+
+pub struct ShutdownRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ShutdownRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ShutdownRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ShutdownRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ShutdownRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ShutdownRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ShutdownRequest");
+      ds.finish()
+  }
+}
+pub enum ChunkRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ChunkRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ChunkRequest<'a> {
+  type Inner = ChunkRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ChunkRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_MAX_CHARS: flatbuffers::VOffsetT = 6;
+  pub const VT_MAX_TOKENS: flatbuffers::VOffsetT = 8;
+  pub const VT_WITH_TEXT: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ChunkRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ChunkRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<ChunkRequest<'bldr>> {
+    let mut builder = ChunkRequestBuilder::new(_fbb);
+    builder.add_max_tokens(args.max_tokens);
+    if let Some(x) = args.max_chars { builder.add_max_chars(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.add_with_text(args.with_text);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ChunkRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn max_chars(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ChunkRequest::VT_MAX_CHARS, None)}
+  }
+  #[inline]
+  pub fn max_tokens(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ChunkRequest::VT_MAX_TOKENS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn with_text(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ChunkRequest::VT_WITH_TEXT, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ChunkRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<u32>("max_chars", Self::VT_MAX_CHARS, false)?
+     .visit_field::<u32>("max_tokens", Self::VT_MAX_TOKENS, false)?
+     .visit_field::<bool>("with_text", Self::VT_WITH_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ChunkRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub max_chars: Option<u32>,
+    pub max_tokens: u32,
+    pub with_text: bool,
+}
+impl<'a> Default for ChunkRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ChunkRequestArgs {
+      path: None, // required field
+      max_chars: None,
+      max_tokens: 0,
+      with_text: false,
+    }
+  }
+}
+
+pub struct ChunkRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ChunkRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ChunkRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_max_chars(&mut self, max_chars: u32) {
+    self.fbb_.push_slot_always::<u32>(ChunkRequest::VT_MAX_CHARS, max_chars);
+  }
+  #[inline]
+  pub fn add_max_tokens(&mut self, max_tokens: u32) {
+    self.fbb_.push_slot::<u32>(ChunkRequest::VT_MAX_TOKENS, max_tokens, 0);
+  }
+  #[inline]
+  pub fn add_with_text(&mut self, with_text: bool) {
+    self.fbb_.push_slot::<bool>(ChunkRequest::VT_WITH_TEXT, with_text, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ChunkRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ChunkRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ChunkRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ChunkRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ChunkRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ChunkRequest");
+      ds.field("path", &self.path());
+      ds.field("max_chars", &self.max_chars());
+      ds.field("max_tokens", &self.max_tokens());
+      ds.field("with_text", &self.with_text());
+      ds.finish()
+  }
+}
+pub enum ChunkOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Chunk<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Chunk<'a> {
+  type Inner = Chunk<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Chunk<'a> {
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 4;
+  pub const VT_KINDS: flatbuffers::VOffsetT = 6;
+  pub const VT_START_ROW: flatbuffers::VOffsetT = 8;
+  pub const VT_END_ROW: flatbuffers::VOffsetT = 10;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Chunk { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ChunkArgs<'args>
+  ) -> flatbuffers::WIPOffset<Chunk<'bldr>> {
+    let mut builder = ChunkBuilder::new(_fbb);
+    if let Some(x) = args.text { builder.add_text(x); }
+    builder.add_end_row(args.end_row);
+    builder.add_start_row(args.start_row);
+    if let Some(x) = args.kinds { builder.add_kinds(x); }
+    if let Some(x) = args.location { builder.add_location(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(Chunk::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn kinds(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Chunk::VT_KINDS, None)}
+  }
+  #[inline]
+  pub fn start_row(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Chunk::VT_START_ROW, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn end_row(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Chunk::VT_END_ROW, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn text(&self) -> Option<flatbuffers::Vector<'a, u16>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(Chunk::VT_TEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Chunk<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("kinds", Self::VT_KINDS, false)?
+     .visit_field::<u32>("start_row", Self::VT_START_ROW, false)?
+     .visit_field::<u32>("end_row", Self::VT_END_ROW, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("text", Self::VT_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ChunkArgs<'a> {
+    pub location: Option<&'a Location>,
+    pub kinds: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub start_row: u32,
+    pub end_row: u32,
+    pub text: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+}
+impl<'a> Default for ChunkArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ChunkArgs {
+      location: None,
+      kinds: None,
+      start_row: 0,
+      end_row: 0,
+      text: None,
+    }
+  }
+}
+
+pub struct ChunkBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ChunkBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(Chunk::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_kinds(&mut self, kinds: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Chunk::VT_KINDS, kinds);
+  }
+  #[inline]
+  pub fn add_start_row(&mut self, start_row: u32) {
+    self.fbb_.push_slot::<u32>(Chunk::VT_START_ROW, start_row, 0);
+  }
+  #[inline]
+  pub fn add_end_row(&mut self, end_row: u32) {
+    self.fbb_.push_slot::<u32>(Chunk::VT_END_ROW, end_row, 0);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Chunk::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ChunkBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ChunkBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Chunk<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Chunk<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Chunk");
+      ds.field("location", &self.location());
+      ds.field("kinds", &self.kinds());
+      ds.field("start_row", &self.start_row());
+      ds.field("end_row", &self.end_row());
+      ds.field("text", &self.text());
+      ds.finish()
+  }
+}
+pub enum ChunkResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ChunkResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ChunkResponse<'a> {
+  type Inner = ChunkResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ChunkResponse<'a> {
+  pub const VT_CHUNKS: flatbuffers::VOffsetT = 4;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ChunkResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ChunkResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<ChunkResponse<'bldr>> {
+    let mut builder = ChunkResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.chunks { builder.add_chunks(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn chunks(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Chunk<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Chunk>>>>(ChunkResponse::VT_CHUNKS, None)}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ChunkResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(ChunkResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ChunkResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Chunk>>>>("chunks", Self::VT_CHUNKS, false)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ChunkResponseArgs<'a> {
+    pub chunks: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Chunk<'a>>>>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for ChunkResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ChunkResponseArgs {
+      chunks: None,
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct ChunkResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ChunkResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_chunks(&mut self, chunks: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Chunk<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ChunkResponse::VT_CHUNKS, chunks);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(ChunkResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(ChunkResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ChunkResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ChunkResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ChunkResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ChunkResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ChunkResponse");
+      ds.field("chunks", &self.chunks());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum MetricsRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MetricsRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MetricsRequest<'a> {
+  type Inner = MetricsRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> MetricsRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    MetricsRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args MetricsRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<MetricsRequest<'bldr>> {
+    let mut builder = MetricsRequestBuilder::new(_fbb);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(MetricsRequest::VT_PATH, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for MetricsRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MetricsRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for MetricsRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MetricsRequestArgs {
+      path: None, // required field
+    }
+  }
+}
+
+pub struct MetricsRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MetricsRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MetricsRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MetricsRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    MetricsRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<MetricsRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, MetricsRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for MetricsRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("MetricsRequest");
+      ds.field("path", &self.path());
+      ds.finish()
+  }
+}
+pub enum KindCountOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct KindCount<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for KindCount<'a> {
+  type Inner = KindCount<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> KindCount<'a> {
+  pub const VT_KIND: flatbuffers::VOffsetT = 4;
+  pub const VT_COUNT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    KindCount { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args KindCountArgs<'args>
+  ) -> flatbuffers::WIPOffset<KindCount<'bldr>> {
+    let mut builder = KindCountBuilder::new(_fbb);
+    builder.add_count(args.count);
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn kind(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(KindCount::VT_KIND, None).unwrap()}
+  }
+  #[inline]
+  pub fn count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(KindCount::VT_COUNT, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for KindCount<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, true)?
+     .visit_field::<u32>("count", Self::VT_COUNT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct KindCountArgs<'a> {
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub count: u32,
+}
+impl<'a> Default for KindCountArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    KindCountArgs {
+      kind: None, // required field
+      count: 0,
+    }
+  }
+}
+
+pub struct KindCountBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> KindCountBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(KindCount::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_count(&mut self, count: u32) {
+    self.fbb_.push_slot::<u32>(KindCount::VT_COUNT, count, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> KindCountBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    KindCountBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<KindCount<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, KindCount::VT_KIND,"kind");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for KindCount<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("KindCount");
+      ds.field("kind", &self.kind());
+      ds.field("count", &self.count());
+      ds.finish()
+  }
+}
+pub enum FunctionMetricsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FunctionMetrics<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FunctionMetrics<'a> {
+  type Inner = FunctionMetrics<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FunctionMetrics<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_KIND: flatbuffers::VOffsetT = 6;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 8;
+  pub const VT_LINES: flatbuffers::VOffsetT = 10;
+  pub const VT_APPROXIMATE_TOKENS: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FunctionMetrics { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FunctionMetricsArgs<'args>
+  ) -> flatbuffers::WIPOffset<FunctionMetrics<'bldr>> {
+    let mut builder = FunctionMetricsBuilder::new(_fbb);
+    builder.add_approximate_tokens(args.approximate_tokens);
+    builder.add_lines(args.lines);
+    if let Some(x) = args.location { builder.add_location(x); }
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FunctionMetrics::VT_NAME, None).unwrap()}
+  }
+  #[inline]
+  pub fn kind(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FunctionMetrics::VT_KIND, None).unwrap()}
+  }
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(FunctionMetrics::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn lines(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FunctionMetrics::VT_LINES, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn approximate_tokens(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FunctionMetrics::VT_APPROXIMATE_TOKENS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FunctionMetrics<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, true)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<u32>("lines", Self::VT_LINES, false)?
+     .visit_field::<u32>("approximate_tokens", Self::VT_APPROXIMATE_TOKENS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FunctionMetricsArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub location: Option<&'a Location>,
+    pub lines: u32,
+    pub approximate_tokens: u32,
+}
+impl<'a> Default for FunctionMetricsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FunctionMetricsArgs {
+      name: None, // required field
+      kind: None, // required field
+      location: None,
+      lines: 0,
+      approximate_tokens: 0,
+    }
+  }
+}
+
+pub struct FunctionMetricsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FunctionMetricsBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FunctionMetrics::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FunctionMetrics::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(FunctionMetrics::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_lines(&mut self, lines: u32) {
+    self.fbb_.push_slot::<u32>(FunctionMetrics::VT_LINES, lines, 0);
+  }
+  #[inline]
+  pub fn add_approximate_tokens(&mut self, approximate_tokens: u32) {
+    self.fbb_.push_slot::<u32>(FunctionMetrics::VT_APPROXIMATE_TOKENS, approximate_tokens, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FunctionMetricsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FunctionMetricsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FunctionMetrics<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, FunctionMetrics::VT_NAME,"name");
+    self.fbb_.required(o, FunctionMetrics::VT_KIND,"kind");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FunctionMetrics<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FunctionMetrics");
+      ds.field("name", &self.name());
+      ds.field("kind", &self.kind());
+      ds.field("location", &self.location());
+      ds.field("lines", &self.lines());
+      ds.field("approximate_tokens", &self.approximate_tokens());
+      ds.finish()
+  }
+}
+pub enum MetricsResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MetricsResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MetricsResponse<'a> {
+  type Inner = MetricsResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> MetricsResponse<'a> {
+  pub const VT_NODE_COUNTS: flatbuffers::VOffsetT = 4;
+  pub const VT_MAX_DEPTH: flatbuffers::VOffsetT = 6;
+  pub const VT_LINES: flatbuffers::VOffsetT = 8;
+  pub const VT_APPROXIMATE_TOKENS: flatbuffers::VOffsetT = 10;
+  pub const VT_FUNCTIONS: flatbuffers::VOffsetT = 12;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 14;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    MetricsResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args MetricsResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<MetricsResponse<'bldr>> {
+    let mut builder = MetricsResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.functions { builder.add_functions(x); }
+    builder.add_approximate_tokens(args.approximate_tokens);
+    builder.add_lines(args.lines);
+    builder.add_max_depth(args.max_depth);
+    if let Some(x) = args.node_counts { builder.add_node_counts(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn node_counts(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KindCount<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KindCount>>>>(MetricsResponse::VT_NODE_COUNTS, None)}
+  }
+  #[inline]
+  pub fn max_depth(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(MetricsResponse::VT_MAX_DEPTH, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn lines(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(MetricsResponse::VT_LINES, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn approximate_tokens(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(MetricsResponse::VT_APPROXIMATE_TOKENS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn functions(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FunctionMetrics<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FunctionMetrics>>>>(MetricsResponse::VT_FUNCTIONS, None)}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(MetricsResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(MetricsResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for MetricsResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KindCount>>>>("node_counts", Self::VT_NODE_COUNTS, false)?
+     .visit_field::<u32>("max_depth", Self::VT_MAX_DEPTH, false)?
+     .visit_field::<u32>("lines", Self::VT_LINES, false)?
+     .visit_field::<u32>("approximate_tokens", Self::VT_APPROXIMATE_TOKENS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<FunctionMetrics>>>>("functions", Self::VT_FUNCTIONS, false)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MetricsResponseArgs<'a> {
+    pub node_counts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KindCount<'a>>>>>,
+    pub max_depth: u32,
+    pub lines: u32,
+    pub approximate_tokens: u32,
+    pub functions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FunctionMetrics<'a>>>>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for MetricsResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MetricsResponseArgs {
+      node_counts: None,
+      max_depth: 0,
+      lines: 0,
+      approximate_tokens: 0,
+      functions: None,
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct MetricsResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MetricsResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_node_counts(&mut self, node_counts: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<KindCount<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MetricsResponse::VT_NODE_COUNTS, node_counts);
+  }
+  #[inline]
+  pub fn add_max_depth(&mut self, max_depth: u32) {
+    self.fbb_.push_slot::<u32>(MetricsResponse::VT_MAX_DEPTH, max_depth, 0);
+  }
+  #[inline]
+  pub fn add_lines(&mut self, lines: u32) {
+    self.fbb_.push_slot::<u32>(MetricsResponse::VT_LINES, lines, 0);
+  }
+  #[inline]
+  pub fn add_approximate_tokens(&mut self, approximate_tokens: u32) {
+    self.fbb_.push_slot::<u32>(MetricsResponse::VT_APPROXIMATE_TOKENS, approximate_tokens, 0);
+  }
+  #[inline]
+  pub fn add_functions(&mut self, functions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<FunctionMetrics<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MetricsResponse::VT_FUNCTIONS, functions);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(MetricsResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(MetricsResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MetricsResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    MetricsResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<MetricsResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for MetricsResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("MetricsResponse");
+      ds.field("node_counts", &self.node_counts());
+      ds.field("max_depth", &self.max_depth());
+      ds.field("lines", &self.lines());
+      ds.field("approximate_tokens", &self.approximate_tokens());
+      ds.field("functions", &self.functions());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum ComplexityRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ComplexityRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ComplexityRequest<'a> {
+  type Inner = ComplexityRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ComplexityRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ComplexityRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ComplexityRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<ComplexityRequest<'bldr>> {
+    let mut builder = ComplexityRequestBuilder::new(_fbb);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ComplexityRequest::VT_PATH, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ComplexityRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ComplexityRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ComplexityRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ComplexityRequestArgs {
+      path: None, // required field
+    }
+  }
+}
+
+pub struct ComplexityRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ComplexityRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ComplexityRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ComplexityRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ComplexityRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ComplexityRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ComplexityRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ComplexityRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ComplexityRequest");
+      ds.field("path", &self.path());
+      ds.finish()
+  }
+}
+pub enum FunctionComplexityOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FunctionComplexity<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FunctionComplexity<'a> {
+  type Inner = FunctionComplexity<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FunctionComplexity<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_KIND: flatbuffers::VOffsetT = 6;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 8;
+  pub const VT_COMPLEXITY: flatbuffers::VOffsetT = 10;
+  pub const VT_LINES: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FunctionComplexity { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FunctionComplexityArgs<'args>
+  ) -> flatbuffers::WIPOffset<FunctionComplexity<'bldr>> {
+    let mut builder = FunctionComplexityBuilder::new(_fbb);
+    builder.add_lines(args.lines);
+    builder.add_complexity(args.complexity);
+    if let Some(x) = args.location { builder.add_location(x); }
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FunctionComplexity::VT_NAME, None).unwrap()}
+  }
+  #[inline]
+  pub fn kind(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FunctionComplexity::VT_KIND, None).unwrap()}
+  }
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(FunctionComplexity::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn complexity(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FunctionComplexity::VT_COMPLEXITY, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn lines(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FunctionComplexity::VT_LINES, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FunctionComplexity<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, true)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<u32>("complexity", Self::VT_COMPLEXITY, false)?
+     .visit_field::<u32>("lines", Self::VT_LINES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FunctionComplexityArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub location: Option<&'a Location>,
+    pub complexity: u32,
+    pub lines: u32,
+}
+impl<'a> Default for FunctionComplexityArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FunctionComplexityArgs {
+      name: None, // required field
+      kind: None, // required field
+      location: None,
+      complexity: 0,
+      lines: 0,
+    }
+  }
+}
+
+pub struct FunctionComplexityBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FunctionComplexityBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FunctionComplexity::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FunctionComplexity::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(FunctionComplexity::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_complexity(&mut self, complexity: u32) {
+    self.fbb_.push_slot::<u32>(FunctionComplexity::VT_COMPLEXITY, complexity, 0);
+  }
+  #[inline]
+  pub fn add_lines(&mut self, lines: u32) {
+    self.fbb_.push_slot::<u32>(FunctionComplexity::VT_LINES, lines, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FunctionComplexityBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FunctionComplexityBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FunctionComplexity<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, FunctionComplexity::VT_NAME,"name");
+    self.fbb_.required(o, FunctionComplexity::VT_KIND,"kind");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FunctionComplexity<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FunctionComplexity");
+      ds.field("name", &self.name());
+      ds.field("kind", &self.kind());
+      ds.field("location", &self.location());
+      ds.field("complexity", &self.complexity());
+      ds.field("lines", &self.lines());
+      ds.finish()
+  }
+}
+pub enum ComplexityResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ComplexityResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ComplexityResponse<'a> {
+  type Inner = ComplexityResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ComplexityResponse<'a> {
+  pub const VT_FUNCTIONS: flatbuffers::VOffsetT = 4;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ComplexityResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ComplexityResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<ComplexityResponse<'bldr>> {
+    let mut builder = ComplexityResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.functions { builder.add_functions(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn functions(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FunctionComplexity<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FunctionComplexity>>>>(ComplexityResponse::VT_FUNCTIONS, None)}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ComplexityResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(ComplexityResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ComplexityResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<FunctionComplexity>>>>("functions", Self::VT_FUNCTIONS, false)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ComplexityResponseArgs<'a> {
+    pub functions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FunctionComplexity<'a>>>>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for ComplexityResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ComplexityResponseArgs {
+      functions: None,
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct ComplexityResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ComplexityResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_functions(&mut self, functions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<FunctionComplexity<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ComplexityResponse::VT_FUNCTIONS, functions);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(ComplexityResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(ComplexityResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ComplexityResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ComplexityResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ComplexityResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ComplexityResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ComplexityResponse");
+      ds.field("functions", &self.functions());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum ImportsRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ImportsRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ImportsRequest<'a> {
+  type Inner = ImportsRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ImportsRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ImportsRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ImportsRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<ImportsRequest<'bldr>> {
+    let mut builder = ImportsRequestBuilder::new(_fbb);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ImportsRequest::VT_PATH, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ImportsRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ImportsRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ImportsRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ImportsRequestArgs {
+      path: None, // required field
+    }
+  }
+}
+
+pub struct ImportsRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ImportsRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ImportsRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ImportsRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ImportsRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ImportsRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ImportsRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ImportsRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ImportsRequest");
+      ds.field("path", &self.path());
+      ds.finish()
+  }
+}
+pub enum ImportOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Import<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Import<'a> {
+  type Inner = Import<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Import<'a> {
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 4;
+  pub const VT_KIND: flatbuffers::VOffsetT = 6;
+  pub const VT_MODULE: flatbuffers::VOffsetT = 8;
+  pub const VT_RESOLVED: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Import { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ImportArgs<'args>
+  ) -> flatbuffers::WIPOffset<Import<'bldr>> {
+    let mut builder = ImportBuilder::new(_fbb);
+    if let Some(x) = args.resolved { builder.add_resolved(x); }
+    if let Some(x) = args.module { builder.add_module(x); }
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    if let Some(x) = args.location { builder.add_location(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(Import::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn kind(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Import::VT_KIND, None).unwrap()}
+  }
+  #[inline]
+  pub fn module(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Import::VT_MODULE, None).unwrap()}
+  }
+  #[inline]
+  pub fn resolved(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Import::VT_RESOLVED, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Import<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("module", Self::VT_MODULE, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("resolved", Self::VT_RESOLVED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ImportArgs<'a> {
+    pub location: Option<&'a Location>,
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub module: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub resolved: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ImportArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ImportArgs {
+      location: None,
+      kind: None, // required field
+      module: None, // required field
+      resolved: None,
+    }
+  }
+}
+
+pub struct ImportBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ImportBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(Import::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Import::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_module(&mut self, module: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Import::VT_MODULE, module);
+  }
+  #[inline]
+  pub fn add_resolved(&mut self, resolved: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Import::VT_RESOLVED, resolved);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ImportBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ImportBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Import<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Import::VT_KIND,"kind");
+    self.fbb_.required(o, Import::VT_MODULE,"module");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Import<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Import");
+      ds.field("location", &self.location());
+      ds.field("kind", &self.kind());
+      ds.field("module", &self.module());
+      ds.field("resolved", &self.resolved());
+      ds.finish()
+  }
+}
+pub enum ImportsResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ImportsResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ImportsResponse<'a> {
+  type Inner = ImportsResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ImportsResponse<'a> {
+  pub const VT_IMPORTS: flatbuffers::VOffsetT = 4;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ImportsResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ImportsResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<ImportsResponse<'bldr>> {
+    let mut builder = ImportsResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.imports { builder.add_imports(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn imports(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Import<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Import>>>>(ImportsResponse::VT_IMPORTS, None)}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ImportsResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(ImportsResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ImportsResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Import>>>>("imports", Self::VT_IMPORTS, false)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ImportsResponseArgs<'a> {
+    pub imports: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Import<'a>>>>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for ImportsResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ImportsResponseArgs {
+      imports: None,
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct ImportsResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ImportsResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_imports(&mut self, imports: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Import<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ImportsResponse::VT_IMPORTS, imports);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(ImportsResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(ImportsResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ImportsResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ImportsResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ImportsResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ImportsResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ImportsResponse");
+      ds.field("imports", &self.imports());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum TodosRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TodosRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TodosRequest<'a> {
+  type Inner = TodosRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TodosRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_MARKERS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TodosRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TodosRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<TodosRequest<'bldr>> {
+    let mut builder = TodosRequestBuilder::new(_fbb);
+    if let Some(x) = args.markers { builder.add_markers(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TodosRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn markers(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(TodosRequest::VT_MARKERS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TodosRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("markers", Self::VT_MARKERS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TodosRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub markers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for TodosRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TodosRequestArgs {
+      path: None, // required field
+      markers: None,
+    }
+  }
+}
+
+pub struct TodosRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TodosRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TodosRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_markers(&mut self, markers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TodosRequest::VT_MARKERS, markers);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TodosRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TodosRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TodosRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, TodosRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TodosRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TodosRequest");
+      ds.field("path", &self.path());
+      ds.field("markers", &self.markers());
+      ds.finish()
+  }
+}
+pub enum TodoOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Todo<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Todo<'a> {
+  type Inner = Todo<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Todo<'a> {
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 4;
+  pub const VT_ROW: flatbuffers::VOffsetT = 6;
+  pub const VT_MARKER: flatbuffers::VOffsetT = 8;
+  pub const VT_AUTHOR: flatbuffers::VOffsetT = 10;
+  pub const VT_MESSAGE: flatbuffers::VOffsetT = 12;
+  pub const VT_CONTEXT: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Todo { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TodoArgs<'args>
+  ) -> flatbuffers::WIPOffset<Todo<'bldr>> {
+    let mut builder = TodoBuilder::new(_fbb);
+    if let Some(x) = args.context { builder.add_context(x); }
+    if let Some(x) = args.message { builder.add_message(x); }
+    if let Some(x) = args.author { builder.add_author(x); }
+    if let Some(x) = args.marker { builder.add_marker(x); }
+    builder.add_row(args.row);
+    if let Some(x) = args.location { builder.add_location(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(Todo::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn row(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Todo::VT_ROW, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn marker(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Todo::VT_MARKER, None).unwrap()}
+  }
+  #[inline]
+  pub fn author(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Todo::VT_AUTHOR, None)}
+  }
+  #[inline]
+  pub fn message(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Todo::VT_MESSAGE, None).unwrap()}
+  }
+  #[inline]
+  pub fn context(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Todo::VT_CONTEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Todo<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<u32>("row", Self::VT_ROW, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("marker", Self::VT_MARKER, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("author", Self::VT_AUTHOR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("context", Self::VT_CONTEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TodoArgs<'a> {
+    pub location: Option<&'a Location>,
+    pub row: u32,
+    pub marker: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub author: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub context: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for TodoArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TodoArgs {
+      location: None,
+      row: 0,
+      marker: None, // required field
+      author: None,
+      message: None, // required field
+      context: None,
+    }
+  }
+}
+
+pub struct TodoBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TodoBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(Todo::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_row(&mut self, row: u32) {
+    self.fbb_.push_slot::<u32>(Todo::VT_ROW, row, 0);
+  }
+  #[inline]
+  pub fn add_marker(&mut self, marker: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Todo::VT_MARKER, marker);
+  }
+  #[inline]
+  pub fn add_author(&mut self, author: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Todo::VT_AUTHOR, author);
+  }
+  #[inline]
+  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Todo::VT_MESSAGE, message);
+  }
+  #[inline]
+  pub fn add_context(&mut self, context: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Todo::VT_CONTEXT, context);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TodoBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TodoBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Todo<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Todo::VT_MARKER,"marker");
+    self.fbb_.required(o, Todo::VT_MESSAGE,"message");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Todo<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Todo");
+      ds.field("location", &self.location());
+      ds.field("row", &self.row());
+      ds.field("marker", &self.marker());
+      ds.field("author", &self.author());
+      ds.field("message", &self.message());
+      ds.field("context", &self.context());
+      ds.finish()
+  }
+}
+pub enum TodosResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TodosResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TodosResponse<'a> {
+  type Inner = TodosResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TodosResponse<'a> {
+  pub const VT_TODOS: flatbuffers::VOffsetT = 4;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TodosResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TodosResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<TodosResponse<'bldr>> {
+    let mut builder = TodosResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.todos { builder.add_todos(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn todos(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Todo<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Todo>>>>(TodosResponse::VT_TODOS, None)}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TodosResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(TodosResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for TodosResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Todo>>>>("todos", Self::VT_TODOS, false)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TodosResponseArgs<'a> {
+    pub todos: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Todo<'a>>>>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for TodosResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TodosResponseArgs {
+      todos: None,
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct TodosResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TodosResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_todos(&mut self, todos: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Todo<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TodosResponse::VT_TODOS, todos);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(TodosResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(TodosResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TodosResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TodosResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TodosResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TodosResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TodosResponse");
+      ds.field("todos", &self.todos());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum ClonesRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ClonesRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ClonesRequest<'a> {
+  type Inner = ClonesRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ClonesRequest<'a> {
+  pub const VT_MIN_NODES: flatbuffers::VOffsetT = 4;
+  pub const VT_IGNORE_IDENTIFIERS: flatbuffers::VOffsetT = 6;
+  pub const VT_IGNORE_LITERALS: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ClonesRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ClonesRequestArgs
+  ) -> flatbuffers::WIPOffset<ClonesRequest<'bldr>> {
+    let mut builder = ClonesRequestBuilder::new(_fbb);
+    builder.add_min_nodes(args.min_nodes);
+    builder.add_ignore_literals(args.ignore_literals);
+    builder.add_ignore_identifiers(args.ignore_identifiers);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn min_nodes(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ClonesRequest::VT_MIN_NODES, Some(40)).unwrap()}
+  }
+  #[inline]
+  pub fn ignore_identifiers(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ClonesRequest::VT_IGNORE_IDENTIFIERS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn ignore_literals(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ClonesRequest::VT_IGNORE_LITERALS, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ClonesRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("min_nodes", Self::VT_MIN_NODES, false)?
+     .visit_field::<bool>("ignore_identifiers", Self::VT_IGNORE_IDENTIFIERS, false)?
+     .visit_field::<bool>("ignore_literals", Self::VT_IGNORE_LITERALS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ClonesRequestArgs {
+    pub min_nodes: u32,
+    pub ignore_identifiers: bool,
+    pub ignore_literals: bool,
+}
+impl<'a> Default for ClonesRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    ClonesRequestArgs {
+      min_nodes: 40,
+      ignore_identifiers: false,
+      ignore_literals: false,
+    }
+  }
+}
+
+pub struct ClonesRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ClonesRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_min_nodes(&mut self, min_nodes: u32) {
+    self.fbb_.push_slot::<u32>(ClonesRequest::VT_MIN_NODES, min_nodes, 40);
+  }
+  #[inline]
+  pub fn add_ignore_identifiers(&mut self, ignore_identifiers: bool) {
+    self.fbb_.push_slot::<bool>(ClonesRequest::VT_IGNORE_IDENTIFIERS, ignore_identifiers, false);
+  }
+  #[inline]
+  pub fn add_ignore_literals(&mut self, ignore_literals: bool) {
+    self.fbb_.push_slot::<bool>(ClonesRequest::VT_IGNORE_LITERALS, ignore_literals, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ClonesRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ClonesRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ClonesRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ClonesRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ClonesRequest");
+      ds.field("min_nodes", &self.min_nodes());
+      ds.field("ignore_identifiers", &self.ignore_identifiers());
+      ds.field("ignore_literals", &self.ignore_literals());
+      ds.finish()
+  }
+}
+pub enum CloneInstanceOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CloneInstance<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CloneInstance<'a> {
+  type Inner = CloneInstance<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CloneInstance<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 6;
+  pub const VT_KIND: flatbuffers::VOffsetT = 8;
+  pub const VT_START_ROW: flatbuffers::VOffsetT = 10;
+  pub const VT_END_ROW: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CloneInstance { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CloneInstanceArgs<'args>
+  ) -> flatbuffers::WIPOffset<CloneInstance<'bldr>> {
+    let mut builder = CloneInstanceBuilder::new(_fbb);
+    builder.add_end_row(args.end_row);
+    builder.add_start_row(args.start_row);
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    if let Some(x) = args.location { builder.add_location(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CloneInstance::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(CloneInstance::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn kind(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CloneInstance::VT_KIND, None).unwrap()}
+  }
+  #[inline]
+  pub fn start_row(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(CloneInstance::VT_START_ROW, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn end_row(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(CloneInstance::VT_END_ROW, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for CloneInstance<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, true)?
+     .visit_field::<u32>("start_row", Self::VT_START_ROW, false)?
+     .visit_field::<u32>("end_row", Self::VT_END_ROW, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CloneInstanceArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub location: Option<&'a Location>,
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub start_row: u32,
+    pub end_row: u32,
+}
+impl<'a> Default for CloneInstanceArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CloneInstanceArgs {
+      path: None, // required field
+      location: None,
+      kind: None, // required field
+      start_row: 0,
+      end_row: 0,
+    }
+  }
+}
+
+pub struct CloneInstanceBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CloneInstanceBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CloneInstance::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(CloneInstance::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CloneInstance::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_start_row(&mut self, start_row: u32) {
+    self.fbb_.push_slot::<u32>(CloneInstance::VT_START_ROW, start_row, 0);
+  }
+  #[inline]
+  pub fn add_end_row(&mut self, end_row: u32) {
+    self.fbb_.push_slot::<u32>(CloneInstance::VT_END_ROW, end_row, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CloneInstanceBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CloneInstanceBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CloneInstance<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, CloneInstance::VT_PATH,"path");
+    self.fbb_.required(o, CloneInstance::VT_KIND,"kind");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CloneInstance<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CloneInstance");
+      ds.field("path", &self.path());
+      ds.field("location", &self.location());
+      ds.field("kind", &self.kind());
+      ds.field("start_row", &self.start_row());
+      ds.field("end_row", &self.end_row());
+      ds.finish()
+  }
+}
+pub enum CloneGroupOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CloneGroup<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CloneGroup<'a> {
+  type Inner = CloneGroup<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CloneGroup<'a> {
+  pub const VT_HASH: flatbuffers::VOffsetT = 4;
+  pub const VT_NODE_COUNT: flatbuffers::VOffsetT = 6;
+  pub const VT_INSTANCES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CloneGroup { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CloneGroupArgs<'args>
+  ) -> flatbuffers::WIPOffset<CloneGroup<'bldr>> {
+    let mut builder = CloneGroupBuilder::new(_fbb);
+    builder.add_hash(args.hash);
+    if let Some(x) = args.instances { builder.add_instances(x); }
+    builder.add_node_count(args.node_count);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(CloneGroup::VT_HASH, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn node_count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(CloneGroup::VT_NODE_COUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn instances(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CloneInstance<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CloneInstance>>>>(CloneGroup::VT_INSTANCES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CloneGroup<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("hash", Self::VT_HASH, false)?
+     .visit_field::<u32>("node_count", Self::VT_NODE_COUNT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CloneInstance>>>>("instances", Self::VT_INSTANCES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CloneGroupArgs<'a> {
+    pub hash: u64,
+    pub node_count: u32,
+    pub instances: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CloneInstance<'a>>>>>,
+}
+impl<'a> Default for CloneGroupArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CloneGroupArgs {
+      hash: 0,
+      node_count: 0,
+      instances: None,
+    }
+  }
+}
+
+pub struct CloneGroupBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CloneGroupBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_hash(&mut self, hash: u64) {
+    self.fbb_.push_slot::<u64>(CloneGroup::VT_HASH, hash, 0);
+  }
+  #[inline]
+  pub fn add_node_count(&mut self, node_count: u32) {
+    self.fbb_.push_slot::<u32>(CloneGroup::VT_NODE_COUNT, node_count, 0);
+  }
+  #[inline]
+  pub fn add_instances(&mut self, instances: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CloneInstance<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CloneGroup::VT_INSTANCES, instances);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CloneGroupBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CloneGroupBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CloneGroup<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CloneGroup<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CloneGroup");
+      ds.field("hash", &self.hash());
+      ds.field("node_count", &self.node_count());
+      ds.field("instances", &self.instances());
+      ds.finish()
+  }
+}
+pub enum ClonesResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ClonesResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ClonesResponse<'a> {
+  type Inner = ClonesResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ClonesResponse<'a> {
+  pub const VT_GROUPS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ClonesResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ClonesResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<ClonesResponse<'bldr>> {
+    let mut builder = ClonesResponseBuilder::new(_fbb);
+    if let Some(x) = args.groups { builder.add_groups(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn groups(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CloneGroup<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CloneGroup>>>>(ClonesResponse::VT_GROUPS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ClonesResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CloneGroup>>>>("groups", Self::VT_GROUPS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ClonesResponseArgs<'a> {
+    pub groups: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CloneGroup<'a>>>>>,
+}
+impl<'a> Default for ClonesResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ClonesResponseArgs {
+      groups: None,
+    }
+  }
+}
+
+pub struct ClonesResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ClonesResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_groups(&mut self, groups: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CloneGroup<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ClonesResponse::VT_GROUPS, groups);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ClonesResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ClonesResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ClonesResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ClonesResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ClonesResponse");
+      ds.field("groups", &self.groups());
+      ds.finish()
+  }
+}
+pub enum PluginRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PluginRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PluginRequest<'a> {
+  type Inner = PluginRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PluginRequest<'a> {
+  pub const VT_PLUGIN: flatbuffers::VOffsetT = 4;
+  pub const VT_METHOD: flatbuffers::VOffsetT = 6;
+  pub const VT_PAYLOAD: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PluginRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PluginRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<PluginRequest<'bldr>> {
+    let mut builder = PluginRequestBuilder::new(_fbb);
+    if let Some(x) = args.payload { builder.add_payload(x); }
+    if let Some(x) = args.method { builder.add_method(x); }
+    if let Some(x) = args.plugin { builder.add_plugin(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn plugin(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PluginRequest::VT_PLUGIN, None).unwrap()}
+  }
+  #[inline]
+  pub fn method(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PluginRequest::VT_METHOD, None)}
+  }
+  #[inline]
+  pub fn payload(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(PluginRequest::VT_PAYLOAD, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PluginRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("plugin", Self::VT_PLUGIN, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("method", Self::VT_METHOD, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("payload", Self::VT_PAYLOAD, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PluginRequestArgs<'a> {
+    pub plugin: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub method: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub payload: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for PluginRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PluginRequestArgs {
+      plugin: None, // required field
+      method: None,
+      payload: None,
+    }
+  }
+}
+
+pub struct PluginRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PluginRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_plugin(&mut self, plugin: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PluginRequest::VT_PLUGIN, plugin);
+  }
+  #[inline]
+  pub fn add_method(&mut self, method: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PluginRequest::VT_METHOD, method);
+  }
+  #[inline]
+  pub fn add_payload(&mut self, payload: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PluginRequest::VT_PAYLOAD, payload);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PluginRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PluginRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PluginRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, PluginRequest::VT_PLUGIN,"plugin");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PluginRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PluginRequest");
+      ds.field("plugin", &self.plugin());
+      ds.field("method", &self.method());
+      ds.field("payload", &self.payload());
+      ds.finish()
+  }
+}
+pub enum PluginResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PluginResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PluginResponse<'a> {
+  type Inner = PluginResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PluginResponse<'a> {
+  pub const VT_PAYLOAD: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PluginResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PluginResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<PluginResponse<'bldr>> {
+    let mut builder = PluginResponseBuilder::new(_fbb);
+    if let Some(x) = args.payload { builder.add_payload(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn payload(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(PluginResponse::VT_PAYLOAD, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PluginResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("payload", Self::VT_PAYLOAD, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PluginResponseArgs<'a> {
+    pub payload: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for PluginResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PluginResponseArgs {
+      payload: None,
+    }
+  }
+}
+
+pub struct PluginResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PluginResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_payload(&mut self, payload: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PluginResponse::VT_PAYLOAD, payload);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PluginResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PluginResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PluginResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PluginResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PluginResponse");
+      ds.field("payload", &self.payload());
+      ds.finish()
+  }
+}
+pub enum RunPluginRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RunPluginRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RunPluginRequest<'a> {
+  type Inner = RunPluginRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RunPluginRequest<'a> {
+  pub const VT_PLUGIN: flatbuffers::VOffsetT = 4;
+  pub const VT_PATH: flatbuffers::VOffsetT = 6;
+  pub const VT_ARGS: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RunPluginRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RunPluginRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<RunPluginRequest<'bldr>> {
+    let mut builder = RunPluginRequestBuilder::new(_fbb);
+    if let Some(x) = args.args { builder.add_args(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    if let Some(x) = args.plugin { builder.add_plugin(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn plugin(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(RunPluginRequest::VT_PLUGIN, None).unwrap()}
+  }
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(RunPluginRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn args(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(RunPluginRequest::VT_ARGS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for RunPluginRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("plugin", Self::VT_PLUGIN, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("args", Self::VT_ARGS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RunPluginRequestArgs<'a> {
+    pub plugin: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub args: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for RunPluginRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RunPluginRequestArgs {
+      plugin: None, // required field
+      path: None, // required field
+      args: None,
+    }
+  }
+}
+
+pub struct RunPluginRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RunPluginRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_plugin(&mut self, plugin: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RunPluginRequest::VT_PLUGIN, plugin);
+  }
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RunPluginRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_args(&mut self, args: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RunPluginRequest::VT_ARGS, args);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RunPluginRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RunPluginRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RunPluginRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, RunPluginRequest::VT_PLUGIN,"plugin");
+    self.fbb_.required(o, RunPluginRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RunPluginRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RunPluginRequest");
+      ds.field("plugin", &self.plugin());
+      ds.field("path", &self.path());
+      ds.field("args", &self.args());
+      ds.finish()
+  }
+}
+pub enum RunPluginResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RunPluginResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RunPluginResponse<'a> {
+  type Inner = RunPluginResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RunPluginResponse<'a> {
+  pub const VT_VERSION: flatbuffers::VOffsetT = 4;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 6;
+  pub const VT_RESULTS: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RunPluginResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RunPluginResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<RunPluginResponse<'bldr>> {
+    let mut builder = RunPluginResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    if let Some(x) = args.results { builder.add_results(x); }
+    builder.add_version(args.version);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(RunPluginResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(RunPluginResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn results(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(RunPluginResponse::VT_RESULTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for RunPluginResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("results", Self::VT_RESULTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RunPluginResponseArgs<'a> {
+    pub version: u32,
+    pub content_hash: u64,
+    pub results: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for RunPluginResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RunPluginResponseArgs {
+      version: 0,
+      content_hash: 0,
+      results: None,
+    }
+  }
+}
+
+pub struct RunPluginResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RunPluginResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(RunPluginResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(RunPluginResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn add_results(&mut self, results: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RunPluginResponse::VT_RESULTS, results);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RunPluginResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RunPluginResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RunPluginResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RunPluginResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RunPluginResponse");
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.field("results", &self.results());
+      ds.finish()
+  }
+}
+pub enum ScriptRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScriptRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScriptRequest<'a> {
+  type Inner = ScriptRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ScriptRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_SCRIPT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ScriptRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ScriptRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScriptRequest<'bldr>> {
+    let mut builder = ScriptRequestBuilder::new(_fbb);
+    if let Some(x) = args.script { builder.add_script(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ScriptRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn script(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ScriptRequest::VT_SCRIPT, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ScriptRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("script", Self::VT_SCRIPT, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ScriptRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub script: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ScriptRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ScriptRequestArgs {
+      path: None, // required field
+      script: None, // required field
+    }
+  }
+}
+
+pub struct ScriptRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ScriptRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScriptRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_script(&mut self, script: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScriptRequest::VT_SCRIPT, script);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ScriptRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ScriptRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScriptRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ScriptRequest::VT_PATH,"path");
+    self.fbb_.required(o, ScriptRequest::VT_SCRIPT,"script");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ScriptRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ScriptRequest");
+      ds.field("path", &self.path());
+      ds.field("script", &self.script());
+      ds.finish()
+  }
+}
+pub enum ScriptResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScriptResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScriptResponse<'a> {
+  type Inner = ScriptResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ScriptResponse<'a> {
+  pub const VT_VERSION: flatbuffers::VOffsetT = 4;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 6;
+  pub const VT_OUTPUT: flatbuffers::VOffsetT = 8;
+  pub const VT_RESULT: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ScriptResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ScriptResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScriptResponse<'bldr>> {
+    let mut builder = ScriptResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    if let Some(x) = args.result { builder.add_result(x); }
+    if let Some(x) = args.output { builder.add_output(x); }
+    builder.add_version(args.version);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ScriptResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(ScriptResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn output(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(ScriptResponse::VT_OUTPUT, None).unwrap()}
+  }
+  #[inline]
+  pub fn result(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ScriptResponse::VT_RESULT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ScriptResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("output", Self::VT_OUTPUT, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("result", Self::VT_RESULT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ScriptResponseArgs<'a> {
+    pub version: u32,
+    pub content_hash: u64,
+    pub output: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub result: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for ScriptResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ScriptResponseArgs {
+      version: 0,
+      content_hash: 0,
+      output: None, // required field
+      result: None,
+    }
+  }
+}
+
+pub struct ScriptResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ScriptResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(ScriptResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(ScriptResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn add_output(&mut self, output: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScriptResponse::VT_OUTPUT, output);
+  }
+  #[inline]
+  pub fn add_result(&mut self, result: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScriptResponse::VT_RESULT, result);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ScriptResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ScriptResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScriptResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ScriptResponse::VT_OUTPUT,"output");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ScriptResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ScriptResponse");
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.field("output", &self.output());
+      ds.field("result", &self.result());
+      ds.finish()
+  }
+}
+pub enum QueryRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryRequest<'a> {
+  type Inner = QueryRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_QUERY: flatbuffers::VOffsetT = 6;
+  pub const VT_LANG: flatbuffers::VOffsetT = 8;
+  pub const VT_FILTER: flatbuffers::VOffsetT = 10;
+  pub const VT_CURSOR: flatbuffers::VOffsetT = 12;
+  pub const VT_MAX_MATCHES: flatbuffers::VOffsetT = 14;
+  pub const VT_WITH_TEXT: flatbuffers::VOffsetT = 16;
+  pub const VT_COUNT_ONLY: flatbuffers::VOffsetT = 18;
+  pub const VT_GROUPED: flatbuffers::VOffsetT = 20;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args QueryRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<QueryRequest<'bldr>> {
+    let mut builder = QueryRequestBuilder::new(_fbb);
+    builder.add_max_matches(args.max_matches);
+    if let Some(x) = args.cursor { builder.add_cursor(x); }
+    if let Some(x) = args.filter { builder.add_filter(x); }
+    if let Some(x) = args.lang { builder.add_lang(x); }
+    if let Some(x) = args.query { builder.add_query(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.add_grouped(args.grouped);
+    builder.add_count_only(args.count_only);
+    builder.add_with_text(args.with_text);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(QueryRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn query(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(QueryRequest::VT_QUERY, None).unwrap()}
+  }
+  #[inline]
+  pub fn lang(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(QueryRequest::VT_LANG, None)}
+  }
+  #[inline]
+  pub fn filter(&self) -> Option<WorkspaceFilter<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<WorkspaceFilter>>(QueryRequest::VT_FILTER, None)}
+  }
+  #[inline]
+  pub fn cursor(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(QueryRequest::VT_CURSOR, None)}
+  }
+  #[inline]
+  pub fn max_matches(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(QueryRequest::VT_MAX_MATCHES, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn with_text(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(QueryRequest::VT_WITH_TEXT, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn count_only(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(QueryRequest::VT_COUNT_ONLY, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn grouped(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(QueryRequest::VT_GROUPED, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for QueryRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("query", Self::VT_QUERY, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("lang", Self::VT_LANG, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<WorkspaceFilter>>("filter", Self::VT_FILTER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("cursor", Self::VT_CURSOR, false)?
+     .visit_field::<u32>("max_matches", Self::VT_MAX_MATCHES, false)?
+     .visit_field::<bool>("with_text", Self::VT_WITH_TEXT, false)?
+     .visit_field::<bool>("count_only", Self::VT_COUNT_ONLY, false)?
+     .visit_field::<bool>("grouped", Self::VT_GROUPED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub query: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub lang: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub filter: Option<flatbuffers::WIPOffset<WorkspaceFilter<'a>>>,
+    pub cursor: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub max_matches: u32,
+    pub with_text: bool,
+    pub count_only: bool,
+    pub grouped: bool,
+}
+impl<'a> Default for QueryRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    QueryRequestArgs {
+      path: None, // required field
+      query: None, // required field
+      lang: None,
+      filter: None,
+      cursor: None,
+      max_matches: 0,
+      with_text: false,
+      count_only: false,
+      grouped: false,
+    }
+  }
+}
+
+pub struct QueryRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_query(&mut self, query: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryRequest::VT_QUERY, query);
+  }
+  #[inline]
+  pub fn add_lang(&mut self, lang: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryRequest::VT_LANG, lang);
+  }
+  #[inline]
+  pub fn add_filter(&mut self, filter: flatbuffers::WIPOffset<WorkspaceFilter<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<WorkspaceFilter>>(QueryRequest::VT_FILTER, filter);
+  }
+  #[inline]
+  pub fn add_cursor(&mut self, cursor: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryRequest::VT_CURSOR, cursor);
+  }
+  #[inline]
+  pub fn add_max_matches(&mut self, max_matches: u32) {
+    self.fbb_.push_slot::<u32>(QueryRequest::VT_MAX_MATCHES, max_matches, 0);
+  }
+  #[inline]
+  pub fn add_with_text(&mut self, with_text: bool) {
+    self.fbb_.push_slot::<bool>(QueryRequest::VT_WITH_TEXT, with_text, false);
+  }
+  #[inline]
+  pub fn add_count_only(&mut self, count_only: bool) {
+    self.fbb_.push_slot::<bool>(QueryRequest::VT_COUNT_ONLY, count_only, false);
+  }
+  #[inline]
+  pub fn add_grouped(&mut self, grouped: bool) {
+    self.fbb_.push_slot::<bool>(QueryRequest::VT_GROUPED, grouped, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, QueryRequest::VT_PATH,"path");
+    self.fbb_.required(o, QueryRequest::VT_QUERY,"query");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryRequest");
+      ds.field("path", &self.path());
+      ds.field("query", &self.query());
+      ds.field("lang", &self.lang());
+      ds.field("filter", &self.filter());
+      ds.field("cursor", &self.cursor());
+      ds.field("max_matches", &self.max_matches());
+      ds.field("with_text", &self.with_text());
+      ds.field("count_only", &self.count_only());
+      ds.field("grouped", &self.grouped());
+      ds.finish()
+  }
+}
+pub enum QueryCaptureOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryCapture<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryCapture<'a> {
+  type Inner = QueryCapture<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryCapture<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 6;
+  pub const VT_START_POINT: flatbuffers::VOffsetT = 8;
+  pub const VT_END_POINT: flatbuffers::VOffsetT = 10;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryCapture { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args QueryCaptureArgs<'args>
+  ) -> flatbuffers::WIPOffset<QueryCapture<'bldr>> {
+    let mut builder = QueryCaptureBuilder::new(_fbb);
+    if let Some(x) = args.text { builder.add_text(x); }
+    if let Some(x) = args.end_point { builder.add_end_point(x); }
+    if let Some(x) = args.start_point { builder.add_start_point(x); }
+    if let Some(x) = args.location { builder.add_location(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(QueryCapture::VT_NAME, None).unwrap()}
+  }
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(QueryCapture::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn start_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(QueryCapture::VT_START_POINT, None)}
+  }
+  #[inline]
+  pub fn end_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(QueryCapture::VT_END_POINT, None)}
+  }
+  #[inline]
+  pub fn text(&self) -> Option<flatbuffers::Vector<'a, u16>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(QueryCapture::VT_TEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for QueryCapture<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<Point>("start_point", Self::VT_START_POINT, false)?
+     .visit_field::<Point>("end_point", Self::VT_END_POINT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("text", Self::VT_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryCaptureArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub location: Option<&'a Location>,
+    pub start_point: Option<&'a Point>,
+    pub end_point: Option<&'a Point>,
+    pub text: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+}
+impl<'a> Default for QueryCaptureArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    QueryCaptureArgs {
+      name: None, // required field
+      location: None,
+      start_point: None,
+      end_point: None,
+      text: None,
+    }
+  }
+}
+
+pub struct QueryCaptureBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryCaptureBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryCapture::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(QueryCapture::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_start_point(&mut self, start_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(QueryCapture::VT_START_POINT, start_point);
+  }
+  #[inline]
+  pub fn add_end_point(&mut self, end_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(QueryCapture::VT_END_POINT, end_point);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryCapture::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryCaptureBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryCaptureBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryCapture<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, QueryCapture::VT_NAME,"name");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryCapture<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryCapture");
+      ds.field("name", &self.name());
+      ds.field("location", &self.location());
+      ds.field("start_point", &self.start_point());
+      ds.field("end_point", &self.end_point());
+      ds.field("text", &self.text());
+      ds.finish()
+  }
+}
+pub enum QueryCaptureGroupOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryCaptureGroup<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryCaptureGroup<'a> {
+  type Inner = QueryCaptureGroup<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryCaptureGroup<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_QUANTIFIER: flatbuffers::VOffsetT = 6;
+  pub const VT_CAPTURES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryCaptureGroup { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args QueryCaptureGroupArgs<'args>
+  ) -> flatbuffers::WIPOffset<QueryCaptureGroup<'bldr>> {
+    let mut builder = QueryCaptureGroupBuilder::new(_fbb);
+    if let Some(x) = args.captures { builder.add_captures(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_quantifier(args.quantifier);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(QueryCaptureGroup::VT_NAME, None).unwrap()}
+  }
+  #[inline]
+  pub fn quantifier(&self) -> CaptureQuantifier {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<CaptureQuantifier>(QueryCaptureGroup::VT_QUANTIFIER, Some(CaptureQuantifier::Zero)).unwrap()}
+  }
+  #[inline]
+  pub fn captures(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCapture<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCapture>>>>(QueryCaptureGroup::VT_CAPTURES, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for QueryCaptureGroup<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+     .visit_field::<CaptureQuantifier>("quantifier", Self::VT_QUANTIFIER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<QueryCapture>>>>("captures", Self::VT_CAPTURES, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryCaptureGroupArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub quantifier: CaptureQuantifier,
+    pub captures: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCapture<'a>>>>>,
+}
+impl<'a> Default for QueryCaptureGroupArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    QueryCaptureGroupArgs {
+      name: None, // required field
+      quantifier: CaptureQuantifier::Zero,
+      captures: None, // required field
+    }
+  }
+}
+
+pub struct QueryCaptureGroupBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryCaptureGroupBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryCaptureGroup::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_quantifier(&mut self, quantifier: CaptureQuantifier) {
+    self.fbb_.push_slot::<CaptureQuantifier>(QueryCaptureGroup::VT_QUANTIFIER, quantifier, CaptureQuantifier::Zero);
+  }
+  #[inline]
+  pub fn add_captures(&mut self, captures: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<QueryCapture<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryCaptureGroup::VT_CAPTURES, captures);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryCaptureGroupBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryCaptureGroupBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryCaptureGroup<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, QueryCaptureGroup::VT_NAME,"name");
+    self.fbb_.required(o, QueryCaptureGroup::VT_CAPTURES,"captures");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryCaptureGroup<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryCaptureGroup");
+      ds.field("name", &self.name());
+      ds.field("quantifier", &self.quantifier());
+      ds.field("captures", &self.captures());
+      ds.finish()
+  }
+}
+pub enum QueryMatchOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryMatch<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryMatch<'a> {
+  type Inner = QueryMatch<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryMatch<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_PATTERN_INDEX: flatbuffers::VOffsetT = 6;
+  pub const VT_CAPTURES: flatbuffers::VOffsetT = 8;
+  pub const VT_GROUPS: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryMatch { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args QueryMatchArgs<'args>
+  ) -> flatbuffers::WIPOffset<QueryMatch<'bldr>> {
+    let mut builder = QueryMatchBuilder::new(_fbb);
+    if let Some(x) = args.groups { builder.add_groups(x); }
+    if let Some(x) = args.captures { builder.add_captures(x); }
+    builder.add_pattern_index(args.pattern_index);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(QueryMatch::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn pattern_index(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(QueryMatch::VT_PATTERN_INDEX, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn captures(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCapture<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCapture>>>>(QueryMatch::VT_CAPTURES, None)}
+  }
+  #[inline]
+  pub fn groups(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCaptureGroup<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCaptureGroup>>>>(QueryMatch::VT_GROUPS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for QueryMatch<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<u32>("pattern_index", Self::VT_PATTERN_INDEX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<QueryCapture>>>>("captures", Self::VT_CAPTURES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<QueryCaptureGroup>>>>("groups", Self::VT_GROUPS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryMatchArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub pattern_index: u32,
+    pub captures: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCapture<'a>>>>>,
+    pub groups: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCaptureGroup<'a>>>>>,
+}
+impl<'a> Default for QueryMatchArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    QueryMatchArgs {
+      path: None, // required field
+      pattern_index: 0,
+      captures: None,
+      groups: None,
+    }
+  }
+}
+
+pub struct QueryMatchBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryMatchBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryMatch::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_pattern_index(&mut self, pattern_index: u32) {
+    self.fbb_.push_slot::<u32>(QueryMatch::VT_PATTERN_INDEX, pattern_index, 0);
+  }
+  #[inline]
+  pub fn add_captures(&mut self, captures: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<QueryCapture<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryMatch::VT_CAPTURES, captures);
+  }
+  #[inline]
+  pub fn add_groups(&mut self, groups: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<QueryCaptureGroup<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryMatch::VT_GROUPS, groups);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryMatchBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryMatchBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryMatch<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, QueryMatch::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryMatch<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryMatch");
+      ds.field("path", &self.path());
+      ds.field("pattern_index", &self.pattern_index());
+      ds.field("captures", &self.captures());
+      ds.field("groups", &self.groups());
+      ds.finish()
+  }
+}
+pub enum QueryCaptureCountOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryCaptureCount<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryCaptureCount<'a> {
+  type Inner = QueryCaptureCount<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryCaptureCount<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_COUNT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryCaptureCount { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args QueryCaptureCountArgs<'args>
+  ) -> flatbuffers::WIPOffset<QueryCaptureCount<'bldr>> {
+    let mut builder = QueryCaptureCountBuilder::new(_fbb);
+    builder.add_count(args.count);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(QueryCaptureCount::VT_NAME, None).unwrap()}
+  }
+  #[inline]
+  pub fn count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(QueryCaptureCount::VT_COUNT, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for QueryCaptureCount<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+     .visit_field::<u32>("count", Self::VT_COUNT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryCaptureCountArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub count: u32,
+}
+impl<'a> Default for QueryCaptureCountArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    QueryCaptureCountArgs {
+      name: None, // required field
+      count: 0,
+    }
+  }
+}
+
+pub struct QueryCaptureCountBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryCaptureCountBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryCaptureCount::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_count(&mut self, count: u32) {
+    self.fbb_.push_slot::<u32>(QueryCaptureCount::VT_COUNT, count, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryCaptureCountBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryCaptureCountBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryCaptureCount<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, QueryCaptureCount::VT_NAME,"name");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryCaptureCount<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryCaptureCount");
+      ds.field("name", &self.name());
+      ds.field("count", &self.count());
+      ds.finish()
+  }
+}
+pub enum QueryFileCountOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryFileCount<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryFileCount<'a> {
+  type Inner = QueryFileCount<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryFileCount<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_MATCHES: flatbuffers::VOffsetT = 6;
+  pub const VT_CAPTURES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryFileCount { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args QueryFileCountArgs<'args>
+  ) -> flatbuffers::WIPOffset<QueryFileCount<'bldr>> {
+    let mut builder = QueryFileCountBuilder::new(_fbb);
+    if let Some(x) = args.captures { builder.add_captures(x); }
+    builder.add_matches(args.matches);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(QueryFileCount::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn matches(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(QueryFileCount::VT_MATCHES, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn captures(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCaptureCount<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCaptureCount>>>>(QueryFileCount::VT_CAPTURES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for QueryFileCount<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<u32>("matches", Self::VT_MATCHES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<QueryCaptureCount>>>>("captures", Self::VT_CAPTURES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryFileCountArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub matches: u32,
+    pub captures: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryCaptureCount<'a>>>>>,
+}
+impl<'a> Default for QueryFileCountArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    QueryFileCountArgs {
+      path: None, // required field
+      matches: 0,
+      captures: None,
+    }
+  }
+}
+
+pub struct QueryFileCountBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryFileCountBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryFileCount::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_matches(&mut self, matches: u32) {
+    self.fbb_.push_slot::<u32>(QueryFileCount::VT_MATCHES, matches, 0);
+  }
+  #[inline]
+  pub fn add_captures(&mut self, captures: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<QueryCaptureCount<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryFileCount::VT_CAPTURES, captures);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryFileCountBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryFileCountBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryFileCount<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, QueryFileCount::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryFileCount<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryFileCount");
+      ds.field("path", &self.path());
+      ds.field("matches", &self.matches());
+      ds.field("captures", &self.captures());
+      ds.finish()
+  }
+}
+pub enum QueryResultsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryResults<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryResults<'a> {
+  type Inner = QueryResults<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryResults<'a> {
+  pub const VT_MATCHES: flatbuffers::VOffsetT = 4;
+  pub const VT_CURSOR: flatbuffers::VOffsetT = 6;
+  pub const VT_DONE: flatbuffers::VOffsetT = 8;
+  pub const VT_COUNTS: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryResults { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args QueryResultsArgs<'args>
+  ) -> flatbuffers::WIPOffset<QueryResults<'bldr>> {
+    let mut builder = QueryResultsBuilder::new(_fbb);
+    if let Some(x) = args.counts { builder.add_counts(x); }
+    if let Some(x) = args.cursor { builder.add_cursor(x); }
+    if let Some(x) = args.matches { builder.add_matches(x); }
+    builder.add_done(args.done);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn matches(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryMatch<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryMatch>>>>(QueryResults::VT_MATCHES, None)}
+  }
+  #[inline]
+  pub fn cursor(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(QueryResults::VT_CURSOR, None)}
+  }
+  #[inline]
+  pub fn done(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(QueryResults::VT_DONE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn counts(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryFileCount<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryFileCount>>>>(QueryResults::VT_COUNTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for QueryResults<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<QueryMatch>>>>("matches", Self::VT_MATCHES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("cursor", Self::VT_CURSOR, false)?
+     .visit_field::<bool>("done", Self::VT_DONE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<QueryFileCount>>>>("counts", Self::VT_COUNTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryResultsArgs<'a> {
+    pub matches: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryMatch<'a>>>>>,
+    pub cursor: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub done: bool,
+    pub counts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryFileCount<'a>>>>>,
+}
+impl<'a> Default for QueryResultsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    QueryResultsArgs {
+      matches: None,
+      cursor: None,
+      done: false,
+      counts: None,
+    }
+  }
+}
+
+pub struct QueryResultsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryResultsBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_matches(&mut self, matches: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<QueryMatch<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryResults::VT_MATCHES, matches);
+  }
+  #[inline]
+  pub fn add_cursor(&mut self, cursor: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryResults::VT_CURSOR, cursor);
+  }
+  #[inline]
+  pub fn add_done(&mut self, done: bool) {
+    self.fbb_.push_slot::<bool>(QueryResults::VT_DONE, done, false);
+  }
+  #[inline]
+  pub fn add_counts(&mut self, counts: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<QueryFileCount<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryResults::VT_COUNTS, counts);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryResultsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryResultsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryResults<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryResults<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryResults");
+      ds.field("matches", &self.matches());
+      ds.field("cursor", &self.cursor());
+      ds.field("done", &self.done());
+      ds.field("counts", &self.counts());
+      ds.finish()
+  }
+}
+pub enum CancelRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CancelRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CancelRequest<'a> {
+  type Inner = CancelRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CancelRequest<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CancelRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CancelRequestArgs
+  ) -> flatbuffers::WIPOffset<CancelRequest<'bldr>> {
+    let mut builder = CancelRequestBuilder::new(_fbb);
+    builder.add_id(args.id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(CancelRequest::VT_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for CancelRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("id", Self::VT_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CancelRequestArgs {
+    pub id: u64,
+}
+impl<'a> Default for CancelRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    CancelRequestArgs {
+      id: 0,
+    }
+  }
+}
+
+pub struct CancelRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CancelRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_id(&mut self, id: u64) {
+    self.fbb_.push_slot::<u64>(CancelRequest::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CancelRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CancelRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CancelRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CancelRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CancelRequest");
+      ds.field("id", &self.id());
+      ds.finish()
+  }
+}
+pub enum CancelResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CancelResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CancelResponse<'a> {
+  type Inner = CancelResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CancelResponse<'a> {
+  pub const VT_RUNNING: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CancelResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CancelResponseArgs
+  ) -> flatbuffers::WIPOffset<CancelResponse<'bldr>> {
+    let mut builder = CancelResponseBuilder::new(_fbb);
+    builder.add_running(args.running);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn running(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(CancelResponse::VT_RUNNING, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for CancelResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("running", Self::VT_RUNNING, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CancelResponseArgs {
+    pub running: bool,
+}
+impl<'a> Default for CancelResponseArgs {
+  #[inline]
+  fn default() -> Self {
+    CancelResponseArgs {
+      running: false,
+    }
+  }
+}
+
+pub struct CancelResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CancelResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_running(&mut self, running: bool) {
+    self.fbb_.push_slot::<bool>(CancelResponse::VT_RUNNING, running, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CancelResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CancelResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CancelResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CancelResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CancelResponse");
+      ds.field("running", &self.running());
+      ds.finish()
+  }
+}
+pub enum ResumeRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ResumeRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ResumeRequest<'a> {
+  type Inner = ResumeRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ResumeRequest<'a> {
+  pub const VT_TOKEN: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ResumeRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ResumeRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<ResumeRequest<'bldr>> {
+    let mut builder = ResumeRequestBuilder::new(_fbb);
+    if let Some(x) = args.token { builder.add_token(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn token(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ResumeRequest::VT_TOKEN, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ResumeRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("token", Self::VT_TOKEN, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResumeRequestArgs<'a> {
+    pub token: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ResumeRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResumeRequestArgs {
+      token: None,
+    }
+  }
+}
+
+pub struct ResumeRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ResumeRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_token(&mut self, token: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ResumeRequest::VT_TOKEN, token);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ResumeRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ResumeRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ResumeRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ResumeRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ResumeRequest");
+      ds.field("token", &self.token());
+      ds.finish()
+  }
+}
+pub enum OpenDocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OpenDocument<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OpenDocument<'a> {
+  type Inner = OpenDocument<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> OpenDocument<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 6;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 8;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OpenDocument { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args OpenDocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<OpenDocument<'bldr>> {
+    let mut builder = OpenDocumentBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.language { builder.add_language(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(OpenDocument::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn language(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(OpenDocument::VT_LANGUAGE, None).unwrap()}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(OpenDocument::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(OpenDocument::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for OpenDocument<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("language", Self::VT_LANGUAGE, true)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OpenDocumentArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for OpenDocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    OpenDocumentArgs {
+      path: None, // required field
+      language: None, // required field
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct OpenDocumentBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> OpenDocumentBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OpenDocument::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OpenDocument::VT_LANGUAGE, language);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(OpenDocument::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(OpenDocument::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> OpenDocumentBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    OpenDocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OpenDocument<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, OpenDocument::VT_PATH,"path");
+    self.fbb_.required(o, OpenDocument::VT_LANGUAGE,"language");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OpenDocument<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OpenDocument");
+      ds.field("path", &self.path());
+      ds.field("language", &self.language());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum ResumeResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ResumeResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ResumeResponse<'a> {
+  type Inner = ResumeResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ResumeResponse<'a> {
+  pub const VT_TOKEN: flatbuffers::VOffsetT = 4;
+  pub const VT_RESUMED: flatbuffers::VOffsetT = 6;
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 8;
+  pub const VT_CHANGED: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ResumeResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ResumeResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<ResumeResponse<'bldr>> {
+    let mut builder = ResumeResponseBuilder::new(_fbb);
+    if let Some(x) = args.changed { builder.add_changed(x); }
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    if let Some(x) = args.token { builder.add_token(x); }
+    builder.add_resumed(args.resumed);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn token(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ResumeResponse::VT_TOKEN, None).unwrap()}
+  }
+  #[inline]
+  pub fn resumed(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ResumeResponse::VT_RESUMED, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn documents(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<OpenDocument<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<OpenDocument>>>>(ResumeResponse::VT_DOCUMENTS, None).unwrap()}
+  }
+  #[inline]
+  pub fn changed(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(ResumeResponse::VT_CHANGED, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ResumeResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("token", Self::VT_TOKEN, true)?
+     .visit_field::<bool>("resumed", Self::VT_RESUMED, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<OpenDocument>>>>("documents", Self::VT_DOCUMENTS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("changed", Self::VT_CHANGED, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResumeResponseArgs<'a> {
+    pub token: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub resumed: bool,
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<OpenDocument<'a>>>>>,
+    pub changed: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for ResumeResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResumeResponseArgs {
+      token: None, // required field
+      resumed: false,
+      documents: None, // required field
+      changed: None, // required field
+    }
+  }
+}
+
+pub struct ResumeResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ResumeResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_token(&mut self, token: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ResumeResponse::VT_TOKEN, token);
+  }
+  #[inline]
+  pub fn add_resumed(&mut self, resumed: bool) {
+    self.fbb_.push_slot::<bool>(ResumeResponse::VT_RESUMED, resumed, false);
+  }
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<OpenDocument<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ResumeResponse::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn add_changed(&mut self, changed: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ResumeResponse::VT_CHANGED, changed);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ResumeResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ResumeResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ResumeResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ResumeResponse::VT_TOKEN,"token");
+    self.fbb_.required(o, ResumeResponse::VT_DOCUMENTS,"documents");
+    self.fbb_.required(o, ResumeResponse::VT_CHANGED,"changed");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ResumeResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ResumeResponse");
+      ds.field("token", &self.token());
+      ds.field("resumed", &self.resumed());
+      ds.field("documents", &self.documents());
+      ds.field("changed", &self.changed());
+      ds.finish()
+  }
+}
+pub enum ParseSnippetRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ParseSnippetRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ParseSnippetRequest<'a> {
+  type Inner = ParseSnippetRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ParseSnippetRequest<'a> {
+  pub const VT_SNIPPET: flatbuffers::VOffsetT = 4;
+  pub const VT_CONTEXT: flatbuffers::VOffsetT = 6;
+  pub const VT_STRUCTURAL_HASHES: flatbuffers::VOffsetT = 8;
+  pub const VT_PROFILE: flatbuffers::VOffsetT = 10;
+  pub const VT_DUAL_OFFSETS: flatbuffers::VOffsetT = 12;
+  pub const VT_TEXT_INCLUSION: flatbuffers::VOffsetT = 14;
+  pub const VT_TEXT_MAX_BYTES: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ParseSnippetRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ParseSnippetRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<ParseSnippetRequest<'bldr>> {
+    let mut builder = ParseSnippetRequestBuilder::new(_fbb);
+    builder.add_text_max_bytes(args.text_max_bytes);
+    if let Some(x) = args.context { builder.add_context(x); }
+    if let Some(x) = args.snippet { builder.add_snippet(x); }
+    builder.add_text_inclusion(args.text_inclusion);
+    builder.add_dual_offsets(args.dual_offsets);
+    builder.add_profile(args.profile);
+    builder.add_structural_hashes(args.structural_hashes);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn snippet(&self) -> flatbuffers::Vector<'a, u16> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(ParseSnippetRequest::VT_SNIPPET, None).unwrap()}
+  }
+  #[inline]
+  pub fn context(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ParseSnippetRequest::VT_CONTEXT, None)}
+  }
+  #[inline]
+  pub fn structural_hashes(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ParseSnippetRequest::VT_STRUCTURAL_HASHES, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn profile(&self) -> SerializationProfile {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SerializationProfile>(ParseSnippetRequest::VT_PROFILE, Some(SerializationProfile::Default)).unwrap()}
+  }
+  #[inline]
+  pub fn dual_offsets(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ParseSnippetRequest::VT_DUAL_OFFSETS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn text_inclusion(&self) -> TextInclusion {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TextInclusion>(ParseSnippetRequest::VT_TEXT_INCLUSION, Some(TextInclusion::Profile)).unwrap()}
+  }
+  #[inline]
+  pub fn text_max_bytes(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ParseSnippetRequest::VT_TEXT_MAX_BYTES, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ParseSnippetRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("snippet", Self::VT_SNIPPET, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("context", Self::VT_CONTEXT, false)?
+     .visit_field::<bool>("structural_hashes", Self::VT_STRUCTURAL_HASHES, false)?
+     .visit_field::<SerializationProfile>("profile", Self::VT_PROFILE, false)?
+     .visit_field::<bool>("dual_offsets", Self::VT_DUAL_OFFSETS, false)?
+     .visit_field::<TextInclusion>("text_inclusion", Self::VT_TEXT_INCLUSION, false)?
+     .visit_field::<u32>("text_max_bytes", Self::VT_TEXT_MAX_BYTES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ParseSnippetRequestArgs<'a> {
+    pub snippet: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+    pub context: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub structural_hashes: bool,
+    pub profile: SerializationProfile,
+    pub dual_offsets: bool,
+    pub text_inclusion: TextInclusion,
+    pub text_max_bytes: u32,
+}
+impl<'a> Default for ParseSnippetRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ParseSnippetRequestArgs {
+      snippet: None, // required field
+      context: None,
+      structural_hashes: false,
+      profile: SerializationProfile::Default,
+      dual_offsets: false,
+      text_inclusion: TextInclusion::Profile,
+      text_max_bytes: 0,
+    }
+  }
+}
+
+pub struct ParseSnippetRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ParseSnippetRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_snippet(&mut self, snippet: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ParseSnippetRequest::VT_SNIPPET, snippet);
+  }
+  #[inline]
+  pub fn add_context(&mut self, context: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ParseSnippetRequest::VT_CONTEXT, context);
+  }
+  #[inline]
+  pub fn add_structural_hashes(&mut self, structural_hashes: bool) {
+    self.fbb_.push_slot::<bool>(ParseSnippetRequest::VT_STRUCTURAL_HASHES, structural_hashes, false);
+  }
+  #[inline]
+  pub fn add_profile(&mut self, profile: SerializationProfile) {
+    self.fbb_.push_slot::<SerializationProfile>(ParseSnippetRequest::VT_PROFILE, profile, SerializationProfile::Default);
+  }
+  #[inline]
+  pub fn add_dual_offsets(&mut self, dual_offsets: bool) {
+    self.fbb_.push_slot::<bool>(ParseSnippetRequest::VT_DUAL_OFFSETS, dual_offsets, false);
+  }
+  #[inline]
+  pub fn add_text_inclusion(&mut self, text_inclusion: TextInclusion) {
+    self.fbb_.push_slot::<TextInclusion>(ParseSnippetRequest::VT_TEXT_INCLUSION, text_inclusion, TextInclusion::Profile);
+  }
+  #[inline]
+  pub fn add_text_max_bytes(&mut self, text_max_bytes: u32) {
+    self.fbb_.push_slot::<u32>(ParseSnippetRequest::VT_TEXT_MAX_BYTES, text_max_bytes, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ParseSnippetRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ParseSnippetRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ParseSnippetRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ParseSnippetRequest::VT_SNIPPET,"snippet");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ParseSnippetRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ParseSnippetRequest");
+      ds.field("snippet", &self.snippet());
+      ds.field("context", &self.context());
+      ds.field("structural_hashes", &self.structural_hashes());
+      ds.field("profile", &self.profile());
+      ds.field("dual_offsets", &self.dual_offsets());
+      ds.field("text_inclusion", &self.text_inclusion());
+      ds.field("text_max_bytes", &self.text_max_bytes());
+      ds.finish()
+  }
+}
+pub enum PlaygroundRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlaygroundRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlaygroundRequest<'a> {
+  type Inner = PlaygroundRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlaygroundRequest<'a> {
+  pub const VT_TEXT: flatbuffers::VOffsetT = 4;
+  pub const VT_LANG: flatbuffers::VOffsetT = 6;
+  pub const VT_HIGHLIGHTS: flatbuffers::VOffsetT = 8;
+  pub const VT_STRUCTURAL_HASHES: flatbuffers::VOffsetT = 10;
+  pub const VT_PROFILE: flatbuffers::VOffsetT = 12;
+  pub const VT_DUAL_OFFSETS: flatbuffers::VOffsetT = 14;
+  pub const VT_TEXT_INCLUSION: flatbuffers::VOffsetT = 16;
+  pub const VT_TEXT_MAX_BYTES: flatbuffers::VOffsetT = 18;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlaygroundRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlaygroundRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlaygroundRequest<'bldr>> {
+    let mut builder = PlaygroundRequestBuilder::new(_fbb);
+    builder.add_text_max_bytes(args.text_max_bytes);
+    if let Some(x) = args.lang { builder.add_lang(x); }
+    if let Some(x) = args.text { builder.add_text(x); }
+    builder.add_text_inclusion(args.text_inclusion);
+    builder.add_dual_offsets(args.dual_offsets);
+    builder.add_profile(args.profile);
+    builder.add_structural_hashes(args.structural_hashes);
+    builder.add_highlights(args.highlights);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn text(&self) -> flatbuffers::Vector<'a, u16> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(PlaygroundRequest::VT_TEXT, None).unwrap()}
+  }
+  #[inline]
+  pub fn lang(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PlaygroundRequest::VT_LANG, None)}
+  }
+  #[inline]
+  pub fn highlights(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(PlaygroundRequest::VT_HIGHLIGHTS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn structural_hashes(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(PlaygroundRequest::VT_STRUCTURAL_HASHES, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn profile(&self) -> SerializationProfile {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SerializationProfile>(PlaygroundRequest::VT_PROFILE, Some(SerializationProfile::Default)).unwrap()}
+  }
+  #[inline]
+  pub fn dual_offsets(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(PlaygroundRequest::VT_DUAL_OFFSETS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn text_inclusion(&self) -> TextInclusion {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TextInclusion>(PlaygroundRequest::VT_TEXT_INCLUSION, Some(TextInclusion::Profile)).unwrap()}
+  }
+  #[inline]
+  pub fn text_max_bytes(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(PlaygroundRequest::VT_TEXT_MAX_BYTES, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PlaygroundRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("text", Self::VT_TEXT, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("lang", Self::VT_LANG, false)?
+     .visit_field::<bool>("highlights", Self::VT_HIGHLIGHTS, false)?
+     .visit_field::<bool>("structural_hashes", Self::VT_STRUCTURAL_HASHES, false)?
+     .visit_field::<SerializationProfile>("profile", Self::VT_PROFILE, false)?
+     .visit_field::<bool>("dual_offsets", Self::VT_DUAL_OFFSETS, false)?
+     .visit_field::<TextInclusion>("text_inclusion", Self::VT_TEXT_INCLUSION, false)?
+     .visit_field::<u32>("text_max_bytes", Self::VT_TEXT_MAX_BYTES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlaygroundRequestArgs<'a> {
+    pub text: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+    pub lang: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub highlights: bool,
+    pub structural_hashes: bool,
+    pub profile: SerializationProfile,
+    pub dual_offsets: bool,
+    pub text_inclusion: TextInclusion,
+    pub text_max_bytes: u32,
+}
+impl<'a> Default for PlaygroundRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlaygroundRequestArgs {
+      text: None, // required field
+      lang: None,
+      highlights: false,
+      structural_hashes: false,
+      profile: SerializationProfile::Default,
+      dual_offsets: false,
+      text_inclusion: TextInclusion::Profile,
+      text_max_bytes: 0,
+    }
+  }
+}
+
+pub struct PlaygroundRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlaygroundRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlaygroundRequest::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn add_lang(&mut self, lang: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlaygroundRequest::VT_LANG, lang);
+  }
+  #[inline]
+  pub fn add_highlights(&mut self, highlights: bool) {
+    self.fbb_.push_slot::<bool>(PlaygroundRequest::VT_HIGHLIGHTS, highlights, false);
+  }
+  #[inline]
+  pub fn add_structural_hashes(&mut self, structural_hashes: bool) {
+    self.fbb_.push_slot::<bool>(PlaygroundRequest::VT_STRUCTURAL_HASHES, structural_hashes, false);
+  }
+  #[inline]
+  pub fn add_profile(&mut self, profile: SerializationProfile) {
+    self.fbb_.push_slot::<SerializationProfile>(PlaygroundRequest::VT_PROFILE, profile, SerializationProfile::Default);
+  }
+  #[inline]
+  pub fn add_dual_offsets(&mut self, dual_offsets: bool) {
+    self.fbb_.push_slot::<bool>(PlaygroundRequest::VT_DUAL_OFFSETS, dual_offsets, false);
+  }
+  #[inline]
+  pub fn add_text_inclusion(&mut self, text_inclusion: TextInclusion) {
+    self.fbb_.push_slot::<TextInclusion>(PlaygroundRequest::VT_TEXT_INCLUSION, text_inclusion, TextInclusion::Profile);
+  }
+  #[inline]
+  pub fn add_text_max_bytes(&mut self, text_max_bytes: u32) {
+    self.fbb_.push_slot::<u32>(PlaygroundRequest::VT_TEXT_MAX_BYTES, text_max_bytes, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlaygroundRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlaygroundRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlaygroundRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, PlaygroundRequest::VT_TEXT,"text");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlaygroundRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlaygroundRequest");
+      ds.field("text", &self.text());
+      ds.field("lang", &self.lang());
+      ds.field("highlights", &self.highlights());
+      ds.field("structural_hashes", &self.structural_hashes());
+      ds.field("profile", &self.profile());
+      ds.field("dual_offsets", &self.dual_offsets());
+      ds.field("text_inclusion", &self.text_inclusion());
+      ds.field("text_max_bytes", &self.text_max_bytes());
+      ds.finish()
+  }
+}
+pub enum DiagnosticOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Diagnostic<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Diagnostic<'a> {
+  type Inner = Diagnostic<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Diagnostic<'a> {
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 4;
+  pub const VT_START_POINT: flatbuffers::VOffsetT = 6;
+  pub const VT_END_POINT: flatbuffers::VOffsetT = 8;
+  pub const VT_MESSAGE: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Diagnostic { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args DiagnosticArgs<'args>
+  ) -> flatbuffers::WIPOffset<Diagnostic<'bldr>> {
+    let mut builder = DiagnosticBuilder::new(_fbb);
+    if let Some(x) = args.message { builder.add_message(x); }
+    if let Some(x) = args.end_point { builder.add_end_point(x); }
+    if let Some(x) = args.start_point { builder.add_start_point(x); }
+    if let Some(x) = args.location { builder.add_location(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(Diagnostic::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn start_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(Diagnostic::VT_START_POINT, None)}
+  }
+  #[inline]
+  pub fn end_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(Diagnostic::VT_END_POINT, None)}
+  }
+  #[inline]
+  pub fn message(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Diagnostic::VT_MESSAGE, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Diagnostic<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<Point>("start_point", Self::VT_START_POINT, false)?
+     .visit_field::<Point>("end_point", Self::VT_END_POINT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DiagnosticArgs<'a> {
+    pub location: Option<&'a Location>,
+    pub start_point: Option<&'a Point>,
+    pub end_point: Option<&'a Point>,
+    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for DiagnosticArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DiagnosticArgs {
+      location: None,
+      start_point: None,
+      end_point: None,
+      message: None, // required field
+    }
+  }
+}
+
+pub struct DiagnosticBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DiagnosticBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(Diagnostic::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_start_point(&mut self, start_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(Diagnostic::VT_START_POINT, start_point);
+  }
+  #[inline]
+  pub fn add_end_point(&mut self, end_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(Diagnostic::VT_END_POINT, end_point);
+  }
+  #[inline]
+  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Diagnostic::VT_MESSAGE, message);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DiagnosticBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DiagnosticBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Diagnostic<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Diagnostic::VT_MESSAGE,"message");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Diagnostic<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Diagnostic");
+      ds.field("location", &self.location());
+      ds.field("start_point", &self.start_point());
+      ds.field("end_point", &self.end_point());
+      ds.field("message", &self.message());
+      ds.finish()
+  }
+}
+pub enum HighlightSpanOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct HighlightSpan<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for HighlightSpan<'a> {
+  type Inner = HighlightSpan<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> HighlightSpan<'a> {
+  pub const VT_CAPTURE: flatbuffers::VOffsetT = 4;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 6;
+  pub const VT_START_POINT: flatbuffers::VOffsetT = 8;
+  pub const VT_END_POINT: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    HighlightSpan { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args HighlightSpanArgs<'args>
+  ) -> flatbuffers::WIPOffset<HighlightSpan<'bldr>> {
+    let mut builder = HighlightSpanBuilder::new(_fbb);
+    if let Some(x) = args.end_point { builder.add_end_point(x); }
+    if let Some(x) = args.start_point { builder.add_start_point(x); }
+    if let Some(x) = args.location { builder.add_location(x); }
+    if let Some(x) = args.capture { builder.add_capture(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn capture(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(HighlightSpan::VT_CAPTURE, None).unwrap()}
+  }
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(HighlightSpan::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn start_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(HighlightSpan::VT_START_POINT, None)}
+  }
+  #[inline]
+  pub fn end_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(HighlightSpan::VT_END_POINT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for HighlightSpan<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("capture", Self::VT_CAPTURE, true)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<Point>("start_point", Self::VT_START_POINT, false)?
+     .visit_field::<Point>("end_point", Self::VT_END_POINT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct HighlightSpanArgs<'a> {
+    pub capture: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub location: Option<&'a Location>,
+    pub start_point: Option<&'a Point>,
+    pub end_point: Option<&'a Point>,
+}
+impl<'a> Default for HighlightSpanArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    HighlightSpanArgs {
+      capture: None, // required field
+      location: None,
+      start_point: None,
+      end_point: None,
+    }
+  }
+}
+
+pub struct HighlightSpanBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HighlightSpanBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_capture(&mut self, capture: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HighlightSpan::VT_CAPTURE, capture);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(HighlightSpan::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_start_point(&mut self, start_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(HighlightSpan::VT_START_POINT, start_point);
+  }
+  #[inline]
+  pub fn add_end_point(&mut self, end_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(HighlightSpan::VT_END_POINT, end_point);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HighlightSpanBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HighlightSpanBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<HighlightSpan<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, HighlightSpan::VT_CAPTURE,"capture");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for HighlightSpan<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("HighlightSpan");
+      ds.field("capture", &self.capture());
+      ds.field("location", &self.location());
+      ds.field("start_point", &self.start_point());
+      ds.field("end_point", &self.end_point());
+      ds.finish()
+  }
+}
+pub enum PlaygroundResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlaygroundResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlaygroundResponse<'a> {
+  type Inner = PlaygroundResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlaygroundResponse<'a> {
+  pub const VT_FILE: flatbuffers::VOffsetT = 4;
+  pub const VT_DIAGNOSTICS: flatbuffers::VOffsetT = 6;
+  pub const VT_HIGHLIGHTS: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlaygroundResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlaygroundResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlaygroundResponse<'bldr>> {
+    let mut builder = PlaygroundResponseBuilder::new(_fbb);
+    if let Some(x) = args.highlights { builder.add_highlights(x); }
+    if let Some(x) = args.diagnostics { builder.add_diagnostics(x); }
+    if let Some(x) = args.file { builder.add_file(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn file(&self) -> flatbuffers::Vector<'a, u8> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(PlaygroundResponse::VT_FILE, None).unwrap()}
+  }
+  pub fn file_nested_flatbuffer(&'a self) -> FileResponse<'a> {
+    let data = self.file();
+    use flatbuffers::Follow;
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid flatbuffer in this slot
+    unsafe { <flatbuffers::ForwardsUOffset<FileResponse<'a>>>::follow(data.bytes(), 0) }
+  }
+  #[inline]
+  pub fn diagnostics(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Diagnostic<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Diagnostic>>>>(PlaygroundResponse::VT_DIAGNOSTICS, None).unwrap()}
+  }
+  #[inline]
+  pub fn highlights(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<HighlightSpan<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<HighlightSpan>>>>(PlaygroundResponse::VT_HIGHLIGHTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PlaygroundResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("file", Self::VT_FILE, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Diagnostic>>>>("diagnostics", Self::VT_DIAGNOSTICS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<HighlightSpan>>>>("highlights", Self::VT_HIGHLIGHTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlaygroundResponseArgs<'a> {
+    pub file: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub diagnostics: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Diagnostic<'a>>>>>,
+    pub highlights: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<HighlightSpan<'a>>>>>,
+}
+impl<'a> Default for PlaygroundResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlaygroundResponseArgs {
+      file: None, // required field
+      diagnostics: None, // required field
+      highlights: None,
+    }
+  }
+}
+
+pub struct PlaygroundResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlaygroundResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_file(&mut self, file: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlaygroundResponse::VT_FILE, file);
+  }
+  #[inline]
+  pub fn add_diagnostics(&mut self, diagnostics: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Diagnostic<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlaygroundResponse::VT_DIAGNOSTICS, diagnostics);
+  }
+  #[inline]
+  pub fn add_highlights(&mut self, highlights: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<HighlightSpan<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlaygroundResponse::VT_HIGHLIGHTS, highlights);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlaygroundResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlaygroundResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlaygroundResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, PlaygroundResponse::VT_FILE,"file");
+    self.fbb_.required(o, PlaygroundResponse::VT_DIAGNOSTICS,"diagnostics");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlaygroundResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlaygroundResponse");
+      ds.field("file", &self.file());
+      ds.field("diagnostics", &self.diagnostics());
+      ds.field("highlights", &self.highlights());
+      ds.finish()
+  }
+}
+pub enum FormatRangesRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FormatRangesRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FormatRangesRequest<'a> {
+  type Inner = FormatRangesRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FormatRangesRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FormatRangesRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FormatRangesRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<FormatRangesRequest<'bldr>> {
+    let mut builder = FormatRangesRequestBuilder::new(_fbb);
+    if let Some(x) = args.location { builder.add_location(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FormatRangesRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn location(&self) -> &'a Location {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(FormatRangesRequest::VT_LOCATION, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FormatRangesRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FormatRangesRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub location: Option<&'a Location>,
+}
+impl<'a> Default for FormatRangesRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FormatRangesRequestArgs {
+      path: None, // required field
+      location: None, // required field
+    }
+  }
+}
+
+pub struct FormatRangesRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FormatRangesRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FormatRangesRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(FormatRangesRequest::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FormatRangesRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FormatRangesRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FormatRangesRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, FormatRangesRequest::VT_PATH,"path");
+    self.fbb_.required(o, FormatRangesRequest::VT_LOCATION,"location");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FormatRangesRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FormatRangesRequest");
+      ds.field("path", &self.path());
+      ds.field("location", &self.location());
+      ds.finish()
+  }
+}
+pub enum FormatRangeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FormatRange<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FormatRange<'a> {
+  type Inner = FormatRange<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FormatRange<'a> {
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 4;
+  pub const VT_START_POINT: flatbuffers::VOffsetT = 6;
+  pub const VT_END_POINT: flatbuffers::VOffsetT = 8;
+  pub const VT_KIND: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FormatRange { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FormatRangeArgs<'args>
+  ) -> flatbuffers::WIPOffset<FormatRange<'bldr>> {
+    let mut builder = FormatRangeBuilder::new(_fbb);
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    if let Some(x) = args.end_point { builder.add_end_point(x); }
+    if let Some(x) = args.start_point { builder.add_start_point(x); }
+    if let Some(x) = args.location { builder.add_location(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(FormatRange::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn start_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(FormatRange::VT_START_POINT, None)}
+  }
+  #[inline]
+  pub fn end_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(FormatRange::VT_END_POINT, None)}
+  }
+  #[inline]
+  pub fn kind(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FormatRange::VT_KIND, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FormatRange<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<Point>("start_point", Self::VT_START_POINT, false)?
+     .visit_field::<Point>("end_point", Self::VT_END_POINT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FormatRangeArgs<'a> {
+    pub location: Option<&'a Location>,
+    pub start_point: Option<&'a Point>,
+    pub end_point: Option<&'a Point>,
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for FormatRangeArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FormatRangeArgs {
+      location: None,
+      start_point: None,
+      end_point: None,
+      kind: None, // required field
+    }
+  }
+}
+
+pub struct FormatRangeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FormatRangeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(FormatRange::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_start_point(&mut self, start_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(FormatRange::VT_START_POINT, start_point);
+  }
+  #[inline]
+  pub fn add_end_point(&mut self, end_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(FormatRange::VT_END_POINT, end_point);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FormatRange::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FormatRangeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FormatRangeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FormatRange<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, FormatRange::VT_KIND,"kind");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FormatRange<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FormatRange");
+      ds.field("location", &self.location());
+      ds.field("start_point", &self.start_point());
+      ds.field("end_point", &self.end_point());
+      ds.field("kind", &self.kind());
+      ds.finish()
+  }
+}
+pub enum FormatRangesResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FormatRangesResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FormatRangesResponse<'a> {
+  type Inner = FormatRangesResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FormatRangesResponse<'a> {
+  pub const VT_RANGES: flatbuffers::VOffsetT = 4;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FormatRangesResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FormatRangesResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<FormatRangesResponse<'bldr>> {
+    let mut builder = FormatRangesResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.ranges { builder.add_ranges(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn ranges(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FormatRange<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FormatRange>>>>(FormatRangesResponse::VT_RANGES, None).unwrap()}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FormatRangesResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(FormatRangesResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FormatRangesResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<FormatRange>>>>("ranges", Self::VT_RANGES, true)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FormatRangesResponseArgs<'a> {
+    pub ranges: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FormatRange<'a>>>>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for FormatRangesResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FormatRangesResponseArgs {
+      ranges: None, // required field
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct FormatRangesResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FormatRangesResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_ranges(&mut self, ranges: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<FormatRange<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FormatRangesResponse::VT_RANGES, ranges);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(FormatRangesResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(FormatRangesResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FormatRangesResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FormatRangesResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FormatRangesResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, FormatRangesResponse::VT_RANGES,"ranges");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FormatRangesResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FormatRangesResponse");
+      ds.field("ranges", &self.ranges());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum TraverseRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TraverseRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TraverseRequest<'a> {
+  type Inner = TraverseRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TraverseRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_ORDER: flatbuffers::VOffsetT = 6;
+  pub const VT_KINDS: flatbuffers::VOffsetT = 8;
+  pub const VT_NAMED_ONLY: flatbuffers::VOffsetT = 10;
+  pub const VT_LIMIT: flatbuffers::VOffsetT = 12;
+  pub const VT_CURSOR: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TraverseRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TraverseRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<TraverseRequest<'bldr>> {
+    let mut builder = TraverseRequestBuilder::new(_fbb);
+    if let Some(x) = args.cursor { builder.add_cursor(x); }
+    builder.add_limit(args.limit);
+    if let Some(x) = args.kinds { builder.add_kinds(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.add_named_only(args.named_only);
+    builder.add_order(args.order);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TraverseRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn order(&self) -> TraversalOrder {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TraversalOrder>(TraverseRequest::VT_ORDER, Some(TraversalOrder::Pre)).unwrap()}
+  }
+  #[inline]
+  pub fn kinds(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(TraverseRequest::VT_KINDS, None)}
+  }
+  #[inline]
+  pub fn named_only(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(TraverseRequest::VT_NAMED_ONLY, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn limit(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TraverseRequest::VT_LIMIT, Some(1000)).unwrap()}
+  }
+  #[inline]
+  pub fn cursor(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TraverseRequest::VT_CURSOR, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TraverseRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<TraversalOrder>("order", Self::VT_ORDER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("kinds", Self::VT_KINDS, false)?
+     .visit_field::<bool>("named_only", Self::VT_NAMED_ONLY, false)?
+     .visit_field::<u32>("limit", Self::VT_LIMIT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("cursor", Self::VT_CURSOR, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TraverseRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub order: TraversalOrder,
+    pub kinds: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub named_only: bool,
+    pub limit: u32,
+    pub cursor: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for TraverseRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TraverseRequestArgs {
+      path: None, // required field
+      order: TraversalOrder::Pre,
+      kinds: None,
+      named_only: false,
+      limit: 1000,
+      cursor: None,
+    }
+  }
+}
+
+pub struct TraverseRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TraverseRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TraverseRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_order(&mut self, order: TraversalOrder) {
+    self.fbb_.push_slot::<TraversalOrder>(TraverseRequest::VT_ORDER, order, TraversalOrder::Pre);
+  }
+  #[inline]
+  pub fn add_kinds(&mut self, kinds: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TraverseRequest::VT_KINDS, kinds);
+  }
+  #[inline]
+  pub fn add_named_only(&mut self, named_only: bool) {
+    self.fbb_.push_slot::<bool>(TraverseRequest::VT_NAMED_ONLY, named_only, false);
+  }
+  #[inline]
+  pub fn add_limit(&mut self, limit: u32) {
+    self.fbb_.push_slot::<u32>(TraverseRequest::VT_LIMIT, limit, 1000);
+  }
+  #[inline]
+  pub fn add_cursor(&mut self, cursor: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TraverseRequest::VT_CURSOR, cursor);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TraverseRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TraverseRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TraverseRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, TraverseRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TraverseRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TraverseRequest");
+      ds.field("path", &self.path());
+      ds.field("order", &self.order());
+      ds.field("kinds", &self.kinds());
+      ds.field("named_only", &self.named_only());
+      ds.field("limit", &self.limit());
+      ds.field("cursor", &self.cursor());
+      ds.finish()
+  }
+}
+pub enum TraversedNodeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TraversedNode<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TraversedNode<'a> {
+  type Inner = TraversedNode<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TraversedNode<'a> {
+  pub const VT_KIND: flatbuffers::VOffsetT = 4;
+  pub const VT_NAMED: flatbuffers::VOffsetT = 6;
+  pub const VT_DEPTH: flatbuffers::VOffsetT = 8;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 10;
+  pub const VT_START_POINT: flatbuffers::VOffsetT = 12;
+  pub const VT_END_POINT: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TraversedNode { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TraversedNodeArgs<'args>
+  ) -> flatbuffers::WIPOffset<TraversedNode<'bldr>> {
+    let mut builder = TraversedNodeBuilder::new(_fbb);
+    if let Some(x) = args.end_point { builder.add_end_point(x); }
+    if let Some(x) = args.start_point { builder.add_start_point(x); }
+    if let Some(x) = args.location { builder.add_location(x); }
+    builder.add_depth(args.depth);
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    builder.add_named(args.named);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn kind(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TraversedNode::VT_KIND, None).unwrap()}
+  }
+  #[inline]
+  pub fn named(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(TraversedNode::VT_NAMED, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn depth(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TraversedNode::VT_DEPTH, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(TraversedNode::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn start_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(TraversedNode::VT_START_POINT, None)}
+  }
+  #[inline]
+  pub fn end_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(TraversedNode::VT_END_POINT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TraversedNode<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, true)?
+     .visit_field::<bool>("named", Self::VT_NAMED, false)?
+     .visit_field::<u32>("depth", Self::VT_DEPTH, false)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<Point>("start_point", Self::VT_START_POINT, false)?
+     .visit_field::<Point>("end_point", Self::VT_END_POINT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TraversedNodeArgs<'a> {
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub named: bool,
+    pub depth: u32,
+    pub location: Option<&'a Location>,
+    pub start_point: Option<&'a Point>,
+    pub end_point: Option<&'a Point>,
+}
+impl<'a> Default for TraversedNodeArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TraversedNodeArgs {
+      kind: None, // required field
+      named: false,
+      depth: 0,
+      location: None,
+      start_point: None,
+      end_point: None,
+    }
+  }
+}
+
+pub struct TraversedNodeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TraversedNodeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TraversedNode::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_named(&mut self, named: bool) {
+    self.fbb_.push_slot::<bool>(TraversedNode::VT_NAMED, named, false);
+  }
+  #[inline]
+  pub fn add_depth(&mut self, depth: u32) {
+    self.fbb_.push_slot::<u32>(TraversedNode::VT_DEPTH, depth, 0);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(TraversedNode::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_start_point(&mut self, start_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(TraversedNode::VT_START_POINT, start_point);
+  }
+  #[inline]
+  pub fn add_end_point(&mut self, end_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(TraversedNode::VT_END_POINT, end_point);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TraversedNodeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TraversedNodeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TraversedNode<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, TraversedNode::VT_KIND,"kind");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TraversedNode<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TraversedNode");
+      ds.field("kind", &self.kind());
+      ds.field("named", &self.named());
+      ds.field("depth", &self.depth());
+      ds.field("location", &self.location());
+      ds.field("start_point", &self.start_point());
+      ds.field("end_point", &self.end_point());
+      ds.finish()
+  }
+}
+pub enum TraverseResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TraverseResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TraverseResponse<'a> {
+  type Inner = TraverseResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TraverseResponse<'a> {
+  pub const VT_NODES: flatbuffers::VOffsetT = 4;
+  pub const VT_CURSOR: flatbuffers::VOffsetT = 6;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 8;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TraverseResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TraverseResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<TraverseResponse<'bldr>> {
+    let mut builder = TraverseResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.cursor { builder.add_cursor(x); }
+    if let Some(x) = args.nodes { builder.add_nodes(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn nodes(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TraversedNode<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TraversedNode>>>>(TraverseResponse::VT_NODES, None).unwrap()}
+  }
+  #[inline]
+  pub fn cursor(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TraverseResponse::VT_CURSOR, None)}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TraverseResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(TraverseResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for TraverseResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TraversedNode>>>>("nodes", Self::VT_NODES, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("cursor", Self::VT_CURSOR, false)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TraverseResponseArgs<'a> {
+    pub nodes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TraversedNode<'a>>>>>,
+    pub cursor: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for TraverseResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TraverseResponseArgs {
+      nodes: None, // required field
+      cursor: None,
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct TraverseResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TraverseResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_nodes(&mut self, nodes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TraversedNode<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TraverseResponse::VT_NODES, nodes);
+  }
+  #[inline]
+  pub fn add_cursor(&mut self, cursor: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TraverseResponse::VT_CURSOR, cursor);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(TraverseResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(TraverseResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TraverseResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TraverseResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TraverseResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, TraverseResponse::VT_NODES,"nodes");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TraverseResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TraverseResponse");
+      ds.field("nodes", &self.nodes());
+      ds.field("cursor", &self.cursor());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum SelectionRangesRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SelectionRangesRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SelectionRangesRequest<'a> {
+  type Inner = SelectionRangesRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SelectionRangesRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_POSITIONS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SelectionRangesRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SelectionRangesRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<SelectionRangesRequest<'bldr>> {
+    let mut builder = SelectionRangesRequestBuilder::new(_fbb);
+    if let Some(x) = args.positions { builder.add_positions(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SelectionRangesRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn positions(&self) -> flatbuffers::Vector<'a, Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Point>>>(SelectionRangesRequest::VT_POSITIONS, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SelectionRangesRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Point>>>("positions", Self::VT_POSITIONS, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SelectionRangesRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub positions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Point>>>,
+}
+impl<'a> Default for SelectionRangesRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SelectionRangesRequestArgs {
+      path: None, // required field
+      positions: None, // required field
+    }
+  }
+}
+
+pub struct SelectionRangesRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SelectionRangesRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SelectionRangesRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_positions(&mut self, positions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Point>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SelectionRangesRequest::VT_POSITIONS, positions);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SelectionRangesRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SelectionRangesRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SelectionRangesRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, SelectionRangesRequest::VT_PATH,"path");
+    self.fbb_.required(o, SelectionRangesRequest::VT_POSITIONS,"positions");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SelectionRangesRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SelectionRangesRequest");
+      ds.field("path", &self.path());
+      ds.field("positions", &self.positions());
+      ds.finish()
+  }
+}
+pub enum SelectionRangeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SelectionRange<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SelectionRange<'a> {
+  type Inner = SelectionRange<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SelectionRange<'a> {
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 4;
+  pub const VT_START_POINT: flatbuffers::VOffsetT = 6;
+  pub const VT_END_POINT: flatbuffers::VOffsetT = 8;
+  pub const VT_PARENT: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SelectionRange { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SelectionRangeArgs<'args>
+  ) -> flatbuffers::WIPOffset<SelectionRange<'bldr>> {
+    let mut builder = SelectionRangeBuilder::new(_fbb);
+    if let Some(x) = args.parent { builder.add_parent(x); }
+    if let Some(x) = args.end_point { builder.add_end_point(x); }
+    if let Some(x) = args.start_point { builder.add_start_point(x); }
+    if let Some(x) = args.location { builder.add_location(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(SelectionRange::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn start_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(SelectionRange::VT_START_POINT, None)}
+  }
+  #[inline]
+  pub fn end_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(SelectionRange::VT_END_POINT, None)}
+  }
+  #[inline]
+  pub fn parent(&self) -> Option<SelectionRange<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<SelectionRange>>(SelectionRange::VT_PARENT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SelectionRange<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<Point>("start_point", Self::VT_START_POINT, false)?
+     .visit_field::<Point>("end_point", Self::VT_END_POINT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<SelectionRange>>("parent", Self::VT_PARENT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SelectionRangeArgs<'a> {
+    pub location: Option<&'a Location>,
+    pub start_point: Option<&'a Point>,
+    pub end_point: Option<&'a Point>,
+    pub parent: Option<flatbuffers::WIPOffset<SelectionRange<'a>>>,
+}
+impl<'a> Default for SelectionRangeArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SelectionRangeArgs {
+      location: None,
+      start_point: None,
+      end_point: None,
+      parent: None,
+    }
+  }
+}
+
+pub struct SelectionRangeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SelectionRangeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(SelectionRange::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_start_point(&mut self, start_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(SelectionRange::VT_START_POINT, start_point);
+  }
+  #[inline]
+  pub fn add_end_point(&mut self, end_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(SelectionRange::VT_END_POINT, end_point);
+  }
+  #[inline]
+  pub fn add_parent(&mut self, parent: flatbuffers::WIPOffset<SelectionRange<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<SelectionRange>>(SelectionRange::VT_PARENT, parent);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SelectionRangeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SelectionRangeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SelectionRange<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SelectionRange<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SelectionRange");
+      ds.field("location", &self.location());
+      ds.field("start_point", &self.start_point());
+      ds.field("end_point", &self.end_point());
+      ds.field("parent", &self.parent());
+      ds.finish()
+  }
+}
+pub enum SelectionRangesResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SelectionRangesResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SelectionRangesResponse<'a> {
+  type Inner = SelectionRangesResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SelectionRangesResponse<'a> {
+  pub const VT_RANGES: flatbuffers::VOffsetT = 4;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SelectionRangesResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SelectionRangesResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<SelectionRangesResponse<'bldr>> {
+    let mut builder = SelectionRangesResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.ranges { builder.add_ranges(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn ranges(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SelectionRange<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SelectionRange>>>>(SelectionRangesResponse::VT_RANGES, None).unwrap()}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(SelectionRangesResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(SelectionRangesResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SelectionRangesResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SelectionRange>>>>("ranges", Self::VT_RANGES, true)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SelectionRangesResponseArgs<'a> {
+    pub ranges: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SelectionRange<'a>>>>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for SelectionRangesResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SelectionRangesResponseArgs {
+      ranges: None, // required field
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct SelectionRangesResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SelectionRangesResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_ranges(&mut self, ranges: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SelectionRange<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SelectionRangesResponse::VT_RANGES, ranges);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(SelectionRangesResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(SelectionRangesResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SelectionRangesResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SelectionRangesResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SelectionRangesResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, SelectionRangesResponse::VT_RANGES,"ranges");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SelectionRangesResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SelectionRangesResponse");
+      ds.field("ranges", &self.ranges());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum SymbolsRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SymbolsRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SymbolsRequest<'a> {
+  type Inner = SymbolsRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SymbolsRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SymbolsRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SymbolsRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<SymbolsRequest<'bldr>> {
+    let mut builder = SymbolsRequestBuilder::new(_fbb);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SymbolsRequest::VT_PATH, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SymbolsRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SymbolsRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SymbolsRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SymbolsRequestArgs {
+      path: None, // required field
+    }
+  }
+}
+
+pub struct SymbolsRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SymbolsRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SymbolsRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SymbolsRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SymbolsRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SymbolsRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, SymbolsRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SymbolsRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SymbolsRequest");
+      ds.field("path", &self.path());
+      ds.finish()
+  }
+}
+pub enum SymbolOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Symbol<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Symbol<'a> {
+  type Inner = Symbol<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Symbol<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_KIND: flatbuffers::VOffsetT = 6;
+  pub const VT_TAGS_KIND: flatbuffers::VOffsetT = 8;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 10;
+  pub const VT_START_POINT: flatbuffers::VOffsetT = 12;
+  pub const VT_END_POINT: flatbuffers::VOffsetT = 14;
+  pub const VT_NAME_LOCATION: flatbuffers::VOffsetT = 16;
+  pub const VT_CHILDREN: flatbuffers::VOffsetT = 18;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Symbol { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SymbolArgs<'args>
+  ) -> flatbuffers::WIPOffset<Symbol<'bldr>> {
+    let mut builder = SymbolBuilder::new(_fbb);
+    if let Some(x) = args.children { builder.add_children(x); }
+    if let Some(x) = args.name_location { builder.add_name_location(x); }
+    if let Some(x) = args.end_point { builder.add_end_point(x); }
+    if let Some(x) = args.start_point { builder.add_start_point(x); }
+    if let Some(x) = args.location { builder.add_location(x); }
+    if let Some(x) = args.tags_kind { builder.add_tags_kind(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_kind(args.kind);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Symbol::VT_NAME, None).unwrap()}
+  }
+  #[inline]
+  pub fn kind(&self) -> SymbolKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SymbolKind>(Symbol::VT_KIND, Some(SymbolKind::Other)).unwrap()}
+  }
+  #[inline]
+  pub fn tags_kind(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Symbol::VT_TAGS_KIND, None).unwrap()}
+  }
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(Symbol::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn start_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(Symbol::VT_START_POINT, None)}
+  }
+  #[inline]
+  pub fn end_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(Symbol::VT_END_POINT, None)}
+  }
+  #[inline]
+  pub fn name_location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(Symbol::VT_NAME_LOCATION, None)}
+  }
+  #[inline]
+  pub fn children(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Symbol<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Symbol>>>>(Symbol::VT_CHILDREN, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Symbol<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+     .visit_field::<SymbolKind>("kind", Self::VT_KIND, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("tags_kind", Self::VT_TAGS_KIND, true)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<Point>("start_point", Self::VT_START_POINT, false)?
+     .visit_field::<Point>("end_point", Self::VT_END_POINT, false)?
+     .visit_field::<Location>("name_location", Self::VT_NAME_LOCATION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Symbol>>>>("children", Self::VT_CHILDREN, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SymbolArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub kind: SymbolKind,
+    pub tags_kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub location: Option<&'a Location>,
+    pub start_point: Option<&'a Point>,
+    pub end_point: Option<&'a Point>,
+    pub name_location: Option<&'a Location>,
+    pub children: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Symbol<'a>>>>>,
+}
+impl<'a> Default for SymbolArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SymbolArgs {
+      name: None, // required field
+      kind: SymbolKind::Other,
+      tags_kind: None, // required field
+      location: None,
+      start_point: None,
+      end_point: None,
+      name_location: None,
+      children: None,
+    }
+  }
+}
+
+pub struct SymbolBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SymbolBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Symbol::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: SymbolKind) {
+    self.fbb_.push_slot::<SymbolKind>(Symbol::VT_KIND, kind, SymbolKind::Other);
+  }
+  #[inline]
+  pub fn add_tags_kind(&mut self, tags_kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Symbol::VT_TAGS_KIND, tags_kind);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(Symbol::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_start_point(&mut self, start_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(Symbol::VT_START_POINT, start_point);
+  }
+  #[inline]
+  pub fn add_end_point(&mut self, end_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(Symbol::VT_END_POINT, end_point);
+  }
+  #[inline]
+  pub fn add_name_location(&mut self, name_location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(Symbol::VT_NAME_LOCATION, name_location);
+  }
+  #[inline]
+  pub fn add_children(&mut self, children: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Symbol<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Symbol::VT_CHILDREN, children);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SymbolBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SymbolBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Symbol<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Symbol::VT_NAME,"name");
+    self.fbb_.required(o, Symbol::VT_TAGS_KIND,"tags_kind");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Symbol<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Symbol");
+      ds.field("name", &self.name());
+      ds.field("kind", &self.kind());
+      ds.field("tags_kind", &self.tags_kind());
+      ds.field("location", &self.location());
+      ds.field("start_point", &self.start_point());
+      ds.field("end_point", &self.end_point());
+      ds.field("name_location", &self.name_location());
+      ds.field("children", &self.children());
+      ds.finish()
+  }
+}
+pub enum SymbolsResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SymbolsResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SymbolsResponse<'a> {
+  type Inner = SymbolsResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SymbolsResponse<'a> {
+  pub const VT_SYMBOLS: flatbuffers::VOffsetT = 4;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SymbolsResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SymbolsResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<SymbolsResponse<'bldr>> {
+    let mut builder = SymbolsResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.symbols { builder.add_symbols(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn symbols(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Symbol<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Symbol>>>>(SymbolsResponse::VT_SYMBOLS, None).unwrap()}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(SymbolsResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(SymbolsResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SymbolsResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Symbol>>>>("symbols", Self::VT_SYMBOLS, true)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SymbolsResponseArgs<'a> {
+    pub symbols: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Symbol<'a>>>>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for SymbolsResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SymbolsResponseArgs {
+      symbols: None, // required field
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct SymbolsResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SymbolsResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_symbols(&mut self, symbols: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Symbol<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SymbolsResponse::VT_SYMBOLS, symbols);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(SymbolsResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(SymbolsResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SymbolsResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SymbolsResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SymbolsResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, SymbolsResponse::VT_SYMBOLS,"symbols");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SymbolsResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SymbolsResponse");
+      ds.field("symbols", &self.symbols());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum FoldingRangesRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FoldingRangesRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FoldingRangesRequest<'a> {
+  type Inner = FoldingRangesRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FoldingRangesRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FoldingRangesRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FoldingRangesRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<FoldingRangesRequest<'bldr>> {
+    let mut builder = FoldingRangesRequestBuilder::new(_fbb);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FoldingRangesRequest::VT_PATH, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FoldingRangesRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FoldingRangesRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for FoldingRangesRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FoldingRangesRequestArgs {
+      path: None, // required field
+    }
+  }
+}
+
+pub struct FoldingRangesRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FoldingRangesRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FoldingRangesRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FoldingRangesRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FoldingRangesRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FoldingRangesRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, FoldingRangesRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FoldingRangesRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FoldingRangesRequest");
+      ds.field("path", &self.path());
+      ds.finish()
+  }
+}
+pub enum FoldingRangeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FoldingRange<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FoldingRange<'a> {
+  type Inner = FoldingRange<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FoldingRange<'a> {
+  pub const VT_START_LINE: flatbuffers::VOffsetT = 4;
+  pub const VT_END_LINE: flatbuffers::VOffsetT = 6;
+  pub const VT_KIND: flatbuffers::VOffsetT = 8;
+  pub const VT_LEVEL: flatbuffers::VOffsetT = 10;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FoldingRange { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FoldingRangeArgs<'args>
+  ) -> flatbuffers::WIPOffset<FoldingRange<'bldr>> {
+    let mut builder = FoldingRangeBuilder::new(_fbb);
+    if let Some(x) = args.location { builder.add_location(x); }
+    builder.add_level(args.level);
+    builder.add_end_line(args.end_line);
+    builder.add_start_line(args.start_line);
+    builder.add_kind(args.kind);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn start_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FoldingRange::VT_START_LINE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn end_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FoldingRange::VT_END_LINE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn kind(&self) -> FoldKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<FoldKind>(FoldingRange::VT_KIND, Some(FoldKind::Other)).unwrap()}
+  }
+  #[inline]
+  pub fn level(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FoldingRange::VT_LEVEL, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(FoldingRange::VT_LOCATION, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for FoldingRange<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("start_line", Self::VT_START_LINE, false)?
+     .visit_field::<u32>("end_line", Self::VT_END_LINE, false)?
+     .visit_field::<FoldKind>("kind", Self::VT_KIND, false)?
+     .visit_field::<u32>("level", Self::VT_LEVEL, false)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FoldingRangeArgs<'a> {
+    pub start_line: u32,
+    pub end_line: u32,
+    pub kind: FoldKind,
+    pub level: u32,
+    pub location: Option<&'a Location>,
+}
+impl<'a> Default for FoldingRangeArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FoldingRangeArgs {
+      start_line: 0,
+      end_line: 0,
+      kind: FoldKind::Other,
+      level: 0,
+      location: None,
+    }
+  }
+}
+
+pub struct FoldingRangeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FoldingRangeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_start_line(&mut self, start_line: u32) {
+    self.fbb_.push_slot::<u32>(FoldingRange::VT_START_LINE, start_line, 0);
+  }
+  #[inline]
+  pub fn add_end_line(&mut self, end_line: u32) {
+    self.fbb_.push_slot::<u32>(FoldingRange::VT_END_LINE, end_line, 0);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: FoldKind) {
+    self.fbb_.push_slot::<FoldKind>(FoldingRange::VT_KIND, kind, FoldKind::Other);
+  }
+  #[inline]
+  pub fn add_level(&mut self, level: u32) {
+    self.fbb_.push_slot::<u32>(FoldingRange::VT_LEVEL, level, 0);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(FoldingRange::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FoldingRangeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FoldingRangeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FoldingRange<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FoldingRange<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FoldingRange");
+      ds.field("start_line", &self.start_line());
+      ds.field("end_line", &self.end_line());
+      ds.field("kind", &self.kind());
+      ds.field("level", &self.level());
+      ds.field("location", &self.location());
+      ds.finish()
+  }
+}
+pub enum FoldingRangesResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FoldingRangesResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FoldingRangesResponse<'a> {
+  type Inner = FoldingRangesResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FoldingRangesResponse<'a> {
+  pub const VT_RANGES: flatbuffers::VOffsetT = 4;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FoldingRangesResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FoldingRangesResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<FoldingRangesResponse<'bldr>> {
+    let mut builder = FoldingRangesResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.ranges { builder.add_ranges(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn ranges(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FoldingRange<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FoldingRange>>>>(FoldingRangesResponse::VT_RANGES, None).unwrap()}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FoldingRangesResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(FoldingRangesResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FoldingRangesResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<FoldingRange>>>>("ranges", Self::VT_RANGES, true)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FoldingRangesResponseArgs<'a> {
+    pub ranges: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FoldingRange<'a>>>>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for FoldingRangesResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FoldingRangesResponseArgs {
+      ranges: None, // required field
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct FoldingRangesResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FoldingRangesResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_ranges(&mut self, ranges: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<FoldingRange<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FoldingRangesResponse::VT_RANGES, ranges);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(FoldingRangesResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(FoldingRangesResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FoldingRangesResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FoldingRangesResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FoldingRangesResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, FoldingRangesResponse::VT_RANGES,"ranges");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FoldingRangesResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FoldingRangesResponse");
+      ds.field("ranges", &self.ranges());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum HistoryRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct HistoryRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for HistoryRequest<'a> {
+  type Inner = HistoryRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> HistoryRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_FROM: flatbuffers::VOffsetT = 6;
+  pub const VT_TO: flatbuffers::VOffsetT = 8;
+  pub const VT_LANG: flatbuffers::VOffsetT = 10;
+  pub const VT_MAX_COMMITS: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    HistoryRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args HistoryRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<HistoryRequest<'bldr>> {
+    let mut builder = HistoryRequestBuilder::new(_fbb);
+    builder.add_max_commits(args.max_commits);
+    if let Some(x) = args.lang { builder.add_lang(x); }
+    if let Some(x) = args.to { builder.add_to(x); }
+    if let Some(x) = args.from { builder.add_from(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(HistoryRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn from(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(HistoryRequest::VT_FROM, None)}
+  }
+  #[inline]
+  pub fn to(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(HistoryRequest::VT_TO, None)}
+  }
+  #[inline]
+  pub fn lang(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(HistoryRequest::VT_LANG, None)}
+  }
+  #[inline]
+  pub fn max_commits(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(HistoryRequest::VT_MAX_COMMITS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for HistoryRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("from", Self::VT_FROM, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("to", Self::VT_TO, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("lang", Self::VT_LANG, false)?
+     .visit_field::<u32>("max_commits", Self::VT_MAX_COMMITS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct HistoryRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub from: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub to: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub lang: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub max_commits: u32,
+}
+impl<'a> Default for HistoryRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    HistoryRequestArgs {
+      path: None, // required field
+      from: None,
+      to: None,
+      lang: None,
+      max_commits: 0,
+    }
+  }
+}
+
+pub struct HistoryRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HistoryRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HistoryRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_from(&mut self, from: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HistoryRequest::VT_FROM, from);
+  }
+  #[inline]
+  pub fn add_to(&mut self, to: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HistoryRequest::VT_TO, to);
+  }
+  #[inline]
+  pub fn add_lang(&mut self, lang: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HistoryRequest::VT_LANG, lang);
+  }
+  #[inline]
+  pub fn add_max_commits(&mut self, max_commits: u32) {
+    self.fbb_.push_slot::<u32>(HistoryRequest::VT_MAX_COMMITS, max_commits, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HistoryRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HistoryRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<HistoryRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, HistoryRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for HistoryRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("HistoryRequest");
+      ds.field("path", &self.path());
+      ds.field("from", &self.from());
+      ds.field("to", &self.to());
+      ds.field("lang", &self.lang());
+      ds.field("max_commits", &self.max_commits());
+      ds.finish()
+  }
+}
+pub enum DefinitionChangeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DefinitionChange<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DefinitionChange<'a> {
+  type Inner = DefinitionChange<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> DefinitionChange<'a> {
+  pub const VT_KIND: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_SYMBOL_KIND: flatbuffers::VOffsetT = 8;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 10;
+  pub const VT_START_ROW: flatbuffers::VOffsetT = 12;
+  pub const VT_END_ROW: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DefinitionChange { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args DefinitionChangeArgs<'args>
+  ) -> flatbuffers::WIPOffset<DefinitionChange<'bldr>> {
+    let mut builder = DefinitionChangeBuilder::new(_fbb);
+    builder.add_end_row(args.end_row);
+    builder.add_start_row(args.start_row);
+    if let Some(x) = args.location { builder.add_location(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_symbol_kind(args.symbol_kind);
+    builder.add_kind(args.kind);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn kind(&self) -> DefinitionChangeKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<DefinitionChangeKind>(DefinitionChange::VT_KIND, Some(DefinitionChangeKind::Added)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DefinitionChange::VT_NAME, None).unwrap()}
+  }
+  #[inline]
+  pub fn symbol_kind(&self) -> SymbolKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SymbolKind>(DefinitionChange::VT_SYMBOL_KIND, Some(SymbolKind::Other)).unwrap()}
+  }
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(DefinitionChange::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn start_row(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DefinitionChange::VT_START_ROW, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn end_row(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DefinitionChange::VT_END_ROW, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for DefinitionChange<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<DefinitionChangeKind>("kind", Self::VT_KIND, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+     .visit_field::<SymbolKind>("symbol_kind", Self::VT_SYMBOL_KIND, false)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<u32>("start_row", Self::VT_START_ROW, false)?
+     .visit_field::<u32>("end_row", Self::VT_END_ROW, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DefinitionChangeArgs<'a> {
+    pub kind: DefinitionChangeKind,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub symbol_kind: SymbolKind,
+    pub location: Option<&'a Location>,
+    pub start_row: u32,
+    pub end_row: u32,
+}
+impl<'a> Default for DefinitionChangeArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DefinitionChangeArgs {
+      kind: DefinitionChangeKind::Added,
+      name: None, // required field
+      symbol_kind: SymbolKind::Other,
+      location: None,
+      start_row: 0,
+      end_row: 0,
+    }
+  }
+}
+
+pub struct DefinitionChangeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DefinitionChangeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_kind(&mut self, kind: DefinitionChangeKind) {
+    self.fbb_.push_slot::<DefinitionChangeKind>(DefinitionChange::VT_KIND, kind, DefinitionChangeKind::Added);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DefinitionChange::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_symbol_kind(&mut self, symbol_kind: SymbolKind) {
+    self.fbb_.push_slot::<SymbolKind>(DefinitionChange::VT_SYMBOL_KIND, symbol_kind, SymbolKind::Other);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(DefinitionChange::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_start_row(&mut self, start_row: u32) {
+    self.fbb_.push_slot::<u32>(DefinitionChange::VT_START_ROW, start_row, 0);
+  }
+  #[inline]
+  pub fn add_end_row(&mut self, end_row: u32) {
+    self.fbb_.push_slot::<u32>(DefinitionChange::VT_END_ROW, end_row, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DefinitionChangeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DefinitionChangeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DefinitionChange<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, DefinitionChange::VT_NAME,"name");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DefinitionChange<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DefinitionChange");
+      ds.field("kind", &self.kind());
+      ds.field("name", &self.name());
+      ds.field("symbol_kind", &self.symbol_kind());
+      ds.field("location", &self.location());
+      ds.field("start_row", &self.start_row());
+      ds.field("end_row", &self.end_row());
+      ds.finish()
+  }
+}
+pub enum HistoryCommitOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct HistoryCommit<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for HistoryCommit<'a> {
+  type Inner = HistoryCommit<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> HistoryCommit<'a> {
+  pub const VT_COMMIT: flatbuffers::VOffsetT = 4;
+  pub const VT_AUTHOR: flatbuffers::VOffsetT = 6;
+  pub const VT_TIME: flatbuffers::VOffsetT = 8;
+  pub const VT_SUMMARY: flatbuffers::VOffsetT = 10;
+  pub const VT_DELETED: flatbuffers::VOffsetT = 12;
+  pub const VT_SYNTAX_ERRORS: flatbuffers::VOffsetT = 14;
+  pub const VT_CHANGES: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    HistoryCommit { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args HistoryCommitArgs<'args>
+  ) -> flatbuffers::WIPOffset<HistoryCommit<'bldr>> {
+    let mut builder = HistoryCommitBuilder::new(_fbb);
+    builder.add_time(args.time);
+    if let Some(x) = args.changes { builder.add_changes(x); }
+    builder.add_syntax_errors(args.syntax_errors);
+    if let Some(x) = args.summary { builder.add_summary(x); }
+    if let Some(x) = args.author { builder.add_author(x); }
+    if let Some(x) = args.commit { builder.add_commit(x); }
+    builder.add_deleted(args.deleted);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn commit(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(HistoryCommit::VT_COMMIT, None).unwrap()}
+  }
+  #[inline]
+  pub fn author(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(HistoryCommit::VT_AUTHOR, None)}
+  }
+  #[inline]
+  pub fn time(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(HistoryCommit::VT_TIME, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn summary(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(HistoryCommit::VT_SUMMARY, None)}
+  }
+  #[inline]
+  pub fn deleted(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(HistoryCommit::VT_DELETED, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn syntax_errors(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(HistoryCommit::VT_SYNTAX_ERRORS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn changes(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DefinitionChange<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DefinitionChange>>>>(HistoryCommit::VT_CHANGES, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for HistoryCommit<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("commit", Self::VT_COMMIT, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("author", Self::VT_AUTHOR, false)?
+     .visit_field::<u64>("time", Self::VT_TIME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("summary", Self::VT_SUMMARY, false)?
+     .visit_field::<bool>("deleted", Self::VT_DELETED, false)?
+     .visit_field::<u32>("syntax_errors", Self::VT_SYNTAX_ERRORS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<DefinitionChange>>>>("changes", Self::VT_CHANGES, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct HistoryCommitArgs<'a> {
+    pub commit: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub author: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub time: u64,
+    pub summary: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub deleted: bool,
+    pub syntax_errors: u32,
+    pub changes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DefinitionChange<'a>>>>>,
+}
+impl<'a> Default for HistoryCommitArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    HistoryCommitArgs {
+      commit: None, // required field
+      author: None,
+      time: 0,
+      summary: None,
+      deleted: false,
+      syntax_errors: 0,
+      changes: None, // required field
+    }
+  }
+}
+
+pub struct HistoryCommitBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HistoryCommitBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_commit(&mut self, commit: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HistoryCommit::VT_COMMIT, commit);
+  }
+  #[inline]
+  pub fn add_author(&mut self, author: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HistoryCommit::VT_AUTHOR, author);
+  }
+  #[inline]
+  pub fn add_time(&mut self, time: u64) {
+    self.fbb_.push_slot::<u64>(HistoryCommit::VT_TIME, time, 0);
+  }
+  #[inline]
+  pub fn add_summary(&mut self, summary: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HistoryCommit::VT_SUMMARY, summary);
+  }
+  #[inline]
+  pub fn add_deleted(&mut self, deleted: bool) {
+    self.fbb_.push_slot::<bool>(HistoryCommit::VT_DELETED, deleted, false);
+  }
+  #[inline]
+  pub fn add_syntax_errors(&mut self, syntax_errors: u32) {
+    self.fbb_.push_slot::<u32>(HistoryCommit::VT_SYNTAX_ERRORS, syntax_errors, 0);
+  }
+  #[inline]
+  pub fn add_changes(&mut self, changes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<DefinitionChange<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HistoryCommit::VT_CHANGES, changes);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HistoryCommitBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HistoryCommitBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<HistoryCommit<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, HistoryCommit::VT_COMMIT,"commit");
+    self.fbb_.required(o, HistoryCommit::VT_CHANGES,"changes");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for HistoryCommit<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("HistoryCommit");
+      ds.field("commit", &self.commit());
+      ds.field("author", &self.author());
+      ds.field("time", &self.time());
+      ds.field("summary", &self.summary());
+      ds.field("deleted", &self.deleted());
+      ds.field("syntax_errors", &self.syntax_errors());
+      ds.field("changes", &self.changes());
+      ds.finish()
+  }
+}
+pub enum HistoryResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct HistoryResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for HistoryResponse<'a> {
+  type Inner = HistoryResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> HistoryResponse<'a> {
+  pub const VT_COMMITS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    HistoryResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args HistoryResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<HistoryResponse<'bldr>> {
+    let mut builder = HistoryResponseBuilder::new(_fbb);
+    if let Some(x) = args.commits { builder.add_commits(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn commits(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<HistoryCommit<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<HistoryCommit>>>>(HistoryResponse::VT_COMMITS, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for HistoryResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<HistoryCommit>>>>("commits", Self::VT_COMMITS, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct HistoryResponseArgs<'a> {
+    pub commits: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<HistoryCommit<'a>>>>>,
+}
+impl<'a> Default for HistoryResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    HistoryResponseArgs {
+      commits: None, // required field
+    }
+  }
+}
+
+pub struct HistoryResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HistoryResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_commits(&mut self, commits: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<HistoryCommit<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HistoryResponse::VT_COMMITS, commits);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HistoryResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HistoryResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<HistoryResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, HistoryResponse::VT_COMMITS,"commits");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for HistoryResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("HistoryResponse");
+      ds.field("commits", &self.commits());
+      ds.finish()
+  }
+}
+pub enum BlameRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BlameRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BlameRequest<'a> {
+  type Inner = BlameRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> BlameRequest<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_NODES: flatbuffers::VOffsetT = 6;
+  pub const VT_RANGES: flatbuffers::VOffsetT = 8;
+  pub const VT_CHILDREN: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BlameRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BlameRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<BlameRequest<'bldr>> {
+    let mut builder = BlameRequestBuilder::new(_fbb);
+    if let Some(x) = args.ranges { builder.add_ranges(x); }
+    if let Some(x) = args.nodes { builder.add_nodes(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.add_children(args.children);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BlameRequest::VT_PATH, None).unwrap()}
+  }
+  #[inline]
+  pub fn nodes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeSelector<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeSelector>>>>(BlameRequest::VT_NODES, None)}
+  }
+  #[inline]
+  pub fn ranges(&self) -> Option<flatbuffers::Vector<'a, Location>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Location>>>(BlameRequest::VT_RANGES, None)}
+  }
+  #[inline]
+  pub fn children(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(BlameRequest::VT_CHILDREN, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for BlameRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<NodeSelector>>>>("nodes", Self::VT_NODES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Location>>>("ranges", Self::VT_RANGES, false)?
+     .visit_field::<bool>("children", Self::VT_CHILDREN, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BlameRequestArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub nodes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeSelector<'a>>>>>,
+    pub ranges: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Location>>>,
+    pub children: bool,
+}
+impl<'a> Default for BlameRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BlameRequestArgs {
+      path: None, // required field
+      nodes: None,
+      ranges: None,
+      children: false,
+    }
+  }
+}
+
+pub struct BlameRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BlameRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BlameRequest::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_nodes(&mut self, nodes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<NodeSelector<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BlameRequest::VT_NODES, nodes);
+  }
+  #[inline]
+  pub fn add_ranges(&mut self, ranges: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Location>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BlameRequest::VT_RANGES, ranges);
+  }
+  #[inline]
+  pub fn add_children(&mut self, children: bool) {
+    self.fbb_.push_slot::<bool>(BlameRequest::VT_CHILDREN, children, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BlameRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BlameRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BlameRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, BlameRequest::VT_PATH,"path");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BlameRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BlameRequest");
+      ds.field("path", &self.path());
+      ds.field("nodes", &self.nodes());
+      ds.field("ranges", &self.ranges());
+      ds.field("children", &self.children());
+      ds.finish()
+  }
+}
+pub enum BlameCommitOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BlameCommit<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BlameCommit<'a> {
+  type Inner = BlameCommit<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> BlameCommit<'a> {
+  pub const VT_COMMIT: flatbuffers::VOffsetT = 4;
+  pub const VT_AUTHOR: flatbuffers::VOffsetT = 6;
+  pub const VT_TIME: flatbuffers::VOffsetT = 8;
+  pub const VT_SUMMARY: flatbuffers::VOffsetT = 10;
+  pub const VT_LINES: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BlameCommit { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BlameCommitArgs<'args>
+  ) -> flatbuffers::WIPOffset<BlameCommit<'bldr>> {
+    let mut builder = BlameCommitBuilder::new(_fbb);
+    builder.add_time(args.time);
+    builder.add_lines(args.lines);
+    if let Some(x) = args.summary { builder.add_summary(x); }
+    if let Some(x) = args.author { builder.add_author(x); }
+    if let Some(x) = args.commit { builder.add_commit(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn commit(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BlameCommit::VT_COMMIT, None).unwrap()}
+  }
+  #[inline]
+  pub fn author(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BlameCommit::VT_AUTHOR, None)}
+  }
+  #[inline]
+  pub fn time(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(BlameCommit::VT_TIME, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn summary(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BlameCommit::VT_SUMMARY, None)}
+  }
+  #[inline]
+  pub fn lines(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BlameCommit::VT_LINES, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for BlameCommit<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("commit", Self::VT_COMMIT, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("author", Self::VT_AUTHOR, false)?
+     .visit_field::<u64>("time", Self::VT_TIME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("summary", Self::VT_SUMMARY, false)?
+     .visit_field::<u32>("lines", Self::VT_LINES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BlameCommitArgs<'a> {
+    pub commit: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub author: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub time: u64,
+    pub summary: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub lines: u32,
+}
+impl<'a> Default for BlameCommitArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BlameCommitArgs {
+      commit: None, // required field
+      author: None,
+      time: 0,
+      summary: None,
+      lines: 0,
+    }
+  }
+}
+
+pub struct BlameCommitBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BlameCommitBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_commit(&mut self, commit: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BlameCommit::VT_COMMIT, commit);
+  }
+  #[inline]
+  pub fn add_author(&mut self, author: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BlameCommit::VT_AUTHOR, author);
+  }
+  #[inline]
+  pub fn add_time(&mut self, time: u64) {
+    self.fbb_.push_slot::<u64>(BlameCommit::VT_TIME, time, 0);
+  }
+  #[inline]
+  pub fn add_summary(&mut self, summary: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BlameCommit::VT_SUMMARY, summary);
+  }
+  #[inline]
+  pub fn add_lines(&mut self, lines: u32) {
+    self.fbb_.push_slot::<u32>(BlameCommit::VT_LINES, lines, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BlameCommitBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BlameCommitBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BlameCommit<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, BlameCommit::VT_COMMIT,"commit");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BlameCommit<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BlameCommit");
+      ds.field("commit", &self.commit());
+      ds.field("author", &self.author());
+      ds.field("time", &self.time());
+      ds.field("summary", &self.summary());
+      ds.field("lines", &self.lines());
+      ds.finish()
+  }
+}
+pub enum NodeBlameOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct NodeBlame<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for NodeBlame<'a> {
+  type Inner = NodeBlame<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> NodeBlame<'a> {
+  pub const VT_KIND: flatbuffers::VOffsetT = 4;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 6;
+  pub const VT_START_ROW: flatbuffers::VOffsetT = 8;
+  pub const VT_END_ROW: flatbuffers::VOffsetT = 10;
+  pub const VT_LAST_COMMIT: flatbuffers::VOffsetT = 12;
+  pub const VT_COMMITS: flatbuffers::VOffsetT = 14;
+  pub const VT_CHILDREN: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    NodeBlame { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args NodeBlameArgs<'args>
+  ) -> flatbuffers::WIPOffset<NodeBlame<'bldr>> {
+    let mut builder = NodeBlameBuilder::new(_fbb);
+    if let Some(x) = args.children { builder.add_children(x); }
+    if let Some(x) = args.commits { builder.add_commits(x); }
+    if let Some(x) = args.last_commit { builder.add_last_commit(x); }
+    builder.add_end_row(args.end_row);
+    builder.add_start_row(args.start_row);
+    if let Some(x) = args.location { builder.add_location(x); }
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn kind(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(NodeBlame::VT_KIND, None).unwrap()}
+  }
+  #[inline]
+  pub fn location(&self) -> Option<&'a Location> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Location>(NodeBlame::VT_LOCATION, None)}
+  }
+  #[inline]
+  pub fn start_row(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(NodeBlame::VT_START_ROW, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn end_row(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(NodeBlame::VT_END_ROW, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn last_commit(&self) -> Option<BlameCommit<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<BlameCommit>>(NodeBlame::VT_LAST_COMMIT, None)}
+  }
+  #[inline]
+  pub fn commits(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<BlameCommit<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<BlameCommit>>>>(NodeBlame::VT_COMMITS, None).unwrap()}
+  }
+  #[inline]
+  pub fn children(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeBlame<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeBlame>>>>(NodeBlame::VT_CHILDREN, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for NodeBlame<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, true)?
+     .visit_field::<Location>("location", Self::VT_LOCATION, false)?
+     .visit_field::<u32>("start_row", Self::VT_START_ROW, false)?
+     .visit_field::<u32>("end_row", Self::VT_END_ROW, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<BlameCommit>>("last_commit", Self::VT_LAST_COMMIT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<BlameCommit>>>>("commits", Self::VT_COMMITS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<NodeBlame>>>>("children", Self::VT_CHILDREN, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct NodeBlameArgs<'a> {
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub location: Option<&'a Location>,
+    pub start_row: u32,
+    pub end_row: u32,
+    pub last_commit: Option<flatbuffers::WIPOffset<BlameCommit<'a>>>,
+    pub commits: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<BlameCommit<'a>>>>>,
+    pub children: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeBlame<'a>>>>>,
+}
+impl<'a> Default for NodeBlameArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    NodeBlameArgs {
+      kind: None, // required field
+      location: None,
+      start_row: 0,
+      end_row: 0,
+      last_commit: None,
+      commits: None, // required field
+      children: None,
+    }
+  }
+}
+
+pub struct NodeBlameBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> NodeBlameBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NodeBlame::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: &Location) {
+    self.fbb_.push_slot_always::<&Location>(NodeBlame::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn add_start_row(&mut self, start_row: u32) {
+    self.fbb_.push_slot::<u32>(NodeBlame::VT_START_ROW, start_row, 0);
+  }
+  #[inline]
+  pub fn add_end_row(&mut self, end_row: u32) {
+    self.fbb_.push_slot::<u32>(NodeBlame::VT_END_ROW, end_row, 0);
+  }
+  #[inline]
+  pub fn add_last_commit(&mut self, last_commit: flatbuffers::WIPOffset<BlameCommit<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<BlameCommit>>(NodeBlame::VT_LAST_COMMIT, last_commit);
+  }
+  #[inline]
+  pub fn add_commits(&mut self, commits: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<BlameCommit<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NodeBlame::VT_COMMITS, commits);
+  }
+  #[inline]
+  pub fn add_children(&mut self, children: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<NodeBlame<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NodeBlame::VT_CHILDREN, children);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NodeBlameBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    NodeBlameBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<NodeBlame<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, NodeBlame::VT_KIND,"kind");
+    self.fbb_.required(o, NodeBlame::VT_COMMITS,"commits");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for NodeBlame<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("NodeBlame");
+      ds.field("kind", &self.kind());
+      ds.field("location", &self.location());
+      ds.field("start_row", &self.start_row());
+      ds.field("end_row", &self.end_row());
+      ds.field("last_commit", &self.last_commit());
+      ds.field("commits", &self.commits());
+      ds.field("children", &self.children());
+      ds.finish()
+  }
+}
+pub enum BlameResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BlameResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BlameResponse<'a> {
+  type Inner = BlameResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> BlameResponse<'a> {
+  pub const VT_NODES: flatbuffers::VOffsetT = 4;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT_HASH: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BlameResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BlameResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<BlameResponse<'bldr>> {
+    let mut builder = BlameResponseBuilder::new(_fbb);
+    builder.add_content_hash(args.content_hash);
+    builder.add_version(args.version);
+    if let Some(x) = args.nodes { builder.add_nodes(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn nodes(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeBlame<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeBlame>>>>(BlameResponse::VT_NODES, None).unwrap()}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BlameResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_hash(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(BlameResponse::VT_CONTENT_HASH, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for BlameResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<NodeBlame>>>>("nodes", Self::VT_NODES, true)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u64>("content_hash", Self::VT_CONTENT_HASH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BlameResponseArgs<'a> {
+    pub nodes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeBlame<'a>>>>>,
+    pub version: u32,
+    pub content_hash: u64,
+}
+impl<'a> Default for BlameResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BlameResponseArgs {
+      nodes: None, // required field
+      version: 0,
+      content_hash: 0,
+    }
+  }
+}
+
+pub struct BlameResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BlameResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_nodes(&mut self, nodes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<NodeBlame<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BlameResponse::VT_NODES, nodes);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(BlameResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_content_hash(&mut self, content_hash: u64) {
+    self.fbb_.push_slot::<u64>(BlameResponse::VT_CONTENT_HASH, content_hash, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BlameResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BlameResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BlameResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, BlameResponse::VT_NODES,"nodes");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BlameResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BlameResponse");
+      ds.field("nodes", &self.nodes());
+      ds.field("version", &self.version());
+      ds.field("content_hash", &self.content_hash());
+      ds.finish()
+  }
+}
+pub enum RewriteDocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RewriteDocument<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RewriteDocument<'a> {
+  type Inner = RewriteDocument<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RewriteDocument<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 6;
+  pub const VT_EDITS: flatbuffers::VOffsetT = 8;
+  pub const VT_LANG: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RewriteDocument { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RewriteDocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<RewriteDocument<'bldr>> {
+    let mut builder = RewriteDocumentBuilder::new(_fbb);
+    if let Some(x) = args.lang { builder.add_lang(x); }
+    if let Some(x) = args.edits { builder.add_edits(x); }
+    if let Some(x) = args.text { builder.add_text(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(RewriteDocument::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn text(&self) -> flatbuffers::Vector<'a, u16> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(RewriteDocument::VT_TEXT, None).unwrap()}
+  }
+  #[inline]
+  pub fn edits(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit>>>>(RewriteDocument::VT_EDITS, None)}
+  }
+  #[inline]
+  pub fn lang(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(RewriteDocument::VT_LANG, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for RewriteDocument<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("text", Self::VT_TEXT, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TextEdit>>>>("edits", Self::VT_EDITS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("lang", Self::VT_LANG, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RewriteDocumentArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub text: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+    pub edits: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextEdit<'a>>>>>,
+    pub lang: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for RewriteDocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RewriteDocumentArgs {
+      name: None,
+      text: None, // required field
+      edits: None,
+      lang: None,
+    }
+  }
+}
+
+pub struct RewriteDocumentBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RewriteDocumentBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RewriteDocument::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RewriteDocument::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn add_edits(&mut self, edits: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TextEdit<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RewriteDocument::VT_EDITS, edits);
+  }
+  #[inline]
+  pub fn add_lang(&mut self, lang: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RewriteDocument::VT_LANG, lang);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RewriteDocumentBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RewriteDocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RewriteDocument<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, RewriteDocument::VT_TEXT,"text");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RewriteDocument<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RewriteDocument");
+      ds.field("name", &self.name());
+      ds.field("text", &self.text());
+      ds.field("edits", &self.edits());
+      ds.field("lang", &self.lang());
+      ds.finish()
+  }
+}
+pub enum InvariantOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Invariant<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Invariant<'a> {
+  type Inner = Invariant<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Invariant<'a> {
+  pub const VT_QUERY: flatbuffers::VOffsetT = 4;
+  pub const VT_EXPECT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Invariant { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args InvariantArgs<'args>
+  ) -> flatbuffers::WIPOffset<Invariant<'bldr>> {
+    let mut builder = InvariantBuilder::new(_fbb);
+    if let Some(x) = args.query { builder.add_query(x); }
+    builder.add_expect(args.expect);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn query(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Invariant::VT_QUERY, None).unwrap()}
+  }
+  #[inline]
+  pub fn expect(&self) -> InvariantExpectation {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<InvariantExpectation>(Invariant::VT_EXPECT, Some(InvariantExpectation::Unchanged)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Invariant<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("query", Self::VT_QUERY, true)?
+     .visit_field::<InvariantExpectation>("expect", Self::VT_EXPECT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct InvariantArgs<'a> {
+    pub query: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub expect: InvariantExpectation,
+}
+impl<'a> Default for InvariantArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    InvariantArgs {
+      query: None, // required field
+      expect: InvariantExpectation::Unchanged,
+    }
+  }
+}
+
+pub struct InvariantBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> InvariantBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_query(&mut self, query: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Invariant::VT_QUERY, query);
+  }
+  #[inline]
+  pub fn add_expect(&mut self, expect: InvariantExpectation) {
+    self.fbb_.push_slot::<InvariantExpectation>(Invariant::VT_EXPECT, expect, InvariantExpectation::Unchanged);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> InvariantBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    InvariantBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Invariant<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Invariant::VT_QUERY,"query");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Invariant<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Invariant");
+      ds.field("query", &self.query());
+      ds.field("expect", &self.expect());
+      ds.finish()
+  }
+}
+pub enum VerifyRewriteRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct VerifyRewriteRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for VerifyRewriteRequest<'a> {
+  type Inner = VerifyRewriteRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> VerifyRewriteRequest<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+  pub const VT_LANG: flatbuffers::VOffsetT = 6;
+  pub const VT_INVARIANTS: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    VerifyRewriteRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args VerifyRewriteRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<VerifyRewriteRequest<'bldr>> {
+    let mut builder = VerifyRewriteRequestBuilder::new(_fbb);
+    if let Some(x) = args.invariants { builder.add_invariants(x); }
+    if let Some(x) = args.lang { builder.add_lang(x); }
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RewriteDocument<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RewriteDocument>>>>(VerifyRewriteRequest::VT_DOCUMENTS, None).unwrap()}
+  }
+  #[inline]
+  pub fn lang(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(VerifyRewriteRequest::VT_LANG, None)}
+  }
+  #[inline]
+  pub fn invariants(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Invariant<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Invariant>>>>(VerifyRewriteRequest::VT_INVARIANTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for VerifyRewriteRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RewriteDocument>>>>("documents", Self::VT_DOCUMENTS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("lang", Self::VT_LANG, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Invariant>>>>("invariants", Self::VT_INVARIANTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct VerifyRewriteRequestArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RewriteDocument<'a>>>>>,
+    pub lang: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub invariants: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Invariant<'a>>>>>,
+}
+impl<'a> Default for VerifyRewriteRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    VerifyRewriteRequestArgs {
+      documents: None, // required field
+      lang: None,
+      invariants: None,
+    }
+  }
+}
+
+pub struct VerifyRewriteRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> VerifyRewriteRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<RewriteDocument<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(VerifyRewriteRequest::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn add_lang(&mut self, lang: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(VerifyRewriteRequest::VT_LANG, lang);
+  }
+  #[inline]
+  pub fn add_invariants(&mut self, invariants: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Invariant<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(VerifyRewriteRequest::VT_INVARIANTS, invariants);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> VerifyRewriteRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    VerifyRewriteRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<VerifyRewriteRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, VerifyRewriteRequest::VT_DOCUMENTS,"documents");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for VerifyRewriteRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("VerifyRewriteRequest");
+      ds.field("documents", &self.documents());
+      ds.field("lang", &self.lang());
+      ds.field("invariants", &self.invariants());
+      ds.finish()
+  }
+}
+pub enum InvariantResultOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct InvariantResult<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for InvariantResult<'a> {
+  type Inner = InvariantResult<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> InvariantResult<'a> {
+  pub const VT_HOLDS: flatbuffers::VOffsetT = 4;
+  pub const VT_MATCHES_BEFORE: flatbuffers::VOffsetT = 6;
+  pub const VT_MATCHES_AFTER: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    InvariantResult { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args InvariantResultArgs
+  ) -> flatbuffers::WIPOffset<InvariantResult<'bldr>> {
+    let mut builder = InvariantResultBuilder::new(_fbb);
+    builder.add_matches_after(args.matches_after);
+    builder.add_matches_before(args.matches_before);
+    builder.add_holds(args.holds);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn holds(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(InvariantResult::VT_HOLDS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn matches_before(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(InvariantResult::VT_MATCHES_BEFORE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn matches_after(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(InvariantResult::VT_MATCHES_AFTER, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for InvariantResult<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("holds", Self::VT_HOLDS, false)?
+     .visit_field::<u32>("matches_before", Self::VT_MATCHES_BEFORE, false)?
+     .visit_field::<u32>("matches_after", Self::VT_MATCHES_AFTER, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct InvariantResultArgs {
+    pub holds: bool,
+    pub matches_before: u32,
+    pub matches_after: u32,
+}
+impl<'a> Default for InvariantResultArgs {
+  #[inline]
+  fn default() -> Self {
+    InvariantResultArgs {
+      holds: false,
+      matches_before: 0,
+      matches_after: 0,
+    }
+  }
+}
+
+pub struct InvariantResultBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> InvariantResultBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_holds(&mut self, holds: bool) {
+    self.fbb_.push_slot::<bool>(InvariantResult::VT_HOLDS, holds, false);
+  }
+  #[inline]
+  pub fn add_matches_before(&mut self, matches_before: u32) {
+    self.fbb_.push_slot::<u32>(InvariantResult::VT_MATCHES_BEFORE, matches_before, 0);
+  }
+  #[inline]
+  pub fn add_matches_after(&mut self, matches_after: u32) {
+    self.fbb_.push_slot::<u32>(InvariantResult::VT_MATCHES_AFTER, matches_after, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> InvariantResultBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    InvariantResultBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<InvariantResult<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for InvariantResult<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("InvariantResult");
+      ds.field("holds", &self.holds());
+      ds.field("matches_before", &self.matches_before());
+      ds.field("matches_after", &self.matches_after());
+      ds.finish()
+  }
+}
+pub enum RewrittenDocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RewrittenDocument<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RewrittenDocument<'a> {
+  type Inner = RewrittenDocument<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RewrittenDocument<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_SYNTAX_ERRORS_BEFORE: flatbuffers::VOffsetT = 6;
+  pub const VT_SYNTAX_ERRORS_AFTER: flatbuffers::VOffsetT = 8;
+  pub const VT_DIAGNOSTICS: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RewrittenDocument { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RewrittenDocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<RewrittenDocument<'bldr>> {
+    let mut builder = RewrittenDocumentBuilder::new(_fbb);
+    if let Some(x) = args.diagnostics { builder.add_diagnostics(x); }
+    builder.add_syntax_errors_after(args.syntax_errors_after);
+    builder.add_syntax_errors_before(args.syntax_errors_before);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(RewrittenDocument::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn syntax_errors_before(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(RewrittenDocument::VT_SYNTAX_ERRORS_BEFORE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn syntax_errors_after(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(RewrittenDocument::VT_SYNTAX_ERRORS_AFTER, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn diagnostics(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Diagnostic<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Diagnostic>>>>(RewrittenDocument::VT_DIAGNOSTICS, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for RewrittenDocument<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u32>("syntax_errors_before", Self::VT_SYNTAX_ERRORS_BEFORE, false)?
+     .visit_field::<u32>("syntax_errors_after", Self::VT_SYNTAX_ERRORS_AFTER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Diagnostic>>>>("diagnostics", Self::VT_DIAGNOSTICS, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RewrittenDocumentArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub syntax_errors_before: u32,
+    pub syntax_errors_after: u32,
+    pub diagnostics: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Diagnostic<'a>>>>>,
+}
+impl<'a> Default for RewrittenDocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RewrittenDocumentArgs {
+      name: None,
+      syntax_errors_before: 0,
+      syntax_errors_after: 0,
+      diagnostics: None, // required field
+    }
+  }
+}
+
+pub struct RewrittenDocumentBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RewrittenDocumentBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RewrittenDocument::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_syntax_errors_before(&mut self, syntax_errors_before: u32) {
+    self.fbb_.push_slot::<u32>(RewrittenDocument::VT_SYNTAX_ERRORS_BEFORE, syntax_errors_before, 0);
+  }
+  #[inline]
+  pub fn add_syntax_errors_after(&mut self, syntax_errors_after: u32) {
+    self.fbb_.push_slot::<u32>(RewrittenDocument::VT_SYNTAX_ERRORS_AFTER, syntax_errors_after, 0);
+  }
+  #[inline]
+  pub fn add_diagnostics(&mut self, diagnostics: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Diagnostic<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RewrittenDocument::VT_DIAGNOSTICS, diagnostics);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RewrittenDocumentBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RewrittenDocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RewrittenDocument<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, RewrittenDocument::VT_DIAGNOSTICS,"diagnostics");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RewrittenDocument<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RewrittenDocument");
+      ds.field("name", &self.name());
+      ds.field("syntax_errors_before", &self.syntax_errors_before());
+      ds.field("syntax_errors_after", &self.syntax_errors_after());
+      ds.field("diagnostics", &self.diagnostics());
+      ds.finish()
+  }
+}
+pub enum VerifyRewriteResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct VerifyRewriteResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for VerifyRewriteResponse<'a> {
+  type Inner = VerifyRewriteResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> VerifyRewriteResponse<'a> {
+  pub const VT_OK: flatbuffers::VOffsetT = 4;
+  pub const VT_INVARIANTS: flatbuffers::VOffsetT = 6;
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    VerifyRewriteResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args VerifyRewriteResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<VerifyRewriteResponse<'bldr>> {
+    let mut builder = VerifyRewriteResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    if let Some(x) = args.invariants { builder.add_invariants(x); }
+    builder.add_ok(args.ok);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn ok(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(VerifyRewriteResponse::VT_OK, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn invariants(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<InvariantResult<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<InvariantResult>>>>(VerifyRewriteResponse::VT_INVARIANTS, None).unwrap()}
+  }
+  #[inline]
+  pub fn documents(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RewrittenDocument<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RewrittenDocument>>>>(VerifyRewriteResponse::VT_DOCUMENTS, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for VerifyRewriteResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("ok", Self::VT_OK, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<InvariantResult>>>>("invariants", Self::VT_INVARIANTS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RewrittenDocument>>>>("documents", Self::VT_DOCUMENTS, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct VerifyRewriteResponseArgs<'a> {
+    pub ok: bool,
+    pub invariants: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<InvariantResult<'a>>>>>,
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RewrittenDocument<'a>>>>>,
+}
+impl<'a> Default for VerifyRewriteResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    VerifyRewriteResponseArgs {
+      ok: false,
+      invariants: None, // required field
+      documents: None, // required field
+    }
+  }
+}
+
+pub struct VerifyRewriteResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> VerifyRewriteResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_ok(&mut self, ok: bool) {
+    self.fbb_.push_slot::<bool>(VerifyRewriteResponse::VT_OK, ok, false);
+  }
+  #[inline]
+  pub fn add_invariants(&mut self, invariants: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<InvariantResult<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(VerifyRewriteResponse::VT_INVARIANTS, invariants);
+  }
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<RewrittenDocument<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(VerifyRewriteResponse::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> VerifyRewriteResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    VerifyRewriteResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<VerifyRewriteResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, VerifyRewriteResponse::VT_INVARIANTS,"invariants");
+    self.fbb_.required(o, VerifyRewriteResponse::VT_DOCUMENTS,"documents");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for VerifyRewriteResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("VerifyRewriteResponse");
+      ds.field("ok", &self.ok());
+      ds.field("invariants", &self.invariants());
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_REQUEST_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_REQUEST: flatbuffers::VOffsetT = 6;
+  pub const VT_ID: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RequestArgs
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_id(args.id);
+    if let Some(x) = args.request { builder.add_request(x); }
+    builder.add_request_type(args.request_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn request_type(&self) -> RequestUnion {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<RequestUnion>(Request::VT_REQUEST_TYPE, Some(RequestUnion::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn request(&self) -> flatbuffers::Table<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Request::VT_REQUEST, None).unwrap()}
+  }
+  #[inline]
+  pub fn id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_init_request(&self) -> Option<InitRequest<'a>> {
+    if self.request_type() == RequestUnion::InitRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { InitRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_file_request(&self) -> Option<FileRequest<'a>> {
+    if self.request_type() == RequestUnion::FileRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { FileRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_reload_grammars_request(&self) -> Option<ReloadGrammarsRequest<'a>> {
+    if self.request_type() == RequestUnion::ReloadGrammarsRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { ReloadGrammarsRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_debug_parse_request(&self) -> Option<DebugParseRequest<'a>> {
+    if self.request_type() == RequestUnion::DebugParseRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { DebugParseRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_edit_request(&self) -> Option<EditRequest<'a>> {
+    if self.request_type() == RequestUnion::EditRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { EditRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_snapshot_request(&self) -> Option<SnapshotRequest<'a>> {
+    if self.request_type() == RequestUnion::SnapshotRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { SnapshotRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_restore_request(&self) -> Option<RestoreRequest<'a>> {
+    if self.request_type() == RequestUnion::RestoreRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { RestoreRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_shutdown_request(&self) -> Option<ShutdownRequest<'a>> {
+    if self.request_type() == RequestUnion::ShutdownRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { ShutdownRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_chunk_request(&self) -> Option<ChunkRequest<'a>> {
+    if self.request_type() == RequestUnion::ChunkRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { ChunkRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_metrics_request(&self) -> Option<MetricsRequest<'a>> {
+    if self.request_type() == RequestUnion::MetricsRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { MetricsRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_complexity_request(&self) -> Option<ComplexityRequest<'a>> {
+    if self.request_type() == RequestUnion::ComplexityRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { ComplexityRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_imports_request(&self) -> Option<ImportsRequest<'a>> {
+    if self.request_type() == RequestUnion::ImportsRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { ImportsRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_todos_request(&self) -> Option<TodosRequest<'a>> {
+    if self.request_type() == RequestUnion::TodosRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { TodosRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_clones_request(&self) -> Option<ClonesRequest<'a>> {
+    if self.request_type() == RequestUnion::ClonesRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { ClonesRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_plugin_request(&self) -> Option<PluginRequest<'a>> {
+    if self.request_type() == RequestUnion::PluginRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { PluginRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_query_request(&self) -> Option<QueryRequest<'a>> {
+    if self.request_type() == RequestUnion::QueryRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueryRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_cancel_request(&self) -> Option<CancelRequest<'a>> {
+    if self.request_type() == RequestUnion::CancelRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { CancelRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_resume_request(&self) -> Option<ResumeRequest<'a>> {
+    if self.request_type() == RequestUnion::ResumeRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { ResumeRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_parse_snippet_request(&self) -> Option<ParseSnippetRequest<'a>> {
+    if self.request_type() == RequestUnion::ParseSnippetRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { ParseSnippetRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_transaction_request(&self) -> Option<TransactionRequest<'a>> {
+    if self.request_type() == RequestUnion::TransactionRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { TransactionRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_node_edit_request(&self) -> Option<NodeEditRequest<'a>> {
+    if self.request_type() == RequestUnion::NodeEditRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { NodeEditRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_list_languages_request(&self) -> Option<ListLanguagesRequest<'a>> {
+    if self.request_type() == RequestUnion::ListLanguagesRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { ListLanguagesRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_playground_request(&self) -> Option<PlaygroundRequest<'a>> {
+    if self.request_type() == RequestUnion::PlaygroundRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { PlaygroundRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_format_ranges_request(&self) -> Option<FormatRangesRequest<'a>> {
+    if self.request_type() == RequestUnion::FormatRangesRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { FormatRangesRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_traverse_request(&self) -> Option<TraverseRequest<'a>> {
+    if self.request_type() == RequestUnion::TraverseRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { TraverseRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_selection_ranges_request(&self) -> Option<SelectionRangesRequest<'a>> {
+    if self.request_type() == RequestUnion::SelectionRangesRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { SelectionRangesRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_symbols_request(&self) -> Option<SymbolsRequest<'a>> {
+    if self.request_type() == RequestUnion::SymbolsRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { SymbolsRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_folding_ranges_request(&self) -> Option<FoldingRangesRequest<'a>> {
+    if self.request_type() == RequestUnion::FoldingRangesRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { FoldingRangesRequest::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn request_as_history_request(&self) -> Option<HistoryRequest<'a>> {
+    if self.request_type() == RequestUnion::HistoryRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { HistoryRequest::init_from_table(u) })
+    } else {
+      None
+    }
   }
 
-
   #[inline]
-  pub fn request_type(&self) -> RequestUnion {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<RequestUnion>(Request::VT_REQUEST_TYPE, Some(RequestUnion::NONE)).unwrap()}
+  #[allow(non_snake_case)]
+  pub fn request_as_blame_request(&self) -> Option<BlameRequest<'a>> {
+    if self.request_type() == RequestUnion::BlameRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { BlameRequest::init_from_table(u) })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn request(&self) -> flatbuffers::Table<'a> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Request::VT_REQUEST, None).unwrap()}
+  #[allow(non_snake_case)]
+  pub fn request_as_verify_rewrite_request(&self) -> Option<VerifyRewriteRequest<'a>> {
+    if self.request_type() == RequestUnion::VerifyRewriteRequest {
+      let u = self.request();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { VerifyRewriteRequest::init_from_table(u) })
+    } else {
+      None
+    }
   }
+
   #[inline]
   #[allow(non_snake_case)]
-  pub fn request_as_init_request(&self) -> Option<InitRequest<'a>> {
-    if self.request_type() == RequestUnion::InitRequest {
+  pub fn request_as_run_plugin_request(&self) -> Option<RunPluginRequest<'a>> {
+    if self.request_type() == RequestUnion::RunPluginRequest {
       let u = self.request();
       // Safety:
       // Created from a valid Table for this object
       // Which contains a valid union in this slot
-      Some(unsafe { InitRequest::init_from_table(u) })
+      Some(unsafe { RunPluginRequest::init_from_table(u) })
     } else {
       None
     }
@@ -600,13 +16768,13 @@ impl<'a> Request<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn request_as_file_request(&self) -> Option<FileRequest<'a>> {
-    if self.request_type() == RequestUnion::FileRequest {
+  pub fn request_as_script_request(&self) -> Option<ScriptRequest<'a>> {
+    if self.request_type() == RequestUnion::ScriptRequest {
       let u = self.request();
       // Safety:
       // Created from a valid Table for this object
       // Which contains a valid union in this slot
-      Some(unsafe { FileRequest::init_from_table(u) })
+      Some(unsafe { ScriptRequest::init_from_table(u) })
     } else {
       None
     }
@@ -625,9 +16793,41 @@ impl flatbuffers::Verifiable for Request<'_> {
         match key {
           RequestUnion::InitRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<InitRequest>>("RequestUnion::InitRequest", pos),
           RequestUnion::FileRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FileRequest>>("RequestUnion::FileRequest", pos),
+          RequestUnion::ReloadGrammarsRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReloadGrammarsRequest>>("RequestUnion::ReloadGrammarsRequest", pos),
+          RequestUnion::DebugParseRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DebugParseRequest>>("RequestUnion::DebugParseRequest", pos),
+          RequestUnion::EditRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EditRequest>>("RequestUnion::EditRequest", pos),
+          RequestUnion::SnapshotRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SnapshotRequest>>("RequestUnion::SnapshotRequest", pos),
+          RequestUnion::RestoreRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RestoreRequest>>("RequestUnion::RestoreRequest", pos),
+          RequestUnion::ShutdownRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ShutdownRequest>>("RequestUnion::ShutdownRequest", pos),
+          RequestUnion::ChunkRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ChunkRequest>>("RequestUnion::ChunkRequest", pos),
+          RequestUnion::MetricsRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MetricsRequest>>("RequestUnion::MetricsRequest", pos),
+          RequestUnion::ComplexityRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ComplexityRequest>>("RequestUnion::ComplexityRequest", pos),
+          RequestUnion::ImportsRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ImportsRequest>>("RequestUnion::ImportsRequest", pos),
+          RequestUnion::TodosRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TodosRequest>>("RequestUnion::TodosRequest", pos),
+          RequestUnion::ClonesRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ClonesRequest>>("RequestUnion::ClonesRequest", pos),
+          RequestUnion::PluginRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PluginRequest>>("RequestUnion::PluginRequest", pos),
+          RequestUnion::QueryRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<QueryRequest>>("RequestUnion::QueryRequest", pos),
+          RequestUnion::CancelRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CancelRequest>>("RequestUnion::CancelRequest", pos),
+          RequestUnion::ResumeRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ResumeRequest>>("RequestUnion::ResumeRequest", pos),
+          RequestUnion::ParseSnippetRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ParseSnippetRequest>>("RequestUnion::ParseSnippetRequest", pos),
+          RequestUnion::TransactionRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TransactionRequest>>("RequestUnion::TransactionRequest", pos),
+          RequestUnion::NodeEditRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<NodeEditRequest>>("RequestUnion::NodeEditRequest", pos),
+          RequestUnion::ListLanguagesRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ListLanguagesRequest>>("RequestUnion::ListLanguagesRequest", pos),
+          RequestUnion::PlaygroundRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlaygroundRequest>>("RequestUnion::PlaygroundRequest", pos),
+          RequestUnion::FormatRangesRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FormatRangesRequest>>("RequestUnion::FormatRangesRequest", pos),
+          RequestUnion::TraverseRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TraverseRequest>>("RequestUnion::TraverseRequest", pos),
+          RequestUnion::SelectionRangesRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SelectionRangesRequest>>("RequestUnion::SelectionRangesRequest", pos),
+          RequestUnion::SymbolsRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SymbolsRequest>>("RequestUnion::SymbolsRequest", pos),
+          RequestUnion::FoldingRangesRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FoldingRangesRequest>>("RequestUnion::FoldingRangesRequest", pos),
+          RequestUnion::HistoryRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<HistoryRequest>>("RequestUnion::HistoryRequest", pos),
+          RequestUnion::BlameRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BlameRequest>>("RequestUnion::BlameRequest", pos),
+          RequestUnion::VerifyRewriteRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<VerifyRewriteRequest>>("RequestUnion::VerifyRewriteRequest", pos),
+          RequestUnion::RunPluginRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RunPluginRequest>>("RequestUnion::RunPluginRequest", pos),
+          RequestUnion::ScriptRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ScriptRequest>>("RequestUnion::ScriptRequest", pos),
           _ => Ok(()),
         }
      })?
+     .visit_field::<u64>("id", Self::VT_ID, false)?
      .finish();
     Ok(())
   }
@@ -635,6 +16835,7 @@ impl flatbuffers::Verifiable for Request<'_> {
 pub struct RequestArgs {
     pub request_type: RequestUnion,
     pub request: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub id: u64,
 }
 impl<'a> Default for RequestArgs {
   #[inline]
@@ -642,6 +16843,7 @@ impl<'a> Default for RequestArgs {
     RequestArgs {
       request_type: RequestUnion::NONE,
       request: None, // required field
+      id: 0,
     }
   }
 }
@@ -660,6 +16862,10 @@ impl<'a: 'b, 'b> RequestBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_REQUEST, request);
   }
   #[inline]
+  pub fn add_id(&mut self, id: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_ID, id, 0);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RequestBuilder<'a, 'b> {
     let start = _fbb.start_table();
     RequestBuilder {
@@ -694,11 +16900,229 @@ impl core::fmt::Debug for Request<'_> {
             ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
+        RequestUnion::ReloadGrammarsRequest => {
+          if let Some(x) = self.request_as_reload_grammars_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::DebugParseRequest => {
+          if let Some(x) = self.request_as_debug_parse_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::EditRequest => {
+          if let Some(x) = self.request_as_edit_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::SnapshotRequest => {
+          if let Some(x) = self.request_as_snapshot_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::RestoreRequest => {
+          if let Some(x) = self.request_as_restore_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::ShutdownRequest => {
+          if let Some(x) = self.request_as_shutdown_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::ChunkRequest => {
+          if let Some(x) = self.request_as_chunk_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::MetricsRequest => {
+          if let Some(x) = self.request_as_metrics_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::ComplexityRequest => {
+          if let Some(x) = self.request_as_complexity_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::ImportsRequest => {
+          if let Some(x) = self.request_as_imports_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::TodosRequest => {
+          if let Some(x) = self.request_as_todos_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::ClonesRequest => {
+          if let Some(x) = self.request_as_clones_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::PluginRequest => {
+          if let Some(x) = self.request_as_plugin_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::QueryRequest => {
+          if let Some(x) = self.request_as_query_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::CancelRequest => {
+          if let Some(x) = self.request_as_cancel_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::ResumeRequest => {
+          if let Some(x) = self.request_as_resume_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::ParseSnippetRequest => {
+          if let Some(x) = self.request_as_parse_snippet_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::TransactionRequest => {
+          if let Some(x) = self.request_as_transaction_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::NodeEditRequest => {
+          if let Some(x) = self.request_as_node_edit_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::ListLanguagesRequest => {
+          if let Some(x) = self.request_as_list_languages_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::PlaygroundRequest => {
+          if let Some(x) = self.request_as_playground_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::FormatRangesRequest => {
+          if let Some(x) = self.request_as_format_ranges_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::TraverseRequest => {
+          if let Some(x) = self.request_as_traverse_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::SelectionRangesRequest => {
+          if let Some(x) = self.request_as_selection_ranges_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::SymbolsRequest => {
+          if let Some(x) = self.request_as_symbols_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::FoldingRangesRequest => {
+          if let Some(x) = self.request_as_folding_ranges_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::HistoryRequest => {
+          if let Some(x) = self.request_as_history_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::BlameRequest => {
+          if let Some(x) = self.request_as_blame_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::VerifyRewriteRequest => {
+          if let Some(x) = self.request_as_verify_rewrite_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::RunPluginRequest => {
+          if let Some(x) = self.request_as_run_plugin_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestUnion::ScriptRequest => {
+          if let Some(x) = self.request_as_script_request() {
+            ds.field("request", &x)
+          } else {
+            ds.field("request", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
         _ => {
           let x: Option<()> = None;
           ds.field("request", &x)
         },
       };
+      ds.field("id", &self.id());
       ds.finish()
   }
 }
@@ -723,6 +17147,14 @@ impl<'a> Node<'a> {
   pub const VT_CHILDREN: flatbuffers::VOffsetT = 8;
   pub const VT_NAMED: flatbuffers::VOffsetT = 10;
   pub const VT_TEXT: flatbuffers::VOffsetT = 12;
+  pub const VT_HASH: flatbuffers::VOffsetT = 14;
+  pub const VT_FIELD: flatbuffers::VOffsetT = 16;
+  pub const VT_START_POINT: flatbuffers::VOffsetT = 18;
+  pub const VT_END_POINT: flatbuffers::VOffsetT = 20;
+  pub const VT_OFFSETS: flatbuffers::VOffsetT = 22;
+  pub const VT_CHILD_COUNT: flatbuffers::VOffsetT = 24;
+  pub const VT_NAMED_DESCENDANT_COUNT: flatbuffers::VOffsetT = 26;
+  pub const VT_SUPERTYPES: flatbuffers::VOffsetT = 28;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -734,6 +17166,14 @@ impl<'a> Node<'a> {
     args: &'args NodeArgs<'args>
   ) -> flatbuffers::WIPOffset<Node<'bldr>> {
     let mut builder = NodeBuilder::new(_fbb);
+    if let Some(x) = args.hash { builder.add_hash(x); }
+    if let Some(x) = args.supertypes { builder.add_supertypes(x); }
+    if let Some(x) = args.named_descendant_count { builder.add_named_descendant_count(x); }
+    if let Some(x) = args.child_count { builder.add_child_count(x); }
+    if let Some(x) = args.offsets { builder.add_offsets(x); }
+    if let Some(x) = args.end_point { builder.add_end_point(x); }
+    if let Some(x) = args.start_point { builder.add_start_point(x); }
+    if let Some(x) = args.field { builder.add_field(x); }
     if let Some(x) = args.text { builder.add_text(x); }
     if let Some(x) = args.children { builder.add_children(x); }
     if let Some(x) = args.location { builder.add_location(x); }
@@ -778,6 +17218,62 @@ impl<'a> Node<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(Node::VT_TEXT, None)}
   }
+  #[inline]
+  pub fn hash(&self) -> Option<u64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Node::VT_HASH, None)}
+  }
+  #[inline]
+  pub fn field(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Node::VT_FIELD, None)}
+  }
+  #[inline]
+  pub fn start_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(Node::VT_START_POINT, None)}
+  }
+  #[inline]
+  pub fn end_point(&self) -> Option<&'a Point> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Point>(Node::VT_END_POINT, None)}
+  }
+  #[inline]
+  pub fn offsets(&self) -> Option<&'a Offsets> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Offsets>(Node::VT_OFFSETS, None)}
+  }
+  #[inline]
+  pub fn child_count(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Node::VT_CHILD_COUNT, None)}
+  }
+  #[inline]
+  pub fn named_descendant_count(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Node::VT_NAMED_DESCENDANT_COUNT, None)}
+  }
+  #[inline]
+  pub fn supertypes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Node::VT_SUPERTYPES, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for Node<'_> {
@@ -792,6 +17288,14 @@ impl flatbuffers::Verifiable for Node<'_> {
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Node>>>>("children", Self::VT_CHILDREN, false)?
      .visit_field::<bool>("named", Self::VT_NAMED, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("text", Self::VT_TEXT, false)?
+     .visit_field::<u64>("hash", Self::VT_HASH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("field", Self::VT_FIELD, false)?
+     .visit_field::<Point>("start_point", Self::VT_START_POINT, false)?
+     .visit_field::<Point>("end_point", Self::VT_END_POINT, false)?
+     .visit_field::<Offsets>("offsets", Self::VT_OFFSETS, false)?
+     .visit_field::<u32>("child_count", Self::VT_CHILD_COUNT, false)?
+     .visit_field::<u32>("named_descendant_count", Self::VT_NAMED_DESCENDANT_COUNT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("supertypes", Self::VT_SUPERTYPES, false)?
      .finish();
     Ok(())
   }
@@ -802,6 +17306,14 @@ pub struct NodeArgs<'a> {
     pub children: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node<'a>>>>>,
     pub named: bool,
     pub text: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+    pub hash: Option<u64>,
+    pub field: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub start_point: Option<&'a Point>,
+    pub end_point: Option<&'a Point>,
+    pub offsets: Option<&'a Offsets>,
+    pub child_count: Option<u32>,
+    pub named_descendant_count: Option<u32>,
+    pub supertypes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
 }
 impl<'a> Default for NodeArgs<'a> {
   #[inline]
@@ -812,6 +17324,14 @@ impl<'a> Default for NodeArgs<'a> {
       children: None,
       named: false,
       text: None,
+      hash: None,
+      field: None,
+      start_point: None,
+      end_point: None,
+      offsets: None,
+      child_count: None,
+      named_descendant_count: None,
+      supertypes: None,
     }
   }
 }
@@ -842,6 +17362,38 @@ impl<'a: 'b, 'b> NodeBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_TEXT, text);
   }
   #[inline]
+  pub fn add_hash(&mut self, hash: u64) {
+    self.fbb_.push_slot_always::<u64>(Node::VT_HASH, hash);
+  }
+  #[inline]
+  pub fn add_field(&mut self, field: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_FIELD, field);
+  }
+  #[inline]
+  pub fn add_start_point(&mut self, start_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(Node::VT_START_POINT, start_point);
+  }
+  #[inline]
+  pub fn add_end_point(&mut self, end_point: &Point) {
+    self.fbb_.push_slot_always::<&Point>(Node::VT_END_POINT, end_point);
+  }
+  #[inline]
+  pub fn add_offsets(&mut self, offsets: &Offsets) {
+    self.fbb_.push_slot_always::<&Offsets>(Node::VT_OFFSETS, offsets);
+  }
+  #[inline]
+  pub fn add_child_count(&mut self, child_count: u32) {
+    self.fbb_.push_slot_always::<u32>(Node::VT_CHILD_COUNT, child_count);
+  }
+  #[inline]
+  pub fn add_named_descendant_count(&mut self, named_descendant_count: u32) {
+    self.fbb_.push_slot_always::<u32>(Node::VT_NAMED_DESCENDANT_COUNT, named_descendant_count);
+  }
+  #[inline]
+  pub fn add_supertypes(&mut self, supertypes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_SUPERTYPES, supertypes);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NodeBuilder<'a, 'b> {
     let start = _fbb.start_table();
     NodeBuilder {
@@ -865,6 +17417,14 @@ impl core::fmt::Debug for Node<'_> {
       ds.field("children", &self.children());
       ds.field("named", &self.named());
       ds.field("text", &self.text());
+      ds.field("hash", &self.hash());
+      ds.field("field", &self.field());
+      ds.field("start_point", &self.start_point());
+      ds.field("end_point", &self.end_point());
+      ds.field("offsets", &self.offsets());
+      ds.field("child_count", &self.child_count());
+      ds.field("named_descendant_count", &self.named_descendant_count());
+      ds.field("supertypes", &self.supertypes());
       ds.finish()
   }
 }
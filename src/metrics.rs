@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use crate::{
+	chunk::CHARS_PER_TOKEN,
+	document::Document,
+	message_generated::asted::interface::{
+		FunctionMetrics, FunctionMetricsArgs, KindCount, KindCountArgs, Location, MetricsResponse,
+		MetricsResponseArgs,
+	},
+	queries,
+};
+
+/// Computes the metrics of `document` and returns a serialized `MetricsResponse`.
+pub fn metrics(document: &Document) -> Vec<u8> {
+	let _span = tracing::info_span!("metrics").entered();
+
+	let mut counts = HashMap::<&str, u32>::new();
+	let mut max_depth = 0;
+	let mut depth = 0;
+	let mut cursor = document.tree.walk();
+	'walk: loop {
+		let node = cursor.node();
+		if node.is_named() {
+			*counts.entry(node.kind()).or_default() += 1;
+		}
+		max_depth = max_depth.max(depth);
+
+		if cursor.goto_first_child() {
+			depth += 1;
+			continue;
+		}
+		while !cursor.goto_next_sibling() {
+			if !cursor.goto_parent() {
+				break 'walk;
+			}
+			depth -= 1;
+		}
+	}
+	let mut counts = counts.into_iter().collect::<Vec<_>>();
+	counts.sort_by(|(a_kind, a), (b_kind, b)| b.cmp(a).then(a_kind.cmp(b_kind)));
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let counts = counts
+		.into_iter()
+		.map(|(kind, count)| {
+			let kind = builder.create_string(kind);
+			KindCount::create(
+				&mut builder,
+				&KindCountArgs {
+					kind: Some(kind),
+					count,
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let counts = builder.create_vector(&counts);
+
+	let functions = queries::tags(&document.language, &document.tree, &document.text)
+		.into_iter()
+		.filter(|tag| tag.is_function())
+		.map(|tag| {
+			let name = builder.create_string(&tag.name_text(&document.text));
+			let kind = builder.create_string(tag.kind);
+			let node = tag.node;
+			FunctionMetrics::create(
+				&mut builder,
+				&FunctionMetricsArgs {
+					name: Some(name),
+					kind: Some(kind),
+					location: Some(&Location::new(
+						node.start_byte() as u32,
+						node.end_byte() as u32,
+					)),
+					lines: (node.end_position().row - node.start_position().row + 1) as u32,
+					approximate_tokens: approximate_tokens(
+						(node.end_byte() - node.start_byte()) / 2,
+					),
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let functions = builder.create_vector(&functions);
+
+	let resp = MetricsResponse::create(
+		&mut builder,
+		&MetricsResponseArgs {
+			node_counts: Some(counts),
+			max_depth,
+			lines: document.text.line_count() as u32,
+			approximate_tokens: approximate_tokens(document.text.len()),
+			functions: Some(functions),
+			version: document.version,
+			content_hash: document.text.content_hash(),
+		},
+	);
+	builder.finish(resp, None);
+
+	builder.finished_data().to_vec()
+}
+
+fn approximate_tokens(chars: usize) -> u32 {
+	chars.div_ceil(CHARS_PER_TOKEN) as u32
+}
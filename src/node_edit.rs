@@ -0,0 +1,225 @@
+use std::ops::Range;
+
+use anyhow::Result;
+use tree_sitter::{Node, Tree};
+
+use crate::{
+	edit,
+	error::Error,
+	message_generated::asted::interface::{
+		NodeEditRequest, NodeEditResponse, NodeEditResponseArgs, NodeOperation, NodeSelector,
+		TextEdit, TextEditArgs,
+	},
+	replay,
+	text::Text,
+	tree_cache,
+	tree_serialize::TextPolicy,
+	uri, State,
+};
+
+/// Works out the text edits the operation in `req` comes down to, applies them to the document
+/// (unless it's a preview) and returns a serialized `NodeEditResponse`.
+pub fn node_edit(state: &mut State, req: NodeEditRequest) -> Result<Vec<u8>> {
+	let _span = tracing::info_span!("node_edit", operation = ?req.operation()).entered();
+	let key = uri::key(req.path())?;
+	let base_version = req.base_version();
+	let document = state.edited_document(&key, base_version, req.if_hash())?;
+	let edits = edits(&document.text, &document.tree, &req)?;
+
+	let byte_edits = edits
+		.iter()
+		.map(|(range, text)| (range.start * 2, range.end * 2, text.clone()))
+		.collect::<Vec<_>>();
+	let options = state.serialize_options(
+		req.profile(),
+		req.structural_hashes(),
+		req.dual_offsets(),
+		TextPolicy::from_request(req.text_inclusion(), req.text_max_bytes()),
+	);
+	let logged = (replay::enabled() && !req.preview()).then(|| byte_edits.clone());
+	let (text, tree, mtime) = state.edited(&key, base_version, req.if_hash(), byte_edits)?;
+	let old = Some((Some(tree), base_version + 1));
+	let file = match req.preview() {
+		true => {
+			let (text, tree, parse_info) = state.parse_document(&key, text, mtime, old, false)?;
+			tree_cache::serialize(&text, &tree, &parse_info, options)
+		}
+		false => {
+			let (document, parse_info) = state.reparse(&key, text, mtime, old, false)?;
+			if let Some(byte_edits) = &logged {
+				let input = replay::Input::Edits {
+					base_version,
+					edits: byte_edits,
+				};
+				replay::record(&key, document, &parse_info, input);
+			}
+			document.serialize(&parse_info, options)
+		}
+	};
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let edits = edits
+		.iter()
+		.map(|(range, text)| {
+			let text = builder.create_vector(text);
+			TextEdit::create(
+				&mut builder,
+				&TextEditArgs {
+					start_byte: range.start as u32 * 2,
+					old_end_byte: range.end as u32 * 2,
+					text: Some(text),
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let edits = builder.create_vector(&edits);
+	let file = builder.create_vector(&file);
+	let resp = NodeEditResponse::create(
+		&mut builder,
+		&NodeEditResponseArgs {
+			edits: Some(edits),
+			file: Some(file),
+		},
+	);
+	builder.finish(resp, None);
+
+	Ok(builder.finished_data().to_vec())
+}
+
+/// The replacements (of ranges of code units) making up the operation in `req`, normalized.
+fn edits(text: &Text, tree: &Tree, req: &NodeEditRequest) -> Result<Vec<(Range<usize>, Vec<u16>)>> {
+	let node = find(tree, req.node(), "node")?;
+	let target = || -> Result<Node> {
+		let target = req.target().ok_or_else(|| {
+			Error::InvalidRequest(format!("A {:?} needs a target", req.operation()))
+		})?;
+		let target = find(tree, target, "target")?;
+		if node.start_byte() < target.end_byte() && target.start_byte() < node.end_byte() {
+			return Err(
+				Error::InvalidRequest("The node and its target overlap".to_string()).into(),
+			);
+		}
+		Ok(target)
+	};
+
+	let edits = match req.operation() {
+		NodeOperation::Delete => vec![(deletion(text, node), Vec::new())],
+		NodeOperation::Replace => {
+			let new_text = req
+				.text()
+				.ok_or_else(|| Error::InvalidRequest("A Replace needs the new text".to_string()))?;
+			vec![(units(node), new_text.iter().collect())]
+		}
+		NodeOperation::Swap => {
+			let target = target()?;
+			vec![
+				(units(node), text.slice(units(target))),
+				(units(target), text.slice(units(node))),
+			]
+		}
+		NodeOperation::MoveBefore | NodeOperation::MoveAfter => {
+			let target = target()?;
+			let moved = text.slice(units(node));
+			let separator = separator(text, target);
+			let (at, inserted) = match req.operation() {
+				NodeOperation::MoveBefore => (units(target).start, [moved, separator].concat()),
+				_ => (units(target).end, [separator, moved].concat()),
+			};
+			vec![(deletion(text, node), Vec::new()), (at..at, inserted)]
+		}
+		operation => {
+			return Err(
+				Error::InvalidRequest(format!("Unknown node operation {:?}", operation)).into(),
+			)
+		}
+	};
+
+	edit::normalize(text, edits)
+}
+
+/// The node `selector` picks out of `tree`: the outermost spanning exactly its location, of its
+/// kind if it has one.
+pub fn find<'t>(tree: &'t Tree, selector: NodeSelector, what: &str) -> Result<Node<'t>> {
+	let location = selector
+		.location()
+		.ok_or_else(|| Error::InvalidRequest(format!("The {} has no location", what)))?;
+	let (start, end) = (location.start_byte() as usize, location.end_byte() as usize);
+	let spans = |node: &Node| node.start_byte() == start && node.end_byte() == end;
+
+	let mut found = None;
+	let mut node = tree.root_node().descendant_for_byte_range(start, end);
+	while let Some(outer) = node.filter(spans) {
+		if selector.kind().is_none_or(|kind| kind == outer.kind()) {
+			found = Some(outer);
+		}
+		node = outer.parent();
+	}
+	found.ok_or_else(|| {
+		Error::InvalidRequest(format!(
+			"No {}{} spans {}..{}",
+			selector
+				.kind()
+				.map_or(String::new(), |kind| format!("{} ", kind)),
+			what,
+			start,
+			end
+		))
+		.into()
+	})
+}
+
+/// What to delete to remove `node`: it and its separator from a neighbouring named sibling, or
+/// without any, the whole line it's on if nothing else is.
+fn deletion(text: &Text, node: Node) -> Range<usize> {
+	let Range { start, end } = units(node);
+	if let Some(next) = node.next_named_sibling() {
+		return start..units(next).start;
+	}
+	if let Some(prev) = node.prev_named_sibling() {
+		return units(prev).end..end;
+	}
+
+	let start_row = text.point_at(start).row;
+	let end_row = text.point_at(end).row;
+	let line_start = text.offset_at(start_row, 0);
+	let line_end = text.offset_at(end_row, usize::MAX);
+	if !blank(&text.slice(line_start..start)) || !blank(&text.slice(end..line_end)) {
+		return start..end;
+	}
+	let next_line = text.offset_at(end_row + 1, 0);
+	match next_line > line_end {
+		true => line_start..next_line,
+		// the last line goes along with the line break before it
+		false if start_row > 0 => text.offset_at(start_row - 1, usize::MAX)..line_end,
+		false => line_start..line_end,
+	}
+}
+
+/// What goes between `node` and a node put next to it: what's between it and its next named
+/// sibling (or else its previous one), or without any, a line break and its indentation if it
+/// starts its line, or a space.
+fn separator(text: &Text, node: Node) -> Vec<u16> {
+	if let Some(next) = node.next_named_sibling() {
+		return text.slice(units(node).end..units(next).start);
+	}
+	if let Some(prev) = node.prev_named_sibling() {
+		return text.slice(units(prev).end..units(node).start);
+	}
+
+	let start = units(node).start;
+	let indentation = text.slice(text.offset_at(text.point_at(start).row, 0)..start);
+	match blank(&indentation) {
+		true => [vec!['\n' as u16], indentation].concat(),
+		false => vec![' ' as u16],
+	}
+}
+
+/// The code units `node` spans.
+fn units(node: Node) -> Range<usize> {
+	// byte offsets from a UTF-16 parse are twice the code unit offsets
+	node.start_byte() / 2..node.end_byte() / 2
+}
+
+fn blank(units: &[u16]) -> bool {
+	units.iter().all(|&c| c == ' ' as u16 || c == '\t' as u16)
+}
@@ -0,0 +1,117 @@
+use std::{
+	collections::HashMap,
+	sync::{Arc, PoisonError, RwLock},
+};
+
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use tree_sitter::Language;
+
+use crate::languages;
+
+/// An entry of a grammar's `node-types.json`, of which only supertypes (which list their subtypes)
+/// matter here.
+#[derive(Deserialize)]
+struct NodeType {
+	#[serde(rename = "type")]
+	kind: String,
+	named: bool,
+	#[serde(default)]
+	subtypes: Vec<NodeType>,
+}
+
+/// The supertypes of each kind of node of a grammar, nearest first.
+#[derive(Default)]
+pub struct Supertypes {
+	named: HashMap<String, Vec<String>>,
+	anonymous: HashMap<String, Vec<String>>,
+}
+
+impl Supertypes {
+	fn parse(node_types: &str) -> Result<Self> {
+		let node_types = serde_json::from_str::<Vec<NodeType>>(node_types)?;
+		// supertypes are always named, but their subtypes needn't be
+		let mut direct = HashMap::<(&str, bool), Vec<&str>>::new();
+		for supertype in &node_types {
+			for subtype in &supertype.subtypes {
+				direct
+					.entry((&subtype.kind, subtype.named))
+					.or_default()
+					.push(&supertype.kind);
+			}
+		}
+
+		let mut all = Supertypes::default();
+		for (&(kind, named), nearest) in &direct {
+			// breadth-first, since supertypes can be subtypes of others (like `declaration` of
+			// `statement`)
+			let mut supertypes = nearest.clone();
+			let mut i = 0;
+			while i < supertypes.len() {
+				for &supertype in direct.get(&(supertypes[i], true)).into_iter().flatten() {
+					if !supertypes.contains(&supertype) {
+						supertypes.push(supertype);
+					}
+				}
+				i += 1;
+			}
+			let by_kind = match named {
+				true => &mut all.named,
+				false => &mut all.anonymous,
+			};
+			by_kind.insert(
+				kind.to_string(),
+				supertypes.into_iter().map(String::from).collect(),
+			);
+		}
+		Ok(all)
+	}
+
+	/// The supertypes of nodes of `kind`, nearest first.
+	pub fn of(&self, kind: &str, named: bool) -> &[String] {
+		let by_kind = match named {
+			true => &self.named,
+			false => &self.anonymous,
+		};
+		by_kind.get(kind).map_or(&[], Vec::as_slice)
+	}
+}
+
+static SUPERTYPES: Lazy<RwLock<HashMap<Language, Arc<Supertypes>>>> = Lazy::new(Default::default);
+
+/// The supertypes of the grammar `language`, which has none if it has no `node-types.json`.
+pub fn supertypes(language: Language) -> Arc<Supertypes> {
+	if let Some(supertypes) = SUPERTYPES
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.get(&language)
+	{
+		return supertypes.clone();
+	}
+
+	let supertypes = languages::node_types(language)
+		.and_then(|node_types| match node_types {
+			Some(node_types) => Supertypes::parse(&node_types).context("Error parsing node types"),
+			None => Ok(Supertypes::default()),
+		})
+		.unwrap_or_else(|e| {
+			// logged once, since the empty supertypes are cached like any others
+			tracing::warn!("{:#}", e);
+			Supertypes::default()
+		});
+	let supertypes = Arc::new(supertypes);
+	SUPERTYPES
+		.write()
+		.unwrap_or_else(PoisonError::into_inner)
+		.insert(language, supertypes.clone());
+	supertypes
+}
+
+/// Forgets every grammar's supertypes, for when the `node_types` of languages may have changed.
+pub fn forget() {
+	SUPERTYPES
+		.write()
+		.unwrap_or_else(PoisonError::into_inner)
+		.clear();
+}
@@ -0,0 +1,113 @@
+use std::{
+	collections::HashSet,
+	sync::{PoisonError, RwLock},
+};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+use crate::{error::Error, message_generated::asted::interface::RequestUnion};
+
+/// Something that changes the server's state, which clients can be kept from doing with `--deny`
+/// (or `--read-only`, for all of them).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Capability {
+	/// Changing open documents with edits (including transactions and node edits), snapshots and restores
+	Edit,
+	/// Dropping every cached document with `POST /admin/flush`
+	Flush,
+	/// Reloading grammars
+	Grammars,
+	/// Shutting the server down, and running plugins (whose handlers can do anything)
+	Admin,
+}
+
+static DENIED: Lazy<RwLock<HashSet<Capability>>> = Lazy::new(Default::default);
+
+/// Denies `capabilities` (or every one, if `read_only`), allowing the others again.
+pub fn deny(read_only: bool, capabilities: impl IntoIterator<Item = Capability>) {
+	let denied = match read_only {
+		true => all().collect(),
+		false => capabilities.into_iter().collect(),
+	};
+	*DENIED.write().unwrap_or_else(PoisonError::into_inner) = denied;
+}
+
+/// Every capability, for `--read-only`.
+fn all() -> impl Iterator<Item = Capability> {
+	<Capability as clap::ValueEnum>::value_variants()
+		.iter()
+		.copied()
+}
+
+/// Fails with a forbidden error if `capability` was denied.
+pub fn check(capability: Capability) -> Result<()> {
+	if DENIED
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.contains(&capability)
+	{
+		let name = clap::ValueEnum::to_possible_value(&capability).unwrap();
+		return Err(Error::Forbidden(format!(
+			"The {} capability is denied on this server",
+			name.get_name()
+		))
+		.into());
+	}
+	Ok(())
+}
+
+/// The capability a request needs, if any.
+pub fn required(request_type: RequestUnion) -> Option<Capability> {
+	match request_type {
+		RequestUnion::EditRequest
+		| RequestUnion::TransactionRequest
+		| RequestUnion::NodeEditRequest
+		| RequestUnion::SnapshotRequest
+		| RequestUnion::RestoreRequest => Some(Capability::Edit),
+		RequestUnion::ReloadGrammarsRequest => Some(Capability::Grammars),
+		RequestUnion::ShutdownRequest | RequestUnion::PluginRequest => Some(Capability::Admin),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Whether a request of `request_type` would be let through.
+	fn allowed(request_type: RequestUnion) -> bool {
+		required(request_type).is_none_or(|capability| check(capability).is_ok())
+	}
+
+	// one test, since what's denied is global to the server
+	#[test]
+	fn read_only_denies_everything_that_changes_the_server() {
+		deny(true, []);
+		for request_type in [
+			RequestUnion::EditRequest,
+			RequestUnion::TransactionRequest,
+			RequestUnion::NodeEditRequest,
+			RequestUnion::SnapshotRequest,
+			RequestUnion::RestoreRequest,
+			RequestUnion::ReloadGrammarsRequest,
+			RequestUnion::ShutdownRequest,
+			RequestUnion::PluginRequest,
+		] {
+			assert!(!allowed(request_type), "{:?} is allowed", request_type);
+		}
+		assert!(check(Capability::Flush).is_err());
+		assert!(allowed(RequestUnion::FileRequest));
+		assert!(allowed(RequestUnion::QueryRequest));
+
+		deny(false, [Capability::Admin]);
+		assert!(allowed(RequestUnion::EditRequest));
+		assert!(!allowed(RequestUnion::ShutdownRequest));
+		assert!(!allowed(RequestUnion::PluginRequest));
+
+		deny(false, []);
+		assert!(allowed(RequestUnion::ShutdownRequest));
+	}
+}
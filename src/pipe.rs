@@ -0,0 +1,102 @@
+use std::{
+	net::{Ipv4Addr, SocketAddr},
+	pin::pin,
+	sync::Arc,
+};
+
+use anyhow::{Context, Result};
+use axum::{extract::ConnectInfo, Extension, Router};
+use tokio::io::{AsyncRead, AsyncWrite};
+
+use crate::{daemon, idle::Activity};
+
+/// Serves `app` over HTTP on a local transport until the server shuts down: the named pipe
+/// `name` on Windows (`\\.\pipe\<name>`, unless a full pipe path is given), and a Unix domain
+/// socket at the path `name` elsewhere.
+pub async fn serve(name: String, app: Router, activity: Arc<Activity>) -> Result<()> {
+	// pipe clients don't have an address, so they're all rate limited as one local client
+	let app = app.layer(Extension(ConnectInfo(SocketAddr::from((
+		Ipv4Addr::LOCALHOST,
+		0,
+	)))));
+
+	#[cfg(windows)]
+	return serve_pipe(name, app, activity).await;
+	#[cfg(unix)]
+	return serve_socket(name.into(), app, activity).await;
+}
+
+#[cfg(windows)]
+async fn serve_pipe(name: String, app: Router, activity: Arc<Activity>) -> Result<()> {
+	use tokio::net::windows::named_pipe::ServerOptions;
+
+	let name = if name.starts_with(r"\\.\pipe\") {
+		name
+	} else {
+		format!(r"\\.\pipe\{}", name)
+	};
+	let create = |first| {
+		ServerOptions::new()
+			.first_pipe_instance(first)
+			.create(&name)
+			.with_context(|| format!("Error creating pipe {}", name))
+	};
+
+	// a pipe instance only ever serves one client, so a new one is created for the next client as
+	// soon as one connects
+	let mut server = create(true)?;
+	tracing::info!("listening on {}", name);
+	let mut shutdown = pin!(daemon::shutdown_signal());
+	loop {
+		tokio::select! {
+			_ = &mut shutdown => return Ok(()),
+			connected = server.connect() => connected.context("Error accepting pipe client")?,
+		}
+		let client = std::mem::replace(&mut server, create(false)?);
+		serve_connection(client, app.clone(), &activity);
+	}
+}
+
+#[cfg(unix)]
+async fn serve_socket(
+	path: std::path::PathBuf,
+	app: Router,
+	activity: Arc<Activity>,
+) -> Result<()> {
+	let listener = tokio::net::UnixListener::bind(&path)
+		.with_context(|| format!("Error listening on {}", path.display()))?;
+	tracing::info!("listening on {}", path.display());
+
+	let mut shutdown = pin!(daemon::shutdown_signal());
+	let res = loop {
+		let stream = tokio::select! {
+			_ = &mut shutdown => break Ok(()),
+			accepted = listener.accept() => match accepted {
+				Ok((stream, _)) => stream,
+				Err(e) => break Err(e).context("Error accepting connection"),
+			},
+		};
+		serve_connection(stream, app.clone(), &activity);
+	};
+	// unlike pipes, sockets stay behind on disk, and would keep the next server from binding
+	let _ = std::fs::remove_file(&path);
+	res
+}
+
+fn serve_connection(
+	io: impl AsyncRead + AsyncWrite + Unpin + Send + 'static,
+	app: Router,
+	activity: &Arc<Activity>,
+) {
+	// connected clients keep the server from going idle
+	let busy = activity.busy();
+	tokio::spawn(async move {
+		let _busy = busy;
+		if let Err(e) = hyper::server::conn::Http::new()
+			.serve_connection(io, app)
+			.await
+		{
+			tracing::error!("Error serving local connection: {}", e);
+		}
+	});
+}
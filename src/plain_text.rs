@@ -0,0 +1,131 @@
+use std::time::{Duration, SystemTime};
+
+use axum::body::Bytes;
+
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+use crate::{
+	message_generated::asted::interface::{Location, Node, NodeArgs},
+	text::Text,
+	tree_serialize::{self, Counts, ParseInfo},
+};
+
+/// Serializes a `FileResponse` for text without a grammar: a `document` node holding one `line`
+/// node per line, each made up of named `word` and anonymous `whitespace` tokens. Line breaks are
+/// `whitespace` tokens at the end of their line.
+pub fn serialize(text: &Text, mtime: Option<SystemTime>) -> Bytes {
+	let content_hash = text.content_hash();
+	let line_ending = tree_serialize::line_ending(text);
+	let utf16 = text.to_utf16();
+	let text = utf16.as_slice();
+	let _span = tracing::info_span!("serialize").entered();
+	let mut builder = FlatBufferBuilder::with_capacity(1024);
+	let mut counts = Counts::default();
+
+	let mut lines = Vec::new();
+	let mut start = 0;
+	while start < text.len() {
+		let end = text[start..]
+			.iter()
+			.position(|&c| c == '\n' as u16)
+			.map_or(text.len(), |i| start + i + 1);
+		lines.push(build_line(text, start, end, &mut builder, &mut counts));
+		start = end;
+	}
+
+	let root_node = build_parent(&mut builder, "document", 0, text.len(), &lines, &mut counts);
+	let parse_info = ParseInfo {
+		// tokenizing happens as part of serialization
+		duration: Duration::ZERO,
+		reused_old_tree: false,
+		cached_tree: false,
+		changed_ranges: Vec::new(),
+		version: 0,
+		content_hash,
+		mtime,
+	};
+	tree_serialize::finish(builder, root_node, &counts, &parse_info, line_ending)
+}
+
+fn build_line<'a>(
+	text: &[u16],
+	start: usize,
+	end: usize,
+	builder: &mut FlatBufferBuilder<'a>,
+	counts: &mut Counts,
+) -> WIPOffset<Node<'a>> {
+	let is_whitespace = |c: u16| char::from_u32(c as u32).is_some_and(char::is_whitespace);
+
+	let mut tokens = Vec::new();
+	let mut token_start = start;
+	while token_start < end {
+		let whitespace = is_whitespace(text[token_start]);
+		let token_end = text[token_start..end]
+			.iter()
+			.position(|&c| is_whitespace(c) != whitespace)
+			.map_or(end, |i| token_start + i);
+
+		counts.nodes += 1;
+		let kind = builder.create_string(if whitespace { "whitespace" } else { "word" });
+		let children = builder.create_vector::<WIPOffset<Node>>(&[]);
+		let token_text = builder.create_vector(&text[token_start..token_end]);
+		tokens.push(Node::create(
+			builder,
+			&NodeArgs {
+				kind: Some(kind),
+				location: Some(&location(token_start, token_end)),
+				children: Some(children),
+				named: !whitespace,
+				text: Some(token_text),
+				hash: None,
+				field: None,
+				start_point: None,
+				offsets: None,
+				end_point: None,
+				child_count: None,
+				named_descendant_count: None,
+				supertypes: None,
+			},
+		));
+
+		token_start = token_end;
+	}
+
+	build_parent(builder, "line", start, end, &tokens, counts)
+}
+
+fn build_parent<'a>(
+	builder: &mut FlatBufferBuilder<'a>,
+	kind: &str,
+	start: usize,
+	end: usize,
+	children: &[WIPOffset<Node<'a>>],
+	counts: &mut Counts,
+) -> WIPOffset<Node<'a>> {
+	counts.nodes += 1;
+	let kind = builder.create_string(kind);
+	let children = builder.create_vector(children);
+	Node::create(
+		builder,
+		&NodeArgs {
+			kind: Some(kind),
+			location: Some(&location(start, end)),
+			children: Some(children),
+			named: true,
+			text: None,
+			hash: None,
+			field: None,
+			start_point: None,
+			offsets: None,
+			end_point: None,
+			child_count: None,
+			named_descendant_count: None,
+			supertypes: None,
+		},
+	)
+}
+
+/// Locations are byte offsets into the UTF-16 text, matching what tree-sitter reports.
+fn location(start: usize, end: usize) -> Location {
+	Location::new(start as u32 * 2, end as u32 * 2)
+}
@@ -0,0 +1,149 @@
+use std::time::Instant;
+
+use anyhow::Result;
+use flatbuffers::FlatBufferBuilder;
+use tree_sitter::{Node, QueryCursor, Tree};
+
+use crate::{
+	error::Error,
+	languages,
+	message_generated::asted::interface::{
+		Diagnostic, DiagnosticArgs, HighlightSpan, HighlightSpanArgs, Location, PlaygroundRequest,
+		PlaygroundResponse, PlaygroundResponseArgs, Point, SerializationProfile,
+	},
+	queries::{self, Kind},
+	syntax_errors,
+	text::Text,
+	tree_serialize::{self, ParseInfo, TextPolicy},
+	State,
+};
+
+/// Parses the text in `req` from scratch, without a document or any caching, and returns a
+/// serialized `PlaygroundResponse` of its tree, syntax errors and (if asked for) highlights.
+pub fn playground(state: &State, req: PlaygroundRequest) -> Result<Vec<u8>> {
+	let lang = match (req.lang(), &state.lang, state.plain_text) {
+		(Some(lang), _, _) => lang,
+		(None, Some(lang), false) => lang.as_str(),
+		_ => {
+			return Err(Error::UnknownLanguage(
+				"The playground needs a language with a grammar".to_string(),
+			)
+			.into())
+		}
+	};
+	let _span = tracing::info_span!("playground", lang).entered();
+
+	let text = Text::from_utf16(&req.text().iter().collect::<Vec<_>>())?;
+	let started = Instant::now();
+	// the session's own parser for its language, so asking for it doesn't set up another one
+	let parser_lang = (Some(lang) != state.lang.as_deref() || state.plain_text).then_some(lang);
+	let (tree, _) = state.parse_tree(parser_lang, &text, None, true)?;
+	let parse_info = ParseInfo {
+		duration: started.elapsed(),
+		reused_old_tree: false,
+		cached_tree: false,
+		changed_ranges: Vec::new(),
+		version: 0,
+		content_hash: text.content_hash(),
+		mtime: None,
+	};
+
+	let profile = match req.profile() {
+		SerializationProfile::Default => languages::config(lang).profile.unwrap_or_default(),
+		profile => profile,
+	};
+	let options = tree_serialize::Options::new(profile, req.structural_hashes());
+	let options = tree_serialize::Options {
+		dual_offsets: req.dual_offsets(),
+		text: TextPolicy::from_request(req.text_inclusion(), req.text_max_bytes())
+			.unwrap_or(options.text),
+		..options
+	};
+	let file = tree_serialize::serialize(&text, &tree, &parse_info, options);
+
+	let mut builder = FlatBufferBuilder::new();
+	let diagnostics = syntax_errors::syntax_errors(&text, &tree)
+		.into_iter()
+		.map(|error| {
+			let message = builder.create_string(&error.message);
+			Diagnostic::create(
+				&mut builder,
+				&DiagnosticArgs {
+					location: Some(&location(error.node)),
+					start_point: Some(&point(error.node.start_position())),
+					end_point: Some(&point(error.node.end_position())),
+					message: Some(message),
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let diagnostics = builder.create_vector(&diagnostics);
+	let highlights = req.highlights().then(|| {
+		let highlights = highlights(lang, &text, &tree)
+			.into_iter()
+			.map(|(capture, node)| {
+				let capture = builder.create_string(capture);
+				HighlightSpan::create(
+					&mut builder,
+					&HighlightSpanArgs {
+						capture: Some(capture),
+						location: Some(&location(node)),
+						start_point: Some(&point(node.start_position())),
+						end_point: Some(&point(node.end_position())),
+					},
+				)
+			})
+			.collect::<Vec<_>>();
+		builder.create_vector(&highlights)
+	});
+	let file = builder.create_vector(&file);
+	let resp = PlaygroundResponse::create(
+		&mut builder,
+		&PlaygroundResponseArgs {
+			file: Some(file),
+			diagnostics: Some(diagnostics),
+			highlights,
+		},
+	);
+	builder.finish(resp, None);
+
+	Ok(builder.finished_data().to_vec())
+}
+
+/// The nodes the highlights query of `lang` captures, by capture name. Where captures overlap,
+/// the first one wins.
+fn highlights<'tree>(
+	lang: &str,
+	text: &Text,
+	tree: &'tree Tree,
+) -> Vec<(&'static str, Node<'tree>)> {
+	let query = match queries::get(lang, tree.language(), Kind::Highlights) {
+		Some(query) => query,
+		None => return Vec::new(),
+	};
+	let names = query.capture_names();
+
+	let mut spans = Vec::new();
+	let mut cursor = QueryCursor::new();
+	let captures = cursor.captures(query, tree.root_node(), |node: Node| {
+		text.chunks(node.start_byte() / 2..node.end_byte() / 2)
+	});
+	let mut covered_until = 0;
+	for (m, index) in captures {
+		let capture = m.captures[index];
+		if capture.node.start_byte() < covered_until {
+			continue;
+		}
+		covered_until = capture.node.end_byte();
+		spans.push((names[capture.index as usize].as_str(), capture.node));
+	}
+	spans
+}
+
+fn location(node: Node) -> Location {
+	Location::new(node.start_byte() as u32, node.end_byte() as u32)
+}
+
+fn point(point: tree_sitter::Point) -> Point {
+	Point::new(point.row as u32, point.column as u32)
+}
@@ -0,0 +1,287 @@
+use std::{
+	cell::RefCell,
+	collections::HashMap,
+	ffi::c_void,
+	path::Path,
+	sync::{PoisonError, RwLock},
+};
+
+use anyhow::{bail, Context, Result};
+use libloading::Library;
+use once_cell::sync::Lazy;
+
+use crate::{document::Document, error::Error, export, languages, uri, uri::DocumentKey};
+
+/// Bumped whenever the structs below change.
+pub const ABI_VERSION: u32 = 1;
+
+/// A native plugin: a shared library handling `PluginRequest`s, which exports `asted_plugin_v1`,
+/// a C function taking no arguments and returning a pointer to a static `Plugin`.
+///
+/// The `#[repr(C)]` structs here are the whole ABI, which plugins written in Rust mirror. Strings
+/// are UTF-8, passed as a pointer and a length without a NUL. Requests are handled while the
+/// session is locked, so a plugin is never called concurrently, and everything the host hands it
+/// is only valid until the call returns.
+#[repr(C)]
+pub struct Plugin {
+	/// Must be `ABI_VERSION`
+	pub abi_version: u32,
+	/// What `PluginRequest.plugin` calls the plugin
+	pub name: *const u8,
+	pub name_len: usize,
+	/// Handles a request, with the `method` and `payload` of the `PluginRequest`. On success the
+	/// plugin writes the response payload to `out` and returns true; on failure it returns false,
+	/// and `out` holds an error message (or is left empty).
+	pub handle: unsafe extern "C" fn(
+		host: *const Host,
+		method: *const u8,
+		method_len: usize,
+		payload: *const u8,
+		payload_len: usize,
+		out: *mut Buffer,
+	) -> bool,
+	/// Frees a buffer the plugin wrote to `out`, once the host is done with it
+	pub free: unsafe extern "C" fn(buffer: Buffer),
+}
+
+/// Bytes allocated by the plugin.
+#[repr(C)]
+pub struct Buffer {
+	pub data: *mut u8,
+	pub len: usize,
+}
+
+/// What a plugin can call back into.
+#[repr(C)]
+pub struct Host {
+	/// Passed back to the functions below
+	pub context: *const c_void,
+	/// Fills in `out` with the open document at `uri`, returning false if it isn't open
+	pub document: unsafe extern "C" fn(
+		context: *const c_void,
+		uri: *const u8,
+		uri_len: usize,
+		out: *mut PluginDocument,
+	) -> bool,
+	/// The `TSLanguage` of a grammar in the language registry, or null if there's none by that
+	/// name. Plugins linking tree-sitter themselves can use it to look up node kinds.
+	pub language: unsafe extern "C" fn(name: *const u8, name_len: usize) -> *const c_void,
+}
+
+#[repr(C)]
+pub struct PluginDocument {
+	pub language: *const u8,
+	pub language_len: usize,
+	pub version: u32,
+	pub content_hash: u64,
+	/// The whole text, in UTF-16
+	pub text: *const u16,
+	pub text_len: usize,
+	/// The tree as a flat table, in pre-order
+	pub nodes: *const PluginNode,
+	pub node_count: usize,
+}
+
+/// A node of the tree, like the rows of the `export` subcommand.
+#[repr(C)]
+pub struct PluginNode {
+	pub kind: *const u8,
+	pub kind_len: usize,
+	/// The index of the parent in the table, or `u32::MAX` for the root
+	pub parent: u32,
+	/// Byte offsets into the UTF-16 text, like the HTTP interface reports
+	pub start_byte: u32,
+	pub end_byte: u32,
+	pub start_row: u32,
+	pub start_column: u32,
+	pub end_row: u32,
+	pub end_column: u32,
+	pub named: bool,
+	/// An ERROR or MISSING node
+	pub error: bool,
+}
+
+struct Loaded {
+	plugin: *const Plugin,
+	// never unloaded, since handed-out buffers may still be freed through it
+	_library: Library,
+}
+
+// Safety: the plugin struct is static, and only ever read
+unsafe impl Send for Loaded {}
+unsafe impl Sync for Loaded {}
+
+static PLUGINS: Lazy<RwLock<HashMap<String, Loaded>>> = Lazy::new(Default::default);
+
+/// Loads the plugin at `path`, returning its name.
+pub fn load(path: &Path) -> Result<String> {
+	let library = unsafe { Library::new(path) }
+		.with_context(|| format!("Error opening plugin {}", path.display()))?;
+	let plugin = unsafe {
+		let entry = library
+			.get::<unsafe extern "C" fn() -> *const Plugin>(b"asted_plugin_v1")
+			.with_context(|| format!("{} isn't a plugin", path.display()))?;
+		entry()
+	};
+	if plugin.is_null() {
+		bail!("Plugin {} didn't describe itself", path.display());
+	}
+	let (abi_version, name) = unsafe {
+		let plugin = &*plugin;
+		(plugin.abi_version, string(plugin.name, plugin.name_len)?)
+	};
+	if abi_version != ABI_VERSION {
+		bail!(
+			"Plugin {} was built for ABI version {}, but this server has version {}",
+			path.display(),
+			abi_version,
+			ABI_VERSION
+		);
+	}
+
+	let mut plugins = PLUGINS.write().unwrap_or_else(PoisonError::into_inner);
+	if plugins.contains_key(&name) {
+		bail!("A plugin called {} is already loaded", name);
+	}
+	plugins.insert(
+		name.clone(),
+		Loaded {
+			plugin,
+			_library: library,
+		},
+	);
+	Ok(name)
+}
+
+/// Calls the plugin called `name` with `documents` as the documents it can see, returning its
+/// response payload.
+pub fn call(
+	documents: &HashMap<DocumentKey, Document>,
+	name: &str,
+	method: &str,
+	payload: &[u8],
+) -> Result<Vec<u8>> {
+	let _span = tracing::info_span!("plugin", plugin = name, method).entered();
+	let plugins = PLUGINS.read().unwrap_or_else(PoisonError::into_inner);
+	let plugin = match plugins.get(name) {
+		Some(loaded) => unsafe { &*loaded.plugin },
+		None => return Err(Error::UnknownCommand(format!("No plugin called {}", name)).into()),
+	};
+
+	let context = CallContext {
+		documents,
+		handed_out: RefCell::new(Vec::new()),
+	};
+	let host = Host {
+		context: &context as *const CallContext as *const c_void,
+		document: host_document,
+		language: host_language,
+	};
+	let mut out = Buffer {
+		data: std::ptr::null_mut(),
+		len: 0,
+	};
+	let ok = unsafe {
+		(plugin.handle)(
+			&host,
+			method.as_ptr(),
+			method.len(),
+			payload.as_ptr(),
+			payload.len(),
+			&mut out,
+		)
+	};
+	let res = if out.data.is_null() {
+		Vec::new()
+	} else {
+		unsafe { std::slice::from_raw_parts(out.data, out.len) }.to_vec()
+	};
+	if !out.data.is_null() {
+		unsafe { (plugin.free)(out) };
+	}
+
+	if !ok {
+		return Err(Error::Internal(format!(
+			"Plugin {} failed: {}",
+			name,
+			String::from_utf8_lossy(&res)
+		))
+		.into());
+	}
+	Ok(res)
+}
+
+/// What `Host.context` points to during a call.
+struct CallContext<'a> {
+	documents: &'a HashMap<DocumentKey, Document>,
+	/// The texts and node tables handed to the plugin, kept alive until the call returns
+	handed_out: RefCell<Vec<(Vec<u16>, Vec<PluginNode>)>>,
+}
+
+unsafe extern "C" fn host_document(
+	context: *const c_void,
+	uri: *const u8,
+	uri_len: usize,
+	out: *mut PluginDocument,
+) -> bool {
+	let context = &*(context as *const CallContext);
+	let document = match string(uri, uri_len)
+		.and_then(|uri| uri::key(&uri))
+		.ok()
+		.and_then(|key| context.documents.get(&key))
+	{
+		Some(document) => document,
+		None => return false,
+	};
+
+	let text = document.text.to_utf16();
+	let nodes = export::nodes(&document.tree)
+		.into_iter()
+		.map(|row| PluginNode {
+			kind: row.kind.as_ptr(),
+			kind_len: row.kind.len(),
+			parent: row.parent.unwrap_or(u32::MAX),
+			start_byte: row.start_byte,
+			end_byte: row.end_byte,
+			start_row: row.start_point.row as u32,
+			start_column: row.start_point.column as u32,
+			end_row: row.end_point.row as u32,
+			end_column: row.end_point.column as u32,
+			named: row.named,
+			error: row.error,
+		})
+		.collect::<Vec<_>>();
+	// moving the vectors doesn't move their contents, so the pointers stay valid
+	*out = PluginDocument {
+		language: document.language.as_ptr(),
+		language_len: document.language.len(),
+		version: document.version,
+		content_hash: document.text.content_hash(),
+		text: text.as_ptr(),
+		text_len: text.len(),
+		nodes: nodes.as_ptr(),
+		node_count: nodes.len(),
+	};
+	context.handed_out.borrow_mut().push((text, nodes));
+	true
+}
+
+unsafe extern "C" fn host_language(name: *const u8, name_len: usize) -> *const c_void {
+	match string(name, name_len)
+		.ok()
+		.and_then(|name| languages::get(&name))
+	{
+		// Language is a transparent wrapper around the TSLanguage pointer
+		Some(language) => std::mem::transmute::<tree_sitter::Language, *const c_void>(language),
+		None => std::ptr::null(),
+	}
+}
+
+unsafe fn string(data: *const u8, len: usize) -> Result<String> {
+	if data.is_null() {
+		return Ok(String::new());
+	}
+	Ok(std::str::from_utf8(std::slice::from_raw_parts(data, len))
+		.context("Invalid UTF-8 from plugin")?
+		.to_string())
+}
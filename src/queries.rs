@@ -0,0 +1,394 @@
+use std::{
+	collections::{HashMap, HashSet},
+	fs,
+	path::{Path, PathBuf},
+	sync::{PoisonError, RwLock},
+};
+
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use tree_sitter::{Language, Node, Query, QueryCursor, Tree};
+
+use crate::{languages, text::Text, workspace_config};
+
+/// What a bundled query is for.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+	/// Syntax highlighting, with captures named like `@function.method`
+	Highlights,
+	/// Definitions, captured as `@definition.<kind>` with their name as `@name`, and uses of names
+	/// captured as `@reference.<kind>`
+	Tags,
+	/// Constructs that add a path through a function, captured as `@branch`
+	Complexity,
+	/// Dependencies on other modules, captured as `@import.<kind>` with the module name as
+	/// `@module`
+	Imports,
+	/// Ranges of lines to fold, captured as `@fold`, `@fold.comment` or `@fold.imports`, and the
+	/// markers of a region as `@fold.region.start` and `@fold.region.end`
+	Folds,
+}
+
+impl Kind {
+	const ALL: [Kind; 5] = [
+		Kind::Highlights,
+		Kind::Tags,
+		Kind::Complexity,
+		Kind::Imports,
+		Kind::Folds,
+	];
+
+	/// What the query's file in the queries directory is called.
+	fn file_name(self) -> &'static str {
+		match self {
+			Kind::Highlights => "highlights.scm",
+			Kind::Tags => "tags.scm",
+			Kind::Complexity => "complexity.scm",
+			Kind::Imports => "imports.scm",
+			Kind::Folds => "folds.scm",
+		}
+	}
+}
+
+/// A language's queries, along with the grammar they were compiled for.
+type LanguageQueries = HashMap<Kind, (Language, Query)>;
+
+/// The queries bundled for the built-in grammars. The grammar crates' own queries build on those
+/// of the language they extend (JavaScript and C), so the missing part is bundled along with them.
+static QUERIES: Lazy<HashMap<&'static str, LanguageQueries>> = Lazy::new(|| {
+	let typescript_highlights = format!(
+		"{}\n{}",
+		include_str!("../queries/typescript/highlights.scm"),
+		tree_sitter_typescript::HIGHLIGHT_QUERY
+	);
+	let cpp_highlights = format!(
+		"{}\n{}",
+		include_str!("../queries/cpp/highlights.scm"),
+		tree_sitter_cpp::HIGHLIGHT_QUERY
+	);
+	let typescript_tags = include_str!("../queries/typescript/tags.scm");
+	let cpp_tags = include_str!("../queries/cpp/tags.scm");
+	let typescript_complexity = include_str!("../queries/typescript/complexity.scm");
+	let cpp_complexity = include_str!("../queries/cpp/complexity.scm");
+	let typescript_imports = include_str!("../queries/typescript/imports.scm");
+	let cpp_imports = include_str!("../queries/cpp/imports.scm");
+	let typescript_folds = include_str!("../queries/typescript/folds.scm");
+	let cpp_folds = include_str!("../queries/cpp/folds.scm");
+
+	let mut queries = HashMap::<_, HashMap<_, _>>::new();
+	let mut add = |lang, kind, language: Language, source: &str| {
+		// the queries are bundled, so one that doesn't compile is a bug rather than bad input
+		let query = Query::new(language, source)
+			.unwrap_or_else(|e| panic!("Bundled query for {} doesn't compile: {}", lang, e));
+		queries
+			.entry(lang)
+			.or_default()
+			.insert(kind, (language, query));
+	};
+	add(
+		"typescript",
+		Kind::Highlights,
+		tree_sitter_typescript::language_typescript(),
+		&typescript_highlights,
+	);
+	add(
+		"tsx",
+		Kind::Highlights,
+		tree_sitter_typescript::language_tsx(),
+		&typescript_highlights,
+	);
+	add(
+		"cpp",
+		Kind::Highlights,
+		tree_sitter_cpp::language(),
+		&cpp_highlights,
+	);
+	add(
+		"typescript",
+		Kind::Tags,
+		tree_sitter_typescript::language_typescript(),
+		typescript_tags,
+	);
+	add(
+		"tsx",
+		Kind::Tags,
+		tree_sitter_typescript::language_tsx(),
+		typescript_tags,
+	);
+	add("cpp", Kind::Tags, tree_sitter_cpp::language(), cpp_tags);
+	add(
+		"typescript",
+		Kind::Complexity,
+		tree_sitter_typescript::language_typescript(),
+		typescript_complexity,
+	);
+	add(
+		"tsx",
+		Kind::Complexity,
+		tree_sitter_typescript::language_tsx(),
+		typescript_complexity,
+	);
+	add(
+		"cpp",
+		Kind::Complexity,
+		tree_sitter_cpp::language(),
+		cpp_complexity,
+	);
+	add(
+		"typescript",
+		Kind::Imports,
+		tree_sitter_typescript::language_typescript(),
+		typescript_imports,
+	);
+	add(
+		"tsx",
+		Kind::Imports,
+		tree_sitter_typescript::language_tsx(),
+		typescript_imports,
+	);
+	add(
+		"cpp",
+		Kind::Imports,
+		tree_sitter_cpp::language(),
+		cpp_imports,
+	);
+	add(
+		"typescript",
+		Kind::Folds,
+		tree_sitter_typescript::language_typescript(),
+		typescript_folds,
+	);
+	add(
+		"tsx",
+		Kind::Folds,
+		tree_sitter_typescript::language_tsx(),
+		typescript_folds,
+	);
+	add("cpp", Kind::Folds, tree_sitter_cpp::language(), cpp_folds);
+	queries
+});
+
+/// Queries read from queries directories, by language. They're leaked rather than dropped on
+/// reload, like grammars' libraries, since callers hold on to them (and the capture names borrowed
+/// from them).
+pub type Overridden = HashMap<String, HashMap<Kind, (Language, &'static Query)>>;
+
+/// Queries read from the queries directory, which take precedence over the bundled ones.
+#[derive(Default)]
+struct Overrides {
+	dir: Option<PathBuf>,
+	queries: Overridden,
+}
+
+static OVERRIDES: Lazy<RwLock<Overrides>> = Lazy::new(Default::default);
+
+/// The `kind` query for `lang` from the queries directories of the workspace the current request
+/// is in, the queries directory, or the bundled one, if there is one. Grammars loaded from the
+/// grammar directory don't come with any, even if they replace a built-in one, but the queries
+/// directories can have some for them.
+pub fn get(lang: &str, language: Language, kind: Kind) -> Option<&'static Query> {
+	let in_workspace = workspace_config::current().and_then(|workspace| {
+		workspace
+			.queries
+			.get(lang)
+			.and_then(|queries| queries.get(&kind))
+			.copied()
+	});
+	let overridden = in_workspace.or_else(|| {
+		OVERRIDES
+			.read()
+			.unwrap_or_else(PoisonError::into_inner)
+			.queries
+			.get(lang)
+			.and_then(|queries| queries.get(&kind))
+			.copied()
+	});
+	let found = overridden.or_else(|| {
+		QUERIES
+			.get(lang)
+			.and_then(|queries| queries.get(&kind))
+			.map(|(language, query)| (*language, query))
+	});
+	match found {
+		Some((compiled_for, query)) if compiled_for == language => Some(query),
+		_ => None,
+	}
+}
+
+/// Sets the directory queries are read from and reads them, along with the directories configured
+/// for single languages. The directory has a subdirectory per language, named like the language,
+/// with any of `highlights.scm`, `tags.scm`, `complexity.scm`, `imports.scm` and `folds.scm`.
+/// Each replaces the bundled query of its kind rather than adding to it.
+pub fn set_dir(dir: Option<PathBuf>) -> Result<Vec<String>> {
+	OVERRIDES
+		.write()
+		.unwrap_or_else(PoisonError::into_inner)
+		.dir = dir;
+	reload()
+}
+
+/// Re-reads the queries directory and the languages' own, compiling their queries for the grammars
+/// currently in the language registry. Returns the names of the languages that have queries there.
+pub fn reload() -> Result<Vec<String>> {
+	let mut overrides = OVERRIDES.write().unwrap_or_else(PoisonError::into_inner);
+
+	// the directories to look for each language's queries in, first the one configured for it
+	let mut dirs = HashMap::<String, Vec<PathBuf>>::new();
+	for (lang, config) in languages::configs() {
+		if let Some(dir) = config.queries {
+			dirs.entry(lang).or_default().push(dir);
+		}
+	}
+	if let Some(dir) = &overrides.dir {
+		language_dirs(dir, &mut dirs)?;
+	}
+
+	overrides.queries = read_languages(dirs)?;
+	let mut langs = overrides.queries.keys().cloned().collect::<Vec<_>>();
+	langs.sort();
+	Ok(langs)
+}
+
+/// Reads the queries in `dirs`, laid out like the queries directory, compiling them for the
+/// grammars currently in the language registry. Each kind of query for a language comes from the
+/// first of them that has it.
+pub fn read_dirs(dirs: &[PathBuf]) -> Result<Overridden> {
+	let mut found = HashMap::new();
+	for dir in dirs {
+		language_dirs(dir, &mut found)?;
+	}
+	read_languages(found)
+}
+
+/// Adds the subdirectory of `dir` for each language to the directories to read its queries from.
+fn language_dirs(dir: &Path, dirs: &mut HashMap<String, Vec<PathBuf>>) -> Result<()> {
+	for entry in fs::read_dir(dir)
+		.with_context(|| format!("Error reading queries directory {}", dir.display()))?
+	{
+		let path = entry?.path();
+		match path.file_name().and_then(|name| name.to_str()) {
+			Some(lang) if path.is_dir() => {
+				dirs.entry(lang.to_string()).or_default().push(path.clone())
+			}
+			_ => continue,
+		}
+	}
+	Ok(())
+}
+
+fn read_languages(dirs: HashMap<String, Vec<PathBuf>>) -> Result<Overridden> {
+	let mut queries = HashMap::new();
+	for (lang, dirs) in dirs {
+		let language_queries = read_language(&dirs, &lang)?;
+		if !language_queries.is_empty() {
+			queries.insert(lang, language_queries);
+		}
+	}
+	Ok(queries)
+}
+
+/// Reads each kind of query for `lang` from the first of `dirs` that has it.
+fn read_language(
+	dirs: &[PathBuf],
+	lang: &str,
+) -> Result<HashMap<Kind, (Language, &'static Query)>> {
+	let mut queries = HashMap::new();
+	for kind in Kind::ALL {
+		let path = match dirs
+			.iter()
+			.map(|dir| dir.join(kind.file_name()))
+			.find(|path| path.exists())
+		{
+			Some(path) => path,
+			None => continue,
+		};
+		let language = languages::get(lang).with_context(|| {
+			format!(
+				"No grammar called {} for the query {}",
+				lang,
+				path.display()
+			)
+		})?;
+		let source = fs::read_to_string(&path)
+			.with_context(|| format!("Error reading query {}", path.display()))?;
+		let query = Query::new(language, &source)
+			.with_context(|| format!("Error compiling query {}", path.display()))?;
+		queries.insert(kind, (language, &*Box::leak(Box::new(query))));
+	}
+	Ok(queries)
+}
+
+/// A definition or reference found by a tags query.
+pub struct Tag<'tree> {
+	/// The whole construct, like a function declaration or a call
+	pub node: Node<'tree>,
+	/// The name it defines or uses
+	pub name: Node<'tree>,
+	/// The suffix of the `@definition` or `@reference` capture
+	pub kind: &'static str,
+	pub definition: bool,
+}
+
+impl Tag<'_> {
+	/// Whether this is the definition of a function or method.
+	pub fn is_function(&self) -> bool {
+		self.definition && matches!(self.kind, "function" | "method")
+	}
+
+	pub fn name_text(&self, text: &Text) -> String {
+		String::from_utf16_lossy(&text.slice(self.name.start_byte() / 2..self.name.end_byte() / 2))
+	}
+}
+
+/// The definitions and references in `tree` (parsed from `text` as `lang`), in document order.
+/// Languages without a bundled tags query have none.
+pub fn tags<'tree>(lang: &str, tree: &'tree Tree, text: &Text) -> Vec<Tag<'tree>> {
+	let query = match get(lang, tree.language(), Kind::Tags) {
+		Some(query) => query,
+		None => return Vec::new(),
+	};
+	let names = query.capture_names();
+
+	let mut seen = HashSet::new();
+	let mut definition_names = HashSet::new();
+	let mut tags = Vec::new();
+	let mut cursor = QueryCursor::new();
+	let matches = cursor.matches(query, tree.root_node(), |node: Node| {
+		text.chunks(node.start_byte() / 2..node.end_byte() / 2)
+	});
+	for m in matches {
+		let mut tag = None;
+		let mut name = None;
+		for capture in m.captures {
+			let capture_name = names[capture.index as usize].as_str();
+			if let Some(kind) = capture_name.strip_prefix("definition.") {
+				tag = Some((capture.node, kind, true));
+			} else if let Some(kind) = capture_name.strip_prefix("reference.") {
+				tag = Some((capture.node, kind, false));
+			} else if capture_name == "name" {
+				name = Some(capture.node);
+			}
+		}
+		let ((node, kind, definition), name) = match (tag, name) {
+			(Some(tag), Some(name)) => (tag, name),
+			_ => continue,
+		};
+		// more than one pattern can match the same construct
+		if !seen.insert((node.id(), definition)) {
+			continue;
+		}
+		if definition {
+			definition_names.insert(name.id());
+		}
+		tags.push(Tag {
+			node,
+			name,
+			kind,
+			definition,
+		});
+	}
+
+	// the loosest reference patterns also match the names of definitions
+	tags.retain(|tag| tag.definition || !definition_names.contains(&tag.name.id()));
+	tags
+}
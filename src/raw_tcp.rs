@@ -0,0 +1,234 @@
+use std::{net::SocketAddr, pin::pin, sync::Arc, time::Instant};
+
+use anyhow::{Context, Result};
+use axum::{
+	body::Body,
+	extract::ConnectInfo,
+	http::{header::AUTHORIZATION, HeaderValue, Method, Request},
+	Router,
+};
+use tokio::{
+	io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter},
+	net::{tcp::OwnedReadHalf, TcpListener, TcpStream},
+	sync::mpsc,
+};
+use tower::ServiceExt;
+
+use crate::{daemon, heartbeat::Heartbeat, idle::Activity};
+
+/// Request ID 0 doesn't carry a request: its payload is the auth token to send along with the
+/// connection's later requests.
+const TOKEN_REQUEST_ID: u32 = 0;
+
+/// Frames with this ID are pings, which either side answers with a pong frame echoing the payload.
+const PING_REQUEST_ID: u32 = u32::MAX;
+
+const PONG_REQUEST_ID: u32 = u32::MAX - 1;
+
+/// Serves flatbuffers requests over plain TCP connections until the server shuts down, skipping
+/// HTTP's per-request overhead.
+///
+/// A connection carries any number of frames each way, each a little-endian `u32` length of the
+/// rest of the frame followed by a little-endian `u32` request ID. Requests then hold a `Request`
+/// flatbuffer. Responses echo the ID, followed by a little-endian `u16` HTTP status code and the
+/// body the HTTP interface would have responded with. Requests are handled concurrently, so
+/// responses can come back in any order.
+///
+/// Pings and pongs are the exception: they hold nothing but the ID and an arbitrary payload. With a
+/// `heartbeat`, the server pings clients it hasn't heard from in a while, and closes the connection
+/// (dropping responses still to come) if they stay quiet until it times out.
+///
+/// Every request goes through `app` as a `POST /`, so limits, auth and everything else work just
+/// like they do over HTTP.
+pub async fn serve(
+	listener: TcpListener,
+	app: Router,
+	max_frame_size: usize,
+	heartbeat: Option<Heartbeat>,
+	activity: Arc<Activity>,
+) -> Result<()> {
+	let mut shutdown = pin!(daemon::shutdown_signal());
+	loop {
+		let (stream, addr) = tokio::select! {
+			_ = &mut shutdown => return Ok(()),
+			accepted = listener.accept() => accepted.context("Error accepting connection")?,
+		};
+		let app = app.clone();
+		let busy = activity.busy();
+		tokio::spawn(async move {
+			let _busy = busy;
+			if let Err(e) = connection(stream, addr, app, max_frame_size, heartbeat).await {
+				tracing::error!("Raw TCP connection from {} failed: {:#}", addr, e);
+			}
+		});
+	}
+}
+
+async fn connection(
+	stream: TcpStream,
+	addr: SocketAddr,
+	app: Router,
+	max_frame_size: usize,
+	heartbeat: Option<Heartbeat>,
+) -> Result<()> {
+	stream.set_nodelay(true)?;
+	let (reader, writer) = stream.into_split();
+	let (responses, mut rx) = mpsc::channel::<Vec<u8>>(64);
+
+	// frames are read on their own, as waiting for the next ping mustn't interrupt a frame
+	// halfway through
+	let (frames_tx, mut frames) = mpsc::channel(1);
+	let reader = tokio::spawn(async move {
+		let mut reader = BufReader::new(reader);
+		loop {
+			let frame = read_frame(&mut reader, max_frame_size).await;
+			let last = !matches!(frame, Ok(Some(_)));
+			if frames_tx.send(frame).await.is_err() || last {
+				break;
+			}
+		}
+	});
+
+	// the writer finishes once every request task has dropped its sender
+	let writer = tokio::spawn(async move {
+		let mut writer = BufWriter::new(writer);
+		while let Some(frame) = rx.recv().await {
+			writer.write_all(&frame).await?;
+			if rx.is_empty() {
+				writer.flush().await?;
+			}
+		}
+		writer.flush().await
+	});
+
+	let mut token = None;
+	let mut shutdown = pin!(daemon::shutdown_signal());
+	let mut heard = Instant::now();
+	let mut pinged = false;
+	loop {
+		let next_ping = async {
+			match heartbeat {
+				Some(heartbeat) if pinged => {
+					tokio::time::sleep_until((heard + heartbeat.timeout).into()).await
+				}
+				Some(heartbeat) => {
+					tokio::time::sleep_until((heard + heartbeat.interval).into()).await
+				}
+				None => std::future::pending().await,
+			}
+		};
+		let frame = tokio::select! {
+			_ = &mut shutdown => break,
+			_ = next_ping => {
+				if pinged {
+					tracing::info!("Closing raw TCP connection from {}, which stopped answering", addr);
+					reader.abort();
+					writer.abort();
+					return Ok(());
+				}
+				pinged = true;
+				// the writer only stops once the connection is gone anyway
+				let _ = responses.send(ping_frame(PING_REQUEST_ID, &[])).await;
+				continue;
+			}
+			frame = frames.recv() => match frame {
+				Some(frame) => frame?,
+				None => None,
+			},
+		};
+		let (request_id, payload) = match frame {
+			Some(frame) => frame,
+			None => break,
+		};
+		heard = Instant::now();
+		pinged = false;
+
+		if request_id == PING_REQUEST_ID {
+			let _ = responses.send(ping_frame(PONG_REQUEST_ID, &payload)).await;
+			continue;
+		}
+		if request_id == PONG_REQUEST_ID {
+			continue;
+		}
+		if request_id == TOKEN_REQUEST_ID {
+			let header = format!("Bearer {}", String::from_utf8_lossy(&payload));
+			token = Some(HeaderValue::from_str(&header).context("Invalid auth token")?);
+			continue;
+		}
+
+		let mut req = Request::builder()
+			.method(Method::POST)
+			.uri("/")
+			.extension(ConnectInfo(addr));
+		if let Some(token) = &token {
+			req = req.header(AUTHORIZATION, token.clone());
+		}
+		let req = req.body(Body::from(payload))?;
+
+		let app = app.clone();
+		let responses = responses.clone();
+		tokio::spawn(async move {
+			let res = match app.oneshot(req).await {
+				Ok(res) => res,
+				Err(infallible) => match infallible {},
+			};
+			let status = res.status().as_u16();
+			let body = match hyper::body::to_bytes(res.into_body()).await {
+				Ok(body) => body,
+				Err(e) => {
+					tracing::error!("Error reading response to request {}: {}", request_id, e);
+					return;
+				}
+			};
+
+			let mut frame = Vec::with_capacity(10 + body.len());
+			frame.extend_from_slice(&(6 + body.len() as u32).to_le_bytes());
+			frame.extend_from_slice(&request_id.to_le_bytes());
+			frame.extend_from_slice(&status.to_le_bytes());
+			frame.extend_from_slice(&body);
+			// the connection may have gone away in the meantime
+			let _ = responses.send(frame).await;
+		});
+	}
+
+	drop(responses);
+	reader.abort();
+	writer.await??;
+	Ok(())
+}
+
+fn ping_frame(request_id: u32, payload: &[u8]) -> Vec<u8> {
+	let mut frame = Vec::with_capacity(8 + payload.len());
+	frame.extend_from_slice(&(4 + payload.len() as u32).to_le_bytes());
+	frame.extend_from_slice(&request_id.to_le_bytes());
+	frame.extend_from_slice(payload);
+	frame
+}
+
+/// Reads the next request frame, or `None` if the client closed the connection.
+async fn read_frame(
+	reader: &mut BufReader<OwnedReadHalf>,
+	max_frame_size: usize,
+) -> Result<Option<(u32, Vec<u8>)>> {
+	let len = match reader.read_u32_le().await {
+		Ok(len) => len as usize,
+		Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+		Err(e) => return Err(e.into()),
+	};
+	if len < 4 {
+		anyhow::bail!("Frame of {} bytes is too short to hold a request ID", len);
+	}
+	// there's no way to skip a frame without reading it, so the connection is given up on
+	if len - 4 > max_frame_size {
+		anyhow::bail!(
+			"Frame of {} bytes exceeds the maximum of {}",
+			len - 4,
+			max_frame_size
+		);
+	}
+
+	let request_id = reader.read_u32_le().await?;
+	let mut payload = vec![0; len - 4];
+	reader.read_exact(&mut payload).await?;
+	Ok(Some((request_id, payload)))
+}
@@ -0,0 +1,154 @@
+use std::{
+	fs, io,
+	path::Path,
+	sync::{Mutex, PoisonError},
+	time::Duration,
+};
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	uri::{self, DocumentKey},
+	State, STATE_MAP,
+};
+
+/// How often the open documents are written to the state file.
+pub const SAVE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// What the `--state-file` holds: the documents open in every session, to open again after a
+/// restart. Only their URIs are kept, so it's the files as they are on disk that are reread.
+#[derive(Serialize, Deserialize)]
+struct Saved {
+	sessions: Vec<Session>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Session {
+	/// The key of the session in `STATE_MAP`
+	name: String,
+	language: String,
+	plain_text: bool,
+	documents: Vec<SavedDocument>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedDocument {
+	uri: String,
+	language: String,
+	version: u32,
+	content_hash: u64,
+}
+
+/// The state file as last written, so it's only rewritten when something changed.
+static WRITTEN: Lazy<Mutex<Option<Vec<u8>>>> = Lazy::new(Default::default);
+
+/// Writes the documents open in every session to `path`, unless they're the same as last time.
+/// The file is replaced in one step, so a crash never leaves half of it behind.
+pub fn save(path: &Path) -> Result<()> {
+	let mut sessions = STATE_MAP
+		.iter()
+		.filter_map(|state| {
+			let mut documents = state
+				.documents
+				.iter()
+				.map(|(key, document)| SavedDocument {
+					uri: key.uri(),
+					language: document.language.clone(),
+					version: document.version,
+					content_hash: document.text.content_hash(),
+				})
+				.chain(state.unparsed.iter().map(|(key, unparsed)| SavedDocument {
+					uri: key.uri(),
+					language: state.lang.clone().unwrap_or_default(),
+					version: 1,
+					content_hash: unparsed.text.content_hash(),
+				}))
+				.collect::<Vec<_>>();
+			documents.sort_by(|a, b| a.uri.cmp(&b.uri));
+			Some(Session {
+				name: state.key().clone(),
+				language: state.lang.clone()?,
+				plain_text: state.plain_text,
+				documents,
+			})
+		})
+		.collect::<Vec<_>>();
+	sessions.sort_by(|a, b| a.name.cmp(&b.name));
+	let contents = serde_json::to_vec_pretty(&Saved { sessions })?;
+
+	let mut written = WRITTEN.lock().unwrap_or_else(PoisonError::into_inner);
+	if written.as_ref() == Some(&contents) {
+		return Ok(());
+	}
+	let dir = match path.parent() {
+		Some(dir) if !dir.as_os_str().is_empty() => dir,
+		_ => Path::new("."),
+	};
+	let mut file = tempfile::NamedTempFile::new_in(dir)
+		.with_context(|| format!("Error creating a file in {}", dir.display()))?;
+	io::Write::write_all(&mut file, &contents)
+		.with_context(|| format!("Error writing {}", path.display()))?;
+	file.persist(path)
+		.with_context(|| format!("Error writing {}", path.display()))?;
+	*written = Some(contents);
+	Ok(())
+}
+
+/// Opens the documents saved in `path` again, in the sessions they were open in, returning how
+/// many were. A document whose file changed since gets the next version, so clients that had it
+/// open see it changed.
+pub fn restore(path: &Path) -> Result<usize> {
+	let contents = match fs::read(path) {
+		Ok(contents) => contents,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+		Err(e) => return Err(e).with_context(|| format!("Error reading {}", path.display())),
+	};
+	let saved = serde_json::from_slice::<Saved>(&contents)
+		.with_context(|| format!("Error parsing {}", path.display()))?;
+
+	let mut restored = 0;
+	for session in saved.sessions {
+		let mut state = match STATE_MAP.get_mut(&session.name) {
+			Some(state) => state,
+			None => {
+				tracing::warn!(
+					"Not restoring session {}, which no longer exists",
+					session.name
+				);
+				continue;
+			}
+		};
+		// keeping what was preloaded into it
+		if state.lang.as_ref() != Some(&session.language) {
+			if let Err(e) = state.set_language(&session.language, session.plain_text) {
+				tracing::warn!("Failed to restore session {}: {:#}", session.name, e);
+				continue;
+			}
+		}
+		for document in &session.documents {
+			match restore_document(&mut state, document) {
+				Ok(()) => restored += 1,
+				Err(e) => tracing::warn!("Failed to restore {}: {:#}", document.uri, e),
+			}
+		}
+	}
+	Ok(restored)
+}
+
+fn restore_document(state: &mut State, document: &SavedDocument) -> Result<()> {
+	let key = uri::key(&document.uri)?;
+	let path = match &key {
+		DocumentKey::File(path) => path,
+		DocumentKey::Uri(_) => bail!("Only files can be reread"),
+	};
+	state.check_root(path)?;
+	let text = crate::read_text(path)?;
+	let changed = text.content_hash() != document.content_hash;
+	state.parse(&key, text, crate::modified(path), false)?;
+	if let Some(restored) = state.documents.get_mut(&key) {
+		restored.version = document.version + changed as u32;
+	}
+	Ok(())
+}
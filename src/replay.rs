@@ -0,0 +1,287 @@
+use std::{
+	collections::hash_map::DefaultHasher,
+	fs::{self, OpenOptions},
+	hash::{Hash, Hasher},
+	io::Write,
+	path::PathBuf,
+};
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use tree_sitter::{Parser, Tree};
+
+use crate::{
+	bench, document::Document, edit, languages, text::Text, tree_serialize::ParseInfo,
+	uri::DocumentKey,
+};
+
+// The subcommand re-executing the logs of --replay-dir. (Not a doc comment, which clap would use
+// to describe the whole program.)
+#[derive(clap::Subcommand)]
+pub enum ReplayCommand {
+	/// Re-execute a document's replay log, checking that every reparse comes out with the text,
+	/// changed ranges and tree it had on the server
+	Replay { log: PathBuf },
+}
+
+/// Where documents' replay logs are written, if they are.
+static DIR: OnceCell<PathBuf> = OnceCell::new();
+
+/// Has every edit and reparse of a document recorded to a log for it in `dir`, for the rest of the
+/// process. Only the first call has any effect.
+pub fn set_dir(dir: PathBuf) -> Result<()> {
+	fs::create_dir_all(&dir).with_context(|| format!("Error creating {}", dir.display()))?;
+	let _ = DIR.set(dir);
+	Ok(())
+}
+
+pub fn enabled() -> bool {
+	DIR.get().is_some()
+}
+
+/// What a document was reparsed from.
+pub enum Input<'a> {
+	/// Its whole text, like when it's opened or reread from disk
+	Text,
+	/// Edits (start byte, old end byte, new text) to `base_version`
+	Edits {
+		base_version: u32,
+		edits: &'a [(usize, usize, Vec<u16>)],
+	},
+	/// A snapshot it was rolled back to, whose tree came from an earlier parse
+	Snapshot,
+}
+
+/// A line of a replay log.
+#[derive(Serialize, Deserialize)]
+struct Entry {
+	uri: String,
+	language: String,
+	version: u32,
+	#[serde(flatten)]
+	input: LoggedInput,
+	content_hash: u64,
+	reused_old_tree: bool,
+	/// As byte ranges
+	changed_ranges: Vec<(usize, usize)>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "input", rename_all = "snake_case")]
+enum LoggedInput {
+	Text { text: String },
+	Edits { base_version: u32, edits: Vec<Edit> },
+	Snapshot { text: String },
+}
+
+/// Like `TextEdit`, with byte offsets into the UTF-16 text.
+#[derive(Serialize, Deserialize)]
+struct Edit {
+	start_byte: usize,
+	old_end_byte: usize,
+	text: String,
+}
+
+/// Appends the reparse of the document at `key` from `input`, which made it `document`, to its
+/// replay log if there's a replay directory. Failing to is only logged, so the request goes on.
+pub fn record(key: &DocumentKey, document: &Document, parse_info: &ParseInfo, input: Input) {
+	let dir = match DIR.get() {
+		Some(dir) => dir,
+		None => return,
+	};
+	let uri = key.uri();
+	let text = || String::from_utf16_lossy(&document.text.to_utf16());
+	let entry = Entry {
+		uri: uri.clone(),
+		language: document.language.clone(),
+		version: document.version,
+		input: match input {
+			Input::Text => LoggedInput::Text { text: text() },
+			Input::Edits {
+				base_version,
+				edits,
+			} => LoggedInput::Edits {
+				base_version,
+				edits: edits
+					.iter()
+					.map(|(start_byte, old_end_byte, text)| Edit {
+						start_byte: *start_byte,
+						old_end_byte: *old_end_byte,
+						text: String::from_utf16_lossy(text),
+					})
+					.collect(),
+			},
+			Input::Snapshot => LoggedInput::Snapshot { text: text() },
+		},
+		content_hash: parse_info.content_hash,
+		reused_old_tree: parse_info.reused_old_tree,
+		changed_ranges: parse_info
+			.changed_ranges
+			.iter()
+			.map(|range| (range.start, range.end))
+			.collect(),
+	};
+
+	let path = dir.join(log_name(&uri));
+	let res = serde_json::to_string(&entry)
+		.map_err(anyhow::Error::from)
+		.and_then(|mut line| {
+			line.push('\n');
+			// one write per line, so sessions sharing a document don't tear each other's lines
+			let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+			Ok(file.write_all(line.as_bytes())?)
+		});
+	if let Err(e) = res {
+		tracing::warn!("Error writing to replay log {}: {:#}", path.display(), e);
+	}
+}
+
+/// The file the replay log of the document at `uri` is kept in: its file name, to find it by, and a
+/// hash of the whole URI, to tell documents with the same name apart.
+fn log_name(uri: &str) -> String {
+	let name = uri
+		.rsplit('/')
+		.next()
+		.unwrap_or_default()
+		.chars()
+		.map(|c| match c {
+			'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' => c,
+			_ => '_',
+		})
+		.collect::<String>();
+	let mut hasher = DefaultHasher::new();
+	uri.hash(&mut hasher);
+	format!("{}-{:016x}.jsonl", name, hasher.finish())
+}
+
+pub fn run(command: ReplayCommand) -> Result<()> {
+	let ReplayCommand::Replay { log } = command;
+	let contents =
+		fs::read_to_string(&log).with_context(|| format!("Error reading {}", log.display()))?;
+
+	let mut parser = Parser::new();
+	let mut language = None;
+	let mut current: Option<(Text, Tree, u32)> = None;
+	let (mut entries, mut mismatches) = (0, 0);
+	for (i, line) in contents.lines().enumerate() {
+		if line.trim().is_empty() {
+			continue;
+		}
+		let entry = serde_json::from_str::<Entry>(line)
+			.with_context(|| format!("Invalid entry on line {} of {}", i + 1, log.display()))?;
+		entries += 1;
+		if language.as_ref() != Some(&entry.language) {
+			let grammar = languages::get(&entry.language)
+				.with_context(|| format!("No grammar for {}", entry.language))?;
+			parser.set_language(grammar).with_context(|| {
+				format!("Error loading tree-sitter {} language", entry.language)
+			})?;
+			language = Some(entry.language.clone());
+			// a tree in another language can't be reused
+			current = None;
+		}
+
+		let mut problems = Vec::new();
+		let (text, old_tree) = match &entry.input {
+			LoggedInput::Text { text } | LoggedInput::Snapshot { text } => {
+				let text = Text::from_utf16(&text.encode_utf16().collect::<Vec<_>>())?;
+				let old_tree = current.and_then(|(old_text, mut tree, _)| {
+					edit::edit_to_match(&mut tree, &old_text, &text);
+					matches!(entry.input, LoggedInput::Text { .. }).then_some(tree)
+				});
+				(text, old_tree)
+			}
+			LoggedInput::Edits {
+				base_version,
+				edits,
+			} => {
+				let (mut text, mut tree, version) = current.with_context(|| {
+					format!("Line {} edits a document the log never opened", i + 1)
+				})?;
+				if version != *base_version {
+					problems.push(format!(
+						"the edits are against version {}, but the log left off at version {}",
+						base_version, version
+					));
+				}
+				for edit in edits {
+					let new_text = edit.text.encode_utf16().collect::<Vec<_>>();
+					edit::apply(
+						&mut text,
+						&mut tree,
+						edit.start_byte / 2,
+						edit.old_end_byte / 2,
+						&new_text,
+					)
+					.with_context(|| format!("Error applying the edits on line {}", i + 1))?;
+				}
+				(text, Some(tree))
+			}
+		};
+		// the server parsed without the old tree when it took another document's
+		let old_tree = old_tree.filter(|_| entry.reused_old_tree);
+
+		let tree = text
+			.parse(&mut parser, old_tree.as_ref())
+			.with_context(|| format!("Error parsing line {}", i + 1))?;
+		if text.content_hash() != entry.content_hash {
+			problems.push("the text differs from the server's".to_string());
+		}
+		if let Some(old_tree) = &old_tree {
+			let changed_ranges = old_tree
+				.changed_ranges(&tree)
+				.map(|range| (range.start_byte, range.end_byte))
+				.collect::<Vec<_>>();
+			if changed_ranges != entry.changed_ranges {
+				problems.push(format!(
+					"the changed ranges are {:?}, but the server's were {:?}",
+					changed_ranges, entry.changed_ranges
+				));
+			}
+			let full = text
+				.parse(&mut parser, None)
+				.with_context(|| format!("Error parsing line {}", i + 1))?;
+			if !bench::same_tree(&tree, &full) {
+				problems.push("the incremental tree differs from a full parse".to_string());
+			}
+		}
+
+		let input = match &entry.input {
+			LoggedInput::Text { .. } => "text".to_string(),
+			LoggedInput::Edits { edits, .. } => format!("{} edits", edits.len()),
+			LoggedInput::Snapshot { .. } => "snapshot".to_string(),
+		};
+		match problems.is_empty() {
+			true => println!(
+				"line {}: version {} from {}: ok",
+				i + 1,
+				entry.version,
+				input
+			),
+			false => {
+				mismatches += 1;
+				for problem in problems {
+					println!(
+						"line {}: version {} from {}: {}",
+						i + 1,
+						entry.version,
+						input,
+						problem
+					);
+				}
+			}
+		}
+		current = Some((text, tree, entry.version));
+	}
+
+	if mismatches > 0 {
+		bail!(
+			"{} of {} reparses came out differently",
+			mismatches,
+			entries
+		);
+	}
+	println!("all {} reparses came out the same", entries);
+	Ok(())
+}
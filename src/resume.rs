@@ -0,0 +1,92 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+
+use crate::{
+	changes,
+	message_generated::asted::interface::{
+		OpenDocument, OpenDocumentArgs, ResumeRequest, ResumeResponse, ResumeResponseArgs,
+	},
+	State,
+};
+
+/// Lists the documents open in `state` for a client reattaching after a reconnect, along with the
+/// ones that changed since its token was handed out, and returns a serialized `ResumeResponse`.
+pub fn resume(state: &State, req: ResumeRequest) -> Result<Vec<u8>> {
+	let _span = tracing::info_span!("resume").entered();
+	let mut documents = state
+		.documents
+		.iter()
+		.map(|(key, document)| {
+			(
+				key.uri(),
+				document.language.clone(),
+				document.version,
+				document.text.content_hash(),
+			)
+		})
+		.chain(state.unparsed.iter().map(|(key, unparsed)| {
+			(
+				key.uri(),
+				state.language_of(key).unwrap_or_default().to_string(),
+				1,
+				unparsed.text.content_hash(),
+			)
+		}))
+		.collect::<Vec<_>>();
+	documents.sort();
+
+	// taken before looking at the changes, so a change coming in meanwhile is reported again next
+	// time rather than not at all
+	let token = changes::latest_token();
+	let resumed = match req.token() {
+		Some(token) => changes::resume(token)?,
+		None => None,
+	};
+	let changed = match &resumed {
+		Some((missed, _)) => missed
+			.iter()
+			.map(|(_, change)| &change.key)
+			.filter(|key| state.documents.contains_key(key) || state.unparsed.contains_key(key))
+			.map(|key| key.uri())
+			.collect::<BTreeSet<_>>(),
+		None => BTreeSet::new(),
+	};
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let documents = documents
+		.iter()
+		.map(|(uri, language, version, content_hash)| {
+			let path = builder.create_string(uri);
+			let language = builder.create_string(language);
+			OpenDocument::create(
+				&mut builder,
+				&OpenDocumentArgs {
+					path: Some(path),
+					language: Some(language),
+					version: *version,
+					content_hash: *content_hash,
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let documents = builder.create_vector(&documents);
+	let changed = changed
+		.iter()
+		.map(|uri| builder.create_string(uri))
+		.collect::<Vec<_>>();
+	let changed = builder.create_vector(&changed);
+	let token = builder.create_string(&token);
+	let resp = ResumeResponse::create(
+		&mut builder,
+		&ResumeResponseArgs {
+			token: Some(token),
+			resumed: resumed.is_some(),
+			documents: Some(documents),
+			changed: Some(changed),
+		},
+	);
+	builder.finish(resp, None);
+
+	Ok(builder.finished_data().to_vec())
+}
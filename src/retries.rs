@@ -0,0 +1,150 @@
+use std::{
+	collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+	hash::{Hash, Hasher},
+	sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use anyhow::Result;
+use axum::{
+	body::{self, Bytes, Full},
+	http::{HeaderMap, StatusCode},
+	response::{IntoResponse, Response},
+};
+use once_cell::sync::Lazy;
+
+use crate::{encoding::Encoding, error::Error};
+
+/// How many of the latest requests with IDs that changed documents each session remembers the
+/// responses to.
+const MAX_REMEMBERED: usize = 64;
+
+/// A request with an ID that changes documents, with its response once it went through.
+struct Remembered {
+	id: u64,
+	/// Of the request's bytes and encoding, so that an ID reused for another request doesn't get
+	/// this one's response
+	digest: u64,
+	/// `None` while the request runs
+	response: Option<(StatusCode, HeaderMap, Bytes)>,
+}
+
+/// The requests remembered, by session, oldest first.
+static REMEMBERED: Lazy<Mutex<HashMap<String, VecDeque<Remembered>>>> = Lazy::new(Default::default);
+
+/// What to do with a request that changes documents.
+pub enum Retry {
+	/// It's a retry of one that went through; answer it with that one's response
+	Replay(Response),
+	/// It's new (or its first attempt failed); run it and `finish` it with its response
+	First(Attempt),
+}
+
+/// A request being run for the first time, which is forgotten again if it fails so that retrying
+/// it runs it again.
+pub struct Attempt {
+	session: String,
+	id: u64,
+	digest: u64,
+}
+
+/// Looks up request `id` of `session`, which changes documents and came as `body` to be answered
+/// in `encoding`. Fails with a conflict error if it's a retry of one still running.
+pub fn begin(session: &str, id: u64, body: &[u8], encoding: Encoding) -> Result<Retry> {
+	let mut hasher = DefaultHasher::new();
+	body.hash(&mut hasher);
+	encoding.hash(&mut hasher);
+	let digest = hasher.finish();
+
+	let mut remembered = remembered();
+	let requests = remembered.entry(session.to_string()).or_default();
+	if let Some(index) = requests.iter().position(|request| request.id == id) {
+		match &requests[index] {
+			Remembered {
+				digest: first,
+				response: Some((status, headers, body)),
+				..
+			} if *first == digest => {
+				tracing::info!("answering retried request {} with its first response", id);
+				return Ok(Retry::Replay(
+					(*status, headers.clone(), body.clone()).into_response(),
+				));
+			}
+			Remembered {
+				digest: first,
+				response: None,
+				..
+			} if *first == digest => {
+				return Err(Error::Conflict(format!(
+					"Request {} is still running; retry it once it's done",
+					id
+				))
+				.into());
+			}
+			// a reused ID, which now stands for this request
+			_ => {
+				requests.remove(index);
+			}
+		}
+	}
+	if requests.len() == MAX_REMEMBERED {
+		requests.pop_front();
+	}
+	requests.push_back(Remembered {
+		id,
+		digest,
+		response: None,
+	});
+
+	Ok(Retry::First(Attempt {
+		session: session.to_string(),
+		id,
+		digest,
+	}))
+}
+
+impl Attempt {
+	/// Remembers `res` as the response to retries of the request (if it's a success).
+	pub fn finish(self, res: Response) -> Result<Response> {
+		if !res.status().is_success() {
+			return Ok(res);
+		}
+		let (parts, res_body) = res.into_parts();
+		// the responses to these requests are never streamed, so this doesn't actually wait
+		let res_body =
+			tokio::runtime::Handle::current().block_on(hyper::body::to_bytes(res_body))?;
+		if let Some(request) = self.remembered(&mut remembered()) {
+			request.response = Some((parts.status, parts.headers.clone(), res_body.clone()));
+		}
+
+		Ok(Response::from_parts(
+			parts,
+			body::boxed(Full::from(res_body)),
+		))
+	}
+
+	fn remembered<'a>(
+		&self,
+		remembered: &'a mut HashMap<String, VecDeque<Remembered>>,
+	) -> Option<&'a mut Remembered> {
+		remembered
+			.get_mut(&self.session)?
+			.iter_mut()
+			.find(|request| request.id == self.id && request.digest == self.digest)
+	}
+}
+
+impl Drop for Attempt {
+	fn drop(&mut self) {
+		let mut remembered = remembered();
+		if let Some(requests) = remembered.get_mut(&self.session) {
+			// went through if it has a response by now; otherwise it failed, so retries run it again
+			requests.retain(|request| {
+				request.id != self.id || request.digest != self.digest || request.response.is_some()
+			});
+		}
+	}
+}
+
+fn remembered() -> MutexGuard<'static, HashMap<String, VecDeque<Remembered>>> {
+	REMEMBERED.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,325 @@
+use std::path::{Component, Path};
+
+use serde::Serialize;
+use tree_sitter::Point;
+use url::Url;
+
+use crate::message_generated::asted::interface as fb;
+
+/// A SARIF 2.1.0 log, for code scanning tools (like GitHub's) to read results from.
+#[derive(Serialize)]
+pub struct Log {
+	#[serde(rename = "$schema")]
+	schema: &'static str,
+	version: &'static str,
+	runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+struct Run {
+	tool: Tool,
+	results: Vec<SarifResult>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	properties: Option<RunProperties>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+	driver: Driver,
+}
+
+#[derive(Serialize)]
+struct Driver {
+	name: &'static str,
+	version: &'static str,
+	rules: Vec<Rule>,
+}
+
+/// What SARIF leaves to the tool: where a search stopped at `max_matches`, to carry on from.
+#[derive(Serialize)]
+struct RunProperties {
+	cursor: String,
+}
+
+/// A kind of result, which results point at by its ID.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rule {
+	id: String,
+	short_description: Message,
+}
+
+impl Rule {
+	pub fn new(id: impl Into<String>, description: &str) -> Self {
+		Rule {
+			id: id.into(),
+			short_description: Message {
+				text: description.to_string(),
+			},
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct Message {
+	text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+	rule_id: String,
+	level: Level,
+	message: Message,
+	locations: Vec<Location>,
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub related_locations: Vec<Location>,
+}
+
+impl SarifResult {
+	pub fn new(
+		rule_id: impl Into<String>,
+		level: Level,
+		message: String,
+		location: Location,
+	) -> Self {
+		SarifResult {
+			rule_id: rule_id.into(),
+			level,
+			message: Message { text: message },
+			locations: vec![location],
+			related_locations: Vec::new(),
+		}
+	}
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+	Error,
+	Warning,
+	Note,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+	physical_location: PhysicalLocation,
+}
+
+impl Location {
+	/// `region` of the file at `uri`, which is relative to where the tool ran if it isn't absolute.
+	pub fn new(uri: &str, region: Region) -> Self {
+		Location {
+			physical_location: PhysicalLocation {
+				artifact_location: ArtifactLocation {
+					uri: uri.to_string(),
+				},
+				region,
+			},
+		}
+	}
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+	artifact_location: ArtifactLocation,
+	region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+	uri: String,
+}
+
+// SARIF's lines and columns count from 1, and its columns are UTF-16 code units by default.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+	start_line: usize,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	start_column: Option<usize>,
+	end_line: usize,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	end_column: Option<usize>,
+}
+
+impl Region {
+	/// From `start` to `end`, with columns in UTF-16 bytes like the server's points.
+	pub fn new(start: Point, end: Point) -> Self {
+		Region {
+			start_line: start.row + 1,
+			start_column: Some(start.column / 2 + 1),
+			end_line: end.row + 1,
+			end_column: Some(end.column / 2 + 1),
+		}
+	}
+
+	/// The whole of rows `start_row` to `end_row`.
+	pub fn lines(start_row: usize, end_row: usize) -> Self {
+		Region {
+			start_line: start_row + 1,
+			start_column: None,
+			end_line: end_row + 1,
+			end_column: None,
+		}
+	}
+}
+
+/// The URI to point results at the file at `path` by: a `file://` URI if it's absolute, or else a
+/// relative one.
+pub fn path_uri(path: &Path) -> String {
+	if path.is_absolute() {
+		if let Ok(url) = Url::from_file_path(path) {
+			return url.into();
+		}
+	}
+	path.components()
+		.filter_map(|component| match component {
+			Component::CurDir => None,
+			component => {
+				Some(urlencoding::encode(&component.as_os_str().to_string_lossy()).into_owned())
+			}
+		})
+		.collect::<Vec<_>>()
+		.join("/")
+}
+
+/// A log of one run of the server's engine, which found `results` of `rules`.
+pub fn log(rules: Vec<Rule>, results: Vec<SarifResult>) -> Log {
+	Log {
+		schema: "https://json.schemastore.org/sarif-2.1.0.json",
+		version: "2.1.0",
+		runs: vec![Run {
+			tool: Tool {
+				driver: Driver {
+					name: env!("CARGO_PKG_NAME"),
+					version: env!("CARGO_PKG_VERSION"),
+					rules,
+				},
+			},
+			results,
+			properties: None,
+		}],
+	}
+}
+
+/// A note for every instance of every group of clones, pointing at the group's other instances.
+pub fn clones(res: fb::ClonesResponse) -> Log {
+	let location = |instance: &fb::CloneInstance| {
+		Location::new(
+			instance.path(),
+			Region::lines(instance.start_row() as usize, instance.end_row() as usize),
+		)
+	};
+	let mut results = Vec::new();
+	for group in res.groups().into_iter().flatten() {
+		let instances = group.instances().into_iter().flatten().collect::<Vec<_>>();
+		for (i, instance) in instances.iter().enumerate() {
+			let mut result = SarifResult::new(
+				"clone",
+				Level::Note,
+				format!(
+					"This {} ({} nodes) is duplicated in {} other place{}",
+					instance.kind(),
+					group.node_count(),
+					instances.len() - 1,
+					if instances.len() == 2 { "" } else { "s" }
+				),
+				location(instance),
+			);
+			result.related_locations = instances
+				.iter()
+				.enumerate()
+				.filter(|&(j, _)| j != i)
+				.map(|(_, other)| location(other))
+				.collect();
+			results.push(result);
+		}
+	}
+	log(
+		vec![Rule::new(
+			"clone",
+			"Code duplicated elsewhere, going by its structure",
+		)],
+		results,
+	)
+}
+
+/// A warning for every match in the batches of a search, spanning all of the match's captures,
+/// with a rule for each of the query's patterns that matched.
+pub fn query_matches(batches: &[fb::QueryResults]) -> Log {
+	let point = |point: Option<&fb::Point>| {
+		point.map_or(Point::default(), |point| {
+			Point::new(point.row() as usize, point.column() as usize)
+		})
+	};
+	let mut patterns = Vec::new();
+	let mut results = Vec::new();
+	for m in batches.iter().flat_map(|batch| batch.matches()).flatten() {
+		let captures = m
+			.captures()
+			.into_iter()
+			.flatten()
+			.chain(
+				m.groups()
+					.into_iter()
+					.flatten()
+					.flat_map(|group| group.captures()),
+			)
+			.collect::<Vec<_>>();
+		let (start, end) = match (
+			captures
+				.iter()
+				.map(|capture| point(capture.start_point()))
+				.min(),
+			captures
+				.iter()
+				.map(|capture| point(capture.end_point()))
+				.max(),
+		) {
+			(Some(start), Some(end)) => (start, end),
+			_ => continue,
+		};
+		if !patterns.contains(&m.pattern_index()) {
+			patterns.push(m.pattern_index());
+		}
+		let names = captures
+			.iter()
+			.map(|capture| format!("@{}", capture.name()))
+			.collect::<Vec<_>>();
+		results.push(SarifResult::new(
+			format!("pattern-{}", m.pattern_index()),
+			Level::Warning,
+			format!(
+				"Match of pattern {} capturing {}",
+				m.pattern_index(),
+				names.join(", ")
+			),
+			Location::new(m.path(), Region::new(start, end)),
+		));
+	}
+
+	patterns.sort();
+	let rules = patterns
+		.iter()
+		.map(|pattern| {
+			Rule::new(
+				format!("pattern-{}", pattern),
+				&format!("Pattern {} of the query", pattern),
+			)
+		})
+		.collect();
+	let mut log = log(rules, results);
+	if let Some(cursor) = batches
+		.last()
+		.filter(|batch| !batch.done())
+		.and_then(|batch| batch.cursor())
+	{
+		log.runs[0].properties = Some(RunProperties {
+			cursor: cursor.to_string(),
+		});
+	}
+	log
+}
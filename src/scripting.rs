@@ -0,0 +1,278 @@
+use std::{cell::RefCell, rc::Rc};
+
+use anyhow::Result;
+use rhai::{module_resolvers::DummyModuleResolver, Dynamic, Engine, EvalAltResult, Map, Scope};
+
+use crate::{
+	cancel,
+	document::Document,
+	error::Error,
+	export::{self, NodeRow},
+	text::Text,
+};
+
+/// How many operations a script can run for, so one stuck in a loop doesn't hold the session
+/// forever.
+const MAX_OPERATIONS: u64 = 50_000_000;
+/// How deep calls (and expressions) can nest.
+const MAX_DEPTH: usize = 64;
+/// How big strings, arrays and maps can grow.
+const MAX_SIZE: usize = 1 << 20;
+
+/// What running a script came to.
+#[derive(Debug)]
+pub struct Output {
+	/// Printed with `print()` or `debug()`, a line each
+	pub lines: Vec<String>,
+	/// The value the script ended with, as MessagePack
+	pub result: Vec<u8>,
+}
+
+/// The tree of the document a script runs over, as the flat node table.
+struct Tree {
+	text: Text,
+	rows: Vec<NodeRow>,
+	/// The index of each node's next sibling
+	next_sibling: Vec<Option<u32>>,
+}
+
+/// A cursor on a node of the tree, which the script moves around.
+#[derive(Clone)]
+struct Cursor {
+	tree: Rc<Tree>,
+	index: usize,
+}
+
+impl Cursor {
+	fn row(&self) -> &NodeRow {
+		&self.tree.rows[self.index]
+	}
+
+	/// Moves to `index`, if there's a node there.
+	fn goto(&mut self, index: Option<usize>) -> bool {
+		match index {
+			Some(index) => {
+				self.index = index;
+				true
+			}
+			None => false,
+		}
+	}
+
+	fn goto_first_child(&mut self) -> bool {
+		// children come right after their parent
+		let child = self.index + 1;
+		let is_child = self
+			.tree
+			.rows
+			.get(child)
+			.is_some_and(|row| row.parent == Some(self.index as u32));
+		self.goto(is_child.then_some(child))
+	}
+
+	fn goto_next_sibling(&mut self) -> bool {
+		let sibling = self.tree.next_sibling[self.index];
+		self.goto(sibling.map(|sibling| sibling as usize))
+	}
+
+	fn goto_parent(&mut self) -> bool {
+		let parent = self.row().parent;
+		self.goto(parent.map(|parent| parent as usize))
+	}
+
+	fn goto_next(&mut self) -> bool {
+		let next = self.index + 1;
+		self.goto((next < self.tree.rows.len()).then_some(next))
+	}
+
+	fn text(&mut self) -> String {
+		let row = self.row();
+		let range = row.start_byte as usize / 2..row.end_byte as usize / 2;
+		String::from_utf16_lossy(&self.tree.text.slice(range))
+	}
+}
+
+/// Runs `script` over `document`, at `path`.
+pub fn run(document: &Document, path: &str, script: &str) -> Result<Output> {
+	run_with_operations(document, path, script, MAX_OPERATIONS)
+}
+
+fn run_with_operations(
+	document: &Document,
+	path: &str,
+	script: &str,
+	operations: u64,
+) -> Result<Output> {
+	let _span = tracing::info_span!("script").entered();
+	let tree = Rc::new(tree(document));
+	let lines = Rc::new(RefCell::new(Vec::new()));
+
+	let mut engine = Engine::new();
+	// scripts can't import modules from disk
+	engine.set_module_resolver(DummyModuleResolver::new());
+	engine.set_max_operations(operations);
+	engine.set_max_call_levels(MAX_DEPTH);
+	engine.set_max_expr_depths(MAX_DEPTH, MAX_DEPTH);
+	engine.set_max_string_size(MAX_SIZE);
+	engine.set_max_array_size(MAX_SIZE);
+	engine.set_max_map_size(MAX_SIZE);
+	engine.on_progress(|_| cancel::check().is_err().then_some(Dynamic::UNIT));
+	let printed = lines.clone();
+	engine.on_print(move |line| printed.borrow_mut().push(line.to_string()));
+	let printed = lines.clone();
+	engine.on_debug(move |line, _, _| printed.borrow_mut().push(line.to_string()));
+
+	engine
+		.register_type_with_name::<Cursor>("Cursor")
+		.register_fn("cursor", move || Cursor {
+			tree: tree.clone(),
+			index: 0,
+		})
+		.register_fn("goto_first_child", Cursor::goto_first_child)
+		.register_fn("goto_next_sibling", Cursor::goto_next_sibling)
+		.register_fn("goto_parent", Cursor::goto_parent)
+		.register_fn("goto_next", Cursor::goto_next)
+		.register_get("kind", |cursor: &mut Cursor| cursor.row().kind.to_string())
+		.register_get("named", |cursor: &mut Cursor| cursor.row().named)
+		.register_get("error", |cursor: &mut Cursor| cursor.row().error)
+		.register_get("start_byte", |cursor: &mut Cursor| {
+			cursor.row().start_byte as i64
+		})
+		.register_get("end_byte", |cursor: &mut Cursor| {
+			cursor.row().end_byte as i64
+		})
+		.register_get("start_row", |cursor: &mut Cursor| {
+			cursor.row().start_point.row as i64
+		})
+		.register_get("start_column", |cursor: &mut Cursor| {
+			cursor.row().start_point.column as i64
+		})
+		.register_get("end_row", |cursor: &mut Cursor| {
+			cursor.row().end_point.row as i64
+		})
+		.register_get("end_column", |cursor: &mut Cursor| {
+			cursor.row().end_point.column as i64
+		})
+		.register_get("text", Cursor::text);
+
+	let mut info = Map::new();
+	info.insert("path".into(), path.into());
+	info.insert("language".into(), document.language.clone().into());
+	info.insert("version".into(), (document.version as i64).into());
+	let mut scope = Scope::new();
+	scope.push_constant("document", info);
+
+	let result = engine
+		.eval_with_scope::<Dynamic>(&mut scope, script)
+		.map_err(|e| script_error(*e))?;
+	Ok(Output {
+		lines: lines.take(),
+		result: rmp_serde::to_vec_named(&result)?,
+	})
+}
+
+fn tree(document: &Document) -> Tree {
+	let rows = export::nodes(&document.tree);
+	let mut next_sibling = vec![None; rows.len()];
+	let mut last_child = vec![None; rows.len()];
+	for row in &rows {
+		if let Some(parent) = row.parent {
+			if let Some(previous) = last_child[parent as usize].replace(row.id) {
+				next_sibling[previous as usize] = Some(row.id);
+			}
+		}
+	}
+	Tree {
+		text: document.text.clone(),
+		rows,
+		next_sibling,
+	}
+}
+
+fn script_error(e: EvalAltResult) -> anyhow::Error {
+	match e {
+		EvalAltResult::ErrorTerminated(..) => match cancel::check() {
+			Err(e) => e,
+			Ok(()) => Error::Internal("Script was stopped".to_string()).into(),
+		},
+		EvalAltResult::ErrorTooManyOperations(_) => {
+			Error::Timeout("Script ran for longer than it's allowed to".to_string()).into()
+		}
+		e => Error::InvalidRequest(format!("Script failed: {}", e)).into(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::SystemTime;
+
+	use super::*;
+
+	fn document(source: &str) -> Document {
+		let text = Text::from(source);
+		let mut parser = tree_sitter::Parser::new();
+		parser.set_language(tree_sitter_cpp::language()).unwrap();
+		Document {
+			tree: text.parse(&mut parser, None).unwrap(),
+			text,
+			version: 2,
+			language: "cpp".to_string(),
+			mtime: None,
+			accessed: SystemTime::now(),
+		}
+	}
+
+	#[test]
+	fn walks_the_tree_with_a_cursor() {
+		let script = r#"
+			let c = cursor();
+			c.goto_first_child();
+			let names = [];
+			loop {
+				print(c.kind);
+				names.push(c.text);
+				if !c.goto_next_sibling() { break; }
+			}
+			c.goto_parent();
+			let nodes = 1;
+			while c.goto_next() { nodes += 1; }
+			#{ names: names, nodes: nodes, language: document.language }
+		"#;
+		let output = run(&document("int x;\nint y;"), "file:///a.cpp", script).unwrap();
+		assert_eq!(output.lines, ["declaration", "declaration"]);
+		let result: ciborium::Value = rmp_serde::from_slice(&output.result).unwrap();
+		let field = |name: &str| {
+			result
+				.as_map()
+				.unwrap()
+				.iter()
+				.find(|(key, _)| key.as_text() == Some(name))
+				.map(|(_, value)| value.clone())
+				.unwrap()
+		};
+		assert_eq!(
+			field("names"),
+			ciborium::Value::Array(vec!["int x;".into(), "int y;".into()])
+		);
+		assert_eq!(field("nodes"), 9.into());
+		assert_eq!(field("language"), "cpp".into());
+	}
+
+	#[test]
+	fn sandboxes_scripts() {
+		let document = document("int x;");
+		let e = run_with_operations(&document, "file:///a.cpp", "loop {}", 100_000).unwrap_err();
+		assert!(matches!(e.downcast_ref(), Some(Error::Timeout(_))));
+
+		let e = run(&document, "file:///a.cpp", r#"import "/etc/passwd" as p;"#).unwrap_err();
+		assert!(matches!(e.downcast_ref(), Some(Error::InvalidRequest(_))));
+
+		let e = run(
+			&document,
+			"file:///a.cpp",
+			"let s = \"x\"; loop { s += s; }",
+		)
+		.unwrap_err();
+		assert!(matches!(e.downcast_ref(), Some(Error::InvalidRequest(_))));
+	}
+}
@@ -0,0 +1,445 @@
+use std::{
+	ops::ControlFlow,
+	path::PathBuf,
+	thread,
+	time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use axum::{body::Bytes, response::Response};
+use flatbuffers::WIPOffset;
+use tree_sitter::{CaptureQuantifier, Node, Query, QueryCursor};
+
+use crate::{
+	cancel, deterministic,
+	encoding::Encoding,
+	error::Error,
+	languages,
+	message_generated::asted::interface::{
+		self as fb, Location, Point, QueryCapture, QueryCaptureArgs, QueryCaptureCount,
+		QueryCaptureCountArgs, QueryCaptureGroup, QueryCaptureGroupArgs, QueryFileCount,
+		QueryFileCountArgs, QueryMatch, QueryMatchArgs, QueryRequest, QueryResults,
+		QueryResultsArgs,
+	},
+	uri::{self, DocumentKey},
+	webhooks,
+	workspace::{self, Filter},
+	workspace_config, State,
+};
+
+/// How many matches a batch holds at most.
+const BATCH_MATCHES: usize = 256;
+
+/// How long the first match of a batch waits for more before it's sent anyway, so clients see
+/// results soon even when they're far apart.
+const BATCH_DELAY: Duration = Duration::from_millis(50);
+
+/// Where a search left off: after the first `matches` matches in the file at `path`.
+struct Cursor {
+	path: PathBuf,
+	matches: usize,
+}
+
+impl Cursor {
+	fn parse(cursor: &str) -> Result<Self> {
+		let invalid = || Error::InvalidRequest(format!("Invalid cursor {:?}", cursor));
+		let (matches, uri) = cursor.split_once(':').ok_or_else(invalid)?;
+		let matches = matches.parse().map_err(|_| invalid())?;
+		match uri::key(uri) {
+			Ok(DocumentKey::File(path)) => Ok(Cursor { path, matches }),
+			_ => Err(invalid().into()),
+		}
+	}
+
+	fn format(uri: &str, matches: usize) -> String {
+		format!("{}:{}", matches, uri)
+	}
+}
+
+struct Match {
+	uri: String,
+	pattern_index: usize,
+	captures: Vec<Capture>,
+}
+
+struct Capture {
+	name: usize,
+	node_start: (usize, tree_sitter::Point),
+	node_end: (usize, tree_sitter::Point),
+	text: Option<Vec<u16>>,
+}
+
+/// The matches of a count-only search in a file.
+struct FileCount {
+	uri: String,
+	matches: usize,
+	/// By capture index
+	captures: Vec<usize>,
+}
+
+/// Starts running the query in `req` over the files under its directory and returns the response
+/// the batches of matches are streamed in. The search runs on its own thread (and the worker
+/// threads) until it's done, the client goes away or the request is cancelled.
+pub fn start(state: &State, req: QueryRequest, encoding: Encoding) -> Result<Response> {
+	let dir = match uri::key(req.path())? {
+		DocumentKey::File(path) if path.is_dir() => path,
+		_ => return Err(Error::UnknownFile(format!("{} is not a directory", req.path())).into()),
+	};
+	state.check_root(&dir)?;
+	let lang = match req.lang().or(state.lang.as_deref()) {
+		Some(lang) => lang.to_string(),
+		None => {
+			return Err(Error::InvalidRequest(
+				"QueryRequest needs a lang, or a session with a language".to_string(),
+			)
+			.into())
+		}
+	};
+	let language = languages::get(&lang)
+		.ok_or_else(|| Error::UnknownLanguage(format!("Unsupported language: {}", lang)))?;
+	// tree-sitter 0.20 can panic describing where a query that ends early went wrong
+	let query = match std::panic::catch_unwind(|| Query::new(language, req.query())) {
+		Ok(Ok(query)) => query,
+		Ok(Err(e)) => return Err(Error::InvalidRequest(format!("Invalid query: {}", e)).into()),
+		Err(_) => return Err(Error::InvalidRequest("Invalid query".to_string()).into()),
+	};
+	if encoding == Encoding::Sarif && req.count_only() {
+		return Err(Error::InvalidRequest("Counts can't be encoded as SARIF".to_string()).into());
+	}
+	let filter = Filter::from_request(req.filter())?;
+	let cursor = req
+		.cursor()
+		.filter(|cursor| !cursor.is_empty())
+		.map(Cursor::parse)
+		.transpose()?;
+
+	let mut files = Vec::new();
+	// never stopped early
+	let _ = filter.walk(&dir, None, |path, _| {
+		let file_lang = workspace_config::language_for(path);
+		let searched = cursor
+			.as_ref()
+			.is_none_or(|cursor| path >= cursor.path.as_path());
+		if searched && file_lang.as_ref() == Some(&lang) {
+			files.push((path.to_path_buf(), lang.clone()));
+		}
+		Ok(ControlFlow::Continue(()))
+	})?;
+
+	let search = Search {
+		query,
+		skip: cursor.filter(|cursor| files.first().is_some_and(|(path, _)| *path == cursor.path)),
+		max_matches: match req.max_matches() {
+			0 => None,
+			max => Some(max as usize),
+		},
+		with_text: req.with_text(),
+		count_only: req.count_only(),
+		grouped: req.grouped(),
+	};
+	let span = tracing::info_span!("search", dir = %dir.display(), files = files.len());
+	if encoding == Encoding::Sarif {
+		// a SARIF log is a single document, so it's only sent once the search is done
+		let _span = span.entered();
+		let mut batches = Vec::new();
+		search.run(&files, |batch| {
+			batches.push(batch);
+			Ok(())
+		})?;
+		return encoding.query_results_sarif(batches);
+	}
+	let (mut sender, body) = hyper::Body::channel();
+	let runtime = tokio::runtime::Handle::current();
+	let registration = cancel::current();
+	thread::spawn(move || {
+		let _span = span.entered();
+		cancel::scope(registration, || {
+			let res = search.run(&files, |res| {
+				let frame = encoding.query_results_frame(res)?;
+				runtime
+					.block_on(sender.send_data(Bytes::from(frame)))
+					.context("The client went away")
+			});
+			if let Err(e) = res {
+				tracing::info!("Search stopped: {:#}", e);
+			}
+		})
+	});
+	Ok(encoding.stream_response(body))
+}
+
+struct Search {
+	query: Query,
+	/// The cursor the search carries on from, if it's in the first file
+	skip: Option<Cursor>,
+	max_matches: Option<usize>,
+	with_text: bool,
+	count_only: bool,
+	grouped: bool,
+}
+
+impl Search {
+	/// Searches `files` in order, handing each batch of matches to `send` as a serialized
+	/// `QueryResults` (size-prefixed).
+	fn run(
+		&self,
+		files: &[(PathBuf, String)],
+		mut send: impl FnMut(Vec<u8>) -> Result<()>,
+	) -> Result<()> {
+		let mut batch = Vec::new();
+		let mut counts = Vec::new();
+		let mut batch_started = Instant::now();
+		let mut cursor = None;
+		let mut found = 0;
+		let mut limited = false;
+
+		let res = workspace::parse_all(files, |parsed| {
+			cancel::check()?;
+			let parsed = match parsed {
+				Ok(parsed) => parsed,
+				Err(e) => {
+					tracing::warn!("Not searching a file: {:#}", e);
+					return Ok(());
+				}
+			};
+			let uri = DocumentKey::file(&parsed.path).uri();
+			let skip = match &self.skip {
+				Some(skip) if skip.path == parsed.path => skip.matches,
+				_ => 0,
+			};
+			let text = &parsed.text;
+			let mut query_cursor = QueryCursor::new();
+			let matches =
+				query_cursor.matches(&self.query, parsed.tree.root_node(), |node: Node| {
+					text.chunks(node.start_byte() / 2..node.end_byte() / 2)
+				});
+			let mut count = self.count_only.then(|| FileCount {
+				uri: uri.clone(),
+				matches: 0,
+				captures: vec![0; self.query.capture_names().len()],
+			});
+			for (i, m) in matches.enumerate().skip(skip) {
+				if self.max_matches == Some(found) {
+					limited = true;
+					counts.extend(count.filter(|count| count.matches > 0));
+					anyhow::bail!("max_matches reached");
+				}
+				cursor = Some(Cursor::format(&uri, i + 1));
+				found += 1;
+				if let Some(count) = &mut count {
+					count.matches += 1;
+					for capture in m.captures {
+						count.captures[capture.index as usize] += 1;
+					}
+					continue;
+				}
+
+				let captures = m
+					.captures
+					.iter()
+					.map(|capture| {
+						let node = capture.node;
+						Capture {
+							name: capture.index as usize,
+							node_start: (node.start_byte(), node.start_position()),
+							node_end: (node.end_byte(), node.end_position()),
+							text: self
+								.with_text
+								.then(|| text.slice(node.start_byte() / 2..node.end_byte() / 2)),
+						}
+					})
+					.collect();
+				if batch.is_empty() {
+					batch_started = Instant::now();
+				}
+				batch.push(Match {
+					uri: uri.clone(),
+					pattern_index: m.pattern_index,
+					captures,
+				});
+				if batch.len() == BATCH_MATCHES {
+					send(self.results(&batch, &[], cursor.as_deref(), false))?;
+					batch.clear();
+				}
+			}
+			// a batch of counts holds as many files as one of matches holds matches
+			if let Some(count) = count.filter(|count| count.matches > 0) {
+				if counts.is_empty() {
+					batch_started = Instant::now();
+				}
+				counts.push(count);
+				if counts.len() == BATCH_MATCHES {
+					send(self.results(&[], &counts, cursor.as_deref(), false))?;
+					counts.clear();
+				}
+			}
+			if !(batch.is_empty() && counts.is_empty())
+				&& batch_started.elapsed() >= BATCH_DELAY
+				&& !deterministic::enabled()
+			{
+				send(self.results(&batch, &counts, cursor.as_deref(), false))?;
+				batch.clear();
+				counts.clear();
+			}
+			Ok(())
+		});
+		if res.is_err() && !limited {
+			return res;
+		}
+		if res.is_ok() {
+			webhooks::emit(webhooks::Payload {
+				event: webhooks::Event::IndexCompleted,
+				subject: webhooks::Subject::Index { files: files.len() },
+			});
+		}
+		send(self.results(&batch, &counts, cursor.as_deref(), !limited))
+	}
+
+	fn results(
+		&self,
+		batch: &[Match],
+		counts: &[FileCount],
+		cursor: Option<&str>,
+		done: bool,
+	) -> Vec<u8> {
+		let names = self.query.capture_names();
+		let mut builder = flatbuffers::FlatBufferBuilder::new();
+		let matches = batch
+			.iter()
+			.map(|m| {
+				let (captures, groups) = match self.grouped {
+					true => {
+						let quantifiers = self.query.capture_quantifiers(m.pattern_index);
+						let groups = quantifiers
+							.iter()
+							.enumerate()
+							.filter(|(_, &quantifier)| quantifier != CaptureQuantifier::Zero)
+							.map(|(name, &quantifier)| {
+								let captures = m
+									.captures
+									.iter()
+									.filter(|capture| capture.name == name)
+									.map(|capture| build_capture(&mut builder, names, capture))
+									.collect::<Vec<_>>();
+								let captures = builder.create_vector(&captures);
+								let name = builder.create_string(&names[name]);
+								QueryCaptureGroup::create(
+									&mut builder,
+									&QueryCaptureGroupArgs {
+										name: Some(name),
+										quantifier: quantifier_of(quantifier),
+										captures: Some(captures),
+									},
+								)
+							})
+							.collect::<Vec<_>>();
+						(None, Some(builder.create_vector(&groups)))
+					}
+					false => {
+						let captures = m
+							.captures
+							.iter()
+							.map(|capture| build_capture(&mut builder, names, capture))
+							.collect::<Vec<_>>();
+						(Some(builder.create_vector(&captures)), None)
+					}
+				};
+				let path = builder.create_string(&m.uri);
+				QueryMatch::create(
+					&mut builder,
+					&QueryMatchArgs {
+						path: Some(path),
+						pattern_index: m.pattern_index as u32,
+						captures,
+						groups,
+					},
+				)
+			})
+			.collect::<Vec<_>>();
+		let matches = builder.create_vector(&matches);
+		let counts = self.count_only.then(|| {
+			let counts = counts
+				.iter()
+				.map(|count| {
+					let captures = count
+						.captures
+						.iter()
+						.enumerate()
+						.filter(|(_, &captured)| captured > 0)
+						.map(|(name, &captured)| {
+							let name = builder.create_string(&names[name]);
+							QueryCaptureCount::create(
+								&mut builder,
+								&QueryCaptureCountArgs {
+									name: Some(name),
+									count: captured as u32,
+								},
+							)
+						})
+						.collect::<Vec<_>>();
+					let captures = builder.create_vector(&captures);
+					let path = builder.create_string(&count.uri);
+					QueryFileCount::create(
+						&mut builder,
+						&QueryFileCountArgs {
+							path: Some(path),
+							matches: count.matches as u32,
+							captures: Some(captures),
+						},
+					)
+				})
+				.collect::<Vec<_>>();
+			builder.create_vector(&counts)
+		});
+		let cursor = cursor.map(|cursor| builder.create_string(cursor));
+		let resp = QueryResults::create(
+			&mut builder,
+			&QueryResultsArgs {
+				matches: Some(matches),
+				cursor,
+				done,
+				counts,
+			},
+		);
+		builder.finish_size_prefixed(resp, None);
+
+		builder.finished_data().to_vec()
+	}
+}
+
+fn build_capture<'a>(
+	builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+	names: &[String],
+	capture: &Capture,
+) -> WIPOffset<QueryCapture<'a>> {
+	let name = builder.create_string(&names[capture.name]);
+	let text = capture
+		.text
+		.as_ref()
+		.map(|text| builder.create_vector(text));
+	let (start_byte, start_point) = capture.node_start;
+	let (end_byte, end_point) = capture.node_end;
+	QueryCapture::create(
+		builder,
+		&QueryCaptureArgs {
+			name: Some(name),
+			location: Some(&Location::new(start_byte as u32, end_byte as u32)),
+			start_point: Some(&Point::new(
+				start_point.row as u32,
+				start_point.column as u32,
+			)),
+			end_point: Some(&Point::new(end_point.row as u32, end_point.column as u32)),
+			text,
+		},
+	)
+}
+
+fn quantifier_of(quantifier: tree_sitter::CaptureQuantifier) -> fb::CaptureQuantifier {
+	match quantifier {
+		CaptureQuantifier::Zero => fb::CaptureQuantifier::Zero,
+		CaptureQuantifier::ZeroOrOne => fb::CaptureQuantifier::ZeroOrOne,
+		CaptureQuantifier::ZeroOrMore => fb::CaptureQuantifier::ZeroOrMore,
+		CaptureQuantifier::One => fb::CaptureQuantifier::One,
+		CaptureQuantifier::OneOrMore => fb::CaptureQuantifier::OneOrMore,
+	}
+}
@@ -0,0 +1,90 @@
+use flatbuffers::FlatBufferBuilder;
+use tree_sitter::Node;
+
+use crate::{
+	document::Document,
+	message_generated::asted::interface::{
+		Location, Point, SelectionRange, SelectionRangeArgs, SelectionRangesRequest,
+		SelectionRangesResponse, SelectionRangesResponseArgs,
+	},
+};
+
+/// The named node at `point` and all of its ancestors, innermost first. A node spanning the same
+/// range as the one before it is left out, since selecting it wouldn't grow the selection.
+pub fn nodes_around<'tree>(root: Node<'tree>, point: tree_sitter::Point) -> Vec<Node<'tree>> {
+	let mut nodes = Vec::<Node>::new();
+	let mut node = root.named_descendant_for_point_range(point, point);
+	while let Some(n) = node {
+		if nodes.last().map(Node::byte_range) != Some(n.byte_range()) {
+			nodes.push(n);
+		}
+		node = n.parent();
+	}
+	nodes
+}
+
+/// Finds the nodes around each position in `req` in `document` and returns a serialized
+/// `SelectionRangesResponse`.
+pub fn selection_ranges(document: &Document, req: SelectionRangesRequest) -> Vec<u8> {
+	let _span =
+		tracing::info_span!("selection_ranges", positions = req.positions().len()).entered();
+	let text = &document.text;
+	let root = document.tree.root_node();
+
+	let mut builder = FlatBufferBuilder::new();
+	let ranges = req
+		.positions()
+		.iter()
+		.map(|position| {
+			let offset = text.offset_at(position.row() as usize, position.column() as usize / 2);
+			let point = text.point_at(offset);
+			let nodes = nodes_around(root, point);
+			if nodes.is_empty() {
+				let point = Point::new(point.row as u32, point.column as u32);
+				return SelectionRange::create(
+					&mut builder,
+					&SelectionRangeArgs {
+						location: Some(&Location::new(offset as u32 * 2, offset as u32 * 2)),
+						start_point: Some(&point),
+						end_point: Some(&point),
+						parent: None,
+					},
+				);
+			}
+
+			// parents have to be built before the ranges pointing at them
+			let mut parent = None;
+			for node in nodes.iter().rev() {
+				let (start_point, end_point) = (node.start_position(), node.end_position());
+				parent = Some(SelectionRange::create(
+					&mut builder,
+					&SelectionRangeArgs {
+						location: Some(&Location::new(
+							node.start_byte() as u32,
+							node.end_byte() as u32,
+						)),
+						start_point: Some(&Point::new(
+							start_point.row as u32,
+							start_point.column as u32,
+						)),
+						end_point: Some(&Point::new(end_point.row as u32, end_point.column as u32)),
+						parent,
+					},
+				));
+			}
+			parent.unwrap()
+		})
+		.collect::<Vec<_>>();
+	let ranges = builder.create_vector(&ranges);
+	let resp = SelectionRangesResponse::create(
+		&mut builder,
+		&SelectionRangesResponseArgs {
+			ranges: Some(ranges),
+			version: document.version,
+			content_hash: document.text.content_hash(),
+		},
+	);
+	builder.finish(resp, None);
+
+	builder.finished_data().to_vec()
+}
@@ -0,0 +1,133 @@
+use std::time::Instant;
+
+use anyhow::Result;
+use axum::body::Bytes;
+
+use crate::{
+	error::Error,
+	languages,
+	message_generated::asted::interface::ParseSnippetRequest,
+	text::Text,
+	tree_serialize::{self, ParseInfo, TextPolicy},
+	State,
+};
+
+/// Where the snippet goes in a context's scaffolding.
+const PLACEHOLDER: &str = "$SNIPPET";
+
+/// The built-in contexts, as (language, context, scaffolding). The snippet gets lines of its own,
+/// so its first line isn't shifted over by the scaffolding.
+const CONTEXTS: &[(&str, &str, &str)] = &[
+	("typescript", "function_body", "function _() {\n$SNIPPET\n}"),
+	("typescript", "class_body", "class _ {\n$SNIPPET\n}"),
+	("typescript", "expression", "(\n$SNIPPET\n);"),
+	("typescript", "parameters", "function _(\n$SNIPPET\n) {}"),
+	("typescript", "type", "type _ =\n$SNIPPET\n;"),
+	("tsx", "function_body", "function _() {\n$SNIPPET\n}"),
+	("tsx", "class_body", "class _ {\n$SNIPPET\n}"),
+	("tsx", "expression", "(\n$SNIPPET\n);"),
+	("tsx", "parameters", "function _(\n$SNIPPET\n) {}"),
+	("tsx", "type", "type _ =\n$SNIPPET\n;"),
+	("cpp", "function_body", "void _() {\n$SNIPPET\n}"),
+	("cpp", "class_body", "class _ {\n$SNIPPET\n};"),
+	("cpp", "expression", "auto _ =\n$SNIPPET\n;"),
+	("cpp", "parameters", "void _(\n$SNIPPET\n);"),
+	("cpp", "type", "using _ =\n$SNIPPET\n;"),
+];
+
+/// Parses the snippet in `req` in its context, in the session's language, and returns a
+/// serialized `FileResponse` of the snippet's part of the tree.
+pub fn parse_snippet(state: &State, req: ParseSnippetRequest) -> Result<Bytes> {
+	let lang = match (&state.lang, state.plain_text) {
+		(Some(lang), false) => lang,
+		_ => {
+			return Err(Error::UnknownLanguage(
+				"Parsing snippets needs a session with a grammar".to_string(),
+			)
+			.into())
+		}
+	};
+	let snippet = req.snippet().iter().collect::<Vec<_>>();
+	let (prefix, suffix) = match req.context().filter(|context| !context.is_empty()) {
+		Some(context) => scaffolding(lang, context)?,
+		None => (String::new(), String::new()),
+	};
+	let _span = tracing::info_span!("parse_snippet", context = req.context()).entered();
+
+	let prefix = prefix.encode_utf16().collect::<Vec<_>>();
+	// byte offsets into the UTF-16 text
+	let range = prefix.len() * 2..(prefix.len() + snippet.len()) * 2;
+	let snippet = Text::from_utf16(&snippet)?;
+	let mut text = prefix;
+	text.extend(snippet.units());
+	text.extend(suffix.encode_utf16());
+	let text = Text::from_utf16(&text)?;
+	let started = Instant::now();
+	let (tree, cached_tree) = state.parse_tree(None, &text, None, false)?;
+	let parse_info = ParseInfo {
+		duration: started.elapsed(),
+		reused_old_tree: false,
+		cached_tree,
+		changed_ranges: Vec::new(),
+		version: 0,
+		content_hash: snippet.content_hash(),
+		mtime: None,
+	};
+
+	let options = state.serialize_options(
+		req.profile(),
+		req.structural_hashes(),
+		req.dual_offsets(),
+		TextPolicy::from_request(req.text_inclusion(), req.text_max_bytes()),
+	);
+	Ok(tree_serialize::serialize_part(
+		&text,
+		&tree,
+		range,
+		&parse_info,
+		tree_serialize::line_ending(&snippet),
+		options,
+	))
+}
+
+/// The code going before and after a snippet in `context` of `lang`.
+fn scaffolding(lang: &str, context: &str) -> Result<(String, String)> {
+	let mut contexts = CONTEXTS
+		.iter()
+		.filter(|(context_lang, _, _)| *context_lang == lang)
+		.map(|&(_, name, scaffolding)| (name.to_string(), scaffolding.to_string()))
+		.collect::<Vec<_>>();
+	for (name, scaffolding) in languages::config(lang).snippet_contexts {
+		contexts.retain(|(context, _)| *context != name);
+		contexts.push((name, scaffolding));
+	}
+
+	let scaffolding = match contexts.iter().find(|(name, _)| name == context) {
+		Some((_, scaffolding)) => scaffolding,
+		None => {
+			let mut names = contexts
+				.iter()
+				.map(|(name, _)| name.as_str())
+				.collect::<Vec<_>>();
+			names.sort();
+			return Err(Error::InvalidRequest(format!(
+				"Unknown snippet context {:?} for {}; there are {}",
+				context,
+				lang,
+				match names.is_empty() {
+					true => "none".to_string(),
+					false => names.join(", "),
+				}
+			))
+			.into());
+		}
+	};
+	match scaffolding.split_once(PLACEHOLDER) {
+		Some((prefix, suffix)) => Ok((prefix.to_string(), suffix.to_string())),
+		None => Err(Error::InvalidRequest(format!(
+			"The {:?} snippet context for {} has no {}",
+			context, lang, PLACEHOLDER
+		))
+		.into()),
+	}
+}
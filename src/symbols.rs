@@ -0,0 +1,151 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+use tree_sitter::Node;
+
+use crate::{
+	document::Document,
+	languages,
+	message_generated::asted::interface::{
+		Location, Point, Symbol, SymbolArgs, SymbolKind, SymbolsResponse, SymbolsResponseArgs,
+	},
+	queries::{self, Tag},
+};
+
+/// The names symbol kinds go by, both as the `<kind>` of tags queries' `@definition.<kind>`
+/// captures and in `symbol_kinds` of the config file.
+pub const KIND_NAMES: &[(&str, SymbolKind)] = &[
+	("module", SymbolKind::Module),
+	("namespace", SymbolKind::Namespace),
+	("class", SymbolKind::Class),
+	("struct", SymbolKind::Struct),
+	("interface", SymbolKind::Interface),
+	("enum", SymbolKind::Enum),
+	("enum_member", SymbolKind::EnumMember),
+	("function", SymbolKind::Function),
+	("method", SymbolKind::Method),
+	("field", SymbolKind::Field),
+	("property", SymbolKind::Property),
+	("constant", SymbolKind::Constant),
+	("variable", SymbolKind::Variable),
+];
+
+pub fn kind_named(name: &str) -> Option<SymbolKind> {
+	KIND_NAMES
+		.iter()
+		.find(|(n, _)| *n == name)
+		.map(|&(_, kind)| kind)
+}
+
+/// What a definition found as `@definition.<tags_kind>` in `lang` is, going by the language's
+/// configured `symbol_kinds` before the names every language shares.
+pub fn kind(lang: &str, tags_kind: &str) -> SymbolKind {
+	configured_kind(&languages::config(lang).symbol_kinds, tags_kind)
+}
+
+fn configured_kind(symbol_kinds: &HashMap<String, SymbolKind>, tags_kind: &str) -> SymbolKind {
+	symbol_kinds
+		.get(tags_kind)
+		.copied()
+		.or_else(|| kind_named(tags_kind))
+		.unwrap_or(SymbolKind::Other)
+}
+
+/// Finds the definitions in `document` and returns a serialized `SymbolsResponse` of them, each
+/// under the innermost one containing it.
+pub fn symbols(document: &Document) -> Vec<u8> {
+	let _span = tracing::info_span!("symbols").entered();
+	let symbol_kinds = languages::config(&document.language).symbol_kinds;
+	let mut tags = queries::tags(&document.language, &document.tree, &document.text)
+		.into_iter()
+		.filter(|tag| tag.definition)
+		.collect::<Vec<_>>();
+	// parents before their children
+	tags.sort_by_key(|tag| (tag.node.start_byte(), Reverse(tag.node.end_byte())));
+
+	// the indices of each tag's children, and of the outermost tags
+	let mut children = vec![Vec::new(); tags.len()];
+	let mut roots = Vec::new();
+	let mut stack = Vec::<usize>::new();
+	for (i, tag) in tags.iter().enumerate() {
+		while stack
+			.last()
+			.is_some_and(|&parent| tags[parent].node.end_byte() <= tag.node.start_byte())
+		{
+			stack.pop();
+		}
+		match stack.last() {
+			Some(&parent) => children[parent].push(i),
+			None => roots.push(i),
+		}
+		stack.push(i);
+	}
+
+	let mut builder = FlatBufferBuilder::new();
+	let mut build = Build {
+		document,
+		builder: &mut builder,
+		tags: &tags,
+		children: &children,
+		symbol_kinds: &symbol_kinds,
+	};
+	let symbols = roots.iter().map(|&i| build.symbol(i)).collect::<Vec<_>>();
+	let symbols = builder.create_vector(&symbols);
+	let resp = SymbolsResponse::create(
+		&mut builder,
+		&SymbolsResponseArgs {
+			symbols: Some(symbols),
+			version: document.version,
+			content_hash: document.text.content_hash(),
+		},
+	);
+	builder.finish(resp, None);
+
+	builder.finished_data().to_vec()
+}
+
+struct Build<'a, 'b, 'tree> {
+	document: &'a Document,
+	builder: &'a mut FlatBufferBuilder<'b>,
+	tags: &'a [Tag<'tree>],
+	children: &'a [Vec<usize>],
+	symbol_kinds: &'a HashMap<String, SymbolKind>,
+}
+
+impl<'b> Build<'_, 'b, '_> {
+	/// Builds the symbol of tag `i`, after the symbols within it.
+	fn symbol(&mut self, i: usize) -> WIPOffset<Symbol<'b>> {
+		let children = self.children[i]
+			.iter()
+			.map(|&child| self.symbol(child))
+			.collect::<Vec<_>>();
+		let children = self.builder.create_vector(&children);
+
+		let tag = &self.tags[i];
+		let name = self
+			.builder
+			.create_string(&tag.name_text(&self.document.text));
+		let tags_kind = self.builder.create_string(tag.kind);
+		let (start_point, end_point) = (tag.node.start_position(), tag.node.end_position());
+		Symbol::create(
+			self.builder,
+			&SymbolArgs {
+				name: Some(name),
+				kind: configured_kind(self.symbol_kinds, tag.kind),
+				tags_kind: Some(tags_kind),
+				location: Some(&location(tag.node)),
+				start_point: Some(&Point::new(
+					start_point.row as u32,
+					start_point.column as u32,
+				)),
+				end_point: Some(&Point::new(end_point.row as u32, end_point.column as u32)),
+				name_location: Some(&location(tag.name)),
+				children: Some(children),
+			},
+		)
+	}
+}
+
+fn location(node: Node) -> Location {
+	Location::new(node.start_byte() as u32, node.end_byte() as u32)
+}
@@ -0,0 +1,155 @@
+use tree_sitter::{Node, Point, Tree};
+
+use crate::text::Text;
+
+/// How much of an unexpected token an error message quotes.
+const MAX_QUOTED: usize = 24;
+
+pub struct SyntaxError<'tree> {
+	/// The ERROR or MISSING node
+	pub node: Node<'tree>,
+	pub message: String,
+}
+
+/// An error for every ERROR and MISSING node, in order. A MISSING node is what the parser expected
+/// to find, so its error says what that was; tree-sitter doesn't let on what it expected in place
+/// of an ERROR node, so all its error can do is quote the token it didn't expect.
+pub fn syntax_errors<'tree>(text: &Text, tree: &'tree Tree) -> Vec<SyntaxError<'tree>> {
+	let mut errors = Vec::new();
+	if !tree.root_node().has_error() {
+		return errors;
+	}
+
+	// walk the tree iteratively, since it can be deeper than the stack allows
+	let mut cursor = tree.walk();
+	'walk: loop {
+		let node = cursor.node();
+		if node.is_missing() {
+			errors.push(SyntaxError {
+				node,
+				message: format!("Expected {}", describe_kind(node)),
+			});
+		} else if node.is_error() {
+			errors.push(SyntaxError {
+				node,
+				message: match unexpected_token(text, node) {
+					Some(token) => format!("Unexpected {}", token),
+					None => "Syntax error".to_string(),
+				},
+			});
+		}
+
+		if node.has_error() && cursor.goto_first_child() {
+			continue;
+		}
+		while !cursor.goto_next_sibling() {
+			if !cursor.goto_parent() {
+				break 'walk;
+			}
+		}
+	}
+	errors
+}
+
+/// How many lines apart syntax errors can be and still be taken for the same mistake.
+const MERGE_LINES: usize = 1;
+
+/// Syntax errors nested in or close to each other, which are usually one mistake the parser
+/// tripped over again and again.
+pub struct ErrorGroup<'tree> {
+	/// The first of the errors, standing for the mistake
+	pub first: SyntaxError<'tree>,
+	pub rest: Vec<SyntaxError<'tree>>,
+}
+
+/// Groups `errors` (in the order `syntax_errors` gives them) by the mistake they likely are.
+pub fn merge_errors(errors: Vec<SyntaxError>) -> Vec<ErrorGroup> {
+	let mut groups = Vec::<ErrorGroup>::new();
+	let mut group_end = Point::default();
+	for error in errors {
+		match groups.last_mut() {
+			Some(group) if error.node.start_position().row <= group_end.row + MERGE_LINES => {
+				group_end = group_end.max(error.node.end_position());
+				group.rest.push(error);
+			}
+			_ => {
+				group_end = error.node.end_position();
+				groups.push(ErrorGroup {
+					first: error,
+					rest: Vec::new(),
+				});
+			}
+		}
+	}
+	groups
+}
+
+/// How an error message refers to a kind of node: tokens are quoted (`')'`), and other nodes named
+/// (`identifier`).
+fn describe_kind(node: Node) -> String {
+	if node.is_named() {
+		node.kind().replace('_', " ")
+	} else {
+		format!("'{}'", node.kind())
+	}
+}
+
+/// The first token of an ERROR node, quoted like in "Unexpected 'else'".
+fn unexpected_token(text: &Text, node: Node) -> Option<String> {
+	let mut token = node;
+	while let Some(child) = token.child(0) {
+		token = child;
+	}
+	let text = text.slice(token.start_byte() / 2..token.end_byte() / 2);
+	let text = String::from_utf16_lossy(&text);
+	let text = text.lines().next().unwrap_or_default().trim();
+	if text.is_empty() {
+		return None;
+	}
+	let quoted = text.chars().take(MAX_QUOTED).collect::<String>();
+	let ellipsis = if quoted.len() < text.len() { "…" } else { "" };
+	Some(format!("'{}{}'", quoted, ellipsis))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(source: &str) -> (Text, Tree) {
+		let text = Text::from_utf16(&source.encode_utf16().collect::<Vec<_>>()).unwrap();
+		let mut parser = tree_sitter::Parser::new();
+		parser.set_language(tree_sitter_cpp::language()).unwrap();
+		let tree = text.parse(&mut parser, None).unwrap();
+		(text, tree)
+	}
+
+	#[test]
+	fn says_what_was_missing() {
+		let (text, tree) = parse("int main() {\n\tint x = 1\n\treturn x;\n}\n");
+		let errors = syntax_errors(&text, &tree);
+		assert_eq!(errors.len(), 1);
+		assert!(errors[0].node.is_missing());
+		assert_eq!(errors[0].message, "Expected ';'");
+	}
+
+	#[test]
+	fn quotes_unexpected_tokens() {
+		let (text, tree) = parse("int main() {\n\treturn 1;\n} }\n");
+		let errors = syntax_errors(&text, &tree);
+		assert!(!errors.is_empty());
+		assert_eq!(errors[0].message, "Unexpected '}'");
+	}
+
+	#[test]
+	fn merges_errors_close_to_each_other() {
+		let (text, tree) = parse("int main() {\n\tint x = 1\n\tint y = 2\n\n\n\tint z = 3\n}\n");
+		let errors = syntax_errors(&text, &tree);
+		assert_eq!(errors.len(), 3);
+		let groups = merge_errors(errors);
+		assert_eq!(groups.len(), 2);
+		assert_eq!(groups[0].first.node.start_position().row, 1);
+		assert_eq!(groups[0].rest.len(), 1);
+		assert_eq!(groups[1].first.node.start_position().row, 5);
+		assert!(groups[1].rest.is_empty());
+	}
+}
@@ -0,0 +1,36 @@
+use anyhow::{Context, Result};
+use opentelemetry::{
+	sdk::{trace, Resource},
+	KeyValue,
+};
+use opentelemetry_otlp::WithExportConfig;
+use tracing::Subscriber;
+use tracing_subscriber::{registry::LookupSpan, Layer};
+
+/// A layer exporting request spans to the OTLP collector at `endpoint` (gRPC, e.g.
+/// http://localhost:4317).
+pub fn layer<S>(endpoint: &str) -> Result<impl Layer<S>>
+where
+	S: Subscriber + for<'span> LookupSpan<'span>,
+{
+	let tracer = opentelemetry_otlp::new_pipeline()
+		.tracing()
+		.with_exporter(
+			opentelemetry_otlp::new_exporter()
+				.tonic()
+				.with_endpoint(endpoint),
+		)
+		.with_trace_config(trace::config().with_resource(Resource::new([KeyValue::new(
+			"service.name",
+			env!("CARGO_PKG_NAME"),
+		)])))
+		.install_batch(opentelemetry::runtime::Tokio)
+		.context("Error setting up OTLP export")?;
+
+	Ok(tracing_opentelemetry::layer().with_tracer(tracer))
+}
+
+/// Flushes spans that haven't been exported yet.
+pub fn shutdown() {
+	opentelemetry::global::shutdown_tracer_provider();
+}
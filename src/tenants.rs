@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+
+use serde::{de, Deserialize, Deserializer};
+
+use crate::limits::RateLimiter;
+
+/// What the sessions of tenants are called, followed by the tenant's name.
+pub const SESSION_PREFIX: &str = "tenant:";
+
+/// A tenant from the config file's `tenants` list. Clients authenticating with its key get a
+/// session of its own, which other tenants' requests never see.
+#[derive(Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TenantConfig {
+	pub name: String,
+	/// Sent as `Authorization: Bearer <key>`
+	pub key: String,
+	/// How many documents the tenant's session keeps cached before dropping the least recently
+	/// used one
+	pub max_documents: Option<usize>,
+	/// Requests per second the tenant can make, across all its clients
+	#[serde(default, deserialize_with = "rate_limit")]
+	pub rate_limit: Option<u32>,
+	/// The directories the tenant can open files in (and below); anywhere if left out
+	#[serde(default)]
+	pub roots: Vec<PathBuf>,
+}
+
+impl TenantConfig {
+	/// The key of the tenant's session in `STATE_MAP`.
+	pub fn session(&self) -> String {
+		format!("{}{}", SESSION_PREFIX, self.name)
+	}
+}
+
+/// Deserializes a rate limit, which can't be 0.
+pub fn rate_limit<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u32>, D::Error> {
+	match u32::deserialize(deserializer)? {
+		// every request would be turned away
+		0 => Err(de::Error::invalid_value(
+			de::Unexpected::Unsigned(0),
+			&"at least 1 request per second",
+		)),
+		rate => Ok(Some(rate)),
+	}
+}
+
+pub struct Tenant {
+	pub name: String,
+	pub key: String,
+	/// The key of the tenant's session in `STATE_MAP`
+	pub session: String,
+	pub rate: Option<RateLimiter<()>>,
+}
+
+impl Tenant {
+	pub fn new(config: &TenantConfig) -> Self {
+		Tenant {
+			name: config.name.clone(),
+			key: config.key.clone(),
+			session: config.session(),
+			rate: config.rate_limit.map(RateLimiter::new),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn rejects_a_rate_limit_of_zero() {
+		let parse = |rate_limit| {
+			toml::from_str::<TenantConfig>(&format!(
+				"name = \"a\"\nkey = \"k\"\nrate_limit = {}",
+				rate_limit
+			))
+		};
+		assert!(parse(0).is_err());
+		assert_eq!(parse(5).unwrap().rate_limit, Some(5));
+	}
+}
@@ -0,0 +1,232 @@
+use std::{collections::hash_map::DefaultHasher, hash::Hasher, ops::Range};
+
+use anyhow::Result;
+use ropey::Rope;
+use tree_sitter::{Parser, Point, Tree};
+
+use crate::{error::Error, fnv::Fnv};
+
+/// A document's text, kept in a rope so edits don't reallocate the whole document.
+///
+/// Offsets are in UTF-16 code units, like everywhere else in the protocol (tree-sitter's byte
+/// offsets are twice that). ropey only counts `\n` as a line break here, matching tree-sitter's
+/// rows.
+#[derive(Clone)]
+pub struct Text {
+	rope: Rope,
+}
+
+impl Text {
+	pub fn from_utf16(text: &[u16]) -> Result<Self> {
+		let text = String::from_utf16(text)
+			.map_err(|_| Error::InvalidRequest("Text isn't valid UTF-16".to_string()))?;
+		Ok(Text {
+			rope: Rope::from_str(&text),
+		})
+	}
+
+	pub fn len(&self) -> usize {
+		self.rope.len_utf16_cu()
+	}
+
+	/// Replaces `range` with `new_text`. Both ends of the range have to fall between characters,
+	/// not inside a surrogate pair.
+	pub fn replace(&mut self, range: Range<usize>, new_text: &[u16]) -> Result<()> {
+		let start = self.char_at(range.start)?;
+		let end = self.char_at(range.end)?;
+		let new_text = String::from_utf16(new_text)
+			.map_err(|_| Error::InvalidRequest("Edit text isn't valid UTF-16".to_string()))?;
+
+		self.rope.remove(start..end);
+		self.rope.insert(start, &new_text);
+		Ok(())
+	}
+
+	fn char_at(&self, offset: usize) -> Result<usize> {
+		if offset > self.len() {
+			return Err(
+				Error::InvalidRequest(format!("Offset {} is out of bounds", offset)).into(),
+			);
+		}
+		let char_idx = self.rope.utf16_cu_to_char(offset);
+		if self.rope.char_to_utf16_cu(char_idx) != offset {
+			return Err(Error::InvalidRequest(format!(
+				"Offset {} is inside a surrogate pair",
+				offset
+			))
+			.into());
+		}
+		Ok(char_idx)
+	}
+
+	/// The row and (byte) column of `offset`.
+	pub fn point_at(&self, offset: usize) -> Point {
+		let char_idx = self.rope.utf16_cu_to_char(offset);
+		let row = self.rope.char_to_line(char_idx);
+		let line_start = self.rope.char_to_utf16_cu(self.rope.line_to_char(row));
+
+		Point {
+			row,
+			column: (offset - line_start) * 2,
+		}
+	}
+
+	/// The UTF-8 offset of `offset` (in code units).
+	pub fn utf8_offset(&self, offset: usize) -> usize {
+		self.rope.char_to_byte(self.rope.utf16_cu_to_char(offset))
+	}
+
+	/// The offset of `column` (in code units) on `row`. Like LSP positions, rows past the end
+	/// mean the end of the text, and columns past the end of the row mean the end of the row.
+	pub fn offset_at(&self, row: usize, column: usize) -> usize {
+		if row >= self.rope.len_lines() {
+			return self.len();
+		}
+		let line_start = self.rope.char_to_utf16_cu(self.rope.line_to_char(row));
+		line_start + column.min(self.line_len(row))
+	}
+
+	/// How many lines there are, not counting the empty one after a trailing line break.
+	pub fn line_count(&self) -> usize {
+		let lines = self.rope.len_lines();
+		if lines > 1 && self.rope.line(lines - 1).len_chars() == 0 {
+			lines - 1
+		} else {
+			lines
+		}
+	}
+
+	/// The length of `row` in code units, not counting the line break (`\n` or `\r\n`).
+	pub fn line_len(&self, row: usize) -> usize {
+		let line = self.rope.line(row);
+		let chars = line.len_chars();
+		let mut len = line.len_utf16_cu();
+		if chars > 0 && line.char(chars - 1) == '\n' {
+			len -= 1;
+			if chars > 1 && line.char(chars - 2) == '\r' {
+				len -= 1;
+			}
+		}
+		len
+	}
+
+	/// Whether the text has `\r\n` line breaks and whether it has `\n` ones on their own.
+	pub fn line_breaks(&self) -> (bool, bool) {
+		let (mut crlf, mut lf) = (false, false);
+		let mut after_cr = false;
+		for chunk in self.rope.chunks() {
+			for &byte in chunk.as_bytes() {
+				if byte == b'\n' {
+					if after_cr {
+						crlf = true;
+					} else {
+						lf = true;
+					}
+				}
+				after_cr = byte == b'\r';
+			}
+		}
+		(crlf, lf)
+	}
+
+	/// The text with every `\r\n` turned into `\n`.
+	pub fn normalize_line_endings(&self) -> Text {
+		if !self.line_breaks().0 {
+			return self.clone();
+		}
+		Text {
+			rope: Rope::from_str(&self.rope.to_string().replace("\r\n", "\n")),
+		}
+	}
+
+	/// Copies out `range` as UTF-16.
+	pub fn slice(&self, range: Range<usize>) -> Vec<u16> {
+		let start = self.rope.utf16_cu_to_char(range.start);
+		let end = self.rope.utf16_cu_to_char(range.end);
+		let mut utf16 = Vec::with_capacity(range.len());
+		for chunk in self.rope.slice(start..end).chunks() {
+			utf16.extend(chunk.encode_utf16());
+		}
+		utf16
+	}
+
+	/// `range` as UTF-8, a rope chunk at a time, which is what query predicates match against.
+	pub fn chunks(&self, range: Range<usize>) -> impl Iterator<Item = &[u8]> + '_ {
+		let start = self.rope.utf16_cu_to_char(range.start);
+		let end = self.rope.utf16_cu_to_char(range.end);
+		self.rope.slice(start..end).chunks().map(str::as_bytes)
+	}
+
+	/// Every code unit, first to last.
+	pub fn units(&self) -> impl Iterator<Item = u16> + '_ {
+		self.rope.chunks().flat_map(str::encode_utf16)
+	}
+
+	/// Every code unit, last to first.
+	pub fn units_rev(&self) -> impl Iterator<Item = u16> + '_ {
+		self.rope
+			.chars_at(self.rope.len_chars())
+			.reversed()
+			.flat_map(|c| {
+				let mut buf = [0; 2];
+				let len = c.encode_utf16(&mut buf).len();
+				buf.into_iter().take(len).rev()
+			})
+	}
+
+	pub fn to_utf16(&self) -> Vec<u16> {
+		self.slice(0..self.len())
+	}
+
+	/// Hashes the text for `FileResponse.content_hash`. The hash is only meant to be compared
+	/// against others from the same server.
+	pub fn content_hash(&self) -> u64 {
+		self.hash_with(DefaultHasher::new())
+	}
+
+	/// Hashes the text the same way in every build of the server, for hashes kept on disk.
+	pub fn stable_hash(&self) -> u64 {
+		self.hash_with(Fnv::default())
+	}
+
+	fn hash_with(&self, mut hasher: impl Hasher) -> u64 {
+		for chunk in self.rope.chunks() {
+			hasher.write(chunk.as_bytes());
+		}
+		hasher.finish()
+	}
+
+	/// Parses the text a rope chunk at a time, so it never has to be copied into one buffer.
+	pub fn parse(&self, parser: &mut Parser, old_tree: Option<&Tree>) -> Option<Tree> {
+		parser.parse_utf16_with(
+			// the bindings already turn tree-sitter's byte offsets into code unit offsets here
+			&mut |offset, _| {
+				if offset >= self.len() {
+					return Vec::new();
+				}
+				let (chunk, _, chunk_char, _) =
+					self.rope.chunk_at_char(self.rope.utf16_cu_to_char(offset));
+				let chunk_start = self.rope.char_to_utf16_cu(chunk_char);
+				chunk
+					.encode_utf16()
+					.skip(offset - chunk_start)
+					.collect::<Vec<_>>()
+			},
+			old_tree,
+		)
+	}
+}
+
+impl From<&str> for Text {
+	fn from(text: &str) -> Self {
+		Text {
+			rope: Rope::from_str(text),
+		}
+	}
+}
+
+impl PartialEq for Text {
+	fn eq(&self, other: &Text) -> bool {
+		self.rope == other.rope
+	}
+}
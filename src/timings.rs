@@ -0,0 +1,178 @@
+use std::{
+	collections::{HashMap, VecDeque},
+	fmt,
+	sync::{Mutex, PoisonError},
+	time::{Duration, Instant},
+};
+
+use once_cell::sync::Lazy;
+use serde_json::{json, Map, Value};
+use tracing::{
+	field::{Field, Visit},
+	span, Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan};
+
+/// The phases requests are broken down into, by the spans they're timed from. A restamp is how
+/// cached responses are serialized.
+const PHASES: &[(&str, &str)] = &[
+	("read", "read"),
+	("decode", "decode"),
+	("parse", "parse"),
+	("serialize", "serialize"),
+	("restamp", "serialize"),
+];
+
+/// How many of the most recent requests of each type the percentiles are taken over.
+const WINDOW: usize = 1000;
+
+/// The most recent durations by phase (and `total`), in microseconds.
+type Samples = HashMap<&'static str, VecDeque<u64>>;
+
+/// Recent durations of each request type: how long the requests took in total, and (for the
+/// requests that went through them) how long they spent in each phase.
+static RECENT: Lazy<Mutex<HashMap<String, Samples>>> = Lazy::new(Default::default);
+
+/// Times the `request` spans and the phase spans within them.
+pub struct Layer;
+
+/// Kept in the extensions of the spans being timed.
+struct Started(Instant);
+
+/// Kept in the extensions of `request` spans, adding up the phases as they end.
+#[derive(Default)]
+struct Request {
+	request_type: Option<String>,
+	phases: Vec<(&'static str, Duration)>,
+}
+
+impl Visit for Request {
+	fn record_str(&mut self, field: &Field, value: &str) {
+		if field.name() == "request_type" {
+			self.request_type = Some(value.to_string());
+		}
+	}
+
+	fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+		if field.name() == "request_type" {
+			self.request_type = Some(format!("{:?}", value));
+		}
+	}
+}
+
+fn phase(span_name: &str) -> Option<&'static str> {
+	PHASES
+		.iter()
+		.find(|(name, _)| *name == span_name)
+		.map(|(_, phase)| *phase)
+}
+
+impl<S> tracing_subscriber::Layer<S> for Layer
+where
+	S: Subscriber + for<'a> LookupSpan<'a>,
+{
+	fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+		let span = match ctx.span(id) {
+			Some(span) => span,
+			None => return,
+		};
+		let name = span.name();
+		if name == "request" {
+			let mut request = Request::default();
+			attrs.record(&mut request);
+			span.extensions_mut().insert(request);
+		} else if phase(name).is_none() {
+			return;
+		}
+		span.extensions_mut().insert(Started(Instant::now()));
+	}
+
+	fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+		if let Some(span) = ctx.span(id) {
+			if let Some(request) = span.extensions_mut().get_mut::<Request>() {
+				values.record(request);
+			}
+		}
+	}
+
+	fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+		let span = match ctx.span(&id) {
+			Some(span) => span,
+			None => return,
+		};
+		let elapsed = match span.extensions().get::<Started>() {
+			Some(Started(start)) => start.elapsed(),
+			None => return,
+		};
+
+		if let Some(phase) = phase(span.name()) {
+			let mut ancestors = span.scope().skip(1);
+			// a phase within another is part of the outer one
+			let request = ancestors.find(|ancestor| {
+				ancestor.name() == "request" || self::phase(ancestor.name()).is_some()
+			});
+			if let Some(request) = request.filter(|request| request.name() == "request") {
+				if let Some(request) = request.extensions_mut().get_mut::<Request>() {
+					match request.phases.iter_mut().find(|(name, _)| *name == phase) {
+						Some((_, total)) => *total += elapsed,
+						None => request.phases.push((phase, elapsed)),
+					}
+				}
+			}
+			return;
+		}
+
+		let extensions = span.extensions();
+		let request = match extensions.get::<Request>() {
+			Some(request) => request,
+			None => return,
+		};
+		let request_type = match &request.request_type {
+			Some(request_type) => request_type.clone(),
+			// rejected before it was decoded
+			None => "unknown".to_string(),
+		};
+		let mut recent = RECENT.lock().unwrap_or_else(PoisonError::into_inner);
+		let recent = recent.entry(request_type).or_default();
+		let phases = std::iter::once(("total", elapsed)).chain(request.phases.iter().copied());
+		for (phase, duration) in phases {
+			let samples = recent.entry(phase).or_default();
+			if samples.len() == WINDOW {
+				samples.pop_front();
+			}
+			samples.push_back(duration.as_micros() as u64);
+		}
+	}
+}
+
+/// The percentiles of the recent durations, by request type and then phase.
+pub fn percentiles() -> Value {
+	let recent = RECENT.lock().unwrap_or_else(PoisonError::into_inner);
+	let request_types = recent
+		.iter()
+		.map(|(request_type, phases)| {
+			let phases = phases
+				.iter()
+				.map(|(phase, samples)| {
+					let mut sorted = samples.iter().copied().collect::<Vec<_>>();
+					sorted.sort_unstable();
+					let percentile = |p: usize| sorted[(sorted.len() * p).div_ceil(100).max(1) - 1];
+					(
+						phase.to_string(),
+						json!({
+							"count": sorted.len(),
+							"p50_us": percentile(50),
+							"p95_us": percentile(95),
+							"p99_us": percentile(99),
+						}),
+					)
+				})
+				.collect::<Map<_, _>>();
+			(request_type.clone(), Value::Object(phases))
+		})
+		.collect::<Map<_, _>>();
+	json!({
+		"window": WINDOW,
+		"request_types": request_types,
+	})
+}
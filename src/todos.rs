@@ -0,0 +1,168 @@
+use tree_sitter::{Node, Tree};
+
+use crate::{
+	document::Document,
+	message_generated::asted::interface::{
+		Location, Todo as FbTodo, TodoArgs, TodosResponse, TodosResponseArgs,
+	},
+	queries,
+	text::Text,
+};
+
+pub const DEFAULT_MARKERS: &[&str] = &["TODO", "FIXME", "HACK"];
+
+/// A marker found in a comment.
+pub struct Todo {
+	/// From the marker to the end of its line, as byte offsets into the UTF-16 text
+	pub start_byte: usize,
+	pub end_byte: usize,
+	pub row: usize,
+	pub marker: String,
+	/// From `TODO(author)`
+	pub author: Option<String>,
+	pub message: String,
+	/// The name of the innermost definition the comment is in
+	pub context: Option<String>,
+}
+
+/// Finds `markers` in the comments of `tree`. Markers only count as whole words, and at most once
+/// per line.
+pub fn scan(lang: &str, tree: &Tree, text: &Text, markers: &[String]) -> Vec<Todo> {
+	let mut comments = Vec::new();
+	let mut cursor = tree.walk();
+	'walk: loop {
+		let node = cursor.node();
+		// grammars name them comment, line_comment, block_comment, ...
+		if node.kind().contains("comment") {
+			comments.push(node);
+		} else if cursor.goto_first_child() {
+			continue;
+		}
+		while !cursor.goto_next_sibling() {
+			if !cursor.goto_parent() {
+				break 'walk;
+			}
+		}
+	}
+	if comments.is_empty() {
+		return Vec::new();
+	}
+
+	let definitions = queries::tags(lang, tree, text)
+		.into_iter()
+		.filter(|tag| tag.definition)
+		.collect::<Vec<_>>();
+	let context = |comment: Node| {
+		definitions
+			.iter()
+			.filter(|tag| {
+				tag.node.start_byte() <= comment.start_byte()
+					&& comment.end_byte() <= tag.node.end_byte()
+			})
+			.min_by_key(|tag| tag.node.end_byte() - tag.node.start_byte())
+			.map(|tag| tag.name_text(text))
+	};
+
+	let mut todos = Vec::new();
+	for comment in comments {
+		let comment_text =
+			String::from_utf16_lossy(&text.slice(comment.start_byte() / 2..comment.end_byte() / 2));
+		let mut offset = comment.start_byte() / 2;
+		for (row, line) in comment_text.split_inclusive('\n').enumerate() {
+			if let Some((marker_start, marker)) = find_marker(line, markers) {
+				let rest = &line[marker_start + marker.len()..];
+				let (author, rest) =
+					match rest.strip_prefix('(').and_then(|rest| rest.split_once(')')) {
+						Some((author, rest)) => (Some(author.trim().to_string()), rest),
+						None => (None, rest),
+					};
+				let message = rest
+					.trim_start_matches(|c: char| c == ':' || c == '-' || c.is_whitespace())
+					.trim_end()
+					.trim_end_matches("*/")
+					.trim_end();
+
+				let line_end = line.trim_end_matches(['\r', '\n']);
+				let start = offset + utf16_len(&line[..marker_start]);
+				todos.push(Todo {
+					start_byte: start * 2,
+					end_byte: (offset + utf16_len(line_end)) * 2,
+					row: comment.start_position().row + row,
+					marker: marker.to_string(),
+					author,
+					message: message.to_string(),
+					context: context(comment),
+				});
+			}
+			offset += utf16_len(line);
+		}
+	}
+	todos
+}
+
+/// The first of `markers` in `line` that isn't part of a longer word.
+fn find_marker<'m>(line: &str, markers: &'m [String]) -> Option<(usize, &'m str)> {
+	let is_word = |c: char| c.is_alphanumeric() || c == '_';
+	markers
+		.iter()
+		.filter_map(|marker| {
+			line.match_indices(marker.as_str())
+				.find(|(start, _)| {
+					let before = line[..*start].chars().next_back();
+					let after = line[start + marker.len()..].chars().next();
+					!before.is_some_and(is_word) && !after.is_some_and(is_word)
+				})
+				.map(|(start, _)| (start, marker.as_str()))
+		})
+		.min_by_key(|(start, _)| *start)
+}
+
+fn utf16_len(s: &str) -> usize {
+	s.encode_utf16().count()
+}
+
+/// Scans `document` and returns a serialized `TodosResponse`.
+pub fn todos(document: &Document, markers: &[String]) -> Vec<u8> {
+	let _span = tracing::info_span!("todos").entered();
+	let todos = scan(&document.language, &document.tree, &document.text, markers);
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let todos = todos
+		.iter()
+		.map(|todo| {
+			let marker = builder.create_string(&todo.marker);
+			let author = todo
+				.author
+				.as_deref()
+				.map(|author| builder.create_string(author));
+			let message = builder.create_string(&todo.message);
+			let context = todo
+				.context
+				.as_deref()
+				.map(|context| builder.create_string(context));
+			FbTodo::create(
+				&mut builder,
+				&TodoArgs {
+					location: Some(&Location::new(todo.start_byte as u32, todo.end_byte as u32)),
+					row: todo.row as u32,
+					marker: Some(marker),
+					author,
+					message: Some(message),
+					context,
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let todos = builder.create_vector(&todos);
+	let resp = TodosResponse::create(
+		&mut builder,
+		&TodosResponseArgs {
+			todos: Some(todos),
+			version: document.version,
+			content_hash: document.text.content_hash(),
+		},
+	);
+	builder.finish(resp, None);
+
+	builder.finished_data().to_vec()
+}
@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::{
+	error::Error,
+	message_generated::asted::interface::{
+		EditedDocument, EditedDocumentArgs, TransactionRequest, TransactionResponse,
+		TransactionResponseArgs,
+	},
+	replay, text_edits,
+	uri::{self, DocumentKey},
+	State,
+};
+
+/// Applies the edits in `req` to their documents and reparses them, and returns a serialized
+/// `TransactionResponse`. If anything goes wrong, every document is left as it was.
+pub fn transaction(state: &mut State, req: TransactionRequest) -> Result<Vec<u8>> {
+	let _span = tracing::info_span!("transaction", documents = req.documents().len()).entered();
+	let mut keys = HashSet::<DocumentKey>::new();
+	let mut parsed = Vec::new();
+	// every document is edited and reparsed before any of them is changed
+	for document in req.documents() {
+		let key = uri::key(document.path())?;
+		if !keys.insert(key.clone()) {
+			return Err(Error::InvalidRequest(format!(
+				"{} is in the transaction more than once",
+				key
+			))
+			.into());
+		}
+		let base_version = document.base_version();
+		let edits = text_edits(document.edits());
+		let logged = replay::enabled().then(|| edits.clone());
+		let (text, tree, mtime) = state.edited(&key, base_version, document.if_hash(), edits)?;
+		let (text, tree, parse_info) = state.parse_document(
+			&key,
+			text,
+			mtime,
+			Some((Some(tree), base_version + 1)),
+			false,
+		)?;
+		parsed.push((key, base_version, logged, text, tree, parse_info));
+	}
+
+	let mut edited = Vec::new();
+	for (key, base_version, logged, text, tree, parse_info) in parsed {
+		let (document, parse_info) = state.insert(&key, text, tree, parse_info)?;
+		if let Some(edits) = &logged {
+			let input = replay::Input::Edits {
+				base_version,
+				edits,
+			};
+			replay::record(&key, document, &parse_info, input);
+		}
+		edited.push((key.uri(), parse_info.version, parse_info.content_hash));
+	}
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let documents = edited
+		.iter()
+		.map(|(uri, version, content_hash)| {
+			let path = builder.create_string(uri);
+			EditedDocument::create(
+				&mut builder,
+				&EditedDocumentArgs {
+					path: Some(path),
+					version: *version,
+					content_hash: *content_hash,
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let documents = builder.create_vector(&documents);
+	let resp = TransactionResponse::create(
+		&mut builder,
+		&TransactionResponseArgs {
+			documents: Some(documents),
+		},
+	);
+	builder.finish(resp, None);
+
+	Ok(builder.finished_data().to_vec())
+}
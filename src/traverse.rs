@@ -0,0 +1,219 @@
+use anyhow::Result;
+use tree_sitter::{Node, TreeCursor};
+
+use crate::{
+	document::Document,
+	error::Error,
+	message_generated::asted::interface::{
+		Location, Point, TraversalOrder, TraverseRequest, TraverseResponse, TraverseResponseArgs,
+		TraversedNode, TraversedNodeArgs,
+	},
+};
+
+/// A tree cursor that keeps track of the child indices leading to its node, which is what
+/// pagination cursors point at nodes by.
+struct Walk<'tree> {
+	cursor: TreeCursor<'tree>,
+	path: Vec<usize>,
+}
+
+impl<'tree> Walk<'tree> {
+	fn first_child(&mut self) -> bool {
+		let moved = self.cursor.goto_first_child();
+		if moved {
+			self.path.push(0);
+		}
+		moved
+	}
+
+	fn next_sibling(&mut self) -> bool {
+		let moved = self.cursor.goto_next_sibling();
+		if moved {
+			*self.path.last_mut().unwrap() += 1;
+		}
+		moved
+	}
+
+	fn parent(&mut self) -> bool {
+		let moved = self.cursor.goto_parent();
+		if moved {
+			self.path.pop();
+		}
+		moved
+	}
+
+	/// Moves to the first node in `order`.
+	fn start(&mut self, order: TraversalOrder) {
+		if order == TraversalOrder::Post {
+			while self.first_child() {}
+		}
+	}
+
+	/// Moves to the node after this one in `order`, returning whether there is one.
+	fn advance(&mut self, order: TraversalOrder) -> bool {
+		match order {
+			TraversalOrder::Post => {
+				if self.next_sibling() {
+					while self.first_child() {}
+					return true;
+				}
+				self.parent()
+			}
+			_ => {
+				if self.first_child() {
+					return true;
+				}
+				loop {
+					if self.next_sibling() {
+						return true;
+					}
+					if !self.parent() {
+						return false;
+					}
+				}
+			}
+		}
+	}
+
+	/// Moves to the node at `path`, returning whether there is one.
+	fn seek(&mut self, path: &[usize]) -> bool {
+		for &index in path {
+			if !self.first_child() {
+				return false;
+			}
+			for _ in 0..index {
+				if !self.next_sibling() {
+					return false;
+				}
+			}
+		}
+		true
+	}
+}
+
+/// Walks `document`'s tree in the order `req` asks for, from where its cursor left off, and
+/// returns a serialized `TraverseResponse` of the next page of nodes.
+pub fn traverse(document: &Document, req: TraverseRequest) -> Result<Vec<u8>> {
+	let _span = tracing::info_span!("traverse", order = ?req.order()).entered();
+	let order = req.order();
+	let content_hash = document.text.content_hash();
+	let kinds = req.kinds().into_iter().flatten().collect::<Vec<_>>();
+	let wanted = |node: Node| {
+		(node.is_named() || !req.named_only()) && (kinds.is_empty() || kinds.contains(&node.kind()))
+	};
+
+	let mut walk = Walk {
+		cursor: document.tree.walk(),
+		path: Vec::new(),
+	};
+	let mut more = match req.cursor() {
+		Some(cursor) => {
+			let path = resume(cursor, document.version, content_hash, order)?;
+			if !walk.seek(&path) {
+				return Err(Error::InvalidRequest(format!("Invalid cursor {:?}", cursor)).into());
+			}
+			walk.advance(order)
+		}
+		None => {
+			walk.start(order);
+			true
+		}
+	};
+
+	let mut nodes = Vec::new();
+	let mut last_path = Vec::new();
+	while more && nodes.len() < req.limit().max(1) as usize {
+		let node = walk.cursor.node();
+		if wanted(node) {
+			nodes.push((node, walk.path.len()));
+			last_path.clone_from(&walk.path);
+		}
+		more = walk.advance(order);
+	}
+
+	let mut builder = flatbuffers::FlatBufferBuilder::new();
+	let nodes = nodes
+		.iter()
+		.map(|(node, depth)| {
+			let kind = builder.create_string(node.kind());
+			let (start_point, end_point) = (node.start_position(), node.end_position());
+			TraversedNode::create(
+				&mut builder,
+				&TraversedNodeArgs {
+					kind: Some(kind),
+					named: node.is_named(),
+					depth: *depth as u32,
+					location: Some(&Location::new(
+						node.start_byte() as u32,
+						node.end_byte() as u32,
+					)),
+					start_point: Some(&Point::new(
+						start_point.row as u32,
+						start_point.column as u32,
+					)),
+					end_point: Some(&Point::new(end_point.row as u32, end_point.column as u32)),
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let nodes = builder.create_vector(&nodes);
+	let cursor = more.then(|| {
+		let path = last_path
+			.iter()
+			.map(|index| index.to_string())
+			.collect::<Vec<_>>()
+			.join(".");
+		builder.create_string(&format!(
+			"{}:{:x}:{}:{}",
+			document.version, content_hash, order.0, path
+		))
+	});
+	let resp = TraverseResponse::create(
+		&mut builder,
+		&TraverseResponseArgs {
+			nodes: Some(nodes),
+			cursor,
+			version: document.version,
+			content_hash,
+		},
+	);
+	builder.finish(resp, None);
+
+	Ok(builder.finished_data().to_vec())
+}
+
+/// The path of the node `cursor` left off at, if it's from a walk of this version of the document
+/// in `order`.
+fn resume(
+	cursor: &str,
+	version: u32,
+	content_hash: u64,
+	order: TraversalOrder,
+) -> Result<Vec<usize>> {
+	let invalid = || Error::InvalidRequest(format!("Invalid cursor {:?}", cursor));
+	let parts = cursor.splitn(4, ':').collect::<Vec<_>>();
+	let (cursor_version, cursor_hash, cursor_order, path) = match parts[..] {
+		[version, hash, order, path] => (version, hash, order, path),
+		_ => return Err(invalid().into()),
+	};
+	if cursor_version != version.to_string() || cursor_hash != format!("{:x}", content_hash) {
+		return Err(Error::Conflict(format!(
+			"The document changed since the cursor was handed out; it is at version {}",
+			version
+		))
+		.into());
+	}
+	if cursor_order != order.0.to_string() {
+		return Err(Error::InvalidRequest(
+			"The cursor is from a walk in another order".to_string(),
+		)
+		.into());
+	}
+	match path {
+		"" => Ok(Vec::new()),
+		path => path
+			.split('.')
+			.map(|index| index.parse().map_err(|_| invalid().into()))
+			.collect(),
+	}
+}
@@ -0,0 +1,132 @@
+use std::{
+	collections::{HashMap, VecDeque},
+	sync::{Arc, Mutex, PoisonError},
+};
+
+use axum::body::Bytes;
+use once_cell::sync::Lazy;
+use tree_sitter::{Language, Tree};
+
+use crate::{
+	text::Text,
+	tree_serialize::{self, Options, ParseInfo},
+};
+
+/// How many distinct texts keep their trees (and serialized responses) cached.
+const MAX_ENTRIES: usize = 64;
+
+/// A text's tree, shared by every document (in any session) with that text and language.
+/// Documents hold their own copies of the text and tree, which share their contents with these
+/// until they're edited.
+struct Entry {
+	text: Text,
+	tree: Tree,
+	/// Responses serialized from `tree`, to be restamped for each document they're sent for
+	serialized: Mutex<HashMap<Options, Bytes>>,
+}
+
+#[derive(Default)]
+struct TreeCache {
+	entries: HashMap<(Language, u64), Arc<Entry>>,
+	/// Least recently used first
+	order: VecDeque<(Language, u64)>,
+}
+
+impl TreeCache {
+	fn get(&mut self, language: Language, text: &Text) -> Option<Arc<Entry>> {
+		let key = (language, text.content_hash());
+		let entry = self.entries.get(&key).filter(|entry| &entry.text == text)?;
+		self.order.retain(|k| k != &key);
+		self.order.push_back(key);
+		Some(entry.clone())
+	}
+}
+
+static CACHE: Lazy<Mutex<TreeCache>> = Lazy::new(Default::default);
+
+fn cache() -> std::sync::MutexGuard<'static, TreeCache> {
+	// the cache is only ever left half-updated by a panic between two infallible steps
+	CACHE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A copy of the cached tree for `text` in `language`, if it has been parsed before.
+pub fn get(language: Language, text: &Text) -> Option<Tree> {
+	cache().get(language, text).map(|entry| entry.tree.clone())
+}
+
+/// Caches `tree` as the tree for `text` (replacing any tree cached for it before), evicting the
+/// least recently used entry if the cache is full.
+pub fn insert(text: &Text, tree: &Tree) {
+	let mut cache = cache();
+	let key = (tree.language(), text.content_hash());
+	if cache.entries.remove(&key).is_some() {
+		cache.order.retain(|k| k != &key);
+	} else if cache.entries.len() >= MAX_ENTRIES {
+		if let Some(oldest) = cache.order.pop_front() {
+			cache.entries.remove(&oldest);
+		}
+	}
+	cache.entries.insert(
+		key,
+		Arc::new(Entry {
+			text: text.clone(),
+			tree: tree.clone(),
+			serialized: Mutex::new(HashMap::new()),
+		}),
+	);
+	cache.order.push_back(key);
+}
+
+/// Drops every cached tree, e.g. because grammars were reloaded and the old ones may be unloaded.
+pub fn clear() {
+	let mut cache = cache();
+	cache.entries.clear();
+	cache.order.clear();
+}
+
+/// Drops every cached serialized response, keeping the trees, e.g. because the kind aliases they
+/// were serialized with changed.
+pub fn forget_serialized() {
+	for entry in cache().entries.values() {
+		entry
+			.serialized
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.clear();
+	}
+}
+
+/// Serializes `tree`, or copies the response already serialized for another document with the
+/// same text and tree and stamps it with `parse_info`. Responses with changed ranges are specific
+/// to the edit, so they're always serialized from scratch and not kept.
+pub fn serialize(text: &Text, tree: &Tree, parse_info: &ParseInfo, options: Options) -> Bytes {
+	let entry = match cache().get(tree.language(), text) {
+		Some(entry) if parse_info.changed_ranges.is_empty() => entry,
+		_ => return tree_serialize::serialize(text, tree, parse_info, options),
+	};
+
+	// the cache only holds on to the serialized responses, not the lock, while serializing
+	let cached = entry
+		.serialized
+		.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+		.get(&options)
+		.cloned();
+	match cached {
+		Some(cached) => {
+			let _span = tracing::info_span!("restamp").entered();
+			let mut buf = cached.to_vec();
+			tree_serialize::restamp(&mut buf, parse_info);
+			Bytes::from(buf)
+		}
+		None => {
+			let res = tree_serialize::serialize(text, tree, parse_info, options);
+			entry
+				.serialized
+				.lock()
+				.unwrap_or_else(PoisonError::into_inner)
+				.insert(options, res.clone());
+			res
+		}
+	}
+}
@@ -1,45 +1,537 @@
-use crate::message_generated::asted::interface::{FileResponse, FileResponseArgs};
+use std::{
+	cell::RefCell,
+	collections::HashMap,
+	ops::Range,
+	sync::Arc,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use axum::body::Bytes;
+
+use crate::{
+	deterministic,
+	fingerprint::{self, Fingerprint},
+	fnv, languages,
+	message_generated::asted::interface::{
+		FileResponse, FileResponseArgs, LineEnding, Offsets, ParseStats, ParseStatsArgs, Point,
+		SerializationProfile, TextInclusion,
+	},
+	node_types::{self, Supertypes},
+	text::Text,
+};
 
 use super::message_generated::asted::interface::{Location, Node, NodeArgs};
 use flatbuffers::{self, WIPOffset};
-use tree_sitter;
 
-pub fn serialize(text: &[u16], tree: &tree_sitter::Tree) -> Vec<u8> {
+/// How the tree being serialized came to be, reported back in the response's stats.
+pub struct ParseInfo {
+	pub duration: Duration,
+	pub reused_old_tree: bool,
+	/// Whether the tree came from `tree_cache` without parsing
+	pub cached_tree: bool,
+	/// Where the tree differs from the reused old tree, as byte offsets into the UTF-16 text
+	pub changed_ranges: Vec<Range<usize>>,
+	/// The document's version, or 0 if it isn't cached
+	pub version: u32,
+	pub content_hash: u64,
+	pub mtime: Option<SystemTime>,
+}
+
+impl ParseInfo {
+	/// How the tree came to be, for logs: `full`, `incremental` (reusing the old tree) or `shared`
+	/// (from `tree_cache`).
+	pub fn outcome(&self) -> &'static str {
+		if self.cached_tree {
+			"shared"
+		} else if self.reused_old_tree {
+			"incremental"
+		} else {
+			"full"
+		}
+	}
+}
+
+/// Which nodes of a serialized tree carry their text.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextPolicy {
+	None,
+	Leaves,
+	/// Nodes spanning fewer bytes than this
+	Shorter(u32),
+	All,
+}
+
+impl TextPolicy {
+	/// The policy a request asks for with `inclusion`, if it doesn't leave it to the profile.
+	pub fn from_request(inclusion: TextInclusion, max_bytes: u32) -> Option<Self> {
+		match inclusion {
+			TextInclusion::None => Some(TextPolicy::None),
+			TextInclusion::Leaves => Some(TextPolicy::Leaves),
+			TextInclusion::Short => Some(TextPolicy::Shorter(max_bytes)),
+			TextInclusion::All => Some(TextPolicy::All),
+			_ => None,
+		}
+	}
+}
+
+/// What goes into a serialized tree beyond its nodes' kinds and locations.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Options {
+	/// Set `Node.hash` to the node's structural fingerprint
+	pub structural_hashes: bool,
+	/// Which nodes' text to set
+	pub text: TextPolicy,
+	/// Leave out anonymous nodes, putting their named descendants in their place
+	pub named_only: bool,
+	/// Leave out the children of nodes this deep, with the root at depth 0
+	pub max_depth: Option<u32>,
+	/// Set `Node.field`
+	pub fields: bool,
+	/// Set `Node.start_point` and `Node.end_point`
+	pub points: bool,
+	/// Set `Node.offsets`
+	pub dual_offsets: bool,
+	/// Set `Node.supertypes`
+	pub supertypes: bool,
+}
+
+impl Options {
+	pub fn new(profile: SerializationProfile, structural_hashes: bool) -> Self {
+		let options = Options {
+			structural_hashes,
+			text: TextPolicy::Leaves,
+			named_only: false,
+			max_depth: None,
+			fields: false,
+			points: false,
+			dual_offsets: false,
+			supertypes: false,
+		};
+		match profile {
+			SerializationProfile::Full => Options {
+				fields: true,
+				points: true,
+				supertypes: true,
+				..options
+			},
+			SerializationProfile::StructureOnly => Options {
+				text: TextPolicy::None,
+				named_only: true,
+				fields: true,
+				supertypes: true,
+				..options
+			},
+			SerializationProfile::Outline => Options {
+				text: TextPolicy::None,
+				named_only: true,
+				max_depth: Some(4),
+				fields: true,
+				points: true,
+				..options
+			},
+			SerializationProfile::Ranges => Options {
+				text: TextPolicy::None,
+				..options
+			},
+			_ => options,
+		}
+	}
+}
+
+impl Default for Options {
+	fn default() -> Self {
+		Options::new(SerializationProfile::Default, false)
+	}
+}
+
+#[derive(Default)]
+pub struct Counts {
+	pub nodes: u32,
+	pub errors: u32,
+}
+
+pub fn serialize(
+	text: &Text,
+	tree: &tree_sitter::Tree,
+	parse_info: &ParseInfo,
+	options: Options,
+) -> Bytes {
+	let _span = tracing::info_span!("serialize").entered();
 	let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(1024);
+	let mut counts = Counts::default();
+	let fingerprints = options
+		.structural_hashes
+		.then(|| fingerprint::fingerprints(tree, text, Default::default()));
+
+	let offsets_in = options.dual_offsets.then_some(text);
+	let line_ending = line_ending(text);
+	// leaves carry their text, so the whole of it ends up in the response anyway; copying it out of
+	// the rope once is much cheaper than slicing the rope for every leaf
+	let text = match options.text {
+		TextPolicy::None => Vec::new(),
+		_ => text.to_utf16(),
+	};
+	let context = Context {
+		text: &text,
+		fingerprints: fingerprints.as_ref(),
+		offsets_in,
+		part: Part::whole(),
+		options,
+		kind_aliases: languages::kind_aliases(tree.language()),
+		supertypes: options
+			.supertypes
+			.then(|| node_types::supertypes(tree.language())),
+		supertype_vectors: Default::default(),
+	};
 
 	// TODO(sauyon): probably convert this into an iterative DFS instead of recursing
-	let root_node = build_node(text, &mut builder, tree.root_node());
+	let root_node = build_node(
+		&context,
+		&mut builder,
+		tree.root_node(),
+		None,
+		0,
+		&mut counts,
+	);
+
+	finish(builder, root_node, &counts, parse_info, line_ending)
+}
+
+/// Serializes the part of `tree` covering `range` of `text` (as byte offsets), with the nodes'
+/// locations, points and offsets relative to its start. The root is the smallest node spanning the
+/// part if that node lies within it, or else a `snippet` node holding the children of that node
+/// which overlap the part. Whitespace at the part's edges is ignored in finding the node, and nodes
+/// straddling its edges (only ever around syntax errors) are cut to it.
+pub fn serialize_part(
+	text: &Text,
+	tree: &tree_sitter::Tree,
+	range: Range<usize>,
+	parse_info: &ParseInfo,
+	line_ending: LineEnding,
+	options: Options,
+) -> Bytes {
+	let _span = tracing::info_span!("serialize").entered();
+	let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(1024);
+	let mut counts = Counts::default();
+	let fingerprints = options
+		.structural_hashes
+		.then(|| fingerprint::fingerprints(tree, text, Default::default()));
+
+	let part = Part {
+		start: text.point_at(range.start / 2),
+		end: text.point_at(range.end / 2),
+		range: range.clone(),
+	};
+	let utf16 = text.to_utf16();
+	let is_whitespace = |c: u16| char::from_u32(c as u32).is_some_and(char::is_whitespace);
+	let units = &utf16[range.start / 2..range.end / 2];
+	let leading = units.iter().take_while(|&&c| is_whitespace(c)).count();
+	let trailing = units[leading..]
+		.iter()
+		.rev()
+		.take_while(|&&c| is_whitespace(c))
+		.count();
+	let trimmed = range.start + leading * 2..range.end - trailing * 2;
+	let context = Context {
+		text: &utf16,
+		fingerprints: fingerprints.as_ref(),
+		offsets_in: options.dual_offsets.then_some(text),
+		part,
+		options,
+		kind_aliases: languages::kind_aliases(tree.language()),
+		supertypes: options
+			.supertypes
+			.then(|| node_types::supertypes(tree.language())),
+		supertype_vectors: Default::default(),
+	};
+
+	let spanning = tree
+		.root_node()
+		.descendant_for_byte_range(trimmed.start, trimmed.end)
+		.filter(|_| !trimmed.is_empty());
+	let root_node = match spanning {
+		Some(node) if range.start <= node.start_byte() && node.end_byte() <= range.end => {
+			build_node(&context, &mut builder, node, None, 0, &mut counts)
+		}
+		_ => {
+			let mut children = Vec::new();
+			if let Some(node) = spanning {
+				let mut cursor = node.walk();
+				for child in node.children(&mut cursor) {
+					if child.end_byte() > trimmed.start && child.start_byte() < trimmed.end {
+						children.push(child);
+					}
+				}
+			}
+			build_snippet(&context, &mut builder, &children, &mut counts)
+		}
+	};
+
+	finish(builder, root_node, &counts, parse_info, line_ending)
+}
+
+pub fn line_ending(text: &Text) -> LineEnding {
+	match text.line_breaks() {
+		(true, true) => LineEnding::Mixed,
+		(true, false) => LineEnding::Crlf,
+		(false, _) => LineEnding::Lf,
+	}
+}
+
+/// Adds the stats to a built tree and finishes the `FileResponse`.
+pub fn finish<'a>(
+	mut builder: flatbuffers::FlatBufferBuilder<'a>,
+	root_node: WIPOffset<Node<'a>>,
+	counts: &Counts,
+	parse_info: &ParseInfo,
+	line_ending: LineEnding,
+) -> Bytes {
+	let serialized_size = builder.unfinished_data().len() as u32;
+	// flatbuffers are built back to front, so the tree ends up at the end of the finished buffer,
+	// past anything `restamp` overwrites
+	let checksum = fnv::hash(builder.unfinished_data());
+
+	let changed_ranges = parse_info
+		.changed_ranges
+		.iter()
+		.map(|range| Location::new(range.start as u32, range.end as u32))
+		.collect::<Vec<_>>();
+	let changed_ranges = builder.create_vector(&changed_ranges);
+	// `restamp` can only overwrite fields that are in the buffer
+	builder.force_defaults(true);
+	let stats = ParseStats::create(
+		&mut builder,
+		&ParseStatsArgs {
+			parse_duration_us: parse_duration_us(parse_info),
+			reused_old_tree: parse_info.reused_old_tree,
+			cached_tree: parse_info.cached_tree,
+			changed_ranges: Some(changed_ranges),
+			node_count: counts.nodes,
+			error_count: counts.errors,
+			serialized_size,
+		},
+	);
 	let file_resp = FileResponse::create(
 		&mut builder,
 		&FileResponseArgs {
 			tree: Some(root_node),
+			stats: Some(stats),
+			version: parse_info.version,
+			content_hash: parse_info.content_hash,
+			mtime_ms: mtime_ms(parse_info),
+			line_ending,
+			checksum,
 		},
 	);
 
 	builder.finish(file_resp, None);
-	// TODO(sauyon): to_vec is a copy, need to bubble up the builder to the actual handler function
-	//               since the builder doesn't have any functions to take ownership of the buffer
-	builder.finished_data().to_vec()
+	// flatbuffers are built back to front, so the finished data sits at the end of the builder's
+	// buffer; hand that buffer over as-is instead of copying what could be a huge tree
+	let (buf, head) = builder.collapse();
+	Bytes::from(buf).slice(head..)
+}
+
+/// Overwrites the fields of a `FileResponse` built by `finish` that describe the document rather
+/// than its tree, so a response can be reused for another document with the same tree. Changed
+/// ranges can't be overwritten, so the response must have been built (and `parse_info` be) without
+/// any.
+pub fn restamp(buf: &mut [u8], parse_info: &ParseInfo) {
+	// where each field sits in the buffer
+	let (resp, stats) = {
+		// Safety: like in `Encoding::file_response`, the buffer was built by `finish`
+		let resp = unsafe { flatbuffers::root_unchecked::<FileResponse>(buf) };
+		let field = |table: &flatbuffers::Table, field| match table.vtable().get(field) {
+			0 => None,
+			offset => Some(table.loc() + offset as usize),
+		};
+		let stats = resp.stats().unwrap()._tab;
+		(
+			[FileResponse::VT_VERSION, FileResponse::VT_MTIME_MS].map(|f| field(&resp._tab, f)),
+			[
+				ParseStats::VT_PARSE_DURATION_US,
+				ParseStats::VT_REUSED_OLD_TREE,
+				ParseStats::VT_CACHED_TREE,
+			]
+			.map(|f| field(&stats, f)),
+		)
+	};
+	let mut write = |at: Option<usize>, bytes: &[u8]| {
+		if let Some(at) = at {
+			buf[at..at + bytes.len()].copy_from_slice(bytes);
+		}
+	};
+
+	write(resp[0], &parse_info.version.to_le_bytes());
+	write(resp[1], &mtime_ms(parse_info).to_le_bytes());
+	write(stats[0], &parse_duration_us(parse_info).to_le_bytes());
+	write(stats[1], &[parse_info.reused_old_tree as u8]);
+	write(stats[2], &[parse_info.cached_tree as u8]);
+}
+
+fn parse_duration_us(parse_info: &ParseInfo) -> u64 {
+	match deterministic::enabled() {
+		true => 0,
+		false => parse_info.duration.as_micros() as u64,
+	}
+}
+
+fn mtime_ms(parse_info: &ParseInfo) -> u64 {
+	if deterministic::enabled() {
+		return 0;
+	}
+	parse_info
+		.mtime
+		.and_then(|mtime| mtime.duration_since(UNIX_EPOCH).ok())
+		.map_or(0, |mtime| mtime.as_millis() as u64)
+}
+
+/// What every node is serialized with.
+struct Context<'t> {
+	text: &'t [u16],
+	fingerprints: Option<&'t HashMap<usize, Fingerprint>>,
+	/// The document's text, for `dual_offsets`
+	offsets_in: Option<&'t Text>,
+	part: Part,
+	options: Options,
+	/// The configured kind aliases of the tree's language
+	kind_aliases: HashMap<String, String>,
+	supertypes: Option<Arc<Supertypes>>,
+	/// The offsets of the `Node.supertypes` built so far, by kind ID, which nodes of the same kind
+	/// share rather than each repeating the list
+	supertype_vectors: RefCell<HashMap<u16, u32>>,
+}
+
+/// The part of the text being serialized, which the nodes' locations, points and offsets are
+/// relative to (and cut to).
+struct Part {
+	/// As byte offsets
+	range: Range<usize>,
+	start: tree_sitter::Point,
+	end: tree_sitter::Point,
+}
+
+impl Part {
+	fn whole() -> Self {
+		Part {
+			range: 0..usize::MAX,
+			start: tree_sitter::Point::default(),
+			end: tree_sitter::Point::new(usize::MAX, usize::MAX),
+		}
+	}
+
+	/// How far into the part the position at `byte` and `point` is.
+	fn position(&self, byte: usize, point: tree_sitter::Point) -> (usize, tree_sitter::Point) {
+		let (byte, point) = match byte {
+			_ if byte <= self.range.start => (self.range.start, self.start),
+			_ if byte >= self.range.end => (self.range.end, self.end),
+			_ => (byte, point),
+		};
+		let column = match point.row == self.start.row {
+			true => point.column - self.start.column,
+			false => point.column,
+		};
+		(
+			byte - self.range.start,
+			tree_sitter::Point::new(point.row - self.start.row, column),
+		)
+	}
+}
+
+/// The `Offsets` into `text` of the part of it from `start` to `end`, relative to `part`.
+fn offsets(
+	start: (usize, tree_sitter::Point),
+	end: (usize, tree_sitter::Point),
+	part: &Part,
+	text: &Text,
+) -> Offsets {
+	// byte offsets from a UTF-16 parse are twice the code unit offsets
+	let origin = part.range.start / 2;
+	let (start, start_column) = (origin + start.0 / 2, start.1.column / 2);
+	let (end, end_column) = (origin + end.0 / 2, end.1.column / 2);
+	let utf8_origin = text.utf8_offset(origin);
+	let utf8_column = |offset: usize, column: usize| {
+		(text.utf8_offset(offset) - text.utf8_offset(offset - column)) as u32
+	};
+	Offsets::new(
+		(text.utf8_offset(start) - utf8_origin) as u32,
+		(text.utf8_offset(end) - utf8_origin) as u32,
+		(start - origin) as u32,
+		(end - origin) as u32,
+		utf8_column(start, start_column),
+		utf8_column(end, end_column),
+		start_column as u32,
+		end_column as u32,
+	)
 }
 
 fn build_node<'a>(
-	text: &[u16],
+	context: &Context,
 	builder: &mut flatbuffers::FlatBufferBuilder<'a>,
 	node: tree_sitter::Node<'a>,
+	field: Option<&str>,
+	depth: u32,
+	counts: &mut Counts,
 ) -> WIPOffset<Node<'a>> {
-	let kind = builder.create_string(node.kind());
-	let location = Location::new(node.start_byte() as u32, node.end_byte() as u32);
-	let child_vec = node
-		.children(&mut node.walk())
-		.map(|child| build_node(text, builder, child))
-		.collect::<Vec<_>>();
+	let options = context.options;
+	counts.nodes += 1;
+	if node.is_error() || node.is_missing() {
+		counts.errors += 1;
+	}
+
+	let kind = node.kind();
+	let kind = builder.create_string(context.kind_aliases.get(kind).map_or(kind, String::as_str));
+	let start = context
+		.part
+		.position(node.start_byte(), node.start_position());
+	let end = context.part.position(node.end_byte(), node.end_position());
+	let location = Location::new(start.0 as u32, end.0 as u32);
+	let mut child_vec = Vec::new();
+	let cut = options
+		.max_depth
+		.is_some_and(|max_depth| depth >= max_depth);
+	let mut stub_counts = None;
+	if cut {
+		// the stats still describe the whole tree's errors
+		if node.has_error() {
+			counts.errors += count_errors(node);
+		}
+		stub_counts = Some((
+			child_count(node, options.named_only),
+			named_descendant_count(node),
+		));
+	} else {
+		build_children(context, builder, node, depth, counts, &mut child_vec);
+	}
 	let children = builder.create_vector(&child_vec);
 
-	let text = if child_vec.len() == 0 {
-		Some(builder.create_vector(text))
+	let with_text = match options.text {
+		TextPolicy::None => false,
+		TextPolicy::Leaves => child_vec.is_empty() && !cut,
+		TextPolicy::Shorter(max_bytes) => end.0 - start.0 < max_bytes as usize,
+		TextPolicy::All => true,
+	};
+	let text = if with_text {
+		// byte offsets from a UTF-16 parse are twice the code unit offsets
+		let origin = context.part.range.start / 2;
+		Some(builder.create_vector(&context.text[origin + start.0 / 2..origin + end.0 / 2]))
 	} else {
 		None
 	};
+	let field = field
+		.filter(|_| options.fields)
+		.map(|field| builder.create_string(field));
+	let point = |point: tree_sitter::Point| Point::new(point.row as u32, point.column as u32);
+	let (start_point, end_point) = match options.points {
+		true => (Some(point(start.1)), Some(point(end.1))),
+		false => (None, None),
+	};
+	let offsets = context
+		.offsets_in
+		.map(|text| offsets(start, end, &context.part, text));
+	let supertypes = context
+		.supertypes
+		.as_ref()
+		.and_then(|supertypes| build_supertypes(context, builder, node, supertypes));
 
 	Node::create(
 		builder,
@@ -48,7 +540,197 @@ fn build_node<'a>(
 			location: Some(&location),
 			children: Some(children),
 			named: node.is_named(),
-			text: text,
+			text,
+			hash: context
+				.fingerprints
+				.and_then(|fingerprints| fingerprints.get(&node.id()))
+				.map(|fingerprint| fingerprint.hash),
+			field,
+			start_point: start_point.as_ref(),
+			end_point: end_point.as_ref(),
+			offsets: offsets.as_ref(),
+			child_count: stub_counts.map(|(children, _)| children),
+			named_descendant_count: stub_counts.map(|(_, descendants)| descendants),
+			supertypes,
 		},
 	)
 }
+
+/// Builds the list of `node`'s supertypes, or finds the one built for another node of its kind.
+fn build_supertypes<'a>(
+	context: &Context,
+	builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+	node: tree_sitter::Node,
+	supertypes: &Supertypes,
+) -> Option<WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>> {
+	let mut built = context.supertype_vectors.borrow_mut();
+	if let Some(&offset) = built.get(&node.kind_id()) {
+		return Some(WIPOffset::new(offset));
+	}
+	let supertypes = supertypes.of(node.kind(), node.is_named());
+	if supertypes.is_empty() {
+		return None;
+	}
+	let supertypes = supertypes
+		.iter()
+		.map(|supertype| builder.create_shared_string(supertype))
+		.collect::<Vec<_>>();
+	let supertypes = builder.create_vector(&supertypes);
+	built.insert(node.kind_id(), supertypes.value());
+	Some(supertypes)
+}
+
+/// Builds the `snippet` node `serialize_part` puts `nodes` under when no one node makes up the
+/// part.
+fn build_snippet<'a>(
+	context: &Context,
+	builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+	nodes: &[tree_sitter::Node<'a>],
+	counts: &mut Counts,
+) -> WIPOffset<Node<'a>> {
+	let options = context.options;
+	counts.nodes += 1;
+	let part = &context.part;
+	let mut child_vec = Vec::new();
+	for &node in nodes {
+		if options.named_only && !node.is_named() {
+			if node.is_missing() {
+				counts.errors += 1;
+			}
+			build_children(context, builder, node, 0, counts, &mut child_vec);
+		} else {
+			child_vec.push(build_node(context, builder, node, None, 1, counts));
+		}
+	}
+	let children = builder.create_vector(&child_vec);
+
+	let kind = builder.create_string("snippet");
+	let start = part.position(part.range.start, part.start);
+	let end = part.position(part.range.end, part.end);
+	let point = |point: tree_sitter::Point| Point::new(point.row as u32, point.column as u32);
+	let (start_point, end_point) = match options.points {
+		true => (Some(point(start.1)), Some(point(end.1))),
+		false => (None, None),
+	};
+	let offsets = context
+		.offsets_in
+		.map(|text| offsets(start, end, part, text));
+	Node::create(
+		builder,
+		&NodeArgs {
+			kind: Some(kind),
+			location: Some(&Location::new(start.0 as u32, end.0 as u32)),
+			children: Some(children),
+			named: true,
+			text: None,
+			hash: None,
+			field: None,
+			start_point: start_point.as_ref(),
+			end_point: end_point.as_ref(),
+			offsets: offsets.as_ref(),
+			child_count: None,
+			named_descendant_count: None,
+			supertypes: None,
+		},
+	)
+}
+
+/// Builds the children of `node` (at `depth`) into `out`, or with `named_only`, the named nodes
+/// under it that aren't under another named node.
+fn build_children<'a>(
+	context: &Context,
+	builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+	node: tree_sitter::Node<'a>,
+	depth: u32,
+	counts: &mut Counts,
+	out: &mut Vec<WIPOffset<Node<'a>>>,
+) {
+	let mut cursor = node.walk();
+	if !cursor.goto_first_child() {
+		return;
+	}
+	loop {
+		let child = cursor.node();
+		if context.options.named_only && !child.is_named() {
+			// MISSING nodes can be anonymous
+			if child.is_missing() {
+				counts.errors += 1;
+			}
+			build_children(context, builder, child, depth, counts, out);
+		} else {
+			let node = build_node(
+				context,
+				builder,
+				child,
+				cursor.field_name(),
+				depth + 1,
+				counts,
+			);
+			out.push(node);
+		}
+		if !cursor.goto_next_sibling() {
+			break;
+		}
+	}
+}
+
+/// How many children `build_children` would give `node`.
+fn child_count(node: tree_sitter::Node, named_only: bool) -> u32 {
+	if !named_only {
+		return node.child_count() as u32;
+	}
+	let mut cursor = node.walk();
+	node.children(&mut cursor)
+		.map(|child| match child.is_named() {
+			true => 1,
+			false => child_count(child, named_only),
+		})
+		.sum()
+}
+
+/// The named nodes below `node`.
+fn named_descendant_count(node: tree_sitter::Node) -> u32 {
+	let mut count = 0;
+	let mut cursor = node.walk();
+	if !cursor.goto_first_child() {
+		return 0;
+	}
+	'walk: loop {
+		if cursor.node().is_named() {
+			count += 1;
+		}
+		if cursor.goto_first_child() {
+			continue;
+		}
+		while !cursor.goto_next_sibling() {
+			if !cursor.goto_parent() {
+				break 'walk;
+			}
+		}
+	}
+	count
+}
+
+/// The ERROR and MISSING nodes below `node`.
+fn count_errors(node: tree_sitter::Node) -> u32 {
+	let mut count = 0;
+	let mut cursor = node.walk();
+	if !cursor.goto_first_child() {
+		return 0;
+	}
+	'walk: loop {
+		let node = cursor.node();
+		if node.is_error() || node.is_missing() {
+			count += 1;
+		}
+		if node.has_error() && cursor.goto_first_child() {
+			continue;
+		}
+		while !cursor.goto_next_sibling() {
+			if !cursor.goto_parent() {
+				break 'walk;
+			}
+		}
+	}
+	count
+}
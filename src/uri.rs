@@ -0,0 +1,248 @@
+use std::{
+	fmt, fs,
+	path::{Component, Path, PathBuf},
+};
+
+use anyhow::Result;
+use once_cell::sync::OnceCell;
+use url::Url;
+
+use crate::error::Error;
+
+/// Whether symlinks in file paths are resolved before the paths are used as document keys.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum SymlinkPolicy {
+	/// Use the symlink's target, so all links to a file share one document
+	#[default]
+	Resolve,
+	/// Treat each link as a document of its own
+	Preserve,
+}
+
+static SYMLINK_POLICY: OnceCell<SymlinkPolicy> = OnceCell::new();
+
+/// Sets the symlink policy for the rest of the process. Only the first call has any effect.
+pub fn set_symlink_policy(policy: SymlinkPolicy) {
+	let _ = SYMLINK_POLICY.set(policy);
+}
+
+/// What a session caches documents under: the path for `file://` URIs, and the URI itself for
+/// anything else (`untitled:Untitled-1`, virtual documents in custom schemes, ...).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DocumentKey {
+	File(PathBuf),
+	Uri(String),
+}
+
+impl DocumentKey {
+	pub fn file(path: &Path) -> Self {
+		DocumentKey::File(normalize(&canonicalize(path)))
+	}
+
+	/// The URI a client would use for the document.
+	pub fn uri(&self) -> String {
+		match self {
+			DocumentKey::File(path) => Url::from_file_path(path)
+				.map(String::from)
+				.unwrap_or_else(|_| path.display().to_string()),
+			DocumentKey::Uri(uri) => uri.clone(),
+		}
+	}
+}
+
+/// Makes `path` absolute and removes `.` and `..` components, following symlinks if the policy
+/// says to. Paths of files that don't exist (e.g. documents whose content was sent in the request)
+/// are only cleaned up lexically.
+fn canonicalize(path: &Path) -> PathBuf {
+	if SYMLINK_POLICY.get().copied().unwrap_or_default() == SymlinkPolicy::Resolve {
+		if let Ok(path) = fs::canonicalize(path) {
+			return path;
+		}
+	}
+
+	let path = std::env::current_dir()
+		.map(|cwd| cwd.join(path))
+		.unwrap_or_else(|_| path.to_path_buf());
+	let mut normalized = PathBuf::new();
+	for component in path.components() {
+		match component {
+			Component::CurDir => {}
+			Component::ParentDir => {
+				normalized.pop();
+			}
+			component => normalized.push(component),
+		}
+	}
+	normalized
+}
+
+/// `path` with every symlink in it resolved, whatever the symlink policy, in the form document
+/// keys are in. Paths that don't exist are only cleaned up lexically.
+pub fn resolve(path: &Path) -> PathBuf {
+	normalize(&fs::canonicalize(path).unwrap_or_else(|_| canonicalize(path)))
+}
+
+/// Whether `path` is inside one of `roots` (resolved like `resolve`) once its symlinks are
+/// resolved. Everywhere is, if there are no roots.
+pub fn is_within(path: &Path, roots: &[PathBuf]) -> bool {
+	if roots.is_empty() {
+		return true;
+	}
+	let resolved = resolve(path);
+	roots.iter().any(|root| resolved.starts_with(root))
+}
+
+/// Puts Windows paths in one canonical form, so the same file reached through differently written
+/// URIs (`file:///C:/a/b`, `file:///c:/A/B`, `\\?\C:\a\b`, ...) shares a cache entry. Paths on
+/// other platforms are left as they are.
+fn normalize(path: &Path) -> PathBuf {
+	if !cfg!(windows) {
+		return path.to_path_buf();
+	}
+	match path.to_str() {
+		Some(path) => PathBuf::from(normalize_windows(path)),
+		None => path.to_path_buf(),
+	}
+}
+
+/// `normalize` on a Windows path, whatever the platform: verbatim `\\?\` prefixes are dropped,
+/// separators become backslashes, and since Windows paths are case insensitive, everything is
+/// lowercased.
+fn normalize_windows(path: &str) -> String {
+	let path = path.replace('/', "\\");
+	let path = if let Some(unc) = path.strip_prefix(r"\\?\UNC\") {
+		format!(r"\\{}", unc)
+	} else if let Some(local) = path.strip_prefix(r"\\?\") {
+		local.to_string()
+	} else {
+		path
+	};
+	path.to_lowercase()
+}
+
+/// VS Code and others percent-encode the colon after a drive letter (`file:///c%3A/...`), which
+/// `Url::to_file_path` doesn't accept as a Windows path.
+fn decode_drive_letter(url: &mut Url) {
+	if !cfg!(windows) {
+		return;
+	}
+	if let Some(decoded) = decoded_drive_letter(url.path()) {
+		url.set_path(&decoded);
+	}
+}
+
+/// The path of a `file://` URL with the colon after its drive letter decoded, if it's encoded.
+fn decoded_drive_letter(path: &str) -> Option<String> {
+	let bytes = path.as_bytes();
+	let encoded = bytes.len() >= 5
+		&& bytes[0] == b'/'
+		&& bytes[1].is_ascii_alphabetic()
+		&& bytes[2..5].eq_ignore_ascii_case(b"%3a");
+	encoded.then(|| format!("/{}:{}", &path[1..2], &path[5..]))
+}
+
+impl fmt::Display for DocumentKey {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			DocumentKey::File(path) => write!(f, "{}", path.display()),
+			DocumentKey::Uri(uri) => write!(f, "{}", uri),
+		}
+	}
+}
+
+fn parse(uri: &str) -> Result<Url> {
+	Ok(Url::parse(uri).map_err(|e| Error::UnknownFile(format!("Invalid URI {:?}: {}", uri, e)))?)
+}
+
+/// The key for the document at `uri`. Nothing is read from disk, so the file needn't exist.
+pub fn key(uri: &str) -> Result<DocumentKey> {
+	let mut url = parse(uri)?;
+	if url.scheme() != "file" {
+		return Ok(DocumentKey::Uri(url.into()));
+	}
+
+	decode_drive_letter(&mut url);
+	// on Windows, this handles both drive letters and UNC paths (file://server/share/...)
+	let path = url
+		.to_file_path()
+		.map_err(|_| Error::UnknownFile(format!("Invalid file path: {}", url.path())))?;
+	Ok(DocumentKey::file(&path))
+}
+
+/// Resolves a `file://` URI to the (normalized) path of an existing file.
+pub fn file_path(uri: &str) -> Result<PathBuf> {
+	let path = match key(uri)? {
+		DocumentKey::File(path) => path,
+		DocumentKey::Uri(_) => {
+			let scheme = parse(uri)?.scheme().to_string();
+			return Err(Error::UnknownFile(format!(
+				"Unsupported URI scheme for reading from disk: {:?}",
+				scheme
+			))
+			.into());
+		}
+	};
+
+	if path.is_dir() {
+		return Err(Error::UnknownFile(format!("{} is a directory!", path.display())).into());
+	}
+	if !path.is_file() {
+		return Err(Error::UnknownFile(format!("File not found: {}", path.display())).into());
+	}
+
+	Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn decodes_encoded_drive_letters() {
+		let url = Url::parse("file:///c%3A/Users/A/main.ts").unwrap();
+		assert_eq!(
+			decoded_drive_letter(url.path()).as_deref(),
+			Some("/c:/Users/A/main.ts")
+		);
+		let url = Url::parse("file:///C%3a/main.ts").unwrap();
+		assert_eq!(
+			decoded_drive_letter(url.path()).as_deref(),
+			Some("/C:/main.ts")
+		);
+	}
+
+	#[test]
+	fn leaves_plain_drive_letters_alone() {
+		let url = Url::parse("file:///C:/Users/A/main.ts").unwrap();
+		assert_eq!(decoded_drive_letter(url.path()), None);
+		assert_eq!(decoded_drive_letter("/ab%3A/main.ts"), None);
+	}
+
+	#[test]
+	fn normalizes_drive_letter_paths() {
+		assert_eq!(
+			normalize_windows("C:/Users/A/main.ts"),
+			r"c:\users\a\main.ts"
+		);
+		assert_eq!(
+			normalize_windows(r"c:\users\a\main.ts"),
+			r"c:\users\a\main.ts"
+		);
+	}
+
+	#[test]
+	fn drops_verbatim_prefixes() {
+		assert_eq!(
+			normalize_windows(r"\\?\C:\Users\A\main.ts"),
+			r"c:\users\a\main.ts"
+		);
+		assert_eq!(
+			normalize_windows(r"\\?\UNC\Server\Share\main.ts"),
+			r"\\server\share\main.ts"
+		);
+		assert_eq!(
+			normalize_windows(r"\\Server\Share\main.ts"),
+			r"\\server\share\main.ts"
+		);
+	}
+}
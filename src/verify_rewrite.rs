@@ -0,0 +1,182 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use anyhow::Result;
+use flatbuffers::FlatBufferBuilder;
+use tree_sitter::{Node, Query, QueryCursor, Tree};
+
+use crate::{
+	cancel, edit,
+	error::Error,
+	languages,
+	message_generated::asted::interface::{
+		Diagnostic, DiagnosticArgs, Invariant, InvariantExpectation, InvariantResult,
+		InvariantResultArgs, Location, Point, RewrittenDocument, RewrittenDocumentArgs,
+		VerifyRewriteRequest, VerifyRewriteResponse, VerifyRewriteResponseArgs,
+	},
+	syntax_errors,
+	text::Text,
+	State,
+};
+
+/// Applies the edits in `req` to its documents, reparses them and returns a serialized
+/// `VerifyRewriteResponse` of whether its invariants still hold and how the syntax errors changed.
+pub fn verify_rewrite(state: &State, req: VerifyRewriteRequest) -> Result<Vec<u8>> {
+	let _span = tracing::info_span!("verify_rewrite", documents = req.documents().len()).entered();
+	let invariants = req.invariants().into_iter().flatten().collect::<Vec<_>>();
+	// each invariant's query, compiled for each language a document is in
+	let mut queries = HashMap::<String, Vec<Query>>::new();
+	let mut matches = vec![(0, 0); invariants.len()];
+
+	let mut builder = FlatBufferBuilder::new();
+	let mut documents = Vec::new();
+	let mut ok = true;
+	for document in req.documents() {
+		cancel::check()?;
+		let lang = match (
+			document.lang().or(req.lang()),
+			&state.lang,
+			state.plain_text,
+		) {
+			(Some(lang), _, _) => lang,
+			(None, Some(lang), false) => lang.as_str(),
+			_ => {
+				return Err(Error::UnknownLanguage(
+					"Verifying a rewrite needs a language with a grammar".to_string(),
+				)
+				.into())
+			}
+		};
+		let queries = match queries.entry(lang.to_string()) {
+			Entry::Occupied(entry) => entry.into_mut(),
+			Entry::Vacant(entry) => entry.insert(compile(lang, &invariants)?),
+		};
+
+		let mut text = Text::from_utf16(&document.text().iter().collect::<Vec<_>>())?;
+		// the session's own parser for its language, so asking for it doesn't set up another one
+		let parser_lang = (Some(lang) != state.lang.as_deref() || state.plain_text).then_some(lang);
+		let (mut tree, _) = state.parse_tree(parser_lang, &text, None, false)?;
+		let errors_before = syntax_errors::syntax_errors(&text, &tree).len();
+		for (i, query) in queries.iter().enumerate() {
+			matches[i].0 += count(query, &tree, &text);
+		}
+
+		let edits = document
+			.edits()
+			.into_iter()
+			.flatten()
+			.map(|edit| {
+				let range = edit.start_byte() as usize / 2..edit.old_end_byte() as usize / 2;
+				(range, edit.text().into_iter().flatten().collect::<Vec<_>>())
+			})
+			.collect();
+		// last to first, so each applies to the text the original offsets are for
+		for (range, new_text) in edit::normalize(&text, edits)? {
+			edit::apply(&mut text, &mut tree, range.start, range.end, &new_text)?;
+		}
+		let (tree, _) = state.parse_tree(parser_lang, &text, Some(&tree), false)?;
+		for (i, query) in queries.iter().enumerate() {
+			matches[i].1 += count(query, &tree, &text);
+		}
+
+		let errors = syntax_errors::syntax_errors(&text, &tree);
+		ok &= errors.len() <= errors_before;
+		let diagnostics = errors
+			.iter()
+			.map(|error| {
+				let message = builder.create_string(&error.message);
+				let (start, end) = (error.node.start_position(), error.node.end_position());
+				Diagnostic::create(
+					&mut builder,
+					&DiagnosticArgs {
+						location: Some(&Location::new(
+							error.node.start_byte() as u32,
+							error.node.end_byte() as u32,
+						)),
+						start_point: Some(&Point::new(start.row as u32, start.column as u32)),
+						end_point: Some(&Point::new(end.row as u32, end.column as u32)),
+						message: Some(message),
+					},
+				)
+			})
+			.collect::<Vec<_>>();
+		let diagnostics = builder.create_vector(&diagnostics);
+		let name = document.name().map(|name| builder.create_string(name));
+		documents.push(RewrittenDocument::create(
+			&mut builder,
+			&RewrittenDocumentArgs {
+				name,
+				syntax_errors_before: errors_before as u32,
+				syntax_errors_after: errors.len() as u32,
+				diagnostics: Some(diagnostics),
+			},
+		));
+	}
+
+	let results = invariants
+		.iter()
+		.zip(&matches)
+		.map(|(invariant, &(before, after))| {
+			let holds = match invariant.expect() {
+				InvariantExpectation::Absent => after == 0,
+				InvariantExpectation::Present => after > 0,
+				_ => after == before,
+			};
+			ok &= holds;
+			InvariantResult::create(
+				&mut builder,
+				&InvariantResultArgs {
+					holds,
+					matches_before: before as u32,
+					matches_after: after as u32,
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+	let invariants = builder.create_vector(&results);
+	let documents = builder.create_vector(&documents);
+	let resp = VerifyRewriteResponse::create(
+		&mut builder,
+		&VerifyRewriteResponseArgs {
+			ok,
+			invariants: Some(invariants),
+			documents: Some(documents),
+		},
+	);
+	builder.finish(resp, None);
+
+	Ok(builder.finished_data().to_vec())
+}
+
+/// The query of each of `invariants` for `lang`.
+fn compile(lang: &str, invariants: &[Invariant]) -> Result<Vec<Query>> {
+	let language = languages::get(lang)
+		.ok_or_else(|| Error::UnknownLanguage(format!("Unsupported language: {}", lang)))?;
+	invariants
+		.iter()
+		.enumerate()
+		.map(|(i, invariant)| {
+			// tree-sitter 0.20 can panic describing where a query that ends early went wrong
+			match std::panic::catch_unwind(|| Query::new(language, invariant.query())) {
+				Ok(Ok(query)) => Ok(query),
+				Ok(Err(e)) => Err(Error::InvalidRequest(format!(
+					"Invalid query of invariant {}: {}",
+					i, e
+				))
+				.into()),
+				Err(_) => {
+					Err(Error::InvalidRequest(format!("Invalid query of invariant {}", i)).into())
+				}
+			}
+		})
+		.collect()
+}
+
+/// How many times `query` matches in `tree`.
+fn count(query: &Query, tree: &Tree, text: &Text) -> usize {
+	let mut cursor = QueryCursor::new();
+	cursor
+		.matches(query, tree.root_node(), |node: Node| {
+			text.chunks(node.start_byte() / 2..node.end_byte() / 2)
+		})
+		.count()
+}
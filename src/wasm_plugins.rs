@@ -0,0 +1,352 @@
+use std::{
+	collections::HashMap,
+	path::Path,
+	sync::{Arc, PoisonError, RwLock},
+};
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use wasmi::{
+	core::{Trap, TrapCode},
+	Caller, Config, Engine, Linker, Memory, Module, Store, StoreLimits, StoreLimitsBuilder,
+};
+
+use crate::{document::Document, error::Error, export};
+
+/// How many instructions (roughly) a plugin can run for per request, so one stuck in a loop
+/// doesn't hold the session forever.
+const FUEL: u64 = 1_000_000_000;
+/// How big a plugin's memory can grow.
+const MAX_MEMORY: usize = 256 << 20;
+
+static ENGINE: Lazy<Engine> = Lazy::new(|| {
+	let mut config = Config::default();
+	config.consume_fuel(true);
+	Engine::new(&config)
+});
+
+static PLUGINS: Lazy<RwLock<HashMap<String, Arc<Module>>>> = Lazy::new(Default::default);
+
+/// Compiles the plugin at `path`, returning its name: the file's name without the extension.
+pub fn load(path: &Path) -> Result<String> {
+	let name = match path.file_stem().and_then(|name| name.to_str()) {
+		Some(name) => name.to_string(),
+		None => bail!("Can't name a plugin after {}", path.display()),
+	};
+	let wasm =
+		std::fs::read(path).with_context(|| format!("Error reading plugin {}", path.display()))?;
+	let module = Module::new(&ENGINE, &wasm[..])
+		.with_context(|| format!("{} isn't a WASM module", path.display()))?;
+
+	let mut plugins = PLUGINS.write().unwrap_or_else(PoisonError::into_inner);
+	if plugins.contains_key(&name) {
+		bail!("A plugin called {} is already loaded", name);
+	}
+	plugins.insert(name.clone(), Arc::new(module));
+	Ok(name)
+}
+
+#[derive(Serialize)]
+struct Input<'a> {
+	language: &'a str,
+	version: u32,
+	text: String,
+	nodes: Vec<Node>,
+	#[serde(serialize_with = "bytes")]
+	args: &'a [u8],
+}
+
+#[derive(Serialize)]
+struct Node {
+	kind: &'static str,
+	parent: Option<u32>,
+	named: bool,
+	error: bool,
+	start_byte: usize,
+	end_byte: usize,
+	start_point: Point,
+	end_point: Point,
+}
+
+#[derive(Serialize)]
+struct Point {
+	row: usize,
+	column: usize,
+}
+
+/// Runs the plugin called `name` over `document`, returning the MessagePack value it returned.
+///
+/// A WASM plugin is a module exporting its `memory` and
+///
+/// - `asted_alloc(len: i32) -> i32`, returning where the host can write `len` bytes of input, and
+/// - `asted_run(input: i32, input_len: i32) -> i64`, returning where its output is (the upper 32
+///   bits) and how long it is (the lower 32 bits).
+///
+/// The input is a MessagePack map of the document (`language`, `version`, its `text` as a string,
+/// and the flat node table in pre-order as `nodes`) and the request's `args`. Each node is a map
+/// of `kind`, `parent` (its index in the table, or nil for the root), `named`, `error` (for ERROR
+/// and MISSING nodes), `start_byte` and `end_byte`, and `start_point` and `end_point` (maps of
+/// `row` and `column`). Unlike everywhere else, offsets and columns are in UTF-8 bytes, so they
+/// can be used on the text as the plugin gets it. The output can be any MessagePack value.
+///
+/// The only import, `fail(message: i32, len: i32)` from the `asted` module, makes the request
+/// fail with a (UTF-8) message instead, once `asted_run` returns.
+pub fn run(document: &Document, name: &str, args: &[u8]) -> Result<Vec<u8>> {
+	run_with_fuel(document, name, args, FUEL)
+}
+
+fn run_with_fuel(document: &Document, name: &str, args: &[u8], fuel: u64) -> Result<Vec<u8>> {
+	let _span = tracing::info_span!("wasm_plugin", plugin = name).entered();
+	let module = match PLUGINS
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.get(name)
+	{
+		Some(module) => module.clone(),
+		None => return Err(Error::UnknownCommand(format!("No plugin called {}", name)).into()),
+	};
+
+	let input = rmp_serde::to_vec_named(&input(document, args))?;
+	let failed = |trap: Trap| plugin_error(name, trap.into());
+	let mut store = Store::new(
+		&ENGINE,
+		Sandbox {
+			limits: StoreLimitsBuilder::new()
+				.memory_size(MAX_MEMORY)
+				.instances(1)
+				.build(),
+			failure: None,
+		},
+	);
+	store.limiter(|sandbox| &mut sandbox.limits);
+	store.add_fuel(fuel).map_err(|e| anyhow::anyhow!("{}", e))?;
+
+	let mut linker = Linker::new(&ENGINE);
+	linker.func_wrap(
+		"asted",
+		"fail",
+		|mut caller: Caller<Sandbox>, message: i32, len: i32| {
+			let message = match caller.get_export("memory").and_then(|e| e.into_memory()) {
+				Some(memory) => read(&memory, &caller, message, len)
+					.map(|message| String::from_utf8_lossy(&message).into_owned()),
+				None => None,
+			};
+			caller.data_mut().failure = Some(message.unwrap_or_default());
+		},
+	)?;
+	let instance = linker
+		.instantiate(&mut store, &module)
+		.and_then(|instance| instance.start(&mut store))
+		.map_err(|e| plugin_error(name, e))?;
+	let memory = instance
+		.get_memory(&store, "memory")
+		.with_context(|| format!("Plugin {} doesn't export its memory", name))?;
+	let alloc = instance
+		.get_typed_func::<i32, i32>(&store, "asted_alloc")
+		.with_context(|| format!("Plugin {} doesn't export asted_alloc", name))?;
+	let run = instance
+		.get_typed_func::<(i32, i32), i64>(&store, "asted_run")
+		.with_context(|| format!("Plugin {} doesn't export asted_run", name))?;
+
+	let len = i32::try_from(input.len()).context("Document too big for a WASM plugin")?;
+	let ptr = alloc.call(&mut store, len).map_err(failed)?;
+	memory
+		.write(&mut store, ptr as u32 as usize, &input)
+		.ok()
+		.with_context(|| format!("Plugin {} allocated memory out of bounds", name))?;
+	let output = run.call(&mut store, (ptr, len)).map_err(failed)?;
+
+	if let Some(message) = store.data_mut().failure.take() {
+		return Err(Error::Internal(format!("Plugin {} failed: {}", name, message)).into());
+	}
+	let output = read(&memory, &store, (output >> 32) as i32, output as i32)
+		.with_context(|| format!("Plugin {} returned output out of bounds", name))?;
+	// checked here, so clients don't have to suspect the server of garbling it
+	if rmp_serde::from_slice::<ciborium::Value>(&output).is_err() {
+		return Err(
+			Error::Internal(format!("Plugin {} didn't return a MessagePack value", name)).into(),
+		);
+	}
+	Ok(output)
+}
+
+/// What a plugin's store holds.
+struct Sandbox {
+	limits: StoreLimits,
+	/// The message the plugin called `fail` with
+	failure: Option<String>,
+}
+
+fn input<'a>(document: &'a Document, args: &'a [u8]) -> Input<'a> {
+	let text = &document.text;
+	// tree-sitter's offsets are in bytes of UTF-16
+	let utf8_offset = |byte: u32| text.utf8_offset(byte as usize / 2);
+	let utf8_point = |byte: u32, point: tree_sitter::Point| Point {
+		row: point.row,
+		column: utf8_offset(byte) - text.utf8_offset(text.offset_at(point.row, 0)),
+	};
+	Input {
+		language: &document.language,
+		version: document.version,
+		text: String::from_utf16_lossy(&text.to_utf16()),
+		nodes: export::nodes(&document.tree)
+			.into_iter()
+			.map(|row| Node {
+				kind: row.kind,
+				parent: row.parent,
+				named: row.named,
+				error: row.error,
+				start_byte: utf8_offset(row.start_byte),
+				end_byte: utf8_offset(row.end_byte),
+				start_point: utf8_point(row.start_byte, row.start_point),
+				end_point: utf8_point(row.end_byte, row.end_point),
+			})
+			.collect(),
+		args,
+	}
+}
+
+/// `len` bytes of the plugin's memory from `ptr`, if they're all in it.
+fn read(memory: &Memory, store: impl wasmi::AsContext, ptr: i32, len: i32) -> Option<Vec<u8>> {
+	let start = ptr as u32 as usize;
+	let end = start.checked_add(len as u32 as usize)?;
+	memory.data(&store).get(start..end).map(<[u8]>::to_vec)
+}
+
+fn plugin_error(name: &str, e: wasmi::Error) -> anyhow::Error {
+	match e {
+		wasmi::Error::Trap(trap) if matches!(trap.trap_code(), Some(TrapCode::OutOfFuel)) => {
+			Error::Timeout(format!(
+				"Plugin {} ran for longer than it's allowed to",
+				name
+			))
+			.into()
+		}
+		_ => Error::Internal(format!("Plugin {} failed: {}", name, e)).into(),
+	}
+}
+
+/// Serializes as a byte string rather than a sequence of numbers, since MessagePack has a type
+/// for those.
+fn bytes<S: serde::Serializer>(
+	bytes: &&[u8],
+	serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+	serializer.serialize_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::SystemTime;
+
+	use serde::Deserialize;
+
+	use super::*;
+	use crate::text::Text;
+
+	fn document(source: &str) -> Document {
+		let text = Text::from(source);
+		let mut parser = tree_sitter::Parser::new();
+		parser.set_language(tree_sitter_cpp::language()).unwrap();
+		Document {
+			tree: text.parse(&mut parser, None).unwrap(),
+			text,
+			version: 3,
+			language: "cpp".to_string(),
+			mtime: None,
+			accessed: SystemTime::now(),
+		}
+	}
+
+	/// Loads a plugin written in the text format, whose `asted_alloc` hands out memory from 1024
+	/// and whose `asted_run` has the given body.
+	fn plugin(name: &str, run: &str) {
+		let wat = format!(
+			r#"(module
+				(import "asted" "fail" (func $fail (param i32 i32)))
+				(memory (export "memory") 1)
+				(data (i32.const 0) "bad args")
+				(func (export "asted_alloc") (param i32) (result i32) i32.const 1024)
+				(func (export "asted_run") (param $ptr i32) (param $len i32) (result i64) {})
+			)"#,
+			run
+		);
+		let path = std::env::temp_dir().join(format!("{}.wasm", name));
+		std::fs::write(&path, wat::parse_str(wat).unwrap()).unwrap();
+		assert_eq!(load(&path).unwrap(), name);
+	}
+
+	#[derive(Deserialize)]
+	struct EchoedNode {
+		kind: String,
+		parent: Option<u32>,
+		start_byte: usize,
+		end_byte: usize,
+	}
+
+	#[derive(Deserialize)]
+	struct Echoed {
+		language: String,
+		version: u32,
+		text: String,
+		nodes: Vec<EchoedNode>,
+		args: ciborium::Value,
+	}
+
+	#[test]
+	fn hands_plugins_the_node_table() {
+		// returns its input
+		plugin(
+			"echo",
+			"local.get $ptr i64.extend_i32_u i64.const 32 i64.shl local.get $len i64.extend_i32_u i64.or",
+		);
+		let document = document("/* é */ int x;");
+		let echoed = run(&document, "echo", b"settings").unwrap();
+		let echoed: Echoed = rmp_serde::from_slice(&echoed).unwrap();
+		assert_eq!(echoed.language, "cpp");
+		assert_eq!(echoed.version, 3);
+		assert_eq!(echoed.args, ciborium::Value::Bytes(b"settings".to_vec()));
+		assert_eq!(echoed.nodes[0].kind, "translation_unit");
+		assert_eq!(echoed.nodes[0].parent, None);
+		// offsets are into the UTF-8 text
+		let declaration = echoed
+			.nodes
+			.iter()
+			.find(|node| node.kind == "declaration")
+			.unwrap();
+		assert_eq!(declaration.parent, Some(0));
+		assert_eq!(
+			&echoed.text[declaration.start_byte..declaration.end_byte],
+			"int x;"
+		);
+	}
+
+	#[test]
+	fn reports_failures() {
+		plugin("fails", "i32.const 0 i32.const 8 call $fail i64.const 0");
+		let e = run(&document("int x;"), "fails", &[]).unwrap_err();
+		assert_eq!(e.to_string(), "Plugin fails failed: bad args");
+
+		// a map with a key and no value
+		plugin(
+			"garbage",
+			"i32.const 0 i32.const 0x81 i32.store8 i64.const 1",
+		);
+		let e = run(&document("int x;"), "garbage", &[]).unwrap_err();
+		assert_eq!(
+			e.to_string(),
+			"Plugin garbage didn't return a MessagePack value"
+		);
+
+		let e = run(&document("int x;"), "missing", &[]).unwrap_err();
+		assert!(matches!(e.downcast_ref(), Some(Error::UnknownCommand(_))));
+	}
+
+	#[test]
+	fn stops_plugins_that_run_out_of_fuel() {
+		plugin("spins", "(loop $spin br $spin) i64.const 0");
+		let e = run_with_fuel(&document("int x;"), "spins", &[], 1_000_000).unwrap_err();
+		assert!(matches!(e.downcast_ref(), Some(Error::Timeout(_))));
+	}
+}
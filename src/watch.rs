@@ -0,0 +1,200 @@
+use std::{
+	collections::HashSet,
+	io::{self, Write},
+	path::PathBuf,
+	sync::mpsc,
+	time::Duration,
+};
+
+use anyhow::{Context, Result};
+use notify::{RecursiveMode, Watcher};
+use serde::Serialize;
+
+use crate::{
+	export::{self, LinePoint, ParsedFile},
+	syntax_errors::{self, ErrorGroup, SyntaxError},
+	workspace,
+};
+
+// The subcommand re-checking files' syntax as they change. (Not a doc comment, which clap would
+// use to describe the whole program.)
+#[derive(clap::Subcommand)]
+pub enum WatchCommand {
+	/// Print the syntax errors of every file under a directory, and then again for each file
+	/// whenever it changes, until interrupted
+	Watch {
+		dir: PathBuf,
+		/// Print a JSON line for every file parsed, with its syntax errors, instead of a line per
+		/// error
+		#[arg(long)]
+		json: bool,
+		#[command(flatten)]
+		options: export::Options,
+	},
+}
+
+/// A file's syntax errors, as printed with `--json`.
+#[derive(Serialize)]
+pub struct FileLine<'a> {
+	file: &'a str,
+	lang: &'a str,
+	errors: Vec<ErrorLine>,
+}
+
+impl<'a> FileLine<'a> {
+	pub fn new(file: &'a str, lang: &'a str, groups: &[ErrorGroup]) -> Self {
+		FileLine {
+			file,
+			lang,
+			errors: groups
+				.iter()
+				.map(|group| ErrorLine {
+					related: group.rest.iter().map(ErrorLine::new).collect(),
+					..ErrorLine::new(&group.first)
+				})
+				.collect(),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct ErrorLine {
+	message: String,
+	start_byte: u32,
+	end_byte: u32,
+	start_point: LinePoint,
+	end_point: LinePoint,
+	/// The errors merged into this one, as likely the same mistake
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	related: Vec<ErrorLine>,
+}
+
+impl ErrorLine {
+	fn new(error: &SyntaxError) -> Self {
+		ErrorLine {
+			message: error.message.clone(),
+			start_byte: error.node.start_byte() as u32,
+			end_byte: error.node.end_byte() as u32,
+			start_point: error.node.start_position().into(),
+			end_point: error.node.end_position().into(),
+			related: Vec::new(),
+		}
+	}
+}
+
+pub fn run(command: WatchCommand) -> Result<()> {
+	let WatchCommand::Watch { dir, json, options } = command;
+	let (tx, rx) = mpsc::channel();
+	let mut watcher = notify::recommended_watcher(tx).context("Error creating file watcher")?;
+	// before the first pass, so files changed during it aren't missed
+	watcher
+		.watch(&dir, RecursiveMode::Recursive)
+		.with_context(|| format!("Error watching {}", dir.display()))?;
+
+	let mut out = io::stdout().lock();
+	let (mut broken, mut files) = (0, 0);
+	export::walk(&dir, &options, |parsed| {
+		files += 1;
+		if report(&mut out, &parsed, json, false)? > 0 {
+			broken += 1;
+		}
+		Ok(())
+	})?;
+	eprintln!(
+		"{} files, {} with syntax errors; watching {} for changes",
+		files,
+		broken,
+		dir.display()
+	);
+
+	while let Ok(event) = rx.recv() {
+		let mut changed = HashSet::new();
+		let mut add = |event: notify::Result<notify::Event>| match event {
+			// canonicalized like the walked files are below, which also drops removed files
+			Ok(event) => changed.extend(
+				event
+					.paths
+					.iter()
+					.filter_map(|path| path.canonicalize().ok()),
+			),
+			Err(e) => eprintln!("Error watching {}: {}", dir.display(), e),
+		};
+		add(event);
+		// editors usually write a file several times in a row, so wait for things to settle
+		while let Ok(event) = rx.recv_timeout(Duration::from_millis(200)) {
+			add(event);
+		}
+
+		// walked again for the filter to apply to new files (and to changed .gitignores)
+		let files = export::files(&dir, &options)?
+			.into_iter()
+			.filter(|(path, _)| {
+				path.canonicalize()
+					.is_ok_and(|path| changed.contains(&path))
+			})
+			.collect::<Vec<_>>();
+		workspace::parse_all(&files, |parsed| {
+			match parsed {
+				Ok(parsed) => {
+					let parsed = ParsedFile {
+						path: parsed
+							.path
+							.strip_prefix(&dir)
+							.unwrap_or(&parsed.path)
+							.to_path_buf(),
+						lang: parsed.lang,
+						text: parsed.text,
+						tree: parsed.tree,
+					};
+					report(&mut out, &parsed, json, true)?;
+				}
+				// most likely removed again since
+				Err(e) => eprintln!("{:#}", e),
+			}
+			Ok(())
+		})?;
+	}
+	Ok(())
+}
+
+/// Prints the syntax errors of `parsed`, or that it has none if it `changed`, returning how many
+/// it has.
+fn report(out: &mut impl Write, parsed: &ParsedFile, json: bool, changed: bool) -> Result<usize> {
+	let errors =
+		syntax_errors::merge_errors(syntax_errors::syntax_errors(&parsed.text, &parsed.tree));
+	let file = parsed.path.to_string_lossy();
+	if json {
+		serde_json::to_writer(&mut *out, &FileLine::new(&file, &parsed.lang, &errors))?;
+		writeln!(out)?;
+	} else if errors.is_empty() && changed {
+		writeln!(out, "{}: no syntax errors", file)?;
+	} else {
+		write_errors(out, &file, &errors)?;
+	}
+	out.flush()?;
+	Ok(errors.len())
+}
+
+/// Prints a `file:line:column: message` line for each group of `errors` of `file`, at its first
+/// error.
+pub fn write_errors(out: &mut impl Write, file: &str, errors: &[ErrorGroup]) -> io::Result<()> {
+	for ErrorGroup { first, rest } in errors {
+		let point = first.node.start_position();
+		let nearby = match rest.len() {
+			0 => String::new(),
+			1 => " (and 1 more error nearby)".to_string(),
+			n => format!(" (and {} more errors nearby)", n),
+		};
+		// columns count UTF-16 code units, like editors that aren't byte-oriented do
+		writeln!(
+			out,
+			"{}:{}:{}: {}{}",
+			file,
+			point.row + 1,
+			point.column / 2 + 1,
+			first.message,
+			nearby
+		)?;
+	}
+	Ok(())
+}
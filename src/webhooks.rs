@@ -0,0 +1,165 @@
+use std::{
+	sync::atomic::{AtomicBool, Ordering},
+	time::Duration,
+};
+
+use anyhow::{bail, Context, Result};
+use axum::http::{header::CONTENT_TYPE, Method, Request, Uri};
+use hyper::Body;
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// Something that happened in the server that webhooks can be notified of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Event {
+	/// A document was parsed (opened, edited or reread)
+	Parsed,
+	/// A document was parsed with a different number of syntax errors than before
+	DiagnosticsChanged,
+	/// A search or preload finished parsing every file of the workspace
+	IndexCompleted,
+}
+
+/// A URL to POST events to, from the `webhooks` list of the config file.
+#[derive(Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Webhook {
+	/// An http:// URL
+	pub url: String,
+	/// The events to send; every event if left out
+	#[serde(default)]
+	pub events: Vec<Event>,
+}
+
+/// The JSON body of a webhook request.
+#[derive(Serialize)]
+pub struct Payload {
+	pub event: Event,
+	#[serde(flatten)]
+	pub subject: Subject,
+}
+
+/// What an event happened to, as the rest of its payload.
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum Subject {
+	Document {
+		/// The document's URI, like `FileRequest.path`
+		uri: String,
+		version: u32,
+		content_hash: u64,
+		language: String,
+	},
+	Diagnostics {
+		uri: String,
+		version: u32,
+		syntax_errors: usize,
+	},
+	Index {
+		/// How many files were parsed
+		files: usize,
+	},
+}
+
+/// How many events can wait to be delivered before new ones are dropped.
+const CAPACITY: usize = 256;
+
+/// How long a webhook has to answer.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+static EVENTS: OnceCell<mpsc::Sender<Payload>> = OnceCell::new();
+
+/// Whether the last event was dropped.
+static DROPPING: AtomicBool = AtomicBool::new(false);
+
+/// Starts delivering events to `webhooks` in the background. Must be called from within the
+/// tokio runtime, and at most once.
+pub fn start(webhooks: Vec<Webhook>) -> Result<()> {
+	let webhooks = webhooks
+		.into_iter()
+		.map(|webhook| {
+			let url = webhook
+				.url
+				.parse::<Uri>()
+				.with_context(|| format!("Invalid webhook URL {}", webhook.url))?;
+			// the HTTP client is built without TLS
+			if url.scheme_str() != Some("http") {
+				bail!("Webhook URL {} isn't an http:// URL", url);
+			}
+			Ok((url, webhook.events))
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	let (tx, mut rx) = mpsc::channel::<Payload>(CAPACITY);
+	EVENTS
+		.set(tx)
+		.map_err(|_| anyhow::anyhow!("Webhooks were already started"))?;
+	tokio::spawn(async move {
+		let client = hyper::Client::new();
+		while let Some(payload) = rx.recv().await {
+			let body = match serde_json::to_vec(&payload) {
+				Ok(body) => body,
+				Err(e) => {
+					tracing::error!("Error serializing webhook payload: {}", e);
+					continue;
+				}
+			};
+			for (url, events) in &webhooks {
+				if !events.is_empty() && !events.contains(&payload.event) {
+					continue;
+				}
+				let client = client.clone();
+				let url = url.clone();
+				let body = body.clone();
+				// deliveries aren't retried, and a slow webhook shouldn't hold up the others
+				tokio::spawn(async move {
+					if let Err(e) = deliver(&client, url.clone(), body).await {
+						tracing::error!("Error calling webhook {}: {:#}", url, e);
+					}
+				});
+			}
+		}
+	});
+	Ok(())
+}
+
+async fn deliver(
+	client: &hyper::Client<hyper::client::HttpConnector>,
+	url: Uri,
+	body: Vec<u8>,
+) -> Result<()> {
+	let req = Request::builder()
+		.method(Method::POST)
+		.uri(url)
+		.header(CONTENT_TYPE, "application/json")
+		.body(Body::from(body))?;
+	let res = tokio::time::timeout(TIMEOUT, client.request(req))
+		.await
+		.context("Timed out")??;
+	if !res.status().is_success() {
+		bail!("Responded with {}", res.status());
+	}
+	Ok(())
+}
+
+/// Whether any webhooks are configured, for events that take work to find out about.
+pub fn enabled() -> bool {
+	EVENTS.get().is_some()
+}
+
+/// Queues `payload` for the webhooks, if any are configured. Never blocks: events are dropped
+/// when the webhooks can't keep up.
+pub fn emit(payload: Payload) {
+	if let Some(events) = EVENTS.get() {
+		match events.try_send(payload) {
+			Ok(()) => DROPPING.store(false, Ordering::Relaxed),
+			// once for every run of dropped events, which preloading can make long
+			Err(_) if !DROPPING.swap(true, Ordering::Relaxed) => {
+				tracing::warn!("Dropping webhook events, too many are waiting to be delivered");
+			}
+			Err(_) => {}
+		}
+	}
+}
@@ -0,0 +1,470 @@
+use std::{
+	collections::{BTreeMap, HashMap},
+	fs,
+	ops::ControlFlow,
+	path::{Path, PathBuf},
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		mpsc, Condvar, Mutex, PoisonError,
+	},
+	thread,
+	time::{Duration, Instant, SystemTime},
+};
+
+use anyhow::{bail, Context, Result};
+use glob::{MatchOptions, Pattern};
+use once_cell::sync::OnceCell;
+use tree_sitter::{Parser, Tree};
+
+use crate::{
+	cancel, error::Error, languages, message_generated::asted::interface::WorkspaceFilter,
+	text::Text, workspace_config,
+};
+
+/// `*` doesn't match across directories, like in `.gitignore`.
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+	case_sensitive: true,
+	require_literal_separator: true,
+	require_literal_leading_dot: false,
+};
+
+/// Which of the files under a directory a bulk operation takes in: those matching any of the
+/// include globs (every file, with none), minus those matching an exclude glob or the `ignore`
+/// globs of the workspace's `.asted.toml` or, unless `include_ignored`, ignored by a `.gitignore`.
+/// Hidden files and directories are always skipped.
+#[derive(Default)]
+pub struct Filter {
+	include: Vec<Glob>,
+	exclude: Vec<Glob>,
+	include_ignored: bool,
+}
+
+/// A glob from a filter or a `.gitignore`. One without a `/` (except at the end) matches names
+/// at any depth; one with a `/` matches paths from the directory it's relative to.
+pub struct Glob {
+	pattern: Pattern,
+	/// Matched against file names rather than relative paths
+	name_only: bool,
+	/// Ends in a `/`, so only matches directories
+	dir_only: bool,
+	/// A `.gitignore` line starting with `!`, taking files back in
+	negated: bool,
+}
+
+impl Glob {
+	pub fn parse(glob: &str) -> Result<Self> {
+		let (negated, glob) = match glob.strip_prefix('!') {
+			Some(glob) => (true, glob),
+			None => (false, glob),
+		};
+		let (dir_only, glob) = match glob.strip_suffix('/') {
+			Some(glob) => (true, glob),
+			None => (false, glob),
+		};
+		let name_only = !glob.contains('/');
+		let glob = glob.strip_prefix('/').unwrap_or(glob);
+		if glob.is_empty() {
+			bail!("Empty glob");
+		}
+		Ok(Glob {
+			pattern: Pattern::new(glob).with_context(|| format!("Invalid glob {}", glob))?,
+			name_only,
+			dir_only,
+			negated,
+		})
+	}
+
+	/// Whether the glob matches `relative`, a path relative to the directory it applies to.
+	pub fn matches(&self, relative: &Path, is_dir: bool) -> bool {
+		if self.dir_only && !is_dir {
+			return false;
+		}
+		if self.name_only {
+			let name = relative.file_name().unwrap_or_default();
+			self.pattern
+				.matches_with(&name.to_string_lossy(), MATCH_OPTIONS)
+		} else {
+			self.pattern.matches_path_with(relative, MATCH_OPTIONS)
+		}
+	}
+}
+
+/// The lines of a `.gitignore`, with the directory they're relative to.
+pub struct Gitignore {
+	dir: PathBuf,
+	globs: Vec<Glob>,
+}
+
+impl Gitignore {
+	/// Globs relative to `dir` that work like the lines of a `.gitignore` in it, failing on any that
+	/// isn't valid.
+	pub fn new(dir: &Path, globs: &[String]) -> Result<Self> {
+		Ok(Gitignore {
+			dir: dir.to_path_buf(),
+			globs: globs
+				.iter()
+				.map(|glob| Glob::parse(glob))
+				.collect::<Result<_>>()?,
+		})
+	}
+
+	/// Reads the `.gitignore` in `dir`, if there is one. Lines that aren't valid globs are skipped,
+	/// like git skips what it can't make sense of.
+	fn read(dir: &Path) -> Option<Self> {
+		let contents = fs::read_to_string(dir.join(".gitignore")).ok()?;
+		let globs = contents
+			.lines()
+			.map(|line| line.trim_end())
+			.filter(|line| !line.is_empty() && !line.starts_with('#'))
+			.map(|line| line.strip_prefix('\\').unwrap_or(line))
+			.filter_map(|line| Glob::parse(line).ok())
+			.collect::<Vec<_>>();
+		(!globs.is_empty()).then(|| Gitignore {
+			dir: dir.to_path_buf(),
+			globs,
+		})
+	}
+
+	/// Whether `path` is ignored (`Some(true)`) or taken back in (`Some(false)`) by the last line
+	/// matching it, if any does.
+	pub fn decides(&self, path: &Path, is_dir: bool) -> Option<bool> {
+		let relative = path.strip_prefix(&self.dir).ok()?;
+		self.globs
+			.iter()
+			.rev()
+			.find(|glob| glob.matches(relative, is_dir))
+			.map(|glob| !glob.negated)
+	}
+}
+
+impl Filter {
+	pub fn new(include: &[String], exclude: &[String], include_ignored: bool) -> Result<Self> {
+		let parse = |globs: &[String]| {
+			globs
+				.iter()
+				.map(|glob| Glob::parse(glob))
+				.collect::<Result<Vec<_>>>()
+		};
+		Ok(Filter {
+			include: parse(include)?,
+			exclude: parse(exclude)?,
+			include_ignored,
+		})
+	}
+
+	/// The filter a request asked for, taking in every file that isn't ignored if it didn't.
+	pub fn from_request(filter: Option<WorkspaceFilter>) -> Result<Self> {
+		let filter = match filter {
+			Some(filter) => filter,
+			None => return Ok(Filter::default()),
+		};
+		let globs = |globs: Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<&str>>>| {
+			globs
+				.into_iter()
+				.flatten()
+				.map(String::from)
+				.collect::<Vec<_>>()
+		};
+		Filter::new(
+			&globs(filter.include()),
+			&globs(filter.exclude()),
+			filter.include_ignored(),
+		)
+		.map_err(|e| Error::InvalidRequest(format!("{:#}", e)).into())
+	}
+
+	/// Hands the files under `dir` that the filter takes in to `f`, with their metadata, going
+	/// `depth` levels of subdirectories down (all of them, with `None`). Symlinks are followed,
+	/// like opening the files would. Stops when `f` breaks, returning whether it did.
+	pub fn walk(
+		&self,
+		dir: &Path,
+		depth: Option<u32>,
+		f: impl FnMut(&Path, &fs::Metadata) -> Result<ControlFlow<()>>,
+	) -> Result<ControlFlow<()>> {
+		// .gitignores match absolute paths, so the ones further up the repository can apply too
+		let root = dir
+			.canonicalize()
+			.with_context(|| format!("Error reading {}", dir.display()))?;
+		let workspace = workspace_config::find(&root);
+		let mut walk = Walk {
+			filter: self,
+			dir,
+			root: &root,
+			gitignores: Vec::new(),
+			ignore: workspace
+				.as_ref()
+				.and_then(|workspace| workspace.ignore.as_ref()),
+			f,
+		};
+		if !self.include_ignored {
+			let ancestors = root.ancestors().skip(1).collect::<Vec<_>>();
+			if let Some(top) = ancestors.iter().position(|dir| dir.join(".git").exists()) {
+				walk.gitignores.extend(
+					ancestors[..=top]
+						.iter()
+						.rev()
+						.filter_map(|dir| Gitignore::read(dir)),
+				);
+			}
+		}
+		walk.dir(&root, depth)
+	}
+
+	/// Whether an include glob matches the file or a directory it's in.
+	fn included(&self, relative: &Path) -> bool {
+		if self.include.is_empty() {
+			return true;
+		}
+		let dirs = relative
+			.ancestors()
+			.skip(1)
+			.filter(|dir| !dir.as_os_str().is_empty())
+			.collect::<Vec<_>>();
+		self.include.iter().any(|glob| {
+			glob.matches(relative, false) || dirs.iter().any(|dir| glob.matches(dir, true))
+		})
+	}
+}
+
+/// A walk of the files under a directory, with the `.gitignore`s applying where it's at.
+struct Walk<'a, F> {
+	filter: &'a Filter,
+	/// The directory as passed to `Filter::walk`, which the paths handed to `f` are under
+	dir: &'a Path,
+	/// The same directory as an absolute path, which the walk goes through
+	root: &'a Path,
+	gitignores: Vec<Gitignore>,
+	/// The `ignore` globs of the workspace the directory is in, which apply even to files the
+	/// filter takes in despite `.gitignore`s
+	ignore: Option<&'a Gitignore>,
+	f: F,
+}
+
+impl<F> Walk<'_, F>
+where
+	F: FnMut(&Path, &fs::Metadata) -> Result<ControlFlow<()>>,
+{
+	fn dir(&mut self, dir: &Path, depth: Option<u32>) -> Result<ControlFlow<()>> {
+		let gitignore = match self.filter.include_ignored {
+			true => None,
+			false => Gitignore::read(dir),
+		};
+		let pushed = gitignore.is_some();
+		self.gitignores.extend(gitignore);
+		let flow = self.entries(dir, depth);
+		if pushed {
+			self.gitignores.pop();
+		}
+		flow
+	}
+
+	fn entries(&mut self, dir: &Path, depth: Option<u32>) -> Result<ControlFlow<()>> {
+		let read = fs::read_dir(dir).with_context(|| format!("Error reading {}", dir.display()))?;
+		let mut paths = Vec::new();
+		for entry in read {
+			let entry = entry.with_context(|| format!("Error reading {}", dir.display()))?;
+			if !entry.file_name().to_string_lossy().starts_with('.') {
+				paths.push(entry.path());
+			}
+		}
+		paths.sort();
+
+		for path in paths {
+			let metadata = match fs::metadata(&path) {
+				Ok(metadata) => metadata,
+				Err(_) => continue,
+			};
+			let is_dir = metadata.is_dir();
+			let relative = path.strip_prefix(self.root).unwrap_or(&path);
+			let excluded = self
+				.filter
+				.exclude
+				.iter()
+				.any(|glob| glob.matches(relative, is_dir));
+			let skipped = self
+				.ignore
+				.is_some_and(|ignore| ignore.decides(&path, is_dir) == Some(true));
+			if excluded || skipped || ignored(&self.gitignores, &path, is_dir) {
+				continue;
+			}
+			if is_dir {
+				if depth != Some(0) {
+					let depth = depth.map(|depth| depth - 1);
+					if self.dir(&path, depth)?.is_break() {
+						return Ok(ControlFlow::Break(()));
+					}
+				}
+				continue;
+			}
+			if self.filter.included(relative)
+				&& (self.f)(&self.dir.join(relative), &metadata)?.is_break()
+			{
+				return Ok(ControlFlow::Break(()));
+			}
+		}
+		Ok(ControlFlow::Continue(()))
+	}
+}
+
+/// Whether the deepest `.gitignore` deciding on `path` ignores it.
+fn ignored(gitignores: &[Gitignore], path: &Path, is_dir: bool) -> bool {
+	gitignores
+		.iter()
+		.rev()
+		.find_map(|gitignore| gitignore.decides(path, is_dir))
+		.unwrap_or(false)
+}
+
+/// How many threads `parse_all` parses files on, if not one per core.
+static WORKERS: OnceCell<usize> = OnceCell::new();
+
+/// Sets how many threads `parse_all` parses files on. Only the first call has any effect.
+pub fn set_workers(workers: usize) {
+	let _ = WORKERS.set(workers.max(1));
+}
+
+fn workers() -> usize {
+	*WORKERS.get_or_init(|| thread::available_parallelism().map_or(1, |workers| workers.get()))
+}
+
+/// A file read and parsed by `parse_all`.
+pub struct Parsed {
+	pub path: PathBuf,
+	pub lang: String,
+	pub text: Text,
+	pub tree: Tree,
+	pub mtime: Option<SystemTime>,
+	/// How long parsing took, not counting reading the file
+	pub duration: Duration,
+}
+
+/// How far `parse_all`'s consumer has got, for the workers to wait on.
+#[derive(Default)]
+struct Progress {
+	handed: usize,
+	stopped: bool,
+}
+
+/// Reads and parses `files` (paths with the language to parse each as) on the worker threads,
+/// each with its own parser for every language, and hands them to `f` in order (or the error
+/// reading or parsing them). Stops at the first error `f` returns.
+pub fn parse_all(
+	files: &[(PathBuf, String)],
+	mut f: impl FnMut(Result<Parsed>) -> Result<()>,
+) -> Result<()> {
+	let workers = workers().min(files.len());
+	// so a slow file doesn't keep every one after it waiting in memory
+	let max_ahead = workers * 4;
+	let next = AtomicUsize::new(0);
+	let progress = (Mutex::new(Progress::default()), Condvar::new());
+	let (sender, receiver) = mpsc::channel();
+
+	thread::scope(|scope| {
+		for _ in 0..workers {
+			let sender = sender.clone();
+			let (next, progress) = (&next, &progress);
+			let registration = cancel::current();
+			scope.spawn(move || {
+				let registration = registration.as_deref();
+				let mut parsers = HashMap::new();
+				loop {
+					let i = next.fetch_add(1, Ordering::Relaxed);
+					if i >= files.len() {
+						break;
+					}
+					let (lock, ready) = progress;
+					let mut waiting = lock.lock().unwrap_or_else(PoisonError::into_inner);
+					while i >= waiting.handed + max_ahead && !waiting.stopped {
+						waiting = ready.wait(waiting).unwrap_or_else(PoisonError::into_inner);
+					}
+					if waiting.stopped {
+						break;
+					}
+					drop(waiting);
+
+					let (path, lang) = &files[i];
+					let parsed = parse_file(&mut parsers, path, lang, registration);
+					if sender.send((i, parsed)).is_err() {
+						break;
+					}
+				}
+			});
+		}
+		drop(sender);
+
+		let mut handed = || {
+			let mut pending = BTreeMap::new();
+			for (i, parsed) in receiver.iter() {
+				pending.insert(i, parsed);
+				let (lock, ready) = &progress;
+				loop {
+					let handed = lock.lock().unwrap_or_else(PoisonError::into_inner).handed;
+					let parsed = match pending.remove(&handed) {
+						Some(parsed) => parsed,
+						None => break,
+					};
+					f(parsed)?;
+					lock.lock().unwrap_or_else(PoisonError::into_inner).handed += 1;
+					ready.notify_all();
+				}
+			}
+			Ok(())
+		};
+		let res = handed();
+		// the workers may be waiting to get further ahead
+		let (lock, ready) = &progress;
+		lock.lock().unwrap_or_else(PoisonError::into_inner).stopped = true;
+		ready.notify_all();
+		drop(receiver);
+		res
+	})
+}
+
+fn parse_file(
+	parsers: &mut HashMap<String, Parser>,
+	path: &Path,
+	lang: &str,
+	registration: Option<&cancel::Registration>,
+) -> Result<Parsed> {
+	let parser = match parsers.get_mut(lang) {
+		Some(parser) => parser,
+		None => {
+			let language =
+				languages::get(lang).with_context(|| format!("No grammar for {}", lang))?;
+			let mut parser = Parser::new();
+			parser
+				.set_language(language)
+				.with_context(|| format!("Error loading tree-sitter {} language", lang))?;
+			let timeout = languages::config(lang).parse_timeout_ms;
+			parser.set_timeout_micros(timeout.map_or(0, |timeout| timeout * 1000));
+			parsers.entry(lang.to_string()).or_insert(parser)
+		}
+	};
+	let text =
+		crate::read_text(path).with_context(|| format!("Error reading {}", path.display()))?;
+	let start = Instant::now();
+	let tree = match cancel::parse(parser, registration, |parser| text.parse(parser, None)) {
+		Some(tree) => tree,
+		None => {
+			// an abandoned parse would otherwise be resumed by the next one
+			parser.reset();
+			if let Some(registration) = registration.filter(|r| r.cancelled()) {
+				return Err(registration.error().into());
+			}
+			return Err(Error::Timeout(format!(
+				"Error parsing {}: it took too long",
+				path.display()
+			))
+			.into());
+		}
+	};
+	Ok(Parsed {
+		path: path.to_path_buf(),
+		lang: lang.to_string(),
+		duration: start.elapsed(),
+		mtime: crate::modified(path),
+		text,
+		tree,
+	})
+}
@@ -0,0 +1,279 @@
+use std::{
+	cell::RefCell,
+	collections::HashMap,
+	fs,
+	path::{Component, Path, PathBuf},
+	sync::{Arc, PoisonError, RwLock},
+	time::SystemTime,
+};
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+use crate::{
+	languages, queries, uri,
+	workspace::{Gitignore, Glob},
+};
+
+/// What the settings file at the root of a workspace is called.
+pub const FILE_NAME: &str = ".asted.toml";
+
+/// Settings read from a workspace's `.asted.toml`, so project-specific behaviour travels with the
+/// repository. Paths and globs are relative to the workspace's root.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WorkspaceConfig {
+	/// The languages of files matching globs, which take precedence over their extensions (and
+	/// over the session's language, for documents opened without one); the first match wins
+	pub languages: Vec<LanguageOverride>,
+	/// Files and directories that searches and listings skip, like the lines of a `.gitignore`
+	pub ignore: Vec<String>,
+	/// Directories laid out like `--queries-dir`, whose queries take precedence over all others for
+	/// requests about documents in the workspace; the first one with a query wins
+	pub queries_dirs: Vec<PathBuf>,
+	/// Budgets for chunk requests that don't set any
+	pub chunking: Chunking,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LanguageOverride {
+	pub glob: String,
+	pub lang: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Chunking {
+	/// In characters (UTF-16 code units)
+	pub max_chars: Option<u32>,
+	/// In approximate LLM tokens, used instead of `max_chars` if set
+	pub max_tokens: Option<u32>,
+}
+
+/// A directory with an `.asted.toml`, with its settings as they were when it was last read.
+pub struct Workspace {
+	/// Canonical, like the paths of walks
+	pub root: PathBuf,
+	pub config: WorkspaceConfig,
+	languages: Vec<(Glob, String)>,
+	pub ignore: Option<Gitignore>,
+	pub queries: queries::Overridden,
+}
+
+impl Workspace {
+	fn read(root: &Path) -> Result<Self> {
+		let path = root.join(FILE_NAME);
+		let text = fs::read_to_string(&path)
+			.with_context(|| format!("Error reading {}", path.display()))?;
+		let config = toml::from_str::<WorkspaceConfig>(&text)
+			.with_context(|| format!("Error parsing {}", path.display()))?;
+		let languages = config
+			.languages
+			.iter()
+			.map(|language| Ok((Glob::parse(&language.glob)?, language.lang.clone())))
+			.collect::<Result<Vec<_>>>()
+			.with_context(|| format!("Error in the languages of {}", path.display()))?;
+		let ignore = (!config.ignore.is_empty())
+			.then(|| Gitignore::new(root, &config.ignore))
+			.transpose()
+			.with_context(|| format!("Error in the ignore globs of {}", path.display()))?;
+		let dirs = config
+			.queries_dirs
+			.iter()
+			.map(|dir| inside(root, dir))
+			.collect::<Result<Vec<_>>>()
+			.with_context(|| format!("Error in the queries_dirs of {}", path.display()))?;
+		let queries = queries::read_dirs(&dirs)
+			.with_context(|| format!("Error reading the queries of {}", path.display()))?;
+		Ok(Workspace {
+			root: root.to_path_buf(),
+			config,
+			languages,
+			ignore,
+			queries,
+		})
+	}
+
+	/// The language the workspace's settings give the file at `path` (canonical, like `root`), if
+	/// they match it.
+	fn language_of(&self, path: &Path) -> Option<&str> {
+		let relative = path.strip_prefix(&self.root).ok()?;
+		self.languages
+			.iter()
+			.find(|(glob, _)| glob.matches(relative, false))
+			.map(|(_, lang)| lang.as_str())
+	}
+}
+
+/// `dir`, relative to the workspace's `root`, as long as it doesn't lead out of the workspace.
+fn inside(root: &Path, dir: &Path) -> Result<PathBuf> {
+	let relative = dir
+		.components()
+		.all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+	let joined = root.join(dir);
+	// nor through a symlink
+	let escapes = joined
+		.canonicalize()
+		.is_ok_and(|canonical| !canonical.starts_with(root));
+	if !relative || escapes {
+		bail!("{} is outside the workspace", dir.display());
+	}
+	Ok(joined)
+}
+
+/// The nearest directory with an `.asted.toml` up from each directory looked up so far, if any.
+static ROOTS: Lazy<RwLock<HashMap<PathBuf, Option<PathBuf>>>> = Lazy::new(Default::default);
+
+/// How many directories `ROOTS` holds before it's cleared.
+const MAX_ROOTS: usize = 4096;
+
+/// A workspace as read from its settings file (`None` if it didn't read), with the file's
+/// modification time then, so it's read again once it changes.
+type Cached = (Option<SystemTime>, Option<Arc<Workspace>>);
+
+/// The workspaces read so far, by root.
+static WORKSPACES: Lazy<RwLock<HashMap<PathBuf, Cached>>> = Lazy::new(Default::default);
+
+/// The workspace the file or directory at `path` is in: the nearest directory up from it with an
+/// `.asted.toml`, without going past the root of the git repository it's in. Only workspaces in
+/// the directories the current request's session can read (see `scope`) count, and no settings
+/// file outside them is read. A settings file is read when its workspace first comes up, and again
+/// after it changes; one that doesn't read is logged and left out until then.
+pub fn find(path: &Path) -> Option<Arc<Workspace>> {
+	// the file needn't exist (anymore), but its directory does
+	let dir = match path.is_dir() {
+		true => path,
+		false => path.parent()?,
+	};
+	let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+	let roots = ALLOWED.with(|allowed| allowed.borrow().clone());
+	if !uri::is_within(&dir, &roots) {
+		return None;
+	}
+	let root = root_of(&dir)?;
+	// the settings file could be a symlink leading out of them
+	if !uri::is_within(&root, &roots) || !uri::is_within(&root.join(FILE_NAME), &roots) {
+		return None;
+	}
+
+	let modified = fs::metadata(root.join(FILE_NAME))
+		.and_then(|metadata| metadata.modified())
+		.ok();
+	if let Some((read, workspace)) = WORKSPACES
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.get(&root)
+	{
+		if *read == modified {
+			return workspace.clone();
+		}
+	}
+	let workspace = match Workspace::read(&root) {
+		Ok(workspace) => {
+			tracing::info!("read {}", root.join(FILE_NAME).display());
+			Some(Arc::new(workspace))
+		}
+		Err(e) => {
+			tracing::warn!("{:#}", e);
+			None
+		}
+	};
+	WORKSPACES
+		.write()
+		.unwrap_or_else(PoisonError::into_inner)
+		.insert(root, (modified, workspace.clone()));
+	workspace
+}
+
+/// The nearest directory up from `dir` (canonical) with an `.asted.toml`, up to the root of the
+/// git repository it's in.
+fn root_of(dir: &Path) -> Option<PathBuf> {
+	if let Some(root) = ROOTS
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.get(dir)
+	{
+		return root.clone();
+	}
+
+	let root = dir
+		.ancestors()
+		.scan(false, |past_repository, dir| {
+			// the repository's own root is the last one looked in
+			let done = *past_repository;
+			*past_repository = dir.join(".git").exists();
+			(!done).then_some(dir)
+		})
+		.find(|dir| dir.join(FILE_NAME).is_file())
+		.map(Path::to_path_buf);
+	let mut roots = ROOTS.write().unwrap_or_else(PoisonError::into_inner);
+	if roots.len() >= MAX_ROOTS {
+		roots.clear();
+	}
+	roots.insert(dir.to_path_buf(), root.clone());
+	root
+}
+
+/// Forgets every workspace, so their settings files are read again when they next come up, like
+/// after the grammars their queries were compiled for were reloaded.
+pub fn forget() {
+	ROOTS
+		.write()
+		.unwrap_or_else(PoisonError::into_inner)
+		.clear();
+	WORKSPACES
+		.write()
+		.unwrap_or_else(PoisonError::into_inner)
+		.clear();
+}
+
+/// The language the workspace of the file at `path` gives it, if its settings have one for it.
+pub fn language_override(path: &Path) -> Option<String> {
+	find(path)?
+		.language_of(&canonical(path))
+		.map(str::to_string)
+}
+
+/// The language of the file at `path`: the one its workspace gives it, or else the one of its
+/// extension.
+pub fn language_for(path: &Path) -> Option<String> {
+	language_override(path).or_else(|| languages::for_extension(path.extension()?.to_str()?))
+}
+
+/// `path` with its directory canonicalized, like the workspaces' roots, even if the file itself
+/// doesn't exist.
+fn canonical(path: &Path) -> PathBuf {
+	match (path.parent(), path.file_name()) {
+		(Some(dir), Some(name)) => dir
+			.canonicalize()
+			.map_or_else(|_| path.to_path_buf(), |dir| dir.join(name)),
+		_ => path.to_path_buf(),
+	}
+}
+
+thread_local! {
+	/// The workspace of the document the request this thread is working on is about, if any.
+	static CURRENT: RefCell<Option<Arc<Workspace>>> = const { RefCell::new(None) };
+	/// The directories the session of the request this thread is working on can read; anywhere if
+	/// empty.
+	static ALLOWED: RefCell<Vec<PathBuf>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs `f` as working on a request of a session that can read files in `roots`, about the
+/// document at `path` (if it's about one), like `cancel::scope`.
+pub fn scope<T>(roots: Vec<PathBuf>, path: Option<&Path>, f: impl FnOnce() -> T) -> T {
+	let outer_roots = ALLOWED.with(|allowed| allowed.replace(roots));
+	let workspace = path.and_then(find);
+	let outer = CURRENT.with(|current| current.replace(workspace));
+	let res = f();
+	CURRENT.with(|current| *current.borrow_mut() = outer);
+	ALLOWED.with(|allowed| *allowed.borrow_mut() = outer_roots);
+	res
+}
+
+/// The workspace of the request this thread is working on.
+pub fn current() -> Option<Arc<Workspace>> {
+	CURRENT.with(|current| current.borrow().clone())
+}